@@ -94,6 +94,7 @@ impl<F: LurkField> CoCircuit<F> for Sha256Coprocessor<F> {
         cs: &mut CS,
         _g: &GlobalAllocations<F>,
         _store: &Store<F>,
+        _not_dummy: &Boolean,
         input_exprs: &[AllocatedPtr<F>],
         input_env: &AllocatedPtr<F>,
         input_cont: &AllocatedContPtr<F>,
@@ -1,12 +1,10 @@
 use std::env;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
 use lurk::circuit::circuit_frame::MultiFrame;
-use lurk::circuit::gadgets::constraints::alloc_equal;
-use lurk::circuit::gadgets::data::{allocate_constant, GlobalAllocations};
-use lurk::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use lurk::coprocessor::digest::DigestOutput;
+use lurk::coprocessor::sha256::Sha256Coprocessor;
 use lurk::coprocessor::{CoCircuit, Coprocessor};
 use lurk::eval::{empty_sym_env, lang::Lang};
 use lurk::field::LurkField;
@@ -17,134 +15,24 @@ use lurk::state::user_sym;
 use lurk::store::Store;
 use lurk_macros::Coproc;
 
-use bellperson::gadgets::boolean::{AllocatedBit, Boolean};
-use bellperson::gadgets::multipack::pack_bits;
-use bellperson::gadgets::num::AllocatedNum;
-use bellperson::gadgets::sha256::sha256;
-use bellperson::{ConstraintSystem, SynthesisError};
-
 use pasta_curves::pallas::Scalar as Fr;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter, Registry};
 use tracing_texray::TeXRayLayer;
 
 const REDUCTION_COUNT: usize = 2;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub(crate) struct Sha256Coprocessor<F: LurkField> {
-    n: usize,
-    expected: [u128; 2],
-    pub(crate) _p: PhantomData<F>,
-}
-
-impl<F: LurkField> CoCircuit<F> for Sha256Coprocessor<F> {
-    fn arity(&self) -> usize {
-        0
-    }
-
-    fn synthesize<CS: ConstraintSystem<F>>(
-        &self,
-        cs: &mut CS,
-        _g: &GlobalAllocations<F>,
-        store: &Store<F>,
-        _input_exprs: &[AllocatedPtr<F>],
-        input_env: &AllocatedPtr<F>,
-        input_cont: &AllocatedContPtr<F>,
-    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
-        let false_bool = Boolean::from(AllocatedBit::alloc(
-            cs.namespace(|| "false bit"),
-            Some(false),
-        )?);
-
-        cs.enforce(
-            || "enforce zero preimage",
-            |lc| lc,
-            |lc| lc,
-            |_| false_bool.lc(CS::one(), F::ONE),
-        );
-
-        let preimage = vec![false_bool; self.n * 8];
-
-        let mut bits = sha256(cs.namespace(|| "SHAhash"), &preimage)?;
-
-        bits.reverse();
-
-        let nums: Vec<AllocatedNum<_>> = (0..2)
-            .map(|i| {
-                pack_bits(
-                    cs.namespace(|| format!("num{i}")),
-                    &bits[(128 * i)..(128 * (i + 1))],
-                )
-                .unwrap()
-            })
-            .collect();
-
-        let eqs: Vec<Boolean> = (0..2)
-            .map(|i| {
-                let num = allocate_constant(
-                    &mut cs.namespace(|| format!("allocate result {i}")),
-                    F::from_u128(self.expected[i]),
-                );
-
-                let eq = alloc_equal(
-                    cs.namespace(|| format!("equate numbers {i}")),
-                    &num,
-                    &nums[i],
-                )
-                .unwrap();
-
-                eq
-            })
-            .collect();
-
-        let both_eq = Boolean::and(cs.namespace(|| "both equal"), &eqs[0], &eqs[1])?;
-
-        let result_ptr =
-            AllocatedPtr::as_lurk_boolean(cs.namespace(|| "result ptr"), store, &both_eq)?;
-
-        Ok((result_ptr, input_env.clone(), input_cont.clone()))
-    }
-}
-
-impl<F: LurkField> Coprocessor<F> for Sha256Coprocessor<F> {
-    fn eval_arity(&self) -> usize {
-        0
-    }
-
-    fn simple_evaluate(&self, s: &Store<F>, _args: &[Ptr<F>]) -> Ptr<F> {
-        let mut hasher = Sha256::new();
-
-        let input = vec![0u8; self.n];
-
-        hasher.update(input);
-        let result = hasher.finalize();
-
-        let mut u: Vec<u128> = (0..2)
-            .map(|i| {
-                let a: [u8; 16] = result[(16 * i)..(16 * (i + 1))].try_into().unwrap();
-                u128::from_be_bytes(a)
-            })
-            .collect();
-
-        u.reverse();
-
-        s.as_lurk_boolean(u == self.expected)
-    }
-
-    fn has_circuit(&self) -> bool {
-        true
-    }
-}
-
-impl<F: LurkField> Sha256Coprocessor<F> {
-    pub(crate) fn new(n: usize, expected: [u128; 2]) -> Self {
-        Self {
-            n,
-            expected,
-            _p: Default::default(),
-        }
-    }
+/// Checks that the digest of `n` zero bytes is the one whose big-endian u64 words are `expected`
+fn sha256_zero_bytes<F: LurkField>(store: &Store<F>, n: usize, expected: &[u64]) -> Ptr<F> {
+    let input = vec!["0"; n].join(" ");
+    let expected = expected
+        .iter()
+        .map(|word| format!("{word}u64"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let program = format!("(eq (sha256-{n}-zero-bytes ({input})) '({expected}))");
+
+    store.read(&program).unwrap()
 }
 
 #[derive(Clone, Debug, Coproc, Serialize, Deserialize)]
@@ -173,24 +61,24 @@ fn main() {
 
     let input_size = 64 * num_of_64_bytes;
 
-    let mut u: [u128; 2] = [0u128; 2];
-    for i in 0..2 {
-        u[i] = u128::from_be_bytes(expect[(i * 16)..(i + 1) * 16].try_into().unwrap())
-    }
-
-    u.reverse();
+    let expected = expect
+        .chunks(8)
+        .map(|word| u64::from_be_bytes(word.try_into().unwrap()))
+        .collect::<Vec<_>>();
 
     let store = &mut Store::<Fr>::new();
     let cproc_sym = user_sym(&format!("sha256-{input_size}-zero-bytes"));
-    let cproc_sym_ptr = store.intern_symbol(&cproc_sym);
+
+    let call = sha256_zero_bytes(store, input_size, &expected);
 
     let lang = Lang::<Fr, Sha256Coproc<Fr>>::new_with_bindings(
         store,
-        vec![(cproc_sym, Sha256Coprocessor::new(input_size, u).into())],
+        vec![(
+            cproc_sym,
+            Sha256Coprocessor::new(input_size, DigestOutput::U64s).into(),
+        )],
     );
 
-    let cproc_call = store.list(&[cproc_sym_ptr]);
-
     let nova_prover = NovaProver::<Fr, Sha256Coproc<Fr>, MultiFrame<'_, _, _>>::new(
         REDUCTION_COUNT,
         lang.clone(),
@@ -221,14 +109,7 @@ fn main() {
     let (proof, z0, zi, num_steps) = tracing_texray::examine(tracing::info_span!("prog_start"))
         .in_scope(|| {
             nova_prover
-                .evaluate_and_prove(
-                    &pp,
-                    cproc_call,
-                    empty_sym_env(store),
-                    store,
-                    10000,
-                    &lang_rc,
-                )
+                .evaluate_and_prove(&pp, call, empty_sym_env(store), store, 10000, &lang_rc)
                 .unwrap()
         });
     let proof_end = proof_start.elapsed();
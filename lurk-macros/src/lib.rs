@@ -81,6 +81,7 @@ fn impl_enum_coproc(name: &Ident, variants: &DataEnum) -> TokenStream {
                 cs: &mut CS,
                 g: &lurk::circuit::gadgets::data::GlobalAllocations<F>,
                 store: &lurk::store::Store<F>,
+                not_dummy: &bellpepper_core::boolean::Boolean,
                 input_exprs: &[lurk::circuit::gadgets::pointer::AllocatedPtr<F>],
                 input_env: &lurk::circuit::gadgets::pointer::AllocatedPtr<F>,
                 input_cont: &lurk::circuit::gadgets::pointer::AllocatedContPtr<F>,
@@ -162,7 +163,7 @@ fn synthesize_match_arms(name: &Ident, variants: &DataEnum) -> proc_macro2::Toke
         let variant_ident = &variant.ident;
 
         match_arms.extend(quote! {
-            #name::#variant_ident(cocircuit) => cocircuit.synthesize(cs, g, store, input_exprs, input_env, input_cont),
+            #name::#variant_ident(cocircuit) => cocircuit.synthesize(cs, g, store, not_dummy, input_exprs, input_env, input_cont),
         });
     }
     match_arms
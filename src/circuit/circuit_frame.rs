@@ -32,7 +32,8 @@ use super::gadgets::constraints::{
     pick, pick_const, sub,
 };
 use crate::circuit::circuit_frame::constraints::{
-    add, allocate_is_negative, boolean_to_num, enforce_pack, enforce_product_and_sum, mul,
+    add, allocate_is_negative, boolean_to_num, enforce_pack, enforce_product_and_sum, implies_u64,
+    implies_u64_shift, mul, pack, u64_and,
 };
 use crate::circuit::gadgets::hashes::{AllocatedConsWitness, AllocatedContWitness};
 use crate::circuit::ToInputs;
@@ -1060,6 +1061,9 @@ impl<'a, F: LurkField> CompResults<'a, F> {
     }
 }
 
+/// Reduces as `reduce_unfueled_expression()`, but for the `Fuel` continuation of an
+/// `eval-with-limit`, whose evaluation is stepped under its inner continuation with one unit of
+/// fuel less, until it terminates, fails or its fuel runs out.
 fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     cs: &mut CS,
     expr: &AllocatedPtr<F>,
@@ -1071,6 +1075,123 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     store: &Store<F>,
     folding_config: Arc<FoldingConfig<F, C>>,
     g: &GlobalAllocations<F>,
+) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+    let cont_is_fuel = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "cont_is_fuel"),
+        ContTag::Fuel.to_field(),
+    )?;
+
+    // NOTE: this allocation is unconstrained. See necessary constraint immediately below.
+    let (fuel_hash, inner, fuel, saved_env, continuation) = cont
+        .allocate_fuel_components_unconstrained(
+            &mut cs.namespace(|| "allocate fuel components"),
+            store,
+        )?;
+    implies_equal!(cs, &cont_is_fuel, &fuel_hash, cont.hash());
+
+    // Out of fuel, the evaluation takes the step of a terminated one, which leaves it as it is
+    let fuel_is_zero = alloc_is_zero(&mut cs.namespace(|| "fuel_is_zero"), fuel.hash())?;
+    let out_of_fuel = and!(cs, &cont_is_fuel, &fuel_is_zero)?;
+    let fueled = and!(cs, &cont_is_fuel, &fuel_is_zero.not())?;
+    let step_cont = pick_cont_ptr!(cs, &fueled, &inner, cont)?;
+    let step_cont = pick_cont_ptr!(cs, &out_of_fuel, &g.terminal_ptr, &step_cont)?;
+
+    let (new_expr, new_env, new_cont) = reduce_unfueled_expression(
+        &mut cs.namespace(|| "unfueled"),
+        expr,
+        env,
+        &step_cont,
+        &cont_is_fuel,
+        witness,
+        allocated_cons_witness,
+        allocated_cont_witness,
+        store,
+        folding_config,
+        g,
+    )?;
+
+    let new_cont_is_terminal = new_cont.alloc_tag_equal(
+        &mut cs.namespace(|| "new_cont_is_terminal"),
+        ContTag::Terminal.to_field(),
+    )?;
+    let new_cont_is_error = new_cont.alloc_tag_equal(
+        &mut cs.namespace(|| "new_cont_is_error"),
+        ContTag::Error.to_field(),
+    )?;
+    let new_cont_is_fuel = new_cont.alloc_tag_equal(
+        &mut cs.namespace(|| "new_cont_is_fuel"),
+        ContTag::Fuel.to_field(),
+    )?;
+
+    // The evaluation also returns when it fails, nested `eval-with-limit` calls included
+    let new_cont_fails = or!(cs, &new_cont_is_error, &new_cont_is_fuel)?;
+
+    // The evaluation returns the list of its outcome and its value, or the partial one it was
+    // left with
+    let (new_expr_thunk_hash, new_expr_thunk_value, _) = new_expr
+        .allocate_thunk_components_unconstrained(
+            &mut cs.namespace(|| "allocate new_expr thunk components"),
+            store,
+        )?;
+    let new_expr_is_thunk = new_expr.is_thunk(&mut cs.namespace(|| "new_expr_is_thunk"))?;
+    implies_equal!(
+        cs,
+        &new_expr_is_thunk,
+        &new_expr_thunk_hash,
+        new_expr.hash()
+    );
+    let value = pick_ptr!(cs, &new_expr_is_thunk, &new_expr_thunk_value, &new_expr)?;
+    let outcome = pick_ptr!(cs, &new_cont_fails, &g.error_key, &g.ok_key)?;
+    let outcome = pick_ptr!(cs, &out_of_fuel, &g.out_of_fuel_key, &outcome)?;
+    let result = AllocatedPtr::construct_list(
+        &mut cs.namespace(|| "result"),
+        g,
+        store,
+        &[&outcome, &value],
+    )?;
+    let result_thunk = AllocatedPtr::construct_thunk(
+        &mut cs.namespace(|| "result thunk"),
+        g,
+        store,
+        &result,
+        &continuation,
+    )?;
+
+    // Otherwise, the evaluation goes on with one unit of fuel less
+    let new_fuel_hash = sub(&mut cs.namespace(|| "new fuel"), fuel.hash(), &g.true_num)?;
+    let new_fuel = AllocatedPtr::from_parts(fuel.tag().clone(), new_fuel_hash);
+    let refueled_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&new_cont, &new_fuel, &saved_env, &continuation];
+    let refueled_cont = AllocatedContPtr::construct(
+        &mut cs.namespace(|| "refueled cont"),
+        store,
+        &g.fuel_cont_tag,
+        refueled_components,
+    )?;
+
+    let new_cont_ends = or!(cs, &new_cont_is_terminal, &new_cont_fails)?;
+    let returns = and!(cs, &cont_is_fuel, &new_cont_ends)?;
+
+    let fueled_cont = pick_cont_ptr!(cs, &returns, &g.dummy_ptr, &refueled_cont)?;
+    let output_expr = pick_ptr!(cs, &returns, &result_thunk, &new_expr)?;
+    let output_env = pick_ptr!(cs, &returns, &saved_env, &new_env)?;
+    let output_cont = pick_cont_ptr!(cs, &cont_is_fuel, &fueled_cont, &new_cont)?;
+
+    Ok((output_expr, output_env, output_cont))
+}
+
+fn reduce_unfueled_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
+    cs: &mut CS,
+    expr: &AllocatedPtr<F>,
+    env: &AllocatedPtr<F>,
+    cont: &AllocatedContPtr<F>,
+    sandboxed: &Boolean,
+    witness: &Option<Witness<F>>,
+    allocated_cons_witness: &mut AllocatedConsWitness<'_, F>,
+    allocated_cont_witness: &mut AllocatedContWitness<'_, F>,
+    store: &Store<F>,
+    folding_config: Arc<FoldingConfig<F, C>>,
+    g: &GlobalAllocations<F>,
 ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
     debug!("reduce_expression");
     debug!("{}", &expr.fetch_and_write_str(store));
@@ -1088,6 +1209,8 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         results.add_clauses_expr(ExprTag::Comm, expr, env, cont, &g.true_num);
         results.add_clauses_expr(ExprTag::Key, expr, env, cont, &g.true_num);
         results.add_clauses_expr(ExprTag::U64, expr, env, cont, &g.true_num);
+        results.add_clauses_expr(ExprTag::I64, expr, env, cont, &g.true_num);
+        results.add_clauses_expr(ExprTag::Vec, expr, env, cont, &g.true_num);
     };
 
     let cont_is_terminal = cont.alloc_tag_equal(
@@ -1162,6 +1285,7 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         env,
         cont,
         &reduce_cons_not_dummy,
+        sandboxed,
         witness,
         allocated_cons_witness,
         allocated_cont_witness,
@@ -1274,6 +1398,86 @@ fn reduce_expression<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &g.false_num,
     )?;
 
+    // An error is thrown, as a keyword naming it consed onto the expression it's raised on. An
+    // error of the reduction is thrown to the continuation of the reduction, and an error of an
+    // applied continuation to the continuation this one returns to
+    let first_result_cont_is_error = first_result_cont.alloc_tag_equal(
+        &mut cs.namespace(|| "first_result_cont_is_error"),
+        ContTag::Error.to_field(),
+    )?;
+    let reduction_raises = and!(
+        cs,
+        &first_result_cont_is_error,
+        &apply_continuation_boolean.not(),
+        &cont_is_not_terminal_or_error
+    )?;
+    let (apply_raises, apply_raised_key, apply_raised_to) = (
+        &apply_continuation_results.4,
+        &apply_continuation_results.5,
+        &apply_continuation_results.6,
+    );
+    let raises = or!(cs, &reduction_raises, apply_raises)?;
+    let expr_is_sym = expr.alloc_tag_equal(
+        &mut cs.namespace(|| "raised expr_is_sym"),
+        ExprTag::Sym.to_field(),
+    )?;
+    let reduction_raised_key = AllocatedPtr::pick(
+        &mut cs.namespace(|| "reduction_raised_key"),
+        &expr_is_sym,
+        &g.unbound_var_key,
+        &g.invalid_form_key,
+    )?;
+    let raised_key = AllocatedPtr::pick(
+        &mut cs.namespace(|| "raised_key"),
+        &reduction_raises,
+        &reduction_raised_key,
+        apply_raised_key,
+    )?;
+    let raised_on = AllocatedPtr::pick(
+        &mut cs.namespace(|| "raised_on"),
+        &reduction_raises,
+        &first_result_expr,
+        &apply_continuation_results.0,
+    )?;
+    let raised = AllocatedPtr::construct_cons(
+        &mut cs.namespace(|| "raised"),
+        g,
+        store,
+        &raised_key,
+        &raised_on,
+    )?;
+    let raised_to = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "raised_to"),
+        &reduction_raises,
+        cont,
+        apply_raised_to,
+    )?;
+
+    let result_expr0 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "pick maybe raised expr"),
+        &raises,
+        &raised,
+        &result_expr0,
+    )?;
+    let result_env0 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "pick maybe raised env"),
+        &raises,
+        &g.thrown_env_ptr,
+        &result_env0,
+    )?;
+    let result_cont0 = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "pick maybe raised cont"),
+        &raises,
+        &raised_to,
+        &result_cont0,
+    )?;
+    let make_thunk_num = pick(
+        &mut cs.namespace(|| "pick maybe raised make_thunk"),
+        &raises,
+        &g.true_num,
+        &make_thunk_num,
+    )?;
+
     // True if make_thunk is called.
     let make_thunk_boolean = &alloc_is_zero(
         &mut cs.namespace(|| "apply_continuation_make_thunk is zero"),
@@ -1667,6 +1871,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     env: &AllocatedPtr<F>,
     cont: &AllocatedContPtr<F>,
     not_dummy: &Boolean,
+    sandboxed: &Boolean,
     _witness: &Option<Witness<F>>,
     allocated_cons_witness: &mut AllocatedConsWitness<'_, F>,
     allocated_cont_witness: &mut AllocatedContWitness<'_, F>,
@@ -1715,6 +1920,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     def_head_val!(head_is_secret, c.secret);
     def_head_val!(head_is_num, c.num);
     def_head_val!(head_is_u64, c.u64);
+    def_head_val!(head_is_i64, c.i64);
     def_head_val!(head_is_comm, c.comm);
     def_head_val!(head_is_char, c.char);
     def_head_val!(head_is_begin, c.begin);
@@ -1733,6 +1939,29 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     def_head_val!(head_is_less_equal, c.less_equal);
     def_head_val!(head_is_greater, c.greater);
     def_head_val!(head_is_greater_equal, c.greater_equal);
+    def_head_val!(head_is_logand, c.logand);
+    def_head_val!(head_is_logior, c.logior);
+    def_head_val!(head_is_logxor, c.logxor);
+    def_head_val!(head_is_lognot, c.lognot);
+    def_head_val!(head_is_shift_left, c.shift_left);
+    def_head_val!(head_is_shift_right, c.shift_right);
+    def_head_val!(head_is_char_to_u64, c.char_to_u64);
+    def_head_val!(head_is_u64_to_char, c.u64_to_char);
+    def_head_val!(head_is_symbol_to_string, c.symbol_to_string);
+    def_head_val!(head_is_string_to_symbol, c.string_to_symbol);
+    def_head_val!(head_is_string_length, c.string_length);
+    def_head_val!(head_is_string_append, c.string_append);
+    def_head_val!(head_is_substring, c.substring);
+    def_head_val!(head_is_string_to_list, c.string_to_list);
+    def_head_val!(head_is_list_to_string, c.list_to_string);
+    def_head_val!(head_is_throw, c.throw);
+    def_head_val!(head_is_catch, c.catch);
+    def_head_val!(head_is_list, c.list);
+    def_head_val!(head_is_make_vector, c.make_vector);
+    def_head_val!(head_is_vector_ref, c.vector_ref);
+    def_head_val!(head_is_vector_set, c.vector_set);
+    def_head_val!(head_is_vector_length, c.vector_length);
+    def_head_val!(head_is_eval_with_limit, c.eval_with_limit);
     def_head_val!(head_is_if0, c.if_);
     def_head_val!(head_is_current_env0, c.current_env);
 
@@ -1758,6 +1987,19 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &head_is_less_equal,
         &head_is_greater,
         &head_is_greater_equal,
+        &head_is_logand,
+        &head_is_logior,
+        &head_is_logxor,
+        &head_is_shift_left,
+        &head_is_shift_right,
+        &head_is_string_append,
+        &head_is_substring,
+        &head_is_catch,
+        &head_is_list,
+        &head_is_make_vector,
+        &head_is_vector_ref,
+        &head_is_vector_set,
+        &head_is_eval_with_limit,
         &head_is_eval
     )?;
 
@@ -1771,12 +2013,23 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &head_is_commit,
         &head_is_num,
         &head_is_u64,
+        &head_is_i64,
         &head_is_comm,
         &head_is_char,
         &head_is_open,
         &head_is_secret,
         &head_is_atom,
         &head_is_emit,
+        &head_is_lognot,
+        &head_is_char_to_u64,
+        &head_is_u64_to_char,
+        &head_is_symbol_to_string,
+        &head_is_string_to_symbol,
+        &head_is_string_length,
+        &head_is_string_to_list,
+        &head_is_list_to_string,
+        &head_is_throw,
+        &head_is_vector_length,
         &head_is_eval
     )?;
 
@@ -1861,36 +2114,19 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     )?;
 
     let more_is_nil = more.is_nil(&mut cs.namespace(|| "more_is_nil"), g)?;
+    let more_is_cons = more.is_cons(&mut cs.namespace(|| "more_is_cons"))?;
 
     let is_binop_missing_arg_error = and!(
         cs,
         &head_is_binop,
         &more_is_nil,
         &head_is_begin.not(),
+        &head_is_list.not(),
         &head_is_eval.not()
     )?;
 
     let arg1_is_cons = arg1.is_cons(&mut cs.namespace(|| "arg1_is_cons"))?;
-    let arg1_is_str = arg1.is_str(&mut cs.namespace(|| "arg1_is_str"))?;
-
     let arg1_is_nil = arg1.is_nil(&mut cs.namespace(|| "arg1_is_nil"), g)?;
-    let expr_cadr_not_dummy0 = or!(cs, &arg1_is_cons, &arg1_is_nil, &arg1_is_str)?;
-    let expr_cadr_not_dummy = and!(
-        cs,
-        &expr_cdr_not_dummy,
-        &expr_cadr_not_dummy0,
-        &head_is_lambda
-    )?;
-
-    let (car_args, cdr_args) = car_cdr_named(
-        &mut cs.namespace(|| "car_cdr(arg1)"),
-        g,
-        &arg1,
-        ConsName::ExprCadr,
-        allocated_cons_witness,
-        &expr_cadr_not_dummy,
-        store,
-    )?;
 
     let end_is_nil = more.is_nil(&mut cs.namespace(|| "end_is_nil"), g)?;
 
@@ -1899,67 +2135,21 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     // --
     let (lambda_expr, lambda_env, lambda_cont) = {
         // head == LAMBDA
-        // body == more == (cdr expr)
-        let (args, body) = (arg1.clone(), more.clone());
-        let args_is_nil = args.is_nil(&mut cs.namespace(|| "args_is_nil"), g)?;
-        let cdr_args_is_nil = cdr_args.is_nil(&mut cs.namespace(|| "cdr_args_is_nil"), g)?;
-
-        let arg = AllocatedPtr::pick(
-            &mut cs.namespace(|| "maybe dummy arg"),
-            &args_is_nil,
-            &g.dummy_arg_ptr,
-            &car_args,
-        )?;
-
-        let arg_is_sym = arg.is_sym(&mut cs.namespace(|| "arg_is_sym"))?;
-        let lambda_not_dummy = and!(cs, &head_is_lambda, not_dummy, &more_is_nil.not())?;
-        let inner_not_dummy = and!(cs, &lambda_not_dummy, &cdr_args_is_nil.not())?;
-
-        let inner = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "inner"),
-            g,
-            &cdr_args,
-            &body,
-            ConsName::InnerLambda,
-            allocated_cons_witness,
-            &inner_not_dummy,
-        )?;
-
-        let l = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "l"),
-            g,
-            &g.lambda_sym,
-            &inner,
-            ConsName::Lambda,
-            allocated_cons_witness,
-            &inner_not_dummy,
-        )?;
-
-        let list = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "list"),
-            g,
-            &l,
-            &g.nil_ptr,
-            ConsName::InnerBody,
-            allocated_cons_witness,
-            &inner_not_dummy,
-        )?;
-        let inner_body = AllocatedPtr::pick(
-            &mut cs.namespace(|| "inner_body"),
-            &cdr_args_is_nil,
-            &body,
-            &list,
-        )?;
+        // params == (car (cdr expr)), body == (cdr (cdr expr))
+        // The parameters are kept as they are, to be bound one by one as the arguments of a call
+        // are evaluated
+        let (params, body) = (arg1.clone(), more.clone());
+        let params_is_list = or!(cs, &arg1_is_cons, &arg1_is_nil)?;
 
         let function = AllocatedPtr::construct_fun(
             &mut cs.namespace(|| "function"),
             g,
             store,
-            &arg,
-            &inner_body,
+            &params,
+            &body,
             env,
         )?;
-        let lambda_arg_error = and!(cs, &arg_is_sym.not(), &lambda_not_dummy)?;
+        let lambda_arg_error = and!(cs, &params_is_list.not(), &head_is_lambda)?;
 
         let lambda_expr = AllocatedPtr::pick(
             &mut cs.namespace(|| "lambda_expr"),
@@ -2014,6 +2204,18 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &head_is_let_or_letrec,
     )?;
 
+    // SUBSTRING, VECTOR-SET and EVAL-WITH-LIMIT need their third argument to know whether they
+    // have all of them
+    let head_is_ternary = or!(
+        cs,
+        &head_is_substring,
+        &head_is_vector_set,
+        &head_is_eval_with_limit
+    )?;
+    let head_is_binop_ternary = and!(cs, &head_is_binop, &head_is_ternary)?;
+    let ternary_not_dummy = and!(cs, not_dummy, &head_is_binop_ternary, &more_is_cons)?;
+    let expr_cddr_not_dummy = or!(cs, &let_letrec_not_dummy, &ternary_not_dummy)?;
+
     let (bindings, body) = (arg1.clone(), more.clone());
     let (body1, rest_body) = car_cdr_named(
         &mut cs.namespace(|| "car_cdr body"),
@@ -2021,7 +2223,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &body,
         ConsName::ExprCddr,
         allocated_cons_witness,
-        &let_letrec_not_dummy,
+        &expr_cddr_not_dummy,
         store,
     )?;
     let bindings_is_nil = bindings.is_nil(&mut cs.namespace(|| "bindings_is_nil"), g)?;
@@ -2045,8 +2247,6 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
 
         let mut cs = cs.namespace(|| "LET_LETREC");
 
-        let (binding1, rest_bindings) = (car_args, cdr_args);
-
         let expr_caadr_not_dummy = and!(
             cs,
             &rest_body_is_nil,
@@ -2056,6 +2256,16 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
             &let_letrec_not_dummy
         )?;
 
+        let (binding1, rest_bindings) = car_cdr_named(
+            &mut cs.namespace(|| "car_cdr bindings"),
+            g,
+            &bindings,
+            ConsName::ExprCadr,
+            allocated_cons_witness,
+            &expr_caadr_not_dummy,
+            store,
+        )?;
+
         let (var_let_letrec, vals) = car_cdr_named(
             &mut cs.namespace(|| "car_cdr binding1"),
             g,
@@ -2371,6 +2581,20 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         u64_continuation_components,
     );
 
+    // head == I64 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let i64_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_i64_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.i64.value(),
+        &g.unop_cont_tag,
+        i64_continuation_components,
+    );
+
     // head == COMM preimage
     /////////////////////////////////////////////////////////////////////////////
     let comm_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
@@ -2580,118 +2804,396 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         greater_equal_continuation_components,
     );
 
-    // head == IF preimage
+    // head == LOGNOT preimage
     /////////////////////////////////////////////////////////////////////////////
-    let if_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &more.clone(),
-        cont,
+    let lognot_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_lognot_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
         &[&g.default_num, &g.default_num],
         &[&g.default_num, &g.default_num],
     ];
     hash_default_results.add_hash_input_clauses(
-        c.if_.value(),
-        &g.if_cont_tag,
-        if_continuation_components,
+        c.lognot.value(),
+        &g.unop_cont_tag,
+        lognot_continuation_components,
     );
 
-    // head == CURRENT-ENV
-    let the_cont_if_rest_is_nil = AllocatedContPtr::pick(
-        &mut cs.namespace(|| "the_cont_if_rest_is_nil"),
-        &rest_is_nil,
-        cont,
-        &g.error_ptr_cont,
-    )?;
-    results.add_clauses_cons(
-        c.current_env.value(),
-        env,
-        env,
-        &the_cont_if_rest_is_nil,
-        &g.true_num,
+    // head == CHAR->U64 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let char_to_u64_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_char_to_u64_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.char_to_u64.value(),
+        &g.unop_cont_tag,
+        char_to_u64_continuation_components,
     );
 
-    // head == fn . args preimage
+    // head == U64->CHAR preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (
-        cont_tag,
-        component0_tag,
-        component0_hash,
-        component1_tag,
-        component1_hash,
-        component2_tag,
-        component2_hash,
-    ) = {
-        let cont_tag = pick(
-            &mut cs.namespace(|| "pick cont_tag"),
-            &rest_is_nil,
-            &g.call0_cont_tag,
-            &g.call_cont_tag,
-        )?;
-        let component1_tag = pick(
-            &mut cs.namespace(|| "pick component1_tag"),
-            &rest_is_nil,
-            cont.tag(),
-            arg1.tag(),
-        )?;
-        let component1_hash = pick(
-            &mut cs.namespace(|| "pick component1_hash"),
-            &rest_is_nil,
-            cont.hash(),
-            arg1.hash(),
-        )?;
-        let component2_tag = pick(
-            &mut cs.namespace(|| "pick component2_tag"),
-            &rest_is_nil,
-            &g.default_num,
-            cont.tag(),
-        )?;
-        let component2_hash = pick(
-            &mut cs.namespace(|| "pick component2_hash"),
-            &rest_is_nil,
-            &g.default_num,
-            cont.hash(),
-        )?;
-
-        (
-            cont_tag,
-            env.tag(),
-            env.hash(),
-            component1_tag,
-            component1_hash,
-            component2_tag,
-            component2_hash,
-        )
-    };
-
-    // default has the `fn args` preimage
-    let defaults = [
-        &cont_tag,
-        component0_tag,
-        component0_hash,
-        &component1_tag,
-        &component1_hash,
-        &component2_tag,
-        &component2_hash,
-        &g.default_num,
-        &g.default_num,
+    let u64_to_char_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_u64_to_char_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
     ];
+    hash_default_results.add_hash_input_clauses(
+        c.u64_to_char.value(),
+        &g.unop_cont_tag,
+        u64_to_char_continuation_components,
+    );
 
-    /////////////////////////// multicase (hash preimage)
-    let all_hash_input_clauses = [
-        &hash_default_results.tag_clauses[..],
-        &hash_default_results.components_clauses[0][..],
-        &hash_default_results.components_clauses[1][..],
-        &hash_default_results.components_clauses[2][..],
-        &hash_default_results.components_clauses[3][..],
-        &hash_default_results.components_clauses[4][..],
-        &hash_default_results.components_clauses[5][..],
-        &hash_default_results.components_clauses[6][..],
-        &hash_default_results.components_clauses[7][..],
+    // head == SYMBOL->STRING preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let symbol_to_string_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_symbol_to_string_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
     ];
+    hash_default_results.add_hash_input_clauses(
+        c.symbol_to_string.value(),
+        &g.unop_cont_tag,
+        symbol_to_string_continuation_components,
+    );
 
-    let components_results = multi_case(
-        &mut cs.namespace(|| "reduce cons hash preimage selection"),
-        head.hash(),
-        &all_hash_input_clauses,
+    // head == STRING->SYMBOL preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let string_to_symbol_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_string_to_symbol_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.string_to_symbol.value(),
+        &g.unop_cont_tag,
+        string_to_symbol_continuation_components,
+    );
+
+    // head == STRING-LENGTH preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let string_length_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_string_length_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.string_length.value(),
+        &g.unop_cont_tag,
+        string_length_continuation_components,
+    );
+
+    // head == STRING->LIST preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let string_to_list_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_string_to_list_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.string_to_list.value(),
+        &g.unop_cont_tag,
+        string_to_list_continuation_components,
+    );
+
+    // head == LIST->STRING preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let list_to_string_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_list_to_string_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.list_to_string.value(),
+        &g.unop_cont_tag,
+        list_to_string_continuation_components,
+    );
+
+    // head == THROW preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let throw_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_throw_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.throw.value(),
+        &g.unop_cont_tag,
+        throw_continuation_components,
+    );
+
+    // head == LOGAND preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let logand_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_logand_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.logand.value(),
+        &g.binop_cont_tag,
+        logand_continuation_components,
+    );
+
+    // head == LOGIOR preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let logior_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_logior_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.logior.value(),
+        &g.binop_cont_tag,
+        logior_continuation_components,
+    );
+
+    // head == LOGXOR preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let logxor_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_logxor_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.logxor.value(),
+        &g.binop_cont_tag,
+        logxor_continuation_components,
+    );
+
+    // head == SHIFT-LEFT preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let shift_left_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_shift_left_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.shift_left.value(),
+        &g.binop_cont_tag,
+        shift_left_continuation_components,
+    );
+
+    // head == SHIFT-RIGHT preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let shift_right_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_shift_right_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.shift_right.value(),
+        &g.binop_cont_tag,
+        shift_right_continuation_components,
+    );
+
+    // head == STRING-APPEND preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let string_append_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op2_string_append_tag, &g.default_num],
+        env,
+        &more,
+        cont,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.string_append.value(),
+        &g.binop_cont_tag,
+        string_append_continuation_components,
+    );
+
+    // head == SUBSTRING preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let substring_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_substring_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.substring.value(),
+        &g.binop_cont_tag,
+        substring_continuation_components,
+    );
+
+    // head == CATCH preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let catch_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_catch_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.catch.value(),
+        &g.binop_cont_tag,
+        catch_continuation_components,
+    );
+
+    // head == LIST preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let list_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_list_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.list.value(),
+        &g.binop_cont_tag,
+        list_continuation_components,
+    );
+
+    // head == MAKE-VECTOR preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let make_vector_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_make_vector_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.make_vector.value(),
+        &g.binop_cont_tag,
+        make_vector_continuation_components,
+    );
+
+    // head == VECTOR-REF preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let vector_ref_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_vector_ref_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.vector_ref.value(),
+        &g.binop_cont_tag,
+        vector_ref_continuation_components,
+    );
+
+    // head == VECTOR-SET preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let vector_set_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&[&g.op2_vector_set_tag, &g.default_num], env, &more, cont];
+    hash_default_results.add_hash_input_clauses(
+        c.vector_set.value(),
+        &g.binop_cont_tag,
+        vector_set_continuation_components,
+    );
+
+    // head == EVAL-WITH-LIMIT preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let eval_with_limit_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op2_eval_with_limit_tag, &g.default_num],
+        env,
+        &more,
+        cont,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.eval_with_limit.value(),
+        &g.binop_cont_tag,
+        eval_with_limit_continuation_components,
+    );
+
+    // head == VECTOR-LENGTH preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let vector_length_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[&g.op1_vector_length_tag, &g.default_num],
+        &[cont.tag(), cont.hash()],
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.vector_length.value(),
+        &g.unop_cont_tag,
+        vector_length_continuation_components,
+    );
+
+    // head == IF preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let if_continuation_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &more.clone(),
+        cont,
+        &[&g.default_num, &g.default_num],
+        &[&g.default_num, &g.default_num],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        c.if_.value(),
+        &g.if_cont_tag,
+        if_continuation_components,
+    );
+
+    // head == CURRENT-ENV
+    let the_cont_if_rest_is_nil = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "the_cont_if_rest_is_nil"),
+        &rest_is_nil,
+        cont,
+        &g.error_ptr_cont,
+    )?;
+    results.add_clauses_cons(
+        c.current_env.value(),
+        env,
+        env,
+        &the_cont_if_rest_is_nil,
+        &g.true_num,
+    );
+
+    // head == fn . args preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (
+        cont_tag,
+        component0_tag,
+        component0_hash,
+        component1_tag,
+        component1_hash,
+        component2_tag,
+        component2_hash,
+    ) = {
+        let cont_tag = pick(
+            &mut cs.namespace(|| "pick cont_tag"),
+            &rest_is_nil,
+            &g.call0_cont_tag,
+            &g.call_cont_tag,
+        )?;
+        let component1_tag = pick(
+            &mut cs.namespace(|| "pick component1_tag"),
+            &rest_is_nil,
+            cont.tag(),
+            rest.tag(),
+        )?;
+        let component1_hash = pick(
+            &mut cs.namespace(|| "pick component1_hash"),
+            &rest_is_nil,
+            cont.hash(),
+            rest.hash(),
+        )?;
+        let component2_tag = pick(
+            &mut cs.namespace(|| "pick component2_tag"),
+            &rest_is_nil,
+            &g.default_num,
+            cont.tag(),
+        )?;
+        let component2_hash = pick(
+            &mut cs.namespace(|| "pick component2_hash"),
+            &rest_is_nil,
+            &g.default_num,
+            cont.hash(),
+        )?;
+
+        (
+            cont_tag,
+            env.tag(),
+            env.hash(),
+            component1_tag,
+            component1_hash,
+            component2_tag,
+            component2_hash,
+        )
+    };
+
+    // default has the `fn args` preimage
+    let defaults = [
+        &cont_tag,
+        component0_tag,
+        component0_hash,
+        &component1_tag,
+        &component1_hash,
+        &component2_tag,
+        &component2_hash,
+        &g.default_num,
+        &g.default_num,
+    ];
+
+    /////////////////////////// multicase (hash preimage)
+    let all_hash_input_clauses = [
+        &hash_default_results.tag_clauses[..],
+        &hash_default_results.components_clauses[0][..],
+        &hash_default_results.components_clauses[1][..],
+        &hash_default_results.components_clauses[2][..],
+        &hash_default_results.components_clauses[3][..],
+        &hash_default_results.components_clauses[4][..],
+        &hash_default_results.components_clauses[5][..],
+        &hash_default_results.components_clauses[6][..],
+        &hash_default_results.components_clauses[7][..],
+    ];
+
+    let components_results = multi_case(
+        &mut cs.namespace(|| "reduce cons hash preimage selection"),
+        head.hash(),
+        &all_hash_input_clauses,
         &defaults,
         g,
     )?;
@@ -2700,7 +3202,13 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     let binop_condition = and!(cs, &end_is_nil.not(), &more_is_nil.not())?;
 
     let newer_cont_unop = and!(cs, &head_is_unop, &unop_condition)?;
-    let newer_cont_binop = and!(cs, &head_is_binop, &binop_condition)?;
+    let newer_cont_binop = and!(cs, &head_is_binop, &head_is_ternary.not(), &binop_condition)?;
+    // Unlike the other binops, LIST takes any number of arguments
+    let newer_cont_list = and!(cs, &head_is_binop, &head_is_list, &rest_is_nil.not())?;
+    // SUBSTRING, VECTOR-SET and EVAL-WITH-LIMIT take exactly three arguments, so they also need
+    // a non-nil `(cddr expr)`
+    let is_ternary_arity_error = or!(cs, &more_is_cons.not(), &rest_body_is_nil)?;
+    let newer_cont_ternary = and!(cs, &head_is_binop_ternary, &is_ternary_arity_error.not())?;
 
     let newer_cont_let_letrec = and!(
         cs,
@@ -2714,6 +3222,8 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     let newer_cont_not_dummy0 = or!(
         cs,
         &newer_cont_binop,
+        &newer_cont_list,
+        &newer_cont_ternary,
         &newer_cont_unop,
         &newer_cont_let_letrec
     )?;
@@ -2811,6 +3321,17 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     )?;
     results.add_clauses_cons(c.begin.value(), &arg1, env, &cont_begin, &g.false_num);
 
+    // head == LIST, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    // (LIST) is nil, which is what `arg1` is in that case
+    let cont_list = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "cont list"),
+        &rest_is_nil,
+        cont,
+        &newer_cont,
+    )?;
+    results.add_clauses_cons(c.list.value(), &arg1, env, &cont_list, &g.false_num);
+
     // head == CAR, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
     let newer_cont_if_end_is_nil = AllocatedContPtr::pick(
@@ -2901,6 +3422,16 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &g.false_num,
     );
 
+    // head == I64, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.i64.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
     // head == COMM, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
     results.add_clauses_cons(
@@ -2995,12 +3526,193 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         &g.false_num,
     );
 
-    // head == IF, newer_cont is allocated
+    // head == LOGNOT, newer_cont is allocated
     /////////////////////////////////////////////////////////////////////////////
-    results.add_clauses_cons(c.if_.value(), &arg1, env, &newer_cont, &g.false_num);
-
-    ////////////////////////////////////////////////////////////////////////////////
-    // HEAD == some Coprocessor's associated name.
+    results.add_clauses_cons(
+        c.lognot.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == CHAR->U64, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.char_to_u64.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == U64->CHAR, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.u64_to_char.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == SYMBOL->STRING, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.symbol_to_string.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == STRING->SYMBOL, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.string_to_symbol.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == STRING-LENGTH, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.string_length.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == STRING->LIST, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.string_to_list.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == LIST->STRING, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.list_to_string.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == THROW, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.throw.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == LOGAND, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.logand.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == LOGIOR, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.logior.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == LOGXOR, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.logxor.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == SHIFT-LEFT, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.shift_left.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == SHIFT-RIGHT, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.shift_right.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == STRING-APPEND, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.string_append.value(),
+        &arg1,
+        env,
+        &newer_cont,
+        &g.false_num,
+    );
+
+    // head == CATCH, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.catch.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == MAKE-VECTOR, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.make_vector.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == VECTOR-REF, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.vector_ref.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    // head == SUBSTRING, VECTOR-SET or EVAL-WITH-LIMIT, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let (ternary_expr, ternary_cont) = {
+        let ternary_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "ternary_expr"),
+            &is_ternary_arity_error,
+            expr,
+            &arg1,
+        )?;
+        let ternary_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "ternary_cont"),
+            &is_ternary_arity_error,
+            &g.error_ptr_cont,
+            &newer_cont,
+        )?;
+        (ternary_expr, ternary_cont)
+    };
+    results.add_clauses_cons(
+        c.substring.value(),
+        &ternary_expr,
+        env,
+        &ternary_cont,
+        &g.false_num,
+    );
+    results.add_clauses_cons(
+        c.vector_set.value(),
+        &ternary_expr,
+        env,
+        &ternary_cont,
+        &g.false_num,
+    );
+    results.add_clauses_cons(
+        c.eval_with_limit.value(),
+        &ternary_expr,
+        env,
+        &ternary_cont,
+        &g.false_num,
+    );
+
+    // head == VECTOR-LENGTH, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(
+        c.vector_length.value(),
+        &arg1_or_expr,
+        env,
+        &newer_cont_if_end_is_nil,
+        &g.false_num,
+    );
+
+    // head == IF, newer_cont is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    results.add_clauses_cons(c.if_.value(), &arg1, env, &newer_cont, &g.false_num);
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // HEAD == some Coprocessor's associated name.
     //
     // NOTE: This means that Coprocessors must not conflict with built-in operators, and cannot be shadowed by lexical bindings.
     // The latter also means that all Coprocessor invocations only require 1 iteration.
@@ -3036,11 +3748,14 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
                     &rest,
                 )?;
 
-                for (sym, (coproc, z_ptr)) in lang.coprocessors().iter() {
-                    if !coproc.has_circuit() {
-                        continue;
-                    };
+                let coprocessors_with_circuit = lang
+                    .coprocessors()
+                    .iter()
+                    .filter(|(_, (coproc, _))| coproc.has_circuit());
 
+                for ((sym, (coproc, z_ptr)), head_is_coproc) in
+                    coprocessors_with_circuit.zip(&head_is_coprocessor_bools)
+                {
                     let cs = &mut cs.namespace(|| format!("{} coprocessor", sym));
 
                     let arity = coproc.arity();
@@ -3051,8 +3766,25 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
                         F::from(arity as u64),
                     )?;
 
-                    let (result_expr, result_env, result_cont) =
-                        coproc.synthesize(cs, g, store, &inputs[..arity], env, cont)?;
+                    // The coprocessor is only invoked if this frame actually calls it, with the right number of
+                    // arguments and outside of an `eval-with-limit`.
+                    let coproc_not_dummy = and!(
+                        cs,
+                        not_dummy,
+                        head_is_coproc,
+                        &arity_is_correct,
+                        &sandboxed.not()
+                    )?;
+
+                    let (result_expr, result_env, result_cont) = coproc.synthesize(
+                        cs,
+                        g,
+                        store,
+                        &coproc_not_dummy,
+                        &inputs[..arity],
+                        env,
+                        cont,
+                    )?;
 
                     let quoted_expr = AllocatedPtr::construct_list(
                         &mut cs.namespace(|| "quote coprocessor result"),
@@ -3074,6 +3806,19 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         }
     }
 
+    // Coprocessors aren't available to the evaluation of an `eval-with-limit`, which errors
+    let coprocessor_results = coprocessor_results
+        .into_iter()
+        .enumerate()
+        .map(|(i, (z_ptr, new_expr, new_env, new_cont, apply_cont))| {
+            let cs = &mut cs.namespace(|| format!("sandboxed coprocessor {i}"));
+            let new_expr = pick_ptr!(cs, sandboxed, expr, &new_expr)?;
+            let new_env = pick_ptr!(cs, sandboxed, env, &new_env)?;
+            let new_cont = pick_cont_ptr!(cs, sandboxed, &g.error_ptr_cont, &new_cont)?;
+            Ok((z_ptr, new_expr, new_env, new_cont, apply_cont))
+        })
+        .collect::<Result<Vec<_>, SynthesisError>>()?;
+
     for c in &coprocessor_results {
         results.add_clauses_cons(*c.0.value(), &c.1, &c.2, &c.3, c.4);
     }
@@ -3085,68 +3830,8 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
     let (res, continuation) = {
         let fun_form = &head;
 
-        let more_args_is_nil = more.is_nil(&mut cs.namespace(|| "more_args_is_nil"), g)?;
-        let args_is_nil_or_more_is_nil = or(
-            &mut cs.namespace(|| "args is nil or more is nil"),
-            &is_zero_arg_call,
-            &more_args_is_nil,
-        )?;
-
-        // NOTE: not_dummy was moved last to avoid namespace conflicts. Until automatic-namespacing of and! and or! get
-        // smarter, put shared elements last and unique ones first.
-        let fn_not_dummy = and!(
-            cs,
-            &args_is_nil_or_more_is_nil.not(),
-            &head_is_any.not(),
-            &head_is_fun,
-            not_dummy
-        )?;
-
-        let expanded_inner0 = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "expanded_inner0"),
-            g,
-            &arg1,
-            &g.nil_ptr,
-            ConsName::ExpandedInner0,
-            allocated_cons_witness,
-            &fn_not_dummy,
-        )?;
-
-        let expanded_inner = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "expanded_inner"),
-            g,
-            fun_form,
-            &expanded_inner0,
-            ConsName::ExpandedInner,
-            allocated_cons_witness,
-            &fn_not_dummy,
-        )?;
-
-        let expanded = AllocatedPtr::construct_cons_named(
-            &mut cs.namespace(|| "expanded"),
-            g,
-            &expanded_inner,
-            &more,
-            ConsName::FunExpanded,
-            allocated_cons_witness,
-            &fn_not_dummy,
-        )?;
-
-        let res = AllocatedPtr::pick(
-            &mut cs.namespace(|| "pick res"),
-            &args_is_nil_or_more_is_nil,
-            fun_form,
-            &expanded,
-        )?;
-
-        let continuation = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "pick continuation"),
-            &args_is_nil_or_more_is_nil,
-            &newer_cont,
-            cont,
-        )?;
-
-        (res, continuation)
+        // All arguments are evaluated in the `Call` and `Call2` continuations
+        (fun_form.clone(), newer_cont.clone())
     };
 
     let defaults = [
@@ -3186,6 +3871,7 @@ fn reduce_cons<F: LurkField, CS: ConstraintSystem<F>, C: Coprocessor<F>>(
         cs,
         &expr_cdr_not_dummy.not(),
         &head_is_begin.not(),
+        &head_is_list.not(),
         &head_is_current_env.not(),
         &head_potentially_fun.not(),
         &head_is_coprocessor.not()
@@ -3276,8 +3962,26 @@ fn make_thunk<F: LurkField, CS: ConstraintSystem<F>>(
         (result_expr, saved_env)
     };
 
-    results.add_clauses_thunk(ContTag::Tail, &result_expr, &saved_env, &g.dummy_ptr);
-    results.add_clauses_thunk(ContTag::Outermost, result, env, &g.terminal_ptr);
+    // A thrown value keeps its env until it's caught, and is an error if it never is
+    let env_is_thrown = env.alloc_tag_equal(
+        &mut cs.namespace(|| "env_is_thrown"),
+        ExprTag::Thunk.to_field(),
+    )?;
+    let tail_env = AllocatedPtr::pick(
+        &mut cs.namespace(|| "tail_env"),
+        &env_is_thrown,
+        env,
+        &saved_env,
+    )?;
+    let outermost_cont = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "outermost_cont"),
+        &env_is_thrown,
+        &g.error_ptr_cont,
+        &g.terminal_ptr,
+    )?;
+
+    results.add_clauses_thunk(ContTag::Tail, &result_expr, &tail_env, &g.dummy_ptr);
+    results.add_clauses_thunk(ContTag::Outermost, result, env, &outermost_cont);
     results.add_clauses_thunk(ContTag::Terminal, result, env, &g.terminal_ptr);
     results.add_clauses_thunk(ContTag::Error, result, env, &g.error_ptr_cont);
 
@@ -3332,6 +4036,9 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         AllocatedPtr<F>,
         AllocatedContPtr<F>,
         AllocatedNum<F>,
+        Boolean,
+        AllocatedPtr<F>,
+        AllocatedContPtr<F>,
     ),
     SynthesisError,
 > {
@@ -3398,6 +4105,52 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         store,
     )?;
 
+    // While a value is being thrown, which the env being a thunk tells, every continuation
+    // but the `Binop` of a `catch` is skipped
+    let env_is_thrown = env.alloc_tag_equal(
+        &mut cs.namespace(|| "env_is_thrown"),
+        ExprTag::Thunk.to_field(),
+    )?;
+    let cont_is_binop = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "cont_is_binop"),
+        ContTag::Binop.to_field(),
+    )?;
+    let op2_is_catch = AllocatedPtr::by_index(0, &continuation_components)
+        .alloc_tag_equal(&mut cs.namespace(|| "op2_is_catch"), Op2::Catch.to_field())?;
+    let cont_is_catching = and!(cs, &cont_is_binop, &op2_is_catch)?;
+    let op2_is_list = AllocatedPtr::by_index(0, &continuation_components)
+        .alloc_tag_equal(&mut cs.namespace(|| "op2_is_list"), Op2::List.to_field())?;
+    let op2_is_substring = AllocatedPtr::by_index(0, &continuation_components).alloc_tag_equal(
+        &mut cs.namespace(|| "op2_is_substring"),
+        Op2::Substring.to_field(),
+    )?;
+    let op2_is_vector_set = AllocatedPtr::by_index(0, &continuation_components).alloc_tag_equal(
+        &mut cs.namespace(|| "op2_is_vector_set"),
+        Op2::VectorSet.to_field(),
+    )?;
+    let op2_is_eval_with_limit = AllocatedPtr::by_index(0, &continuation_components)
+        .alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_eval_with_limit"),
+            Op2::EvalWithLimit.to_field(),
+        )?;
+    // The last two arguments of SUBSTRING, VECTOR-SET and EVAL-WITH-LIMIT are evaluated like the
+    // arguments of LIST
+    let op2_is_list_like = or!(
+        cs,
+        &op2_is_list,
+        &op2_is_substring,
+        &op2_is_vector_set,
+        &op2_is_eval_with_limit
+    )?;
+    let cont_is_skipped = and!(
+        cs,
+        &env_is_thrown,
+        &cont_is_catching.not(),
+        &cont_is_terminal.not(),
+        &cont_is_error.not()
+    )?;
+    let not_dummy = &and!(cs, not_dummy, &cont_is_skipped.not())?;
+
     let continuation = AllocatedContPtr::by_index(0, &continuation_components);
 
     results.add_clauses_cont(
@@ -3416,74 +4169,373 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
     // then we do it only once.
     /////////////////////////////////////////////////////////////////////////////
 
-    // Continuation::Call0 preimage
+    // The function applied by Call0, Call and Call2, which is the result for Call0 and Call, and
+    // the function of the continuation for Call2
     /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, continuation) = {
-        (
-            AllocatedPtr::by_index(0, &continuation_components),
-            AllocatedContPtr::by_index(1, &continuation_components),
-        )
-    };
-
-    let call0_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &saved_env,
-        &continuation,
-        default_num_pair,
-        default_num_pair,
-    ];
-    hash_default_results.add_hash_input_clauses(
+    let cont_is_call0 = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "cont_is_call0"),
         ContTag::Call0.to_field(),
-        &g.tail_cont_tag,
-        call0_components,
-    );
-
-    // Continuation::Call preimage
-    /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, continuation, function) = {
-        (
-            AllocatedPtr::by_index(0, &continuation_components),
-            AllocatedContPtr::by_index(2, &continuation_components),
-            result,
-        )
-    };
-    let call_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
-        &[&saved_env, function, &continuation, default_num_pair];
-    hash_default_results.add_hash_input_clauses(
+    )?;
+    let cont_is_call = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "cont_is_call"),
         ContTag::Call.to_field(),
-        &g.call2_cont_tag,
-        call_components,
-    );
-
-    // Continuation::Call2 preimage
-    /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, continuation) = {
-        (
-            AllocatedPtr::by_index(0, &continuation_components),
-            AllocatedContPtr::by_index(2, &continuation_components),
-        )
-    };
-    let call2_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &saved_env,
-        &continuation,
-        default_num_pair,
-        default_num_pair,
-    ];
-    hash_default_results.add_hash_input_clauses(
+    )?;
+    let cont_is_call2 = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "cont_is_call2"),
         ContTag::Call2.to_field(),
-        &g.tail_cont_tag,
-        call2_components,
-    );
+    )?;
+    let cont_is_call0_or_call2 = or!(cs, &cont_is_call0, &cont_is_call2)?;
+    let cont_is_any_call = or!(cs, &cont_is_call0_or_call2, &cont_is_call)?;
 
-    // Continuation::Let preimage
-    /////////////////////////////////////////////////////////////////////////////
-    let (saved_env, let_cont) = {
-        (
-            AllocatedPtr::by_index(2, &continuation_components),
-            AllocatedContPtr::by_index(3, &continuation_components),
-        )
-    };
-    let let_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
-        &[&saved_env, &let_cont, default_num_pair, default_num_pair];
+    let fun = AllocatedPtr::pick(
+        &mut cs.namespace(|| "fun"),
+        &cont_is_call2,
+        &AllocatedPtr::by_index(1, &continuation_components),
+        result,
+    )?;
+    let fun_is_fun = fun.is_fun(&mut cs.namespace(|| "fun_is_fun"))?;
+    let fun_not_dummy = and!(cs, &cont_is_any_call, &fun_is_fun, not_dummy)?;
+
+    // NOTE: this allocation is unconstrained. See necessary constraint immediately below.
+    let (fun_hash, params, body_t, closed_env) = Ptr::allocate_maybe_fun_unconstrained(
+        &mut cs.namespace(|| "allocate fun"),
+        store,
+        fun.ptr(store).as_ref(),
+    )?;
+
+    // Without this, fun is unconstrained.
+    implies_equal!(cs, &fun_not_dummy, &fun_hash, fun.hash());
+
+    let params_is_nil = params.is_nil(&mut cs.namespace(|| "params_is_nil"), g)?;
+    let params_is_cons = params.is_cons(&mut cs.namespace(|| "params_is_cons"))?;
+    let params_not_dummy = and!(cs, &fun_not_dummy, &params_is_cons)?;
+
+    let (param, more_params) = car_cdr_named(
+        &mut cs.namespace(|| "params"),
+        g,
+        &params,
+        ConsName::FunParams,
+        allocated_cons_witness,
+        &params_not_dummy,
+        store,
+    )?;
+
+    let more_params_is_nil = more_params.is_nil(&mut cs.namespace(|| "more_params_is_nil"), g)?;
+    let more_params_is_cons = more_params.is_cons(&mut cs.namespace(|| "more_params_is_cons"))?;
+    let more_params_not_dummy = and!(
+        cs,
+        &params_not_dummy,
+        &cont_is_call0_or_call2,
+        &more_params_is_cons
+    )?;
+
+    let (next_param, _) = car_cdr_named(
+        &mut cs.namespace(|| "more params"),
+        g,
+        &more_params,
+        ConsName::FunMoreParams,
+        allocated_cons_witness,
+        &more_params_not_dummy,
+        store,
+    )?;
+    let next_param = AllocatedPtr::pick(
+        &mut cs.namespace(|| "next_param"),
+        &more_params_is_cons,
+        &next_param,
+        &g.nil_ptr,
+    )?;
+
+    // A `&rest` parameter is bound to the list of all the arguments left, or to nil by a Call0
+    let param_is_rest = param.alloc_equal(&mut cs.namespace(|| "param_is_rest"), &g.rest_sym)?;
+    let next_param_is_rest =
+        next_param.alloc_equal(&mut cs.namespace(|| "next_param_is_rest"), &g.rest_sym)?;
+
+    let var = AllocatedPtr::pick(
+        &mut cs.namespace(|| "var"),
+        &param_is_rest,
+        &next_param,
+        &param,
+    )?;
+    let var_is_sym = var.is_sym(&mut cs.namespace(|| "var_is_sym"))?;
+    let remaining_params = AllocatedPtr::pick(
+        &mut cs.namespace(|| "remaining_params"),
+        &param_is_rest,
+        &g.nil_ptr,
+        &more_params,
+    )?;
+    let remaining_params_is_nil = or!(cs, &param_is_rest, &more_params_is_nil)?;
+    let remaining_params_is_list = or!(cs, &remaining_params_is_nil, &more_params_is_cons)?;
+
+    let binds_in_call0 = and!(cs, &cont_is_call0, &param_is_rest)?;
+    let binds_in_call2 = and!(
+        cs,
+        &cont_is_call2,
+        &params_is_cons,
+        &remaining_params_is_list
+    )?;
+    let binds = or!(cs, &binds_in_call0, &binds_in_call2)?;
+    let binds_var = and!(cs, &binds, &var_is_sym)?;
+    let extend_not_dummy = and!(cs, &fun_not_dummy, &binds_var)?;
+
+    let val = AllocatedPtr::pick(
+        &mut cs.namespace(|| "val"),
+        &cont_is_call2,
+        result,
+        &g.nil_ptr,
+    )?;
+    let newer_env = extend_named(
+        &mut cs.namespace(|| "extend env"),
+        g,
+        &closed_env,
+        &var,
+        &val,
+        ConsName::ClosedEnv,
+        allocated_cons_witness,
+        &extend_not_dummy,
+    )?;
+
+    // The body is entered once all the parameters are bound
+    let enters_without_params = and!(cs, &cont_is_call0, &params_is_nil)?;
+    let enters_with_params = and!(cs, &binds_var, &remaining_params_is_nil)?;
+    let enters_body = or!(cs, &enters_without_params, &enters_with_params)?;
+
+    let body_t_is_cons = body_t.is_cons(&mut cs.namespace(|| "body_t_is_cons"))?;
+    let fun_body_not_dummy = and!(cs, &fun_not_dummy, &enters_body, &body_t_is_cons)?;
+
+    let (body_form, body_end) = car_cdr_named(
+        &mut cs.namespace(|| "body_form"),
+        g,
+        &body_t,
+        ConsName::FunBody,
+        allocated_cons_witness,
+        &fun_body_not_dummy,
+        store,
+    )?;
+    let body_end_is_nil = body_end.is_nil(&mut cs.namespace(|| "body_end_is_nil"), g)?;
+    let body_is_well_formed = and!(cs, &body_t_is_cons, &body_end_is_nil)?;
+
+    let body_env = AllocatedPtr::pick(
+        &mut cs.namespace(|| "body_env"),
+        &params_is_nil,
+        &closed_env,
+        &newer_env,
+    )?;
+
+    // Otherwise, the parameters left are bound in the same way by a Call2, without entering the
+    // body in between
+    let partial_fun = AllocatedPtr::construct_fun(
+        &mut cs.namespace(|| "partial_fun"),
+        g,
+        store,
+        &remaining_params,
+        &body_t,
+        &newer_env,
+    )?;
+
+    // The arguments of a Call, and the ones left to a Call2
+    let args = AllocatedPtr::pick(
+        &mut cs.namespace(|| "args"),
+        &cont_is_call,
+        &AllocatedPtr::by_index(1, &continuation_components),
+        &AllocatedPtr::by_index(3, &continuation_components),
+    )?;
+    let args_is_nil = args.is_nil(&mut cs.namespace(|| "args_is_nil"), g)?;
+    let args_is_cons = args.is_cons(&mut cs.namespace(|| "args_is_cons"))?;
+
+    // The next argument is evaluated by a Call, for the first parameter, and by a Call2 binding a
+    // parameter other than the last one, for the parameter after it. When that's `&rest`, all the
+    // arguments left are evaluated as `(list ...)`
+    let call_passes_arg = and!(cs, &cont_is_call, &params_is_cons)?;
+    let call2_passes_arg = and!(cs, &binds_var, &remaining_params_is_nil.not())?;
+    let passes_arg0 = or!(cs, &call_passes_arg, &call2_passes_arg)?;
+    let passes_arg = and!(cs, &fun_not_dummy, &passes_arg0, &args_is_cons)?;
+
+    let call_arg_is_rest = and!(cs, &cont_is_call, &param_is_rest)?;
+    let call2_arg_is_rest = and!(cs, &cont_is_call2, &next_param_is_rest)?;
+    let arg_is_rest = or!(cs, &call_arg_is_rest, &call2_arg_is_rest)?;
+
+    let list_args_not_dummy = and!(cs, &passes_arg, &arg_is_rest)?;
+    let list_args = AllocatedPtr::construct_cons_named(
+        &mut cs.namespace(|| "list_args"),
+        g,
+        &g.list_sym,
+        &args,
+        ConsName::CallListArgs,
+        allocated_cons_witness,
+        &list_args_not_dummy,
+    )?;
+
+    let call_arg_not_dummy = and!(cs, &passes_arg, &arg_is_rest.not())?;
+    let (call_arg, call_more_args) = car_cdr_named(
+        &mut cs.namespace(|| "call args"),
+        g,
+        &args,
+        ConsName::CallArgs,
+        allocated_cons_witness,
+        &call_arg_not_dummy,
+        store,
+    )?;
+
+    let next_arg = AllocatedPtr::pick(
+        &mut cs.namespace(|| "next_arg"),
+        &arg_is_rest,
+        &list_args,
+        &call_arg,
+    )?;
+    let next_more_args = AllocatedPtr::pick(
+        &mut cs.namespace(|| "next_more_args"),
+        &arg_is_rest,
+        &g.nil_ptr,
+        &call_more_args,
+    )?;
+    let next_fun = AllocatedPtr::pick(
+        &mut cs.namespace(|| "next_fun"),
+        &cont_is_call,
+        &fun,
+        &partial_fun,
+    )?;
+
+    // Continuation::Call0 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (saved_env, continuation) = {
+        (
+            AllocatedPtr::by_index(0, &continuation_components),
+            AllocatedContPtr::by_index(1, &continuation_components),
+        )
+    };
+
+    let call0_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &saved_env,
+        &continuation,
+        default_num_pair,
+        default_num_pair,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Call0.to_field(),
+        &g.tail_cont_tag,
+        call0_components,
+    );
+
+    // Continuation::Call preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (saved_env, continuation) = {
+        (
+            AllocatedPtr::by_index(0, &continuation_components),
+            AllocatedContPtr::by_index(2, &continuation_components),
+        )
+    };
+    let call_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&saved_env, &next_fun, &continuation, &next_more_args];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Call.to_field(),
+        &g.call2_cont_tag,
+        call_components,
+    );
+
+    // Continuation::Call2 preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (saved_env, continuation) = {
+        (
+            AllocatedPtr::by_index(0, &continuation_components),
+            AllocatedContPtr::by_index(2, &continuation_components),
+        )
+    };
+    // Entering the body, a Tail continuation after the last argument, and a Call of the remaining
+    // arguments otherwise. Before the last parameter, a Call2 of the next argument
+    let enters_cont_tag = pick(
+        &mut cs.namespace(|| "Call2 enters_cont_tag"),
+        &args_is_nil,
+        &g.tail_cont_tag,
+        &g.call_cont_tag,
+    )?;
+    let enters_component1_tag = pick(
+        &mut cs.namespace(|| "Call2 enters_component1_tag"),
+        &args_is_nil,
+        continuation.tag(),
+        args.tag(),
+    )?;
+    let enters_component1_hash = pick(
+        &mut cs.namespace(|| "Call2 enters_component1_hash"),
+        &args_is_nil,
+        continuation.hash(),
+        args.hash(),
+    )?;
+    let enters_component2_tag = pick(
+        &mut cs.namespace(|| "Call2 enters_component2_tag"),
+        &args_is_nil,
+        &g.default_num,
+        continuation.tag(),
+    )?;
+    let enters_component2_hash = pick(
+        &mut cs.namespace(|| "Call2 enters_component2_hash"),
+        &args_is_nil,
+        &g.default_num,
+        continuation.hash(),
+    )?;
+    let call2_cont_tag = pick(
+        &mut cs.namespace(|| "Call2 cont_tag"),
+        &remaining_params_is_nil,
+        &enters_cont_tag,
+        &g.call2_cont_tag,
+    )?;
+    let call2_component1_tag = pick(
+        &mut cs.namespace(|| "Call2 component1_tag"),
+        &remaining_params_is_nil,
+        &enters_component1_tag,
+        next_fun.tag(),
+    )?;
+    let call2_component1_hash = pick(
+        &mut cs.namespace(|| "Call2 component1_hash"),
+        &remaining_params_is_nil,
+        &enters_component1_hash,
+        next_fun.hash(),
+    )?;
+    let call2_component2_tag = pick(
+        &mut cs.namespace(|| "Call2 component2_tag"),
+        &remaining_params_is_nil,
+        &enters_component2_tag,
+        continuation.tag(),
+    )?;
+    let call2_component2_hash = pick(
+        &mut cs.namespace(|| "Call2 component2_hash"),
+        &remaining_params_is_nil,
+        &enters_component2_hash,
+        continuation.hash(),
+    )?;
+    let call2_component3_tag = pick(
+        &mut cs.namespace(|| "Call2 component3_tag"),
+        &remaining_params_is_nil,
+        &g.default_num,
+        next_more_args.tag(),
+    )?;
+    let call2_component3_hash = pick(
+        &mut cs.namespace(|| "Call2 component3_hash"),
+        &remaining_params_is_nil,
+        &g.default_num,
+        next_more_args.hash(),
+    )?;
+    let call2_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &saved_env,
+        &[&call2_component1_tag, &call2_component1_hash],
+        &[&call2_component2_tag, &call2_component2_hash],
+        &[&call2_component3_tag, &call2_component3_hash],
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Call2.to_field(),
+        &call2_cont_tag,
+        call2_components,
+    );
+
+    // Continuation::Let preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (saved_env, let_cont) = {
+        (
+            AllocatedPtr::by_index(2, &continuation_components),
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let let_components: &[&dyn AsAllocatedHashComponents<F>; 4] =
+        &[&saved_env, &let_cont, default_num_pair, default_num_pair];
 
     hash_default_results.add_hash_input_clauses(
         ContTag::Let.to_field(),
@@ -3554,7 +4606,14 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Unop preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (unop_val, unop_continuation) = {
+    let (
+        unop_val,
+        unop_continuation,
+        u64_fits_in_char,
+        unop_string_step,
+        unop_call,
+        unop_string_components,
+    ) = {
         let cs = &mut cs.namespace(|| "Unop preimage");
         let op1 = AllocatedPtr::by_index(0, &continuation_components);
         let unop_continuation = AllocatedContPtr::by_index(1, &continuation_components);
@@ -3579,14 +4638,86 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         )?;
         let result_is_cons_like = or!(cs, &result_is_cons, &result_is_str)?;
 
-        let unop_cons_not_dummy = and!(
+        let unop_car_cdr_not_dummy = and!(
             cs,
             &cont_is_unop,
+            not_dummy,
             &unop_op_is_car_or_cdr,
             &result_is_cons_like,
             &result_is_empty_str.not()
         )?;
 
+        // A symbol is destructured like a cons, except for the root symbol
+        let unop_op_is_symbol_to_string = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_symbol_to_string"),
+            Op1::SymbolToString.to_field(),
+        )?;
+        let result_is_sym = result.is_sym(&mut cs.namespace(|| "result_is_sym"))?;
+        let result_is_root_sym =
+            alloc_is_zero(&mut cs.namespace(|| "result_is_root_sym"), result.hash())?;
+        let unop_symbol_not_dummy = and!(
+            cs,
+            &cont_is_unop,
+            not_dummy,
+            &unop_op_is_symbol_to_string,
+            &result_is_sym,
+            &result_is_root_sym.not()
+        )?;
+
+        // The length of a vector is the car of its node
+        let unop_op_is_vector_length = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_vector_length"),
+            Op1::VectorLength.to_field(),
+        )?;
+        let result_is_vec = result.is_vec(&mut cs.namespace(|| "result_is_vec"))?;
+        let unop_vec_not_dummy = and!(
+            cs,
+            &cont_is_unop,
+            not_dummy,
+            &unop_op_is_vector_length,
+            &result_is_vec
+        )?;
+
+        // A call on a non-empty string or list becomes a call on its tail, whose result its
+        // head is then added to by a `Binop2`
+        let unop_op_is_string_length = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_string_length"),
+            Op1::StringLength.to_field(),
+        )?;
+        let unop_op_is_string_to_list = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_string_to_list"),
+            Op1::StringToList.to_field(),
+        )?;
+        let unop_op_is_list_to_string = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_list_to_string"),
+            Op1::ListToString.to_field(),
+        )?;
+        let unop_op_on_string = or!(cs, &unop_op_is_string_length, &unop_op_is_string_to_list)?;
+        let unop_string_not_dummy = and!(
+            cs,
+            &cont_is_unop,
+            not_dummy,
+            &unop_op_on_string,
+            &result_is_str,
+            &result_is_empty_str.not()
+        )?;
+        let unop_list_not_dummy = and!(
+            cs,
+            &cont_is_unop,
+            not_dummy,
+            &unop_op_is_list_to_string,
+            &result_is_cons
+        )?;
+        let unop_string_step = or!(cs, &unop_string_not_dummy, &unop_list_not_dummy)?;
+
+        let unop_cons_not_dummy = or!(
+            cs,
+            &unop_car_cdr_not_dummy,
+            &unop_symbol_not_dummy,
+            &unop_vec_not_dummy,
+            &unop_string_step
+        )?;
+
         let (allocated_car, allocated_cdr) = car_cdr_named(
             &mut cs.namespace(|| "Unop cons"),
             g,
@@ -3600,6 +4731,103 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         let res_car = pick_ptr!(cs, &result_is_empty_str, &g.nil_ptr, &allocated_car)?;
         let res_cdr = pick_ptr!(cs, &result_is_empty_str, &g.empty_str_ptr, &allocated_cdr)?;
 
+        // The tail of a list is quoted, and `strcons` errors if its head isn't a char
+        let quoted = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "Unop quoted"),
+            g,
+            &allocated_cdr,
+            &g.nil_ptr,
+            ConsName::StrQuoted,
+            allocated_cons_witness,
+            &unop_list_not_dummy,
+        )?;
+        let quoted = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "Unop quote"),
+            g,
+            &g.quote_ptr,
+            &quoted,
+            ConsName::StrQuote,
+            allocated_cons_witness,
+            &unop_list_not_dummy,
+        )?;
+        let call_arg = pick_ptr!(cs, &unop_op_is_list_to_string, &quoted, &allocated_cdr)?;
+        let call_args = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "Unop call args"),
+            g,
+            &call_arg,
+            &g.nil_ptr,
+            ConsName::StrCallArgs,
+            allocated_cons_witness,
+            &unop_string_step,
+        )?;
+        let call_sym = pick_ptr!(
+            cs,
+            &unop_op_is_string_length,
+            &g.string_length_sym,
+            &g.string_to_list_sym
+        )?;
+        let call_sym = pick_ptr!(
+            cs,
+            &unop_op_is_list_to_string,
+            &g.list_to_string_sym,
+            &call_sym
+        )?;
+        let unop_call = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "Unop call"),
+            g,
+            &call_sym,
+            &call_args,
+            ConsName::StrCall,
+            allocated_cons_witness,
+            &unop_string_step,
+        )?;
+
+        // The components of the `Binop2` adding the head to the result of the call
+        let string_op2 = pick(
+            &mut cs.namespace(|| "string_op2 if string-length"),
+            &unop_op_is_string_length,
+            &g.op2_sum_tag,
+            &g.op2_cons_tag,
+        )?;
+        let string_op2 = pick(
+            &mut cs.namespace(|| "string_op2"),
+            &unop_op_is_list_to_string,
+            &g.op2_strcons_tag,
+            &string_op2,
+        )?;
+        let one = AllocatedPtr::from_parts(g.u64_tag.clone(), g.true_num.clone());
+        let string_head = pick_ptr!(cs, &unop_op_is_string_length, &one, &allocated_car)?;
+        let unop_string_components = [
+            AllocatedPtr::from_parts(string_op2, g.default_num.clone()),
+            string_head,
+            AllocatedPtr::from_parts(
+                unop_continuation.tag().clone(),
+                unop_continuation.hash().clone(),
+            ),
+        ];
+
+        // The name of a new symbol is consed onto the user package
+        let unop_op_is_string_to_symbol = op1.alloc_tag_equal(
+            &mut cs.namespace(|| "unop_op_is_string_to_symbol"),
+            Op1::StringToSymbol.to_field(),
+        )?;
+        let unop_symcons_not_dummy = and!(
+            cs,
+            &cont_is_unop,
+            not_dummy,
+            &unop_op_is_string_to_symbol,
+            &result_is_str
+        )?;
+        let symcons = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "Unop symcons"),
+            g,
+            result,
+            &g.user_package_ptr,
+            ConsName::UnopConsLike,
+            allocated_cons_witness,
+            &unop_symcons_not_dummy,
+        )?;
+
         let is_atom_ptr = AllocatedPtr::pick(
             &mut cs.namespace(|| "is_atom_ptr"),
             &result_is_cons,
@@ -3607,82 +4835,756 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &g.t_ptr,
         )?;
 
-        let num = to_num(result, g);
-        let comm = to_comm(result, g);
-
-        let (u32_elem, u64_elem) =
-            to_unsigned_integers(&mut cs.namespace(|| "Unop u32 and u64"), g, result.hash())?;
+        let num = to_num(result, g);
+        let comm = to_comm(result, g);
+
+        let (u32_elem, u64_elem, u64_msb) =
+            to_unsigned_integers(&mut cs.namespace(|| "Unop u32 and u64"), g, result.hash())?;
+
+        // A negative i64 is 2^64 above its num
+        let result_is_i64 = result.is_i64(&mut cs.namespace(|| "result_is_i64"))?;
+        let result_is_negative_i64 = Boolean::and(
+            &mut cs.namespace(|| "result_is_negative_i64"),
+            &result_is_i64,
+            &u64_msb,
+        )?;
+        let result_minus_2p64 = sub(
+            &mut cs.namespace(|| "result minus 2^64"),
+            result.hash(),
+            &g.power2_64_num,
+        )?;
+        let num_elem = pick(
+            &mut cs.namespace(|| "num_elem"),
+            &result_is_negative_i64,
+            &result_minus_2p64,
+            num.hash(),
+        )?;
+        let i64_elem = to_i64(&mut cs.namespace(|| "Unop i64"), g, result.hash())?;
+
+        // The complement of a u64 is its difference to 2^64 - 1
+        let max_u64 = sub(
+            &mut cs.namespace(|| "max_u64"),
+            &g.power2_64_num,
+            &g.true_num,
+        )?;
+        let lognot = sub(&mut cs.namespace(|| "lognot"), &max_u64, result.hash())?;
+
+        let u64_fits_in_char = alloc_equal(
+            &mut cs.namespace(|| "u64_fits_in_char"),
+            result.hash(),
+            &u32_elem,
+        )?;
+
+        let res = multi_case(
+            &mut cs.namespace(|| "Unop case"),
+            op1.tag(),
+            &[
+                &[
+                    CaseClause::new(Op1::Car.to_field(), res_car.tag()),
+                    CaseClause::new(Op1::Cdr.to_field(), res_cdr.tag()),
+                    CaseClause::new(Op1::Atom.to_field(), is_atom_ptr.tag()),
+                    CaseClause::new(Op1::Emit.to_field(), result.tag()),
+                    CaseClause::new(Op1::Commit.to_field(), commitment.tag()),
+                    CaseClause::new(Op1::Open.to_field(), committed_expr.tag()),
+                    CaseClause::new(Op1::Secret.to_field(), &g.num_tag),
+                    CaseClause::new(Op1::Num.to_field(), num.tag()),
+                    CaseClause::new(Op1::U64.to_field(), &g.u64_tag),
+                    CaseClause::new(Op1::I64.to_field(), &g.i64_tag),
+                    CaseClause::new(Op1::Comm.to_field(), comm.tag()),
+                    CaseClause::new(Op1::Char.to_field(), &g.char_tag),
+                    CaseClause::new(Op1::Eval.to_field(), result.tag()),
+                    CaseClause::new(Op1::LogNot.to_field(), &g.u64_tag),
+                    CaseClause::new(Op1::CharToU64.to_field(), &g.u64_tag),
+                    CaseClause::new(Op1::U64ToChar.to_field(), &g.char_tag),
+                    CaseClause::new(Op1::SymbolToString.to_field(), allocated_car.tag()),
+                    CaseClause::new(Op1::StringToSymbol.to_field(), &g.sym_tag),
+                    CaseClause::new(Op1::Throw.to_field(), result.tag()),
+                    CaseClause::new(Op1::VectorLength.to_field(), allocated_car.tag()),
+                    CaseClause::new(Op1::StringLength.to_field(), &g.u64_tag),
+                    CaseClause::new(Op1::StringToList.to_field(), g.nil_ptr.tag()),
+                    CaseClause::new(Op1::ListToString.to_field(), g.empty_str_ptr.tag()),
+                ],
+                &[
+                    CaseClause::new(Op1::Car.to_field(), allocated_car.hash()),
+                    CaseClause::new(Op1::Cdr.to_field(), allocated_cdr.hash()),
+                    CaseClause::new(Op1::Atom.to_field(), is_atom_ptr.hash()),
+                    CaseClause::new(Op1::Emit.to_field(), result.hash()),
+                    CaseClause::new(Op1::Commit.to_field(), commitment.hash()),
+                    CaseClause::new(Op1::Open.to_field(), committed_expr.hash()),
+                    CaseClause::new(Op1::Secret.to_field(), &commitment_secret),
+                    CaseClause::new(Op1::Num.to_field(), &num_elem),
+                    CaseClause::new(Op1::U64.to_field(), &u64_elem),
+                    CaseClause::new(Op1::I64.to_field(), &i64_elem),
+                    CaseClause::new(Op1::Comm.to_field(), comm.hash()),
+                    CaseClause::new(Op1::Char.to_field(), &u32_elem),
+                    CaseClause::new(Op1::Eval.to_field(), result.hash()),
+                    CaseClause::new(Op1::LogNot.to_field(), &lognot),
+                    CaseClause::new(Op1::CharToU64.to_field(), result.hash()),
+                    CaseClause::new(Op1::U64ToChar.to_field(), result.hash()),
+                    CaseClause::new(Op1::SymbolToString.to_field(), allocated_car.hash()),
+                    CaseClause::new(Op1::StringToSymbol.to_field(), symcons.hash()),
+                    CaseClause::new(Op1::Throw.to_field(), result.hash()),
+                    CaseClause::new(Op1::VectorLength.to_field(), allocated_car.hash()),
+                    CaseClause::new(Op1::StringLength.to_field(), &g.default_num),
+                    CaseClause::new(Op1::StringToList.to_field(), g.nil_ptr.hash()),
+                    CaseClause::new(Op1::ListToString.to_field(), g.empty_str_ptr.hash()),
+                ],
+            ],
+            &[&g.default_num, &g.default_num],
+            g,
+        )?;
+
+        (
+            AllocatedPtr::by_index(0, &res),
+            unop_continuation,
+            u64_fits_in_char,
+            unop_string_step,
+            unop_call,
+            unop_string_components,
+        )
+    };
+
+    // The newer continuation of a Unop is the `Emit` of `emit`, or the `Binop2` of a step of
+    // a string built-in
+    let unop_cont_tag = pick(
+        &mut cs.namespace(|| "Unop cont_tag"),
+        &unop_string_step,
+        &g.binop2_cont_tag,
+        &g.emit_cont_tag,
+    )?;
+    let default_ptr = AllocatedPtr::from_parts(g.default_num.clone(), g.default_num.clone());
+    let emit_continuation = AllocatedPtr::from_parts(
+        unop_continuation.tag().clone(),
+        unop_continuation.hash().clone(),
+    );
+    let unop_component0 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Unop component0"),
+        &unop_string_step,
+        &unop_string_components[0],
+        &emit_continuation,
+    )?;
+    let unop_component1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Unop component1"),
+        &unop_string_step,
+        &unop_string_components[1],
+        &default_ptr,
+    )?;
+    let unop_component2 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Unop component2"),
+        &unop_string_step,
+        &unop_string_components[2],
+        &default_ptr,
+    )?;
+    let unop_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &unop_component0,
+        &unop_component1,
+        &unop_component2,
+        default_num_pair,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Unop.to_field(),
+        &unop_cont_tag,
+        unop_components,
+    );
+
+    // Continuation::Binop preimage
+    /////////////////////////////////////////////////////////////////////////////
+    let (op2, continuation) = {
+        (
+            &continuation_components[0],
+            AllocatedContPtr::by_index(3, &continuation_components),
+        )
+    };
+    let binop_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &[op2, &g.default_num],
+        result,
+        &continuation,
+        default_num_pair,
+    ];
+    hash_default_results.add_hash_input_clauses(
+        ContTag::Binop.to_field(),
+        &g.binop2_cont_tag,
+        binop_components,
+    );
+
+    // The steps of the vector operations, which are taken by Binop2 and Vector
+    /////////////////////////////////////////////////////////////////////////////
+    let (
+        op2_is_vector_op,
+        vector_expr,
+        vector_continuation,
+        vector_no_error,
+        vector_descends,
+        vector_child_components,
+    ) = {
+        let mut cs = cs.namespace(|| "Vector step");
+        let op2 = AllocatedPtr::by_index(0, &continuation_components);
+        let arg = AllocatedPtr::by_index(1, &continuation_components);
+        let evaled_arg = AllocatedPtr::by_index(2, &continuation_components);
+        let binop2_continuation = AllocatedContPtr::by_index(2, &continuation_components);
+        let vector_continuation = AllocatedContPtr::by_index(3, &continuation_components);
+
+        let cont_is_binop2 = cont.alloc_tag_equal(
+            &mut cs.namespace(|| "cont_is_binop2"),
+            ContTag::Binop2.to_field(),
+        )?;
+        let cont_is_vector = cont.alloc_tag_equal(
+            &mut cs.namespace(|| "cont_is_vector"),
+            ContTag::Vector.to_field(),
+        )?;
+        let op_is_ref =
+            op2.alloc_tag_equal(&mut cs.namespace(|| "op_is_ref"), Op2::VectorRef.to_field())?;
+        let op_is_make = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op_is_make"),
+            Op2::MakeVector.to_field(),
+        )?;
+        let op2_is_vector_op = or!(cs, &op_is_ref, &op_is_make, &op2_is_vector_set)?;
+
+        let binop2_step = and!(cs, &cont_is_binop2, not_dummy)?;
+        let vector_step = and!(cs, &cont_is_vector, not_dummy)?;
+        let result_is_cons = result.is_cons(&mut cs.namespace(|| "result_is_cons"))?;
+        let result_is_u64 = result.is_u64(&mut cs.namespace(|| "result_is_u64"))?;
+        let arg_is_u64 = arg.is_u64(&mut cs.namespace(|| "arg_is_u64"))?;
+
+        let ref_binop2 = and!(cs, &binop2_step, &op_is_ref)?;
+        let ref_vector = and!(cs, &vector_step, &op_is_ref)?;
+        let set_binop2 = and!(cs, &binop2_step, &op2_is_vector_set)?;
+        let set_vector = and!(cs, &vector_step, &op2_is_vector_set)?;
+        let set_down = and!(cs, &set_vector, &result_is_cons)?;
+        let set_up = and!(cs, &set_vector, &result_is_cons.not())?;
+        let make_binop2 = and!(cs, &binop2_step, &op_is_make, &arg_is_u64)?;
+        let make_vector = and!(cs, &vector_step, &op_is_make)?;
+        let make_down = and!(cs, &make_vector, &result_is_u64)?;
+        let make_up = and!(cs, &make_vector, &result_is_u64.not())?;
+
+        // The index and the element of Binop2, or the child, the index and the element passed
+        // down to Vector
+        let set_args_not_dummy = or!(cs, &set_binop2, &set_down)?;
+        let (set_arg0, set_args_rest) = car_cdr_named(
+            &mut cs.namespace(|| "set args"),
+            g,
+            result,
+            ConsName::VecArgs,
+            allocated_cons_witness,
+            &set_args_not_dummy,
+            store,
+        )?;
+        let (set_arg1, set_arg2) = car_cdr_named(
+            &mut cs.namespace(|| "set args rest"),
+            g,
+            &set_args_rest,
+            ConsName::VecArgsCdr,
+            allocated_cons_witness,
+            &set_args_not_dummy,
+            store,
+        )?;
+        let set_args_end_is_nil =
+            set_arg2.is_nil(&mut cs.namespace(|| "set_args_end_is_nil"), g)?;
+        let set_binop2_ok = and!(cs, &set_binop2, &set_args_end_is_nil)?;
+
+        let node = pick_ptr!(cs, &set_down, &set_arg0, &arg)?;
+        let set_index = pick_ptr!(cs, &set_down, &set_arg1, &set_arg0)?;
+        let set_elt = pick_ptr!(cs, &set_down, &set_arg2, &set_arg1)?;
+        let index = pick_ptr!(cs, &op2_is_vector_set, &set_index, result)?;
+
+        // Where the element at `index` is found from `node`
+        let finds = or!(cs, &ref_binop2, &ref_vector, &set_binop2_ok, &set_down)?;
+        let node_is_vec = node.is_vec(&mut cs.namespace(|| "node_is_vec"))?;
+        let index_is_u64 = index.is_u64(&mut cs.namespace(|| "index_is_u64"))?;
+        let node_not_dummy0 = and!(cs, &finds, &node_is_vec, &index_is_u64)?;
+        let node_not_dummy = or!(cs, &node_not_dummy0, &set_up)?;
+        let (len, content) = car_cdr_named(
+            &mut cs.namespace(|| "vector node"),
+            g,
+            &node,
+            ConsName::VecNode,
+            allocated_cons_witness,
+            &node_not_dummy,
+            store,
+        )?;
+        let len_is_u64 = len.is_u64(&mut cs.namespace(|| "len_is_u64"))?;
+        let finds_in_node = and!(cs, &node_not_dummy0, &len_is_u64)?;
+
+        // The length is the one of the node, or the one of the vector being made
+        let make_len = pick_ptr!(cs, &make_down, result, &arg)?;
+        let n = pick_ptr!(cs, &op_is_make, &make_len, &len)?;
+        let n_premise = or!(cs, &finds_in_node, &set_up, &make_binop2, &make_vector)?;
+        let n_bits = implies_u64(&mut cs.namespace(|| "n bits"), &n_premise, n.hash())?;
+        let j = pick(
+            &mut cs.namespace(|| "j"),
+            &set_up,
+            evaled_arg.hash(),
+            index.hash(),
+        )?;
+        let j_bits = implies_u64(&mut cs.namespace(|| "j bits"), &node_not_dummy, &j)?;
+
+        let n_minus_j = sub(&mut cs.namespace(|| "n minus j"), n.hash(), &j)?;
+        let n_minus_j_minus_1 = sub(
+            &mut cs.namespace(|| "n minus j minus 1"),
+            &n_minus_j,
+            &g.true_num,
+        )?;
+        let out_of_range =
+            allocate_is_negative(&mut cs.namespace(|| "out_of_range"), &n_minus_j_minus_1)?;
+        let found = and!(cs, &finds_in_node, &out_of_range.not())?;
+
+        let content_not_dummy = or!(cs, &found, &set_up)?;
+        let (first, rest) = car_cdr_named(
+            &mut cs.namespace(|| "vector content"),
+            g,
+            &content,
+            ConsName::VecContent,
+            allocated_cons_witness,
+            &content_not_dummy,
+            store,
+        )?;
+
+        // See `Store::intern_vec_node()` for the layout of the content
+        let n_is_odd = &n_bits[0];
+        let j_is_odd = and!(cs, &j_bits[0], &op_is_make.not())?;
+        let j_is_zero = alloc_is_zero(&mut cs.namespace(|| "j_is_zero"), &j)?;
+        let n_is_zero = alloc_is_zero(&mut cs.namespace(|| "n_is_zero"), n.hash())?;
+        let here = and!(cs, n_is_odd, &j_is_zero)?;
+        let in_rest = or!(cs, n_is_odd, &j_is_odd)?;
+        let child = pick_ptr!(cs, &in_rest, &rest, &first)?;
+        let j_minus_1 = sub(&mut cs.namespace(|| "j minus 1"), &j, &g.true_num)?;
+        let j_half = pack(&mut cs.namespace(|| "j half"), &j_bits[1..])?;
+        let child_index = pick(
+            &mut cs.namespace(|| "child_index"),
+            n_is_odd,
+            &j_minus_1,
+            &j_half,
+        )?;
+        let n_minus_1 = sub(&mut cs.namespace(|| "n minus 1"), n.hash(), &g.true_num)?;
+        let n_half = pack(&mut cs.namespace(|| "n half"), &n_bits[1..])?;
+        let half = pick(&mut cs.namespace(|| "half"), n_is_odd, &n_minus_1, &n_half)?;
+        let child_index = AllocatedPtr::from_parts(g.u64_tag.clone(), child_index);
+        let half = AllocatedPtr::from_parts(g.u64_tag.clone(), half);
+
+        let set_found = and!(cs, &found, &op2_is_vector_set)?;
+        let set_here = and!(cs, &set_found, &here)?;
+        let set_child = and!(cs, &set_found, &here.not())?;
+        let goes_up = or!(cs, &set_up, &make_up)?;
+
+        // The new content of a node, or the index and the element passed down with a child
+        let up_first = pick_ptr!(cs, &op_is_make, &evaled_arg, &first)?;
+        let up_rest = pick_ptr!(cs, &op_is_make, result, &rest)?;
+        let up_car = pick_ptr!(cs, &in_rest, &up_first, result)?;
+        let up_cdr = pick_ptr!(cs, &in_rest, result, &up_rest)?;
+        let down_car = pick_ptr!(cs, &here, &set_elt, &child_index)?;
+        let down_cdr = pick_ptr!(cs, &here, &rest, &set_elt)?;
+        let new_content_car = pick_ptr!(cs, &goes_up, &up_car, &down_car)?;
+        let new_content_cdr = pick_ptr!(cs, &goes_up, &up_cdr, &down_cdr)?;
+        let new_content_not_dummy = or!(cs, &set_found, &goes_up)?;
+        let new_content = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "new content"),
+            g,
+            &new_content_car,
+            &new_content_cdr,
+            ConsName::VecNewContent,
+            allocated_cons_witness,
+            &new_content_not_dummy,
+        )?;
+
+        // The new node, or the child passed down with its index and the element
+        let new_len = pick_ptr!(cs, &make_up, &arg, &len)?;
+        let new_car = pick_ptr!(cs, &set_child, &child, &new_len)?;
+        let new = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "new"),
+            g,
+            &new_car,
+            &new_content,
+            ConsName::VecNew,
+            allocated_cons_witness,
+            &new_content_not_dummy,
+        )?;
+        let new_tag = pick(
+            &mut cs.namespace(|| "new_tag"),
+            &set_child,
+            &g.cons_tag,
+            &g.vec_tag,
+        )?;
+        let new = AllocatedPtr::from_parts(new_tag, new.hash().clone());
+
+        // The continuation a value is returned to, or which a child continuation returns to
+        let down = or!(cs, &set_down, &make_down)?;
+        let vector_continuation = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "vector_continuation"),
+            &down,
+            cont,
+            &vector_continuation,
+        )?;
+        let continuation = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "continuation"),
+            &cont_is_binop2,
+            &binop2_continuation,
+            &vector_continuation,
+        )?;
+
+        let makes = or!(cs, &make_binop2, &make_down)?;
+        let makes_child = and!(cs, &makes, &n_is_zero.not())?;
+        let ref_child = and!(cs, &found, &op_is_ref, &here.not())?;
+        let vector_descends = or!(cs, &ref_child, &set_child, &makes_child)?;
+
+        // The components of the `Vector` continuation of a child
+        let make_elt = pick_ptr!(cs, &make_down, &evaled_arg, result)?;
+        let child_vector0 = pick_ptr!(cs, &op2_is_vector_set, &node, &n)?;
+        let child_vector = pick_ptr!(cs, &op_is_ref, &child, &child_vector0)?;
+        let child_evaled_arg0 = pick_ptr!(cs, &op2_is_vector_set, &index, &make_elt)?;
+        let child_evaled_arg = pick_ptr!(cs, &op_is_ref, &g.nil_ptr, &child_evaled_arg0)?;
+        let vector_child_components = [
+            AllocatedPtr::from_parts(op2.tag().clone(), g.default_num.clone()),
+            child_vector,
+            child_evaled_arg,
+            AllocatedPtr::from_parts(continuation.tag().clone(), continuation.hash().clone()),
+        ];
+
+        let vector_expr0 = pick_ptr!(cs, &n_is_zero, &g.empty_vec_ptr, &half)?;
+        let vector_expr1 = pick_ptr!(cs, &new_content_not_dummy, &new, &vector_expr0)?;
+        let ref_expr = pick_ptr!(cs, &here, &first, &child_index)?;
+        let vector_expr2 = pick_ptr!(cs, &op_is_ref, &ref_expr, &vector_expr1)?;
+
+        let no_error = or!(cs, &found, &goes_up, &makes)?;
+        let vector_expr = pick_ptr!(cs, &no_error, &vector_expr2, result)?;
+        (
+            op2_is_vector_op,
+            vector_expr,
+            continuation,
+            no_error,
+            vector_descends,
+            vector_child_components,
+        )
+    };
+
+    // The env and the fuel of EVAL-WITH-LIMIT, and the components of the `Fuel` continuation
+    // its evaluation starts under
+    let (eval_with_limit_env, eval_with_limit_ok, fuel_components) = {
+        let mut cs = cs.namespace(|| "EvalWithLimit");
+        let cont_is_binop2 = cont.alloc_tag_equal(
+            &mut cs.namespace(|| "cont_is_binop2"),
+            ContTag::Binop2.to_field(),
+        )?;
+        let eval_args_not_dummy = and!(cs, &cont_is_binop2, &op2_is_eval_with_limit, not_dummy)?;
+        let (eval_env, eval_args_rest) = car_cdr_named(
+            &mut cs.namespace(|| "eval args"),
+            g,
+            result,
+            ConsName::EvalArgs,
+            allocated_cons_witness,
+            &eval_args_not_dummy,
+            store,
+        )?;
+        let (fuel, eval_args_end) = car_cdr_named(
+            &mut cs.namespace(|| "eval args rest"),
+            g,
+            &eval_args_rest,
+            ConsName::EvalArgsCdr,
+            allocated_cons_witness,
+            &eval_args_not_dummy,
+            store,
+        )?;
+        let eval_args_end_is_nil =
+            eval_args_end.is_nil(&mut cs.namespace(|| "eval_args_end_is_nil"), g)?;
+        let fuel_is_u64 = fuel.is_u64(&mut cs.namespace(|| "fuel_is_u64"))?;
+        let eval_with_limit_ok = and!(
+            cs,
+            &eval_args_not_dummy,
+            &eval_args_end_is_nil,
+            &fuel_is_u64
+        )?;
+        let fuel_components = [
+            AllocatedPtr::from_parts(
+                g.outermost_ptr.tag().clone(),
+                g.outermost_ptr.hash().clone(),
+            ),
+            fuel,
+            env.clone(),
+            AllocatedPtr::by_index(2, &continuation_components),
+        ];
+        (eval_env, eval_with_limit_ok, fuel_components)
+    };
+
+    // The steps of STRING-APPEND and SUBSTRING, which call themselves on the tail of their
+    // string, under a `Binop2` consing its head onto the result unless SUBSTRING drops it
+    let (
+        op2_is_string_op,
+        string_expr,
+        string_no_error,
+        string_returns,
+        string_descends,
+        string_components,
+    ) = {
+        let mut cs = cs.namespace(|| "String step");
+        let op2 = AllocatedPtr::by_index(0, &continuation_components);
+        let arg = AllocatedPtr::by_index(1, &continuation_components);
+        let binop2_continuation = AllocatedPtr::by_index(2, &continuation_components);
+
+        let cont_is_binop2 = cont.alloc_tag_equal(
+            &mut cs.namespace(|| "cont_is_binop2"),
+            ContTag::Binop2.to_field(),
+        )?;
+        let op_is_append = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op_is_append"),
+            Op2::StringAppend.to_field(),
+        )?;
+        let op2_is_string_op = or!(cs, &op_is_append, &op2_is_substring)?;
+        let binop2_step = and!(cs, &cont_is_binop2, not_dummy)?;
+        let append = and!(cs, &binop2_step, &op_is_append)?;
+        let substring = and!(cs, &binop2_step, &op2_is_substring)?;
+
+        let arg_is_str = arg.is_str(&mut cs.namespace(|| "arg_is_str"))?;
+        let arg_is_empty =
+            arg.alloc_equal(&mut cs.namespace(|| "arg_is_empty"), &g.empty_str_ptr)?;
+        let result_is_str = result.is_str(&mut cs.namespace(|| "result_is_str"))?;
+        let append_ok = and!(cs, &append, &arg_is_str, &result_is_str)?;
+        let append_empty = and!(cs, &append_ok, &arg_is_empty)?;
+        let append_steps = and!(cs, &append_ok, &arg_is_empty.not())?;
+
+        // The bounds of SUBSTRING
+        let (start, more) = car_cdr_named(
+            &mut cs.namespace(|| "substring args"),
+            g,
+            result,
+            ConsName::StrArgs,
+            allocated_cons_witness,
+            &substring,
+            store,
+        )?;
+        let (end, rest) = car_cdr_named(
+            &mut cs.namespace(|| "substring args rest"),
+            g,
+            &more,
+            ConsName::StrArgsCdr,
+            allocated_cons_witness,
+            &substring,
+            store,
+        )?;
+        let rest_is_nil = rest.is_nil(&mut cs.namespace(|| "rest_is_nil"), g)?;
+        let start_is_num = start.is_num(&mut cs.namespace(|| "start_is_num"))?;
+        let start_is_u64 = start.is_u64(&mut cs.namespace(|| "start_is_u64"))?;
+        let end_is_num = end.is_num(&mut cs.namespace(|| "end_is_num"))?;
+        let end_is_u64 = end.is_u64(&mut cs.namespace(|| "end_is_u64"))?;
+        let start_is_index = or!(cs, &start_is_num, &start_is_u64)?;
+        let end_is_index = or!(cs, &end_is_num, &end_is_u64)?;
+        let start_is_zero = alloc_is_zero(&mut cs.namespace(|| "start_is_zero"), start.hash())?;
+        let end_is_zero = alloc_is_zero(&mut cs.namespace(|| "end_is_zero"), end.hash())?;
+        let substring_ok = and!(
+            cs,
+            &substring,
+            &arg_is_str,
+            &rest_is_nil,
+            &start_is_index,
+            &end_is_index
+        )?;
+        let substring_empty = and!(cs, &substring_ok, &start_is_zero, &end_is_zero)?;
+        // Both bounds are decremented until the start is reached, and then only the end is,
+        // so the end can't be reached first
+        let substring_steps = and!(cs, &substring_ok, &end_is_zero.not(), &arg_is_empty.not())?;
+
+        let steps = or!(cs, &append_steps, &substring_steps)?;
+        let (car, cdr) = car_cdr_named(
+            &mut cs.namespace(|| "string"),
+            g,
+            &arg,
+            ConsName::StrCarCdr,
+            allocated_cons_witness,
+            &steps,
+            store,
+        )?;
+
+        let start_minus_1 = sub(
+            &mut cs.namespace(|| "start minus 1"),
+            start.hash(),
+            &g.true_num,
+        )?;
+        let new_start = pick(
+            &mut cs.namespace(|| "new_start"),
+            &start_is_zero,
+            start.hash(),
+            &start_minus_1,
+        )?;
+        let new_start = AllocatedPtr::from_parts(start.tag().clone(), new_start);
+        let end_minus_1 = sub(&mut cs.namespace(|| "end minus 1"), end.hash(), &g.true_num)?;
+        let new_end = AllocatedPtr::from_parts(end.tag().clone(), end_minus_1);
 
-        let res = multi_case(
-            &mut cs.namespace(|| "Unop case"),
-            op1.tag(),
-            &[
-                &[
-                    CaseClause::new(Op1::Car.to_field(), res_car.tag()),
-                    CaseClause::new(Op1::Cdr.to_field(), res_cdr.tag()),
-                    CaseClause::new(Op1::Atom.to_field(), is_atom_ptr.tag()),
-                    CaseClause::new(Op1::Emit.to_field(), result.tag()),
-                    CaseClause::new(Op1::Commit.to_field(), commitment.tag()),
-                    CaseClause::new(Op1::Open.to_field(), committed_expr.tag()),
-                    CaseClause::new(Op1::Secret.to_field(), &g.num_tag),
-                    CaseClause::new(Op1::Num.to_field(), num.tag()),
-                    CaseClause::new(Op1::U64.to_field(), &g.u64_tag),
-                    CaseClause::new(Op1::Comm.to_field(), comm.tag()),
-                    CaseClause::new(Op1::Char.to_field(), &g.char_tag),
-                    CaseClause::new(Op1::Eval.to_field(), result.tag()),
-                ],
-                &[
-                    CaseClause::new(Op1::Car.to_field(), allocated_car.hash()),
-                    CaseClause::new(Op1::Cdr.to_field(), allocated_cdr.hash()),
-                    CaseClause::new(Op1::Atom.to_field(), is_atom_ptr.hash()),
-                    CaseClause::new(Op1::Emit.to_field(), result.hash()),
-                    CaseClause::new(Op1::Commit.to_field(), commitment.hash()),
-                    CaseClause::new(Op1::Open.to_field(), committed_expr.hash()),
-                    CaseClause::new(Op1::Secret.to_field(), &commitment_secret),
-                    CaseClause::new(Op1::Num.to_field(), num.hash()),
-                    CaseClause::new(Op1::U64.to_field(), &u64_elem),
-                    CaseClause::new(Op1::Comm.to_field(), comm.hash()),
-                    CaseClause::new(Op1::Char.to_field(), &u32_elem),
-                    CaseClause::new(Op1::Eval.to_field(), result.hash()),
-                ],
-            ],
-            &[&g.default_num, &g.default_num],
+        // `(string-append cdr result)` or `(substring cdr start end)`
+        let last_arg = pick_ptr!(cs, &op_is_append, result, &new_end)?;
+        let last_args = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "call args cddr"),
+            g,
+            &last_arg,
+            &g.nil_ptr,
+            ConsName::StrCallArgsCddr,
+            allocated_cons_witness,
+            &steps,
+        )?;
+        let bound_args = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "call args cdr"),
             g,
+            &new_start,
+            &last_args,
+            ConsName::StrCallArgsCdr,
+            allocated_cons_witness,
+            &substring_steps,
         )?;
-
-        (AllocatedPtr::by_index(0, &res), unop_continuation)
+        let call_args_cdr = pick_ptr!(cs, &op_is_append, &last_args, &bound_args)?;
+        let call_args = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "call args"),
+            g,
+            &cdr,
+            &call_args_cdr,
+            ConsName::StrCallArgs,
+            allocated_cons_witness,
+            &steps,
+        )?;
+        let call_sym = pick_ptr!(cs, &op_is_append, &g.string_append_sym, &g.substring_sym)?;
+        let call = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "call"),
+            g,
+            &call_sym,
+            &call_args,
+            ConsName::StrCall,
+            allocated_cons_witness,
+            &steps,
+        )?;
+
+        let empty = or!(cs, &append_empty, &substring_empty)?;
+        let empty_expr = pick_ptr!(cs, &op_is_append, result, &g.empty_str_ptr)?;
+        let string_expr = pick_ptr!(cs, &empty, &empty_expr, &call)?;
+        let no_error = or!(cs, &empty, &steps)?;
+        let string_expr = pick_ptr!(cs, &no_error, &string_expr, result)?;
+
+        // The head is kept, unless SUBSTRING hasn't reached the start yet
+        let drops_head = and!(cs, &substring_steps, &start_is_zero.not())?;
+        let string_descends = and!(cs, &steps, &drops_head.not())?;
+        let string_components = [
+            AllocatedPtr::from_parts(g.op2_strcons_tag.clone(), g.default_num.clone()),
+            car,
+            binop2_continuation,
+        ];
+        (
+            op2_is_string_op,
+            string_expr,
+            no_error,
+            steps,
+            string_descends,
+            string_components,
+        )
     };
 
-    let emit_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &unop_continuation,
-        default_num_pair,
-        default_num_pair,
-        default_num_pair,
+    // Continuation::Binop2 preimage, for the handler of a `catch`, the child of a vector, the
+    // evaluation of EVAL-WITH-LIMIT or the step of a string built-in
+    /////////////////////////////////////////////////////////////////////////////
+    let binop2_continuation = AllocatedPtr::by_index(2, &continuation_components);
+    let binop2_cont_tag = pick(
+        &mut cs.namespace(|| "Binop2 cont_tag"),
+        &op2_is_catch,
+        &g.call2_cont_tag,
+        &g.vector_cont_tag,
+    )?;
+    let binop2_cont_tag = pick(
+        &mut cs.namespace(|| "Binop2 cont_tag if eval-with-limit"),
+        &op2_is_eval_with_limit,
+        &g.fuel_cont_tag,
+        &binop2_cont_tag,
+    )?;
+    let binop2_component0 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Binop2 component0"),
+        &op2_is_catch,
+        env,
+        &vector_child_components[0],
+    )?;
+    let binop2_component1 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Binop2 component1"),
+        &op2_is_catch,
+        result,
+        &vector_child_components[1],
+    )?;
+    let binop2_component2 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Binop2 component2"),
+        &op2_is_catch,
+        &binop2_continuation,
+        &vector_child_components[2],
+    )?;
+    let binop2_component3 = AllocatedPtr::pick(
+        &mut cs.namespace(|| "Binop2 component3"),
+        &op2_is_catch,
+        &g.nil_ptr,
+        &vector_child_components[3],
+    )?;
+    let binop2_component0 = pick_ptr!(
+        cs,
+        &op2_is_eval_with_limit,
+        &fuel_components[0],
+        &binop2_component0
+    )?;
+    let binop2_component1 = pick_ptr!(
+        cs,
+        &op2_is_eval_with_limit,
+        &fuel_components[1],
+        &binop2_component1
+    )?;
+    let binop2_component2 = pick_ptr!(
+        cs,
+        &op2_is_eval_with_limit,
+        &fuel_components[2],
+        &binop2_component2
+    )?;
+    let binop2_component3 = pick_ptr!(
+        cs,
+        &op2_is_eval_with_limit,
+        &fuel_components[3],
+        &binop2_component3
+    )?;
+    let binop2_cont_tag = pick(
+        &mut cs.namespace(|| "Binop2 cont_tag if string op"),
+        &op2_is_string_op,
+        &g.binop2_cont_tag,
+        &binop2_cont_tag,
+    )?;
+    let binop2_component0 = pick_ptr!(
+        cs,
+        &op2_is_string_op,
+        &string_components[0],
+        &binop2_component0
+    )?;
+    let binop2_component1 = pick_ptr!(
+        cs,
+        &op2_is_string_op,
+        &string_components[1],
+        &binop2_component1
+    )?;
+    let binop2_component2 = pick_ptr!(
+        cs,
+        &op2_is_string_op,
+        &string_components[2],
+        &binop2_component2
+    )?;
+    let binop2_component3 = pick_ptr!(cs, &op2_is_string_op, &default_ptr, &binop2_component3)?;
+    let binop2_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &binop2_component0,
+        &binop2_component1,
+        &binop2_component2,
+        &binop2_component3,
     ];
     hash_default_results.add_hash_input_clauses(
-        ContTag::Unop.to_field(),
-        &g.emit_cont_tag,
-        emit_components,
+        ContTag::Binop2.to_field(),
+        &binop2_cont_tag,
+        binop2_components,
     );
 
-    // Continuation::Binop preimage
+    // Continuation::Vector preimage
     /////////////////////////////////////////////////////////////////////////////
-    let (op2, continuation) = {
-        (
-            &continuation_components[0],
-            AllocatedContPtr::by_index(3, &continuation_components),
-        )
-    };
-    let binop_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
-        &[op2, &g.default_num],
-        result,
-        &continuation,
-        default_num_pair,
+    let vector_components: &[&dyn AsAllocatedHashComponents<F>; 4] = &[
+        &vector_child_components[0],
+        &vector_child_components[1],
+        &vector_child_components[2],
+        &vector_child_components[3],
     ];
     hash_default_results.add_hash_input_clauses(
-        ContTag::Binop.to_field(),
-        &g.binop2_cont_tag,
-        binop_components,
+        ContTag::Vector.to_field(),
+        &g.vector_cont_tag,
+        vector_components,
     );
 
     let preimage_defaults = [
@@ -3727,13 +5629,26 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &[&components_results[1], &components_results[2]] as &dyn AsAllocatedHashComponents<F>,
             &[&components_results[3], &components_results[4]] as &dyn AsAllocatedHashComponents<F>,
             &[&components_results[5], &components_results[6]] as &dyn AsAllocatedHashComponents<F>,
-            &[&g.default_num, &g.default_num],
+            &[&components_results[7], &components_results[8]] as &dyn AsAllocatedHashComponents<F>,
         ],
         allocated_cont_witness,
     )?;
 
-    // Reused in Call0, Call, and Call2.
-    let result_is_fun = function.is_fun(&mut cs.namespace(|| "result_is_fun"))?;
+    // The continuation of a vector step, which is a child one while going down
+    let vector_cont = {
+        let vector_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "vector_cont if descends"),
+            &vector_descends,
+            &newer_cont2,
+            &vector_continuation,
+        )?;
+        AllocatedContPtr::pick(
+            &mut cs.namespace(|| "vector_cont"),
+            &vector_no_error,
+            &vector_cont,
+            &g.error_ptr_cont,
+        )?
+    };
 
     // Continuation::Call0
     /////////////////////////////////////////////////////////////////////////////
@@ -3741,43 +5656,6 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         let mut cs = cs.namespace(|| "Call0");
         let continuation = AllocatedContPtr::by_index(1, &continuation_components);
 
-        let cont_is_call0 = cont.alloc_tag_equal(
-            &mut cs.namespace(|| "cont_is_call0"),
-            ContTag::Call0.to_field(),
-        )?;
-        let call0_not_dummy = and!(cs, &cont_is_call0, &result_is_fun, not_dummy)?;
-
-        // NOTE: this allocation is unconstrained. See necessary constraint immediately below.
-        let (fun_hash, arg_t, body_t, closed_env) = Ptr::allocate_maybe_fun_unconstrained(
-            &mut cs.namespace(|| "allocate fun"),
-            store,
-            result.ptr(store).as_ref(),
-        )?;
-
-        // Without this, fun is unconstrained.
-        implies_equal!(cs, &call0_not_dummy, &fun_hash, result.hash());
-
-        let args_is_dummy =
-            arg_t.alloc_equal(&mut cs.namespace(|| "args_is_dummy"), &g.dummy_arg_ptr)?;
-
-        let body_t_is_cons = body_t.is_cons(&mut cs.namespace(|| "body_t_is_cons"))?;
-        let body_t_is_nil = body_t.is_nil(&mut cs.namespace(|| "body_t_is_nil"), g)?;
-
-        let body_is_list = or!(cs, &body_t_is_cons, &body_t_is_nil)?;
-        let zero_arg_call_not_dummy = and!(cs, &call0_not_dummy, &body_is_list, &args_is_dummy)?;
-
-        let (body_form, end) = car_cdr_named(
-            &mut cs.namespace(|| "body_form"),
-            g,
-            &body_t,
-            ConsName::FunBody,
-            allocated_cons_witness,
-            &zero_arg_call_not_dummy,
-            store,
-        )?;
-
-        let end_is_nil = end.is_nil(&mut cs.namespace(|| "end_is_nil"), g)?;
-
         let continuation_is_tail = continuation.alloc_tag_equal(
             &mut cs.namespace(|| "continuation is tail"),
             ContTag::Tail.to_field(),
@@ -3788,68 +5666,38 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &continuation_is_tail,
             &continuation,
             &newer_cont2,
-        );
-
-        let next_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "pick next expr"),
-            &args_is_dummy,
-            &body_form,
-            result,
-        )?;
-        let next_env = AllocatedPtr::pick(
-            &mut cs.namespace(|| "pick next env"),
-            &args_is_dummy,
-            &closed_env,
-            env,
-        )?;
-        let next_cont = AllocatedContPtr::pick(
-            &mut cs.namespace(|| "pick next cont"),
-            &args_is_dummy,
-            &tail_cont.unwrap(),
-            &continuation,
         )?;
 
-        let body_form_is_nil = body_form.is_nil(&mut cs.namespace(|| "body_form_is_nil"), g)?;
-        let body_is_well_formed = and!(cs, &body_form_is_nil.not(), &end_is_nil)?;
-
-        let result_is_valid_fun = and!(cs, &result_is_fun, &body_is_well_formed)?;
-        let result_is_valid_zero_arg_fun = and!(cs, &result_is_valid_fun, &args_is_dummy)?;
+        // A function still taking arguments is returned unchanged
+        let rest_param_error = and!(cs, &param_is_rest, &var_is_sym.not())?;
+        let body_error = and!(cs, &enters_body, &body_is_well_formed.not())?;
+        let call0_error = or!(cs, &fun_is_fun.not(), &rest_param_error, &body_error)?;
+        let entered = and!(cs, &enters_body, &call0_error.not())?;
 
         let the_expr = AllocatedPtr::pick(
             &mut cs.namespace(|| "the_expr"),
-            &result_is_valid_zero_arg_fun,
-            &next_expr,
+            &entered,
+            &body_form,
             result,
         )?;
 
-        let the_env = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_env"),
-            &result_is_fun,
-            &next_env,
-            env,
-        )?;
+        let the_env =
+            AllocatedPtr::pick(&mut cs.namespace(|| "the_env"), &entered, &body_env, env)?;
 
-        let result_not_fun_and_zero_arg_call_is_dummy =
-            and!(cs, &zero_arg_call_not_dummy.not(), &result_is_fun)?;
-        let the_cont_not_error = or!(
-            cs,
-            &result_is_valid_fun,
-            &result_not_fun_and_zero_arg_call_is_dummy
+        let next_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "next_cont"),
+            &entered,
+            &tail_cont,
+            &continuation,
         )?;
-
         let the_cont = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont"),
-            &the_cont_not_error,
-            &next_cont,
+            &call0_error,
             &g.error_ptr_cont,
+            &next_cont,
         )?;
 
-        let newer_cont2_not_dummy0 = and!(
-            cs,
-            &continuation_is_tail.not(),
-            &args_is_dummy,
-            &result_is_fun
-        )?;
+        let newer_cont2_not_dummy0 = and!(cs, &continuation_is_tail.not(), &entered)?;
         let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_not_dummy0)?;
 
         (the_expr, the_env, the_cont, newer_cont2_not_dummy)
@@ -3866,32 +5714,41 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Call, newer_cont2 is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (next_expr, the_cont, newer_cont2_not_dummy) = {
+    let (next_expr, the_env, the_cont, newer_cont2_not_dummy) = {
         let mut cs = cs.namespace(|| "Call");
-        let next_expr = AllocatedPtr::by_index(1, &continuation_components);
+        let saved_env = AllocatedPtr::by_index(0, &continuation_components);
+
+        let call_is_valid = and!(cs, &fun_is_fun, &params_is_cons, &args_is_cons)?;
 
         let next_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "next_expr"),
-            &result_is_fun,
-            &next_expr,
+            &mut cs.namespace(|| "the_expr"),
+            &call_is_valid,
+            &next_arg,
             result,
         )?;
 
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &call_is_valid,
+            &saved_env,
+            env,
+        )?;
+
         let the_cont = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont"),
-            &result_is_fun,
+            &call_is_valid,
             &newer_cont2,
             &g.error_ptr_cont,
         )?;
 
-        let newer_cont2_not_dummy = boolean_num!(cs, &result_is_fun)?;
+        let newer_cont2_not_dummy = boolean_num!(cs, &call_is_valid)?;
 
-        (next_expr, the_cont, newer_cont2_not_dummy)
+        (next_expr, the_env, the_cont, newer_cont2_not_dummy)
     };
     results.add_clauses_cont(
         ContTag::Call,
         &next_expr,
-        env,
+        &the_env,
         &the_cont,
         &g.false_num,
         &newer_cont2_not_dummy,
@@ -3899,126 +5756,123 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
     // Continuation::Call2, newer_cont2 is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_env, the_cont, newer_cont2_not_dummy) = {
+    let (the_expr, the_env, the_cont, make_thunk_num, newer_cont2_not_dummy) = {
         let mut cs = cs.namespace(|| "Call2");
-        let fun = AllocatedPtr::by_index(1, &continuation_components);
+        let saved_env = AllocatedPtr::by_index(0, &continuation_components);
         let continuation = AllocatedContPtr::by_index(2, &continuation_components);
 
-        {
-            let cont_is_call2_precomp = cont.alloc_tag_equal(
-                &mut cs.namespace(|| "cont_is_call2_precomp"),
-                ContTag::Call2.to_field(),
-            )?;
-            let cont_is_call2_and_not_dummy = and!(cs, &cont_is_call2_precomp, not_dummy)?;
-
-            // NOTE: this allocation is unconstrained. See necessary constraint immediately below.
-            let (hash, arg_t, body_t, closed_env) = Ptr::allocate_maybe_fun_unconstrained(
-                &mut cs.namespace(|| "allocate fun"),
-                store,
-                fun.ptr(store).as_ref(),
-            )?;
-
-            // Without this, fun is unconstrained.
-            implies_equal!(cs, &cont_is_call2_and_not_dummy, fun.hash(), &hash);
-
-            let args_is_dummy = arg_t.alloc_equal(
-                &mut cs.namespace(|| "cont2 args_is_dummy"),
-                &g.dummy_arg_ptr,
-            )?;
-            let args_is_not_dummy = args_is_dummy.not();
-
-            let cont_is_call2_and_not_dummy_and_not_dummy_args =
-                and!(cs, &cont_is_call2_and_not_dummy, &args_is_not_dummy)?;
-
-            let body_t_is_nil = body_t.is_nil(&mut cs.namespace(|| "body_t_is_nil"), g)?;
-
-            let (body_form, end) = car_cdr_named(
-                &mut cs.namespace(|| "body_form"),
-                g,
-                &body_t,
-                ConsName::FunBody,
-                allocated_cons_witness,
-                &cont_is_call2_and_not_dummy_and_not_dummy_args,
-                store,
-            )?;
-
-            let end_is_nil = end.is_nil(&mut cs.namespace(|| "end_is_nil"), g)?;
-            let body_is_well_formed = and!(cs, &body_t_is_nil.not(), &end_is_nil)?;
-
-            let extend_not_dummy = and!(
-                cs,
-                &cont_is_call2_and_not_dummy,
-                &args_is_dummy.not(),
-                &body_is_well_formed
-            )?;
+        let continuation_is_tail = continuation.alloc_tag_equal(
+            &mut cs.namespace(|| "continuation is tail"),
+            ContTag::Tail.to_field(),
+        )?;
 
-            let newer_env = extend_named(
-                &mut cs.namespace(|| "extend env"),
-                g,
-                &closed_env,
-                &arg_t,
-                result,
-                ConsName::ClosedEnv,
-                allocated_cons_witness,
-                &extend_not_dummy,
-            )?;
+        // Entering the body after the last argument, a Tail continuation is reused
+        let binds_valid = and!(cs, &fun_is_fun, &binds_in_call2, &var_is_sym)?;
+        let enters_valid = and!(
+            cs,
+            &binds_valid,
+            &remaining_params_is_nil,
+            &body_is_well_formed
+        )?;
+        let continuation_is_reused = and!(cs, &continuation_is_tail, &args_is_nil)?;
+        let enters_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "enters_cont"),
+            &continuation_is_reused,
+            &continuation,
+            &newer_cont2,
+        )?;
 
-            let continuation_is_tail = continuation.alloc_tag_equal(
-                &mut cs.namespace(|| "continuation is tail"),
-                ContTag::Tail.to_field(),
-            )?;
+        // Before the last parameter, the partially applied function is returned when there are no
+        // arguments left, and the next one is evaluated otherwise
+        let partial = and!(cs, &binds_valid, &remaining_params_is_nil.not())?;
+        let returns_partial = and!(cs, &partial, &args_is_nil)?;
+        let passes_valid = and!(cs, &partial, &args_is_cons)?;
 
-            let tail_cont = AllocatedContPtr::pick(
-                &mut cs.namespace(|| "the tail continuation"),
-                &continuation_is_tail,
-                &continuation,
-                &newer_cont2,
-            );
+        let call2_is_valid = or!(cs, &enters_valid, &returns_partial, &passes_valid)?;
 
-            let cond0 = or!(cs, &args_is_dummy.not(), &result_is_fun)?;
-            let cond = and!(cs, &cond0, &body_is_well_formed)?; // &body_form_is_nil.not(), &end_is_nil)?;
+        let next_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "next_expr"),
+            &enters_valid,
+            &body_form,
+            &next_arg,
+        )?;
+        let next_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "partial_or_next_expr"),
+            &returns_partial,
+            &partial_fun,
+            &next_expr,
+        )?;
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &call2_is_valid,
+            &next_expr,
+            result,
+        )?;
 
-            let the_cont = AllocatedContPtr::pick(
-                &mut cs.namespace(|| "the_cont"),
-                &cond,
-                &tail_cont.unwrap(),
-                &g.error_ptr_cont,
-            )?;
+        let next_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "next_env"),
+            &enters_valid,
+            &newer_env,
+            &saved_env,
+        )?;
+        let uses_next_env = or!(cs, &enters_valid, &passes_valid)?;
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &uses_next_env,
+            &next_env,
+            env,
+        )?;
 
-            let the_env =
-                AllocatedPtr::pick(&mut cs.namespace(|| "the_env"), &cond, &newer_env, env)?;
+        let next_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "next_cont"),
+            &enters_valid,
+            &enters_cont,
+            &newer_cont2,
+        )?;
+        let next_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "partial_or_next_cont"),
+            &returns_partial,
+            &continuation,
+            &next_cont,
+        )?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "the_cont"),
+            &call2_is_valid,
+            &next_cont,
+            &g.error_ptr_cont,
+        )?;
 
-            let the_expr =
-                AllocatedPtr::pick(&mut cs.namespace(|| "the_expr"), &cond, &body_form, result)?;
+        let make_thunk_num = boolean_num!(cs, &returns_partial)?;
 
-            let newer_cont2_not_dummy0 = and!(cs, &continuation_is_tail.not(), &cond)?;
-            let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_not_dummy0)?;
+        let newer_cont2_not_dummy0 = and!(cs, &enters_valid, &continuation_is_reused.not())?;
+        let newer_cont2_not_dummy1 = or!(cs, &newer_cont2_not_dummy0, &passes_valid)?;
+        let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_not_dummy1)?;
 
-            (the_expr, the_env, the_cont, newer_cont2_not_dummy)
-        }
+        (
+            the_expr,
+            the_env,
+            the_cont,
+            make_thunk_num,
+            newer_cont2_not_dummy,
+        )
     };
     results.add_clauses_cont(
         ContTag::Call2,
         &the_expr,
         &the_env,
         &the_cont,
-        &g.false_num,
+        &make_thunk_num,
         &newer_cont2_not_dummy,
     );
 
     // Continuation::Binop, newer_cont2 is allocated
     /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_env, the_cont, newer_cont2_not_dummy) = {
+    let (the_expr, the_env, the_cont, make_thunk_num, newer_cont2_not_dummy) = {
         let mut cs = cs.namespace(|| "Binop");
         let operator = AllocatedPtr::by_index(0, &continuation_components);
         let saved_env = AllocatedPtr::by_index(1, &continuation_components);
         let unevaled_args = AllocatedPtr::by_index(2, &continuation_components);
 
-        let cont_is_binop = cont.alloc_tag_equal(
-            &mut cs.namespace(|| "cont_is_binop"),
-            ContTag::Binop.to_field(),
-        )?;
-
         let binop_not_dummy = Boolean::and(
             &mut cs.namespace(|| "binop_not_dummy"),
             &cont_is_binop,
@@ -4064,7 +5918,53 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             result,
         )?;
 
-        let otherwise = op_is_begin.not();
+        // LIST evaluates the remaining arguments as a list, which `result` is consed onto in
+        // Binop2, or else conses `result` onto nil. VECTOR-SET and EVAL-WITH-LIMIT evaluate
+        // their last two arguments the same way.
+        let unevaled_args_is_nil =
+            unevaled_args.is_nil(&mut cs.namespace(|| "unevaled_args_is_nil"), g)?;
+        let list_continues = Boolean::and(
+            &mut cs.namespace(|| "list_continues"),
+            &op2_is_list_like,
+            &unevaled_args_is_nil.not(),
+        )?;
+        let list_ends = Boolean::and(
+            &mut cs.namespace(|| "list_ends"),
+            &op2_is_list_like,
+            &unevaled_args_is_nil,
+        )?;
+
+        let list_again_not_dummy = and!(cs, &list_continues, &binop_not_dummy)?;
+        let list_again = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "list again"),
+            g,
+            &g.list_sym,
+            &unevaled_args,
+            ConsName::ListArgs,
+            allocated_cons_witness,
+            &list_again_not_dummy,
+        )?;
+
+        let last_list_not_dummy = and!(cs, &list_ends, &binop_not_dummy)?;
+        let last_list = AllocatedPtr::construct_cons_named(
+            &mut cs.namespace(|| "last list"),
+            g,
+            result,
+            &g.nil_ptr,
+            ConsName::TheCons,
+            allocated_cons_witness,
+            &last_list_not_dummy,
+        )?;
+
+        // Nothing was thrown, so `catch` returns the value of its body
+        let catch_returns = and!(cs, &op2_is_catch, &env_is_thrown.not(), &rest_is_nil)?;
+
+        let otherwise = and!(
+            cs,
+            &op_is_begin.not(),
+            &catch_returns.not(),
+            &op2_is_list_like.not()
+        )?;
 
         let otherwise_and_rest_is_nil = Boolean::and(
             &mut cs.namespace(|| "otherwise_and_rest_is_nil"),
@@ -4072,50 +5972,117 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &rest_is_nil,
         )?;
 
-        let the_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_expr"),
+        let the_expr_if_rest_is_nil = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr_if_rest_is_nil"),
             &rest_is_nil,
             &allocated_arg2,
             &the_expr_if_begin,
         )?;
 
-        let the_env = AllocatedPtr::pick(
-            &mut cs.namespace(|| "the_env"),
+        let the_expr_if_not_list = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr_if_not_list"),
+            &catch_returns,
+            result,
+            &the_expr_if_rest_is_nil,
+        )?;
+
+        let the_expr_if_list = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr_if_list"),
+            &unevaled_args_is_nil,
+            &last_list,
+            &list_again,
+        )?;
+
+        let the_expr = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_expr"),
+            &op2_is_list_like,
+            &the_expr_if_list,
+            &the_expr_if_not_list,
+        )?;
+
+        let the_env_if_rest_is_nil = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env_if_rest_is_nil"),
             &rest_is_nil,
             &saved_env,
             env,
         )?;
 
+        let the_env_if_not_list = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env_if_not_list"),
+            &catch_returns,
+            &saved_env,
+            &the_env_if_rest_is_nil,
+        )?;
+
+        let the_env_if_list = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env_if_list"),
+            &unevaled_args_is_nil,
+            env,
+            &saved_env,
+        )?;
+
+        let the_env = AllocatedPtr::pick(
+            &mut cs.namespace(|| "the_env"),
+            &op2_is_list_like,
+            &the_env_if_list,
+            &the_env_if_not_list,
+        )?;
+
+        let newer_cont2_is_used = or(
+            &mut cs.namespace(|| "newer_cont2_is_used"),
+            &otherwise_and_rest_is_nil,
+            &list_continues,
+        )?;
+
         let the_cont_otherwise = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont_otherwise"),
-            &otherwise_and_rest_is_nil,
+            &newer_cont2_is_used,
             &newer_cont2,
             &g.error_ptr_cont,
         )?;
 
+        let otherwise_or_list_continues = or(
+            &mut cs.namespace(|| "otherwise_or_list_continues"),
+            &otherwise,
+            &list_continues,
+        )?;
+
         let the_cont = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont"),
-            &otherwise,
+            &otherwise_or_list_continues,
             &the_cont_otherwise,
             &continuation,
         )?;
 
-        let newer_cont2_not_dummy = boolean_num!(cs, &otherwise_and_rest_is_nil)?;
+        let makes_thunk = or(
+            &mut cs.namespace(|| "makes_thunk"),
+            &catch_returns,
+            &list_ends,
+        )?;
 
-        (the_expr, the_env, the_cont, newer_cont2_not_dummy)
+        let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_is_used)?;
+        let make_thunk_num = boolean_num!(cs, &makes_thunk)?;
+
+        (
+            the_expr,
+            the_env,
+            the_cont,
+            make_thunk_num,
+            newer_cont2_not_dummy,
+        )
     };
     results.add_clauses_cont(
         ContTag::Binop,
         &the_expr,
         &the_env,
         &the_cont,
-        &g.false_num,
+        &make_thunk_num,
         &newer_cont2_not_dummy,
     );
 
     // Continuation::Binop2
     /////////////////////////////////////////////////////////////////////////////
-    let (the_expr, the_env, the_cont, make_thunk_num) = {
+    let (the_expr, the_env, the_cont, make_thunk_num, newer_cont2_not_dummy) = {
         let mut cs = cs.namespace(|| "Binop2");
         let op2 = AllocatedPtr::by_index(0, &continuation_components);
         let arg1 = AllocatedPtr::by_index(1, &continuation_components);
@@ -4137,6 +6104,13 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &arg1_is_u64,
             &arg2_is_u64,
         )?;
+        let arg1_is_i64 = arg1.is_i64(&mut cs.namespace(|| "arg1_is_i64"))?;
+        let arg2_is_i64 = arg2.is_i64(&mut cs.namespace(|| "arg2_is_i64"))?;
+        let both_args_are_i64s = Boolean::and(
+            &mut cs.namespace(|| "both_args_are_i64s"),
+            &arg1_is_i64,
+            &arg2_is_i64,
+        )?;
 
         let arg1_is_num_and_arg2_is_u64 = Boolean::and(
             &mut cs.namespace(|| "arg1_is_num_and_arg2_is_u64"),
@@ -4149,12 +6123,14 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &arg2_is_num,
         )?;
 
+        // i64s are not coerced, so they only go along with i64s
         let args_are_num_or_u64 = or!(
             cs,
             &both_args_are_nums,
             &both_args_are_u64s,
             &arg1_is_num_and_arg2_is_u64,
-            &arg1_is_u64_and_arg2_is_num
+            &arg1_is_u64_and_arg2_is_num,
+            &both_args_are_i64s
         )?;
 
         let arg1_u64_to_num = to_num(&arg1, g);
@@ -4183,13 +6159,120 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &args_equal,
         )?;
 
-        let not_dummy = cont.alloc_tag_equal(
-            &mut cs.namespace(|| "Binop2 not dummy"),
+        let cont_is_binop2 = cont.alloc_tag_equal(
+            &mut cs.namespace(|| "cont_is_binop2"),
             ContTag::Binop2.to_field(),
         )?;
+        let not_dummy = Boolean::and(
+            &mut cs.namespace(|| "Binop2 not dummy"),
+            &cont_is_binop2,
+            not_dummy,
+        )?;
+
+        let op2_is_logand = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_logand"),
+            Op2::LogAnd.to_field(),
+        )?;
+        let op2_is_logior = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_logior"),
+            Op2::LogIor.to_field(),
+        )?;
+        let op2_is_logxor = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_logxor"),
+            Op2::LogXor.to_field(),
+        )?;
+        let op2_is_shift_left = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_shift_left"),
+            Op2::ShiftLeft.to_field(),
+        )?;
+        let op2_is_shift_right = op2.alloc_tag_equal(
+            &mut cs.namespace(|| "op2_is_shift_right"),
+            Op2::ShiftRight.to_field(),
+        )?;
+        let op2_is_bitwise = or!(
+            cs,
+            &op2_is_logand,
+            &op2_is_logior,
+            &op2_is_logxor,
+            &op2_is_shift_left,
+            &op2_is_shift_right
+        )?;
+        let op2_is_bitwise_and_args_are_u64s = Boolean::and(
+            &mut cs.namespace(|| "op2 is bitwise and args are u64s"),
+            &op2_is_bitwise,
+            &both_args_are_u64s,
+        )?;
+        let op2_is_bitwise_and_args_are_not_u64s = Boolean::and(
+            &mut cs.namespace(|| "op2 is bitwise and args are not u64s"),
+            &op2_is_bitwise,
+            &both_args_are_u64s.not(),
+        )?;
+        let real_bitwise = Boolean::and(
+            &mut cs.namespace(|| "real bitwise"),
+            &not_dummy,
+            &op2_is_bitwise_and_args_are_u64s,
+        )?;
+
+        // The bits of i64s are needed for their signs
+        let real_i64 = Boolean::and(
+            &mut cs.namespace(|| "real i64"),
+            &not_dummy,
+            &both_args_are_i64s,
+        )?;
+        let real_bitwise_or_i64 = or(
+            &mut cs.namespace(|| "real bitwise or i64"),
+            &real_bitwise,
+            &real_i64,
+        )?;
+
+        let arg1_bits = implies_u64(
+            &mut cs.namespace(|| "arg1 u64 bits"),
+            &real_bitwise_or_i64,
+            arg1.hash(),
+        )?;
+        let arg2_bits = implies_u64(
+            &mut cs.namespace(|| "arg2 u64 bits"),
+            &real_bitwise_or_i64,
+            arg2.hash(),
+        )?;
+
+        // Negative i64s are brought back 2^64 below, so that they are compared and
+        // subtracted as signed field elements
+        let arg1_is_negative_i64 = Boolean::and(
+            &mut cs.namespace(|| "arg1 is negative i64"),
+            &both_args_are_i64s,
+            &arg1_bits[63],
+        )?;
+        let arg2_is_negative_i64 = Boolean::and(
+            &mut cs.namespace(|| "arg2 is negative i64"),
+            &both_args_are_i64s,
+            &arg2_bits[63],
+        )?;
+        let arg1_minus_2p64 = sub(
+            &mut cs.namespace(|| "arg1 minus 2^64"),
+            arg1.hash(),
+            &g.power2_64_num,
+        )?;
+        let arg2_minus_2p64 = sub(
+            &mut cs.namespace(|| "arg2 minus 2^64"),
+            arg2.hash(),
+            &g.power2_64_num,
+        )?;
+        let signed_a = pick(
+            &mut cs.namespace(|| "signed a"),
+            &arg1_is_negative_i64,
+            &arg1_minus_2p64,
+            a,
+        )?;
+        let signed_b = pick(
+            &mut cs.namespace(|| "signed b"),
+            &arg2_is_negative_i64,
+            &arg2_minus_2p64,
+            b,
+        )?;
 
         let sum = add(&mut cs.namespace(|| "sum"), a, b)?;
-        let diff = sub(&mut cs.namespace(|| "difference"), a, b)?;
+        let diff = sub(&mut cs.namespace(|| "difference"), &signed_a, &signed_b)?;
         let product = mul(&mut cs.namespace(|| "product"), a, b)?;
 
         let op2_is_div =
@@ -4214,8 +6297,14 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
 
         let quotient = div(&mut cs.namespace(|| "quotient"), a, &divisor)?;
 
-        let is_cons =
+        let op2_is_cons =
             op2.alloc_tag_equal(&mut cs.namespace(|| "Op2 is Cons"), Op2::Cons.to_field())?;
+        // LIST conses its first argument onto the list of the others
+        let is_cons = or(
+            &mut cs.namespace(|| "is cons or list"),
+            &op2_is_cons,
+            &op2_is_list,
+        )?;
         let is_strcons = op2.alloc_tag_equal(
             &mut cs.namespace(|| "Op2 is StrCons"),
             Op2::StrCons.to_field(),
@@ -4270,6 +6359,7 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
                 CaseClause::new(Op2::Equal.to_field(), args_equal_ptr.hash()),
                 CaseClause::new(Op2::NumEqual.to_field(), args_equal_ptr.hash()),
                 CaseClause::new(Op2::Cons.to_field(), cons.hash()),
+                CaseClause::new(Op2::List.to_field(), cons.hash()),
                 CaseClause::new(Op2::StrCons.to_field(), cons.hash()),
                 CaseClause::new(Op2::Hide.to_field(), commitment.hash()),
             ],
@@ -4354,8 +6444,8 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         let (is_comparison_tag, comp_val, diff_is_negative) = comparison_helper(
             &mut cs.namespace(|| "enforce comparison"),
             g,
-            a,
-            b,
+            &signed_a,
+            &signed_b,
             &diff,
             op2.tag(),
             store.expect_constants(),
@@ -4392,32 +6482,115 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             field_arithmetic_result.hash(),
         )?;
 
+        // i64s are divided through their absolute values
+        let arg1_negated = sub(
+            &mut cs.namespace(|| "arg1 negated"),
+            &g.power2_64_num,
+            arg1.hash(),
+        )?;
+        let arg2_negated = sub(
+            &mut cs.namespace(|| "arg2 negated"),
+            &g.power2_64_num,
+            arg2.hash(),
+        )?;
+        let arg1_abs = pick(
+            &mut cs.namespace(|| "arg1 abs"),
+            &arg1_is_negative_i64,
+            &arg1_negated,
+            arg1.hash(),
+        )?;
+        let arg2_abs = pick(
+            &mut cs.namespace(|| "arg2 abs"),
+            &arg2_is_negative_i64,
+            &arg2_negated,
+            arg2.hash(),
+        )?;
+
+        let (alloc_q, alloc_r) = enforce_u64_div_mod(
+            &mut cs.namespace(|| "u64 div mod equation"),
+            &op2_is_mod,
+            &AllocatedPtr::from_parts(arg1.tag().clone(), arg1_abs),
+            &AllocatedPtr::from_parts(arg2.tag().clone(), arg2_abs),
+        )?;
+
+        // The quotient of i64s is negative when the signs differ, and the remainder
+        // takes the sign of the dividend. Negating zero gives 2^64, which the
+        // coercion to u64 brings back to zero.
+        let signs_differ = Boolean::xor(
+            &mut cs.namespace(|| "signs differ"),
+            &arg1_is_negative_i64,
+            &arg2_is_negative_i64,
+        )?;
+        let q_negated = sub(
+            &mut cs.namespace(|| "q negated"),
+            &g.power2_64_num,
+            &alloc_q,
+        )?;
+        let i64_q = pick(
+            &mut cs.namespace(|| "i64 q"),
+            &signs_differ,
+            &q_negated,
+            &alloc_q,
+        )?;
+        let r_negated = sub(
+            &mut cs.namespace(|| "r negated"),
+            &g.power2_64_num,
+            &alloc_r,
+        )?;
+        let i64_r = pick(
+            &mut cs.namespace(|| "i64 r"),
+            &arg1_is_negative_i64,
+            &r_negated,
+            &alloc_r,
+        )?;
+        let i64_div_mod = pick(
+            &mut cs.namespace(|| "i64 div mod"),
+            &op2_is_mod,
+            &i64_r,
+            &i64_q,
+        )?;
+        let op2_is_div_or_mod_and_args_are_i64s = Boolean::and(
+            &mut cs.namespace(|| "op2 is div or mod and args are i64s"),
+            &op2_is_div_or_mod,
+            &both_args_are_i64s,
+        )?;
+        let include_i64_div_mod = pick(
+            &mut cs.namespace(|| "include i64 div mod"),
+            &op2_is_div_or_mod_and_args_are_i64s,
+            &i64_div_mod,
+            &field_arith_and_u64_diff_result,
+        )?;
+
         let coerce_to_u64 = to_u64(
             &mut cs.namespace(|| "binop coerce to u64"),
             g,
-            &field_arith_and_u64_diff_result,
+            &include_i64_div_mod,
+        )?;
+        let coerce_tag = pick(
+            &mut cs.namespace(|| "binop coerce tag"),
+            &both_args_are_i64s,
+            &g.i64_tag,
+            &g.u64_tag,
         )?;
-        let coerce_to_u64_ptr = AllocatedPtr::from_parts(g.u64_tag.clone(), coerce_to_u64);
+        let coerce_to_u64_ptr = AllocatedPtr::from_parts(coerce_tag, coerce_to_u64);
 
-        let both_args_are_u64s_and_not_comparison = Boolean::and(
-            &mut cs.namespace(|| "both_args_are_u64_and_not_comparison"),
+        let both_args_are_u64s_or_i64s = or(
+            &mut cs.namespace(|| "both_args_are_u64s_or_i64s"),
             &both_args_are_u64s,
+            &both_args_are_i64s,
+        )?;
+        let both_args_are_u64s_or_i64s_and_not_comparison = Boolean::and(
+            &mut cs.namespace(|| "both_args_are_u64s_or_i64s_and_not_comparison"),
+            &both_args_are_u64s_or_i64s,
             &is_comparison_tag.not(),
         )?;
 
         let partial_u64_result = AllocatedPtr::pick(
             &mut cs.namespace(|| "partial u64 result"),
-            &both_args_are_u64s_and_not_comparison,
+            &both_args_are_u64s_or_i64s_and_not_comparison,
             &coerce_to_u64_ptr,
             &field_arithmetic_result,
         )?;
-
-        let (alloc_q, alloc_r) = enforce_u64_div_mod(
-            &mut cs.namespace(|| "u64 div mod equation"),
-            &op2_is_mod,
-            &arg1,
-            arg2,
-        )?;
         let alloc_q_ptr = AllocatedPtr::from_parts(g.u64_tag.clone(), alloc_q);
         let alloc_r_ptr = AllocatedPtr::from_parts(g.u64_tag.clone(), alloc_r);
 
@@ -4437,19 +6610,60 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &op2_is_mod,
             &both_args_are_u64s,
         )?;
-        let op2_is_mod_and_args_are_not_u64s = Boolean::and(
-            &mut cs.namespace(|| "op2 is mod and args are not u64s"),
+        let op2_is_mod_and_args_are_not_u64s_or_i64s = Boolean::and(
+            &mut cs.namespace(|| "op2 is mod and args are not u64s or i64s"),
             &op2_is_mod,
-            &both_args_are_u64s.not(),
+            &both_args_are_u64s_or_i64s.not(),
         )?;
         // include u64 mod
-        let arithmetic_result = AllocatedPtr::pick(
-            &mut cs.namespace(|| "arithmetic result"),
+        let include_u64_mod = AllocatedPtr::pick(
+            &mut cs.namespace(|| "include u64 mod"),
             &op2_is_mod_and_args_are_u64s,
             &alloc_r_ptr,
             &include_u64_quotient,
         )?;
 
+        // For u64s, a | b = a + b - (a & b) and a ^ b = (a | b) - (a & b)
+        let logand = u64_and(&mut cs.namespace(|| "logand"), &arg1_bits, &arg2_bits)?;
+        let logior = sub(&mut cs.namespace(|| "logior"), &sum, &logand)?;
+        let logxor = sub(&mut cs.namespace(|| "logxor"), &logior, &logand)?;
+        let shifted = implies_u64_shift(
+            &mut cs.namespace(|| "shift"),
+            &real_bitwise,
+            &op2_is_shift_right,
+            arg1.hash(),
+            &arg2_bits,
+        )?;
+
+        let op2_is_shift = or!(cs, &op2_is_shift_left, &op2_is_shift_right)?;
+        let include_logior = pick(
+            &mut cs.namespace(|| "include logior"),
+            &op2_is_logior,
+            &logior,
+            &logand,
+        )?;
+        let include_logxor = pick(
+            &mut cs.namespace(|| "include logxor"),
+            &op2_is_logxor,
+            &logxor,
+            &include_logior,
+        )?;
+        let bitwise_val = pick(
+            &mut cs.namespace(|| "include shift"),
+            &op2_is_shift,
+            &shifted,
+            &include_logxor,
+        )?;
+        let bitwise_ptr = AllocatedPtr::from_parts(g.u64_tag.clone(), bitwise_val);
+
+        // include u64 bitwise
+        let arithmetic_result = AllocatedPtr::pick(
+            &mut cs.namespace(|| "arithmetic result"),
+            &op2_is_bitwise_and_args_are_u64s,
+            &bitwise_ptr,
+            &include_u64_mod,
+        )?;
+
         let valid_types = or(
             &mut cs.namespace(|| "Op2 called with valid types"),
             &is_cons_or_strcons_or_hide_or_equal,
@@ -4486,7 +6700,8 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &op2_not_num_or_u64_and_not_cons_or_strcons_or_hide_or_equal_or_num_equal,
             &invalid_strcons_tag,
             &op2_is_hide_and_arg1_is_not_num,
-            &op2_is_mod_and_args_are_not_u64s,
+            &op2_is_mod_and_args_are_not_u64s_or_i64s,
+            &op2_is_bitwise_and_args_are_not_u64s,
             &invalid_secret_tag_hide
         )?;
 
@@ -4500,13 +6715,21 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &continuation,
         )?;
 
-        let the_cont = AllocatedContPtr::pick(
+        let the_cont_if_not_catch = AllocatedContPtr::pick(
             &mut cs.namespace(|| "maybe eval cont"),
             &op2_is_eval,
             &continuation,
             &the_cont_,
         )?;
 
+        // The handler of a `catch` is called on the thrown value
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "maybe catch cont"),
+            &op2_is_catch,
+            &newer_cont2,
+            &the_cont_if_not_catch,
+        )?;
+
         let the_expr_ = AllocatedPtr::pick(
             &mut cs.namespace(|| "maybe expr error"),
             &any_error,
@@ -4514,9 +6737,10 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &arithmetic_result,
         )?;
 
+        let op2_is_eval_or_catch = or!(cs, &op2_is_eval, &op2_is_catch)?;
         let the_expr = AllocatedPtr::pick(
-            &mut cs.namespace(|| "maybe eval expr"),
-            &op2_is_eval,
+            &mut cs.namespace(|| "maybe eval or catch expr"),
+            &op2_is_eval_or_catch,
             &arg1,
             &the_expr_,
         )?;
@@ -4528,12 +6752,76 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             env,
         )?;
 
+        // EVAL-WITH-LIMIT evaluates its first argument under a `Fuel` continuation, and the
+        // string built-ins evaluate the calls of their steps
+        let op2_is_some_eval = or!(cs, &op2_is_eval, &op2_is_eval_with_limit, &string_returns)?;
         let make_thunk_num = boolean_to_num(
             &mut cs.namespace(|| "maybe eval make_thunk_num"),
-            &op2_is_eval.not(),
+            &op2_is_some_eval.not(),
+        )?;
+        let fuel_expr = pick_ptr!(cs, &eval_with_limit_ok, &arg1, result)?;
+        let fuel_env = pick_ptr!(cs, &eval_with_limit_ok, &eval_with_limit_env, env)?;
+        let fuel_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "fuel cont"),
+            &eval_with_limit_ok,
+            &newer_cont2,
+            &g.error_ptr_cont,
+        )?;
+        let the_expr = pick_ptr!(cs, &op2_is_eval_with_limit, &fuel_expr, &the_expr)?;
+        let the_env = pick_ptr!(cs, &op2_is_eval_with_limit, &fuel_env, &the_env)?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "maybe fuel cont"),
+            &op2_is_eval_with_limit,
+            &fuel_cont,
+            &the_cont,
+        )?;
+
+        // The vector operations take their own steps
+        let the_expr = pick_ptr!(cs, &op2_is_vector_op, &vector_expr, &the_expr)?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "maybe vector cont"),
+            &op2_is_vector_op,
+            &vector_cont,
+            &the_cont,
+        )?;
+
+        // The string built-ins take their own steps too
+        let string_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "string cont if descends"),
+            &string_descends,
+            &newer_cont2,
+            &continuation,
+        )?;
+        let string_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "string cont"),
+            &string_no_error,
+            &string_cont,
+            &g.error_ptr_cont,
+        )?;
+        let the_expr = pick_ptr!(cs, &op2_is_string_op, &string_expr, &the_expr)?;
+        let the_cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "maybe string cont"),
+            &op2_is_string_op,
+            &string_cont,
+            &the_cont,
+        )?;
+
+        let newer_cont2_is_used = or!(
+            cs,
+            &op2_is_catch,
+            &vector_descends,
+            &eval_with_limit_ok,
+            &string_descends
         )?;
+        let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_is_used)?;
 
-        (the_expr, the_env, the_cont, make_thunk_num)
+        (
+            the_expr,
+            the_env,
+            the_cont,
+            make_thunk_num,
+            newer_cont2_not_dummy,
+        )
     };
 
     results.add_clauses_cont(
@@ -4542,7 +6830,19 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         &the_env,
         &the_cont,
         &make_thunk_num,
-        &g.false_num,
+        &newer_cont2_not_dummy,
+    );
+
+    // Continuation::Vector, newer_cont2 is allocated
+    /////////////////////////////////////////////////////////////////////////////
+    let vector_newer_cont2_not_dummy = boolean_num!(cs, &vector_descends)?;
+    results.add_clauses_cont(
+        ContTag::Vector,
+        &vector_expr,
+        env,
+        &vector_cont,
+        &g.true_num,
+        &vector_newer_cont2_not_dummy,
     );
 
     // Continuation::If
@@ -4770,6 +7070,13 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &other_unop_continuation,
         )?;
 
+        let unop_continuation0 = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "unop_continuation if string step"),
+            &unop_string_step,
+            &newer_cont2,
+            &unop_continuation0,
+        )?;
+
         let unop_continuation = AllocatedContPtr::pick(
             &mut cs.namespace(|| "unop_continuation"),
             &op1_is_eval,
@@ -4805,6 +7112,46 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         )?;
         let op1_is_u64 =
             unop_op1.alloc_tag_equal(&mut cs.namespace(|| "op1_is_u64"), Op1::U64.to_field())?;
+        let op1_is_i64 =
+            unop_op1.alloc_tag_equal(&mut cs.namespace(|| "op1_is_i64"), Op1::I64.to_field())?;
+        let op1_is_lognot = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_lognot"),
+            Op1::LogNot.to_field(),
+        )?;
+        let op1_is_char_to_u64 = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_char_to_u64"),
+            Op1::CharToU64.to_field(),
+        )?;
+        let op1_is_u64_to_char = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_u64_to_char"),
+            Op1::U64ToChar.to_field(),
+        )?;
+        let op1_is_symbol_to_string = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_symbol_to_string"),
+            Op1::SymbolToString.to_field(),
+        )?;
+        let op1_is_string_to_symbol = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_string_to_symbol"),
+            Op1::StringToSymbol.to_field(),
+        )?;
+        let op1_is_throw = unop_op1
+            .alloc_tag_equal(&mut cs.namespace(|| "op1_is_throw"), Op1::Throw.to_field())?;
+        let op1_is_vector_length = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_vector_length"),
+            Op1::VectorLength.to_field(),
+        )?;
+        let op1_is_string_length = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_string_length"),
+            Op1::StringLength.to_field(),
+        )?;
+        let op1_is_string_to_list = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_string_to_list"),
+            Op1::StringToList.to_field(),
+        )?;
+        let op1_is_list_to_string = unop_op1.alloc_tag_equal(
+            &mut cs.namespace(|| "op1_is_list_to_string"),
+            Op1::ListToString.to_field(),
+        )?;
 
         let tag_is_char = result.alloc_tag_equal(
             &mut cs.namespace(|| "result_is_char"),
@@ -4822,19 +7169,54 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &mut cs.namespace(|| "result_is_u64"),
             ExprTag::U64.to_field(),
         )?;
+        let tag_is_i64 = result.alloc_tag_equal(
+            &mut cs.namespace(|| "result_is_i64"),
+            ExprTag::I64.to_field(),
+        )?;
+        let tag_is_sym = result.alloc_tag_equal(
+            &mut cs.namespace(|| "result_is_sym"),
+            ExprTag::Sym.to_field(),
+        )?;
+        let result_is_root_sym =
+            alloc_is_zero(&mut cs.namespace(|| "result_is_root_sym"), result.hash())?;
 
         let tag_is_num_or_comm = or!(cs, &tag_is_num, &tag_is_comm)?;
         let tag_is_num_or_char = or!(cs, &tag_is_num, &tag_is_char)?;
+        let tag_is_num_or_i64 = or!(cs, &tag_is_num, &tag_is_i64)?;
+        let tag_is_u64_or_i64 = or!(cs, &tag_is_u64, &tag_is_i64)?;
+        let tag_is_num_or_u64_or_i64 = or!(cs, &tag_is_num, &tag_is_u64_or_i64)?;
         let tag_is_num_or_comm_or_char = or!(cs, &tag_is_num_or_comm, &tag_is_char)?;
-        let tag_is_num_or_comm_or_char_or_u64 = or!(cs, &tag_is_num_or_comm_or_char, &tag_is_u64)?;
+        let tag_is_num_or_comm_or_char_or_u64_or_i64 =
+            or!(cs, &tag_is_num_or_comm_or_char, &tag_is_u64_or_i64)?;
 
         let comm_invalid_tag_error = and!(cs, &tag_is_num_or_comm.not(), &op1_is_comm)?;
-        let num_invalid_tag_error =
-            and!(cs, &tag_is_num_or_comm_or_char_or_u64.not(), &op1_is_num)?;
+        let num_invalid_tag_error = and!(
+            cs,
+            &tag_is_num_or_comm_or_char_or_u64_or_i64.not(),
+            &op1_is_num
+        )?;
         let char_invalid_tag_error = and!(cs, &tag_is_num_or_char.not(), &op1_is_char)?;
         let open_invalid_tag_error = and!(cs, &tag_is_num_or_comm.not(), &op1_is_open)?;
         let secret_invalid_tag_error = and!(cs, &tag_is_num_or_comm.not(), &op1_is_secret)?;
-        let u64_invalid_tag_error = and!(cs, &op1_is_u64, &tag_is_num.not())?;
+        let u64_invalid_tag_error = and!(cs, &op1_is_u64, &tag_is_num_or_i64.not())?;
+        let i64_invalid_tag_error = and!(cs, &op1_is_i64, &tag_is_num_or_u64_or_i64.not())?;
+        let lognot_invalid_tag_error = and!(cs, &op1_is_lognot, &tag_is_u64.not())?;
+        let char_to_u64_invalid_tag_error = and!(cs, &op1_is_char_to_u64, &tag_is_char.not())?;
+        let u64_is_char = and!(cs, &tag_is_u64, &u64_fits_in_char)?;
+        let u64_to_char_invalid_error = and!(cs, &op1_is_u64_to_char, &u64_is_char.not())?;
+        let symbol_is_valid = and!(cs, &tag_is_sym, &result_is_root_sym.not())?;
+        let symbol_to_string_invalid_error =
+            and!(cs, &op1_is_symbol_to_string, &symbol_is_valid.not())?;
+        let string_to_symbol_invalid_tag_error =
+            and!(cs, &op1_is_string_to_symbol, &result_is_str.not())?;
+        let result_is_vec = result.is_vec(&mut cs.namespace(|| "result_is_vec"))?;
+        let vector_length_invalid_tag_error =
+            and!(cs, &op1_is_vector_length, &result_is_vec.not())?;
+        let op1_is_on_string = or!(cs, &op1_is_string_length, &op1_is_string_to_list)?;
+        let string_op_invalid_tag_error = and!(cs, &op1_is_on_string, &result_is_str.not())?;
+        let result_is_list = or!(cs, &result_is_cons, &result_is_nil)?;
+        let list_to_string_invalid_tag_error =
+            and!(cs, &op1_is_list_to_string, &result_is_list.not())?;
 
         let any_error = or!(
             cs,
@@ -4844,12 +7226,23 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &char_invalid_tag_error,
             &open_invalid_tag_error,
             &secret_invalid_tag_error,
-            &u64_invalid_tag_error
+            &u64_invalid_tag_error,
+            &i64_invalid_tag_error,
+            &lognot_invalid_tag_error,
+            &char_to_u64_invalid_tag_error,
+            &u64_to_char_invalid_error,
+            &symbol_to_string_invalid_error,
+            &string_to_symbol_invalid_tag_error,
+            &vector_length_invalid_tag_error,
+            &string_op_invalid_tag_error,
+            &list_to_string_invalid_tag_error
         )?;
 
         let the_expr = pick_ptr!(cs, &any_error, result, &unop_val)?;
+        let the_expr = pick_ptr!(cs, &unop_string_step, &unop_call, &the_expr)?;
 
-        let the_env = pick_ptr!(cs, &op1_is_eval, &g.nil_ptr, env)?;
+        let the_env_if_not_throw = pick_ptr!(cs, &op1_is_eval, &g.nil_ptr, env)?;
+        let the_env = pick_ptr!(cs, &op1_is_throw, &g.thrown_env_ptr, &the_env_if_not_throw)?;
 
         let the_cont = AllocatedContPtr::pick(
             &mut cs.namespace(|| "the_cont"),
@@ -4858,12 +7251,13 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
             &unop_continuation,
         )?;
 
-        let make_thunk_num = boolean_to_num(
-            &mut cs.namespace(|| "pick make_thunk_num"),
-            &op1_is_eval.not(),
-        )?;
+        // The call of a step of a string built-in is evaluated
+        let returns = or!(cs, &op1_is_eval, &unop_string_step)?;
+        let make_thunk_num =
+            boolean_to_num(&mut cs.namespace(|| "pick make_thunk_num"), &returns.not())?;
 
         let newer_cont2_not_dummy0 = and!(cs, &op1_is_emit, &any_error.not())?;
+        let newer_cont2_not_dummy0 = or!(cs, &newer_cont2_not_dummy0, &unop_string_step)?;
         let newer_cont2_not_dummy = boolean_num!(cs, &newer_cont2_not_dummy0)?;
 
         (
@@ -4917,10 +7311,78 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         g,
     )?;
 
-    let result_expr = AllocatedPtr::by_index(0, &case_results);
-    let result_env = AllocatedPtr::by_index(1, &case_results);
-    let result_cont = AllocatedContPtr::by_index(2, &case_results);
-    let make_thunk_num = case_results[6].clone();
+    // A skipped continuation passes the thrown value on to the one it returns to, except
+    // for the outermost one, which makes it an error
+    let parent0 = AllocatedContPtr::by_index(0, &continuation_components);
+    let parent1 = AllocatedContPtr::by_index(1, &continuation_components);
+    let parent2 = AllocatedContPtr::by_index(2, &continuation_components);
+    let parent3 = AllocatedContPtr::by_index(3, &continuation_components);
+    let skipped_to = multi_case(
+        &mut cs.namespace(|| "skipped_to multicase"),
+        cont.tag(),
+        &[
+            &[
+                CaseClause::new(ContTag::Emit.to_field(), parent0.tag()),
+                CaseClause::new(ContTag::Call0.to_field(), parent1.tag()),
+                CaseClause::new(ContTag::Tail.to_field(), parent1.tag()),
+                CaseClause::new(ContTag::Lookup.to_field(), parent1.tag()),
+                CaseClause::new(ContTag::Unop.to_field(), parent1.tag()),
+                CaseClause::new(ContTag::If.to_field(), parent1.tag()),
+                CaseClause::new(ContTag::Call.to_field(), parent2.tag()),
+                CaseClause::new(ContTag::Call2.to_field(), parent2.tag()),
+                CaseClause::new(ContTag::Binop2.to_field(), parent2.tag()),
+                CaseClause::new(ContTag::Binop.to_field(), parent3.tag()),
+                CaseClause::new(ContTag::Let.to_field(), parent3.tag()),
+                CaseClause::new(ContTag::LetRec.to_field(), parent3.tag()),
+                CaseClause::new(ContTag::Vector.to_field(), parent3.tag()),
+            ],
+            &[
+                CaseClause::new(ContTag::Emit.to_field(), parent0.hash()),
+                CaseClause::new(ContTag::Call0.to_field(), parent1.hash()),
+                CaseClause::new(ContTag::Tail.to_field(), parent1.hash()),
+                CaseClause::new(ContTag::Lookup.to_field(), parent1.hash()),
+                CaseClause::new(ContTag::Unop.to_field(), parent1.hash()),
+                CaseClause::new(ContTag::If.to_field(), parent1.hash()),
+                CaseClause::new(ContTag::Call.to_field(), parent2.hash()),
+                CaseClause::new(ContTag::Call2.to_field(), parent2.hash()),
+                CaseClause::new(ContTag::Binop2.to_field(), parent2.hash()),
+                CaseClause::new(ContTag::Binop.to_field(), parent3.hash()),
+                CaseClause::new(ContTag::Let.to_field(), parent3.hash()),
+                CaseClause::new(ContTag::LetRec.to_field(), parent3.hash()),
+                CaseClause::new(ContTag::Vector.to_field(), parent3.hash()),
+            ],
+        ],
+        &[g.error_ptr_cont.tag(), g.error_ptr_cont.hash()],
+        g,
+    )?;
+    let skipped_to = AllocatedContPtr::by_index(0, &skipped_to);
+    let skipped_to_parent = and!(cs, &cont_is_skipped, &cont_is_outermost.not())?;
+    let skipped_make_thunk_num = boolean_num!(cs, &skipped_to_parent)?;
+
+    let result_expr = AllocatedPtr::pick(
+        &mut cs.namespace(|| "result_expr"),
+        &cont_is_skipped,
+        result,
+        &AllocatedPtr::by_index(0, &case_results),
+    )?;
+    let result_env = AllocatedPtr::pick(
+        &mut cs.namespace(|| "result_env"),
+        &cont_is_skipped,
+        env,
+        &AllocatedPtr::by_index(1, &case_results),
+    )?;
+    let result_cont = AllocatedContPtr::pick(
+        &mut cs.namespace(|| "result_cont"),
+        &cont_is_skipped,
+        &skipped_to,
+        &AllocatedContPtr::by_index(2, &case_results),
+    )?;
+    let make_thunk_num = pick(
+        &mut cs.namespace(|| "make_thunk_num"),
+        &cont_is_skipped,
+        &skipped_make_thunk_num,
+        &case_results[6],
+    )?;
 
     // This is all clunky because we can't currently return AllocatedBit from case expressions.
     let newer_cont2_not_dummy_result_num = case_results[7].clone();
@@ -4934,7 +7396,70 @@ fn apply_continuation<F: LurkField, CS: ConstraintSystem<F>>(
         &newer_cont2_not_dummy_num
     );
 
-    Ok((result_expr, result_env, result_cont, make_thunk_num))
+    // A continuation that fails raises an error, named after the kind of continuation, which
+    // is then thrown to the continuation this one returns to
+    let case_cont_is_error = AllocatedContPtr::by_index(2, &case_results).alloc_tag_equal(
+        &mut cs.namespace(|| "case_cont_is_error"),
+        ContTag::Error.to_field(),
+    )?;
+    let raises = and!(
+        cs,
+        not_dummy,
+        &case_cont_is_error,
+        &cont_is_terminal.not(),
+        &cont_is_error.not()
+    )?;
+    let raised_key_hash = multi_case(
+        &mut cs.namespace(|| "raised_key multicase"),
+        cont.tag(),
+        &[&[
+            CaseClause::new(ContTag::Call0.to_field(), g.invalid_call_key.hash()),
+            CaseClause::new(ContTag::Call.to_field(), g.invalid_call_key.hash()),
+            CaseClause::new(ContTag::Call2.to_field(), g.invalid_call_key.hash()),
+            CaseClause::new(ContTag::If.to_field(), g.invalid_form_key.hash()),
+            CaseClause::new(ContTag::Binop.to_field(), g.invalid_form_key.hash()),
+        ]],
+        &[g.invalid_arg_key.hash()],
+        g,
+    )?;
+    let raised_key =
+        AllocatedPtr::from_parts(g.invalid_arg_key.tag().clone(), raised_key_hash[0].clone());
+    let cont_is_unop = cont.alloc_tag_equal(
+        &mut cs.namespace(|| "raised cont_is_unop"),
+        ContTag::Unop.to_field(),
+    )?;
+    let op1_is_car = AllocatedPtr::by_index(0, &continuation_components).alloc_tag_equal(
+        &mut cs.namespace(|| "raised op1_is_car"),
+        Op1::Car.to_field(),
+    )?;
+    let op1_is_cdr = AllocatedPtr::by_index(0, &continuation_components).alloc_tag_equal(
+        &mut cs.namespace(|| "raised op1_is_cdr"),
+        Op1::Cdr.to_field(),
+    )?;
+    let raised_by_car = and!(cs, &cont_is_unop, &op1_is_car)?;
+    let raised_by_cdr = and!(cs, &cont_is_unop, &op1_is_cdr)?;
+    let raised_key = AllocatedPtr::pick(
+        &mut cs.namespace(|| "raised_key car"),
+        &raised_by_car,
+        &g.car_of_non_list_key,
+        &raised_key,
+    )?;
+    let raised_key = AllocatedPtr::pick(
+        &mut cs.namespace(|| "raised_key cdr"),
+        &raised_by_cdr,
+        &g.cdr_of_non_list_key,
+        &raised_key,
+    )?;
+
+    Ok((
+        result_expr,
+        result_env,
+        result_cont,
+        make_thunk_num,
+        raises,
+        raised_key,
+        skipped_to,
+    ))
 }
 
 fn hide<F: LurkField, CS: ConstraintSystem<F>>(
@@ -5171,13 +7696,14 @@ fn to_unsigned_integer_helper<F: LurkField, CS: ConstraintSystem<F>>(
 }
 
 // Convert from num to unsigned integers by taking the least significant bits.
-// The output is a pair of allocated numbers, where the first one corresponds to
-// the u32 coercion, while the second corresponds to the u64 coercion.
+// The output is a triple, where the first allocated number corresponds to the
+// u32 coercion, the second one corresponds to the u64 coercion, and the last
+// element is the most significant bit of the u64 coercion (the sign of an i64).
 fn to_unsigned_integers<F: LurkField, CS: ConstraintSystem<F>>(
     mut cs: CS,
     g: &GlobalAllocations<F>,
     maybe_unsigned: &AllocatedNum<F>,
-) -> Result<(AllocatedNum<F>, AllocatedNum<F>), SynthesisError> {
+) -> Result<(AllocatedNum<F>, AllocatedNum<F>, Boolean), SynthesisError> {
     let field_elem = maybe_unsigned.get_value().unwrap_or(
         // dummy
         F::ZERO,
@@ -5204,7 +7730,7 @@ fn to_unsigned_integers<F: LurkField, CS: ConstraintSystem<F>>(
         UnsignedInt::U64,
     );
 
-    Ok((r32_num, r64_num))
+    Ok((r32_num, r64_num, field_elem_bits[63].clone()))
 }
 
 // Convert from num to u64.
@@ -5229,6 +7755,21 @@ fn to_u64<F: LurkField, CS: ConstraintSystem<F>>(
     Ok(r64_num)
 }
 
+// Convert from num to i64. Shifting by 2^63 maps the range of i64s onto the range
+// of u64s, so that the truncation to the least significant bits happens around
+// zero, and flipping the most significant bit shifts the result back.
+fn to_i64<F: LurkField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    g: &GlobalAllocations<F>,
+    maybe_i64: &AllocatedNum<F>,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let shifted = add(&mut cs.namespace(|| "shifted"), maybe_i64, &g.power2_63_num)?;
+    let shifted_bits = shifted.to_bits_le(&mut cs.namespace(|| "shifted bit decomp"))?;
+    let mut i64_bits = shifted_bits[0..64].to_vec();
+    i64_bits[63] = i64_bits[63].not();
+    pack(&mut cs.namespace(|| "i64 pack"), &i64_bits)
+}
+
 // Enforce div and mod operation for U64. We need to show that
 // arg1 = q * arg2 + r, such that 0 <= r < arg2.
 fn enforce_u64_div_mod<F: LurkField, CS: ConstraintSystem<F>>(
@@ -5743,9 +8284,9 @@ mod tests {
             assert!(delta == Delta::Equal);
 
             // println!("{}", print_cs(&cs));
-            assert_eq!(11823, cs.num_constraints());
+            assert_eq!(17039, cs.num_constraints());
             assert_eq!(13, cs.num_inputs());
-            assert_eq!(11479, cs.aux().len());
+            assert_eq!(16467, cs.aux().len());
 
             let public_inputs = multiframe.public_inputs();
             let mut rng = rand::thread_rng();
@@ -5988,7 +8529,7 @@ mod tests {
         let env = empty_sym_env(&store);
         let var = store.sym("a");
         let body = store.intern_list(&[var]);
-        let fun = store.intern_fun(var, body, env);
+        let fun = store.intern_fun(store.intern_list(&[var]), body, env);
 
         let input = IO {
             expr: fun,
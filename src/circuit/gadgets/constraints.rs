@@ -173,7 +173,7 @@ pub(crate) fn implies_u64<F: LurkField, CS: ConstraintSystem<F>>(
     mut cs: CS,
     premise: &Boolean,
     a: &AllocatedNum<F>,
-) -> Result<(), SynthesisError> {
+) -> Result<Vec<Boolean>, SynthesisError> {
     let mut a_u64 = a.get_value().and_then(|a| a.to_u64()).unwrap_or(0);
 
     let mut bits: Vec<Boolean> = Vec::with_capacity(64);
@@ -196,7 +196,7 @@ pub(crate) fn implies_u64<F: LurkField, CS: ConstraintSystem<F>>(
         a,
     );
 
-    Ok(())
+    Ok(bits)
 }
 
 /// If premise is true, enforce v is the bit decomposition of num, therefore we have that 0 <= num < 2ˆ(sizeof(v)).
@@ -228,6 +228,127 @@ pub(crate) fn enforce_pack<F: LurkField, CS: ConstraintSystem<F>>(
     implies_pack(cs, &Boolean::Constant(true), v, num)
 }
 
+/// Allocate the number whose bit decomposition is v and enforce it.
+pub(crate) fn pack<F: LurkField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    v: &[Boolean],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let num = AllocatedNum::alloc(cs.namespace(|| "num"), || {
+        v.iter().rev().try_fold(F::ZERO, |acc, b| {
+            let b = b.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+            Ok(if b {
+                acc.double() + F::ONE
+            } else {
+                acc.double()
+            })
+        })
+    })?;
+    enforce_pack(cs.namespace(|| "pack"), v, &num);
+
+    Ok(num)
+}
+
+/// Compute the u64 whose bits are the conjunction of the bit decompositions a and b.
+pub(crate) fn u64_and<F: LurkField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &[Boolean],
+    b: &[Boolean],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let bits = a
+        .iter()
+        .zip(b)
+        .enumerate()
+        .map(|(i, (a, b))| Boolean::and(cs.namespace(|| format!("and.{i}")), a, b))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    pack(cs.namespace(|| "u64 and"), &bits)
+}
+
+/// Shift the u64 a by the u64 whose bit decomposition is b, to the right if `right` is true and to the
+/// left otherwise. Shifting by 64 or more results in 0. If premise is true, a must be a u64, which is left
+/// for the caller to enforce.
+pub(crate) fn implies_u64_shift<F: LurkField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    premise: &Boolean,
+    right: &Boolean,
+    a: &AllocatedNum<F>,
+    b: &[Boolean],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    // Shifting right by n is taking the upper 64 bits of a shift left by 63 - n, whose 6 bits are those of n
+    // flipped. Either way, the shifted number fits in 127 bits.
+    let exponent = b[..6]
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| Boolean::xor(cs.namespace(|| format!("exponent.{i}")), bit, right))
+        .collect::<Result<Vec<_>, _>>()?;
+    let factors = exponent
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            pick_const(
+                cs.namespace(|| format!("factor.{i}")),
+                bit,
+                F::from(1u64 << (1 << i)),
+                F::ONE,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut power = factors[0].clone();
+    for (i, factor) in factors.iter().enumerate().skip(1) {
+        power = mul(cs.namespace(|| format!("power.{i}")), &power, factor)?;
+    }
+    let shifted = mul(cs.namespace(|| "shifted"), a, &power)?;
+
+    let a_u128 = a.get_value().and_then(|a| a.to_u64()).map(u128::from);
+    let exponent_u32 = exponent.iter().rev().try_fold(0, |acc, bit| {
+        bit.get_value().map(|bit| 2 * acc + u32::from(bit))
+    });
+    let shifted_u128 = a_u128.zip(exponent_u32).map(|(a, e)| a << e).unwrap_or(0);
+    let mut bits = Vec::with_capacity(127);
+    for i in 0..127 {
+        bits.push(Boolean::Is(AllocatedBit::alloc(
+            &mut cs.namespace(|| format!("b.{i}")),
+            Some((shifted_u128 >> i) & 1 == 1),
+        )?));
+    }
+
+    // premise -> shifted = sum(bits)
+    implies_pack(
+        &mut cs.namespace(|| "shifted bit decomposition check"),
+        premise,
+        &bits,
+        &shifted,
+    );
+
+    let lower = pack(cs.namespace(|| "lower"), &bits[..64])?;
+    let upper = pack(cs.namespace(|| "upper"), &bits[63..])?;
+    let picked = pick(cs.namespace(|| "pick"), right, &upper, &lower)?;
+
+    let b_upper_bits = b[6..].iter().collect::<Vec<_>>();
+    let too_far = or_v_unchecked_for_optimization(cs.namespace(|| "too far"), &b_upper_bits)?;
+
+    let res = AllocatedNum::alloc(cs.namespace(|| "res"), || {
+        if too_far
+            .get_value()
+            .ok_or(SynthesisError::AssignmentMissing)?
+        {
+            Ok(F::ZERO)
+        } else {
+            picked.get_value().ok_or(SynthesisError::AssignmentMissing)
+        }
+    })?;
+
+    // picked * (1 - too_far) = res
+    cs.enforce(
+        || "shift result",
+        |lc| lc + picked.get_variable(),
+        |_| too_far.not().lc(CS::one(), F::ONE),
+        |lc| lc + res.get_variable(),
+    );
+
+    Ok(res)
+}
+
 /// Adds a constraint to CS, enforcing a difference relationship between the allocated numbers a, b, and difference.
 ///
 /// a - b = difference
@@ -1482,5 +1603,46 @@ mod tests {
             let was_u64 = f_u64_roundtrip == f.0;
             prop_assert_eq!(was_u64, cs.is_satisfied());
         }
+
+        #[test]
+        fn test_u64_and((a, b) in any::<(u64, u64)>()) {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_num = AllocatedNum::alloc_infallible(cs.namespace(|| "a"), || Fr::from(a));
+            let b_num = AllocatedNum::alloc_infallible(cs.namespace(|| "b"), || Fr::from(b));
+
+            let t = Boolean::Constant(true);
+            let a_bits = implies_u64(&mut cs.namespace(|| "a_u64"), &t, &a_num).unwrap();
+            let b_bits = implies_u64(&mut cs.namespace(|| "b_u64"), &t, &b_num).unwrap();
+            let res = u64_and(&mut cs.namespace(|| "and"), &a_bits, &b_bits).unwrap();
+
+            prop_assert!(cs.is_satisfied());
+            prop_assert_eq!(res.get_value(), Some(Fr::from(a & b)));
+        }
+
+        #[test]
+        fn test_implies_u64_shift(a in any::<u64>(), b in prop_oneof![0u64..70, any::<u64>()], right in any::<bool>()) {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_num = AllocatedNum::alloc_infallible(cs.namespace(|| "a"), || Fr::from(a));
+            let b_num = AllocatedNum::alloc_infallible(cs.namespace(|| "b"), || Fr::from(b));
+            let right_bit = Boolean::from(
+                AllocatedBit::alloc(cs.namespace(|| "right"), Some(right)).unwrap(),
+            );
+
+            let t = Boolean::Constant(true);
+            let b_bits = implies_u64(&mut cs.namespace(|| "b_u64"), &t, &b_num).unwrap();
+            let res =
+                implies_u64_shift(&mut cs.namespace(|| "shift"), &t, &right_bit, &a_num, &b_bits)
+                    .unwrap();
+
+            let expected = match (b < 64, right) {
+                (false, _) => 0,
+                (true, true) => a >> b,
+                (true, false) => a << b,
+            };
+            prop_assert!(cs.is_satisfied());
+            prop_assert_eq!(res.get_value(), Some(Fr::from(expected)));
+        }
     }
 }
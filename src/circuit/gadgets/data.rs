@@ -19,22 +19,27 @@ use super::pointer::{AllocatedContPtr, AllocatedPtr};
 #[derive(Clone, Debug)]
 pub struct GlobalAllocations<F: LurkField> {
     pub terminal_ptr: AllocatedContPtr<F>,
+    pub outermost_ptr: AllocatedContPtr<F>,
     pub error_ptr_cont: AllocatedContPtr<F>,
     pub error_ptr: AllocatedPtr<F>,
     pub dummy_ptr: AllocatedContPtr<F>,
     pub nil_ptr: AllocatedPtr<F>,
     pub t_ptr: AllocatedPtr<F>,
-    pub dummy_arg_ptr: AllocatedPtr<F>,
     pub empty_str_ptr: AllocatedPtr<F>,
+    pub thrown_env_ptr: AllocatedPtr<F>,
+    pub empty_vec_ptr: AllocatedPtr<F>,
 
     pub thunk_tag: AllocatedNum<F>,
     pub cons_tag: AllocatedNum<F>,
     pub char_tag: AllocatedNum<F>,
     pub str_tag: AllocatedNum<F>,
+    pub sym_tag: AllocatedNum<F>,
     pub num_tag: AllocatedNum<F>,
     pub u64_tag: AllocatedNum<F>,
+    pub i64_tag: AllocatedNum<F>,
     pub comm_tag: AllocatedNum<F>,
     pub fun_tag: AllocatedNum<F>,
+    pub vec_tag: AllocatedNum<F>,
     pub let_cont_tag: AllocatedNum<F>,
     pub letrec_cont_tag: AllocatedNum<F>,
     pub outermost_cont_tag: AllocatedNum<F>,
@@ -48,6 +53,8 @@ pub struct GlobalAllocations<F: LurkField> {
     pub binop_cont_tag: AllocatedNum<F>,
     pub binop2_cont_tag: AllocatedNum<F>,
     pub if_cont_tag: AllocatedNum<F>,
+    pub vector_cont_tag: AllocatedNum<F>,
+    pub fuel_cont_tag: AllocatedNum<F>,
 
     pub op1_car_tag: AllocatedNum<F>,
     pub op1_cdr_tag: AllocatedNum<F>,
@@ -55,11 +62,22 @@ pub struct GlobalAllocations<F: LurkField> {
     pub op1_num_tag: AllocatedNum<F>,
     pub op1_char_tag: AllocatedNum<F>,
     pub op1_u64_tag: AllocatedNum<F>,
+    pub op1_i64_tag: AllocatedNum<F>,
     pub op1_comm_tag: AllocatedNum<F>,
     pub op1_open_tag: AllocatedNum<F>,
     pub op1_secret_tag: AllocatedNum<F>,
     pub op1_atom_tag: AllocatedNum<F>,
     pub op1_emit_tag: AllocatedNum<F>,
+    pub op1_lognot_tag: AllocatedNum<F>,
+    pub op1_char_to_u64_tag: AllocatedNum<F>,
+    pub op1_u64_to_char_tag: AllocatedNum<F>,
+    pub op1_symbol_to_string_tag: AllocatedNum<F>,
+    pub op1_string_to_symbol_tag: AllocatedNum<F>,
+    pub op1_throw_tag: AllocatedNum<F>,
+    pub op1_vector_length_tag: AllocatedNum<F>,
+    pub op1_string_length_tag: AllocatedNum<F>,
+    pub op1_string_to_list_tag: AllocatedNum<F>,
+    pub op1_list_to_string_tag: AllocatedNum<F>,
     pub op2_cons_tag: AllocatedNum<F>,
     pub op2_strcons_tag: AllocatedNum<F>,
     pub op2_hide_tag: AllocatedNum<F>,
@@ -75,14 +93,45 @@ pub struct GlobalAllocations<F: LurkField> {
     pub op2_less_equal_tag: AllocatedNum<F>,
     pub op2_greater_tag: AllocatedNum<F>,
     pub op2_greater_equal_tag: AllocatedNum<F>,
+    pub op2_logand_tag: AllocatedNum<F>,
+    pub op2_logior_tag: AllocatedNum<F>,
+    pub op2_logxor_tag: AllocatedNum<F>,
+    pub op2_shift_left_tag: AllocatedNum<F>,
+    pub op2_shift_right_tag: AllocatedNum<F>,
+    pub op2_catch_tag: AllocatedNum<F>,
+    pub op2_list_tag: AllocatedNum<F>,
+    pub op2_make_vector_tag: AllocatedNum<F>,
+    pub op2_vector_ref_tag: AllocatedNum<F>,
+    pub op2_vector_set_tag: AllocatedNum<F>,
+    pub op2_eval_with_limit_tag: AllocatedNum<F>,
+    pub op2_string_append_tag: AllocatedNum<F>,
+    pub op2_substring_tag: AllocatedNum<F>,
 
     pub lambda_sym: AllocatedPtr<F>,
+    pub list_sym: AllocatedPtr<F>,
+    pub rest_sym: AllocatedPtr<F>,
     pub quote_ptr: AllocatedPtr<F>,
+    pub user_package_ptr: AllocatedPtr<F>,
+    pub ok_key: AllocatedPtr<F>,
+    pub out_of_fuel_key: AllocatedPtr<F>,
+    pub error_key: AllocatedPtr<F>,
+    pub unbound_var_key: AllocatedPtr<F>,
+    pub invalid_form_key: AllocatedPtr<F>,
+    pub invalid_call_key: AllocatedPtr<F>,
+    pub invalid_arg_key: AllocatedPtr<F>,
+    pub car_of_non_list_key: AllocatedPtr<F>,
+    pub cdr_of_non_list_key: AllocatedPtr<F>,
+    pub string_length_sym: AllocatedPtr<F>,
+    pub string_to_list_sym: AllocatedPtr<F>,
+    pub list_to_string_sym: AllocatedPtr<F>,
+    pub string_append_sym: AllocatedPtr<F>,
+    pub substring_sym: AllocatedPtr<F>,
 
     pub true_num: AllocatedNum<F>,
     pub false_num: AllocatedNum<F>,
     pub default_num: AllocatedNum<F>,
     pub power2_32_num: AllocatedNum<F>,
+    pub power2_63_num: AllocatedNum<F>,
     pub power2_64_num: AllocatedNum<F>,
 }
 
@@ -97,6 +146,12 @@ impl<F: LurkField> GlobalAllocations<F> {
             &store.get_cont_terminal(),
         )?;
 
+        let outermost_ptr = AllocatedContPtr::alloc_constant_cont_ptr(
+            &mut cs.namespace(|| "outermost continuation"),
+            store,
+            &store.get_cont_outermost(),
+        )?;
+
         let error_ptr_cont = AllocatedContPtr::alloc_constant_cont_ptr(
             &mut cs.namespace(|| "error continuation"),
             store,
@@ -117,14 +172,29 @@ impl<F: LurkField> GlobalAllocations<F> {
             &store.strnil(),
         )?;
 
+        let thrown_env_ptr = AllocatedPtr::alloc_constant_ptr(
+            &mut cs.namespace(|| "thrown_env_ptr"),
+            store,
+            &store.intern_thrown_env(),
+        )?;
+
+        let empty_vec_ptr = AllocatedPtr::alloc_constant_ptr(
+            &mut cs.namespace(|| "empty_vec_ptr"),
+            store,
+            &store.intern_vec(&[]),
+        )?;
+
         let thunk_tag = ExprTag::Thunk.allocate_constant(&mut cs.namespace(|| "thunk_tag"));
         let cons_tag = ExprTag::Cons.allocate_constant(&mut cs.namespace(|| "cons_tag"));
         let char_tag = ExprTag::Char.allocate_constant(&mut cs.namespace(|| "char_tag"));
         let str_tag = ExprTag::Str.allocate_constant(&mut cs.namespace(|| "str_tag"));
+        let sym_tag = ExprTag::Sym.allocate_constant(&mut cs.namespace(|| "sym_tag"));
         let num_tag = ExprTag::Num.allocate_constant(&mut cs.namespace(|| "num_tag"));
         let u64_tag = ExprTag::U64.allocate_constant(&mut cs.namespace(|| "u64_tag"));
+        let i64_tag = ExprTag::I64.allocate_constant(&mut cs.namespace(|| "i64_tag"));
         let comm_tag = ExprTag::Comm.allocate_constant(&mut cs.namespace(|| "comm_tag"));
         let fun_tag = ExprTag::Fun.allocate_constant(&mut cs.namespace(|| "fun_tag"));
+        let vec_tag = ExprTag::Vec.allocate_constant(&mut cs.namespace(|| "vec_tag"));
 
         let outermost_cont_tag =
             ContTag::Outermost.allocate_constant(&mut cs.namespace(|| "outermost_cont_tag"));
@@ -146,6 +216,9 @@ impl<F: LurkField> GlobalAllocations<F> {
         let binop2_cont_tag =
             ContTag::Binop2.allocate_constant(&mut cs.namespace(|| "binop2_cont_tag"));
         let if_cont_tag = ContTag::If.allocate_constant(&mut cs.namespace(|| "if_cont_tag"));
+        let vector_cont_tag =
+            ContTag::Vector.allocate_constant(&mut cs.namespace(|| "vector_cont_tag"));
+        let fuel_cont_tag = ContTag::Fuel.allocate_constant(&mut cs.namespace(|| "fuel_cont_tag"));
 
         let op1_car_tag = Op1::Car.allocate_constant(&mut cs.namespace(|| "op1_car_tag"));
         let op1_cdr_tag = Op1::Cdr.allocate_constant(&mut cs.namespace(|| "op1_cdr_tag"));
@@ -153,11 +226,30 @@ impl<F: LurkField> GlobalAllocations<F> {
         let op1_num_tag = Op1::Num.allocate_constant(&mut cs.namespace(|| "op1_num_tag"));
         let op1_char_tag = Op1::Char.allocate_constant(&mut cs.namespace(|| "op1_char_tag"));
         let op1_u64_tag = Op1::U64.allocate_constant(&mut cs.namespace(|| "op1_u64_tag"));
+        let op1_i64_tag = Op1::I64.allocate_constant(&mut cs.namespace(|| "op1_i64_tag"));
         let op1_comm_tag = Op1::Comm.allocate_constant(&mut cs.namespace(|| "op1_comm_tag"));
         let op1_open_tag = Op1::Open.allocate_constant(&mut cs.namespace(|| "op1_open_tag"));
         let op1_secret_tag = Op1::Secret.allocate_constant(&mut cs.namespace(|| "op1_secret_tag"));
         let op1_atom_tag = Op1::Atom.allocate_constant(&mut cs.namespace(|| "op1_atom_tag"));
         let op1_emit_tag = Op1::Emit.allocate_constant(&mut cs.namespace(|| "op1_emit_tag"));
+        let op1_lognot_tag = Op1::LogNot.allocate_constant(&mut cs.namespace(|| "op1_lognot_tag"));
+        let op1_char_to_u64_tag =
+            Op1::CharToU64.allocate_constant(&mut cs.namespace(|| "op1_char_to_u64_tag"));
+        let op1_u64_to_char_tag =
+            Op1::U64ToChar.allocate_constant(&mut cs.namespace(|| "op1_u64_to_char_tag"));
+        let op1_symbol_to_string_tag =
+            Op1::SymbolToString.allocate_constant(&mut cs.namespace(|| "op1_symbol_to_string_tag"));
+        let op1_string_to_symbol_tag =
+            Op1::StringToSymbol.allocate_constant(&mut cs.namespace(|| "op1_string_to_symbol_tag"));
+        let op1_throw_tag = Op1::Throw.allocate_constant(&mut cs.namespace(|| "op1_throw_tag"));
+        let op1_vector_length_tag =
+            Op1::VectorLength.allocate_constant(&mut cs.namespace(|| "op1_vector_length_tag"));
+        let op1_string_length_tag =
+            Op1::StringLength.allocate_constant(&mut cs.namespace(|| "op1_string_length_tag"));
+        let op1_string_to_list_tag =
+            Op1::StringToList.allocate_constant(&mut cs.namespace(|| "op1_string_to_list_tag"));
+        let op1_list_to_string_tag =
+            Op1::ListToString.allocate_constant(&mut cs.namespace(|| "op1_list_to_string_tag"));
         let op2_cons_tag = Op2::Cons.allocate_constant(&mut cs.namespace(|| "op2_cons_tag"));
         let op2_strcons_tag =
             Op2::StrCons.allocate_constant(&mut cs.namespace(|| "op2_strcons_tag"));
@@ -186,6 +278,27 @@ impl<F: LurkField> GlobalAllocations<F> {
             AllocatedNum::alloc_infallible(&mut cs.namespace(|| "op2_equal_tag"), || {
                 Op2::Equal.to_field()
             });
+        let op2_logand_tag = Op2::LogAnd.allocate_constant(&mut cs.namespace(|| "op2_logand_tag"));
+        let op2_logior_tag = Op2::LogIor.allocate_constant(&mut cs.namespace(|| "op2_logior_tag"));
+        let op2_logxor_tag = Op2::LogXor.allocate_constant(&mut cs.namespace(|| "op2_logxor_tag"));
+        let op2_shift_left_tag =
+            Op2::ShiftLeft.allocate_constant(&mut cs.namespace(|| "op2_shift_left_tag"));
+        let op2_shift_right_tag =
+            Op2::ShiftRight.allocate_constant(&mut cs.namespace(|| "op2_shift_right_tag"));
+        let op2_catch_tag = Op2::Catch.allocate_constant(&mut cs.namespace(|| "op2_catch_tag"));
+        let op2_list_tag = Op2::List.allocate_constant(&mut cs.namespace(|| "op2_list_tag"));
+        let op2_make_vector_tag =
+            Op2::MakeVector.allocate_constant(&mut cs.namespace(|| "op2_make_vector_tag"));
+        let op2_vector_ref_tag =
+            Op2::VectorRef.allocate_constant(&mut cs.namespace(|| "op2_vector_ref_tag"));
+        let op2_vector_set_tag =
+            Op2::VectorSet.allocate_constant(&mut cs.namespace(|| "op2_vector_set_tag"));
+        let op2_eval_with_limit_tag =
+            Op2::EvalWithLimit.allocate_constant(&mut cs.namespace(|| "op2_eval_with_limit_tag"));
+        let op2_string_append_tag =
+            Op2::StringAppend.allocate_constant(&mut cs.namespace(|| "op2_string_append_tag"));
+        let op2_substring_tag =
+            Op2::Substring.allocate_constant(&mut cs.namespace(|| "op2_substring_tag"));
 
         let c = store.expect_constants();
 
@@ -198,9 +311,25 @@ impl<F: LurkField> GlobalAllocations<F> {
 
         defsym!(nil_ptr, "nil", nil);
         defsym!(t_ptr, "t", t);
-        defsym!(dummy_arg_ptr, "_", dummy);
         defsym!(lambda_sym, "lambda", lambda);
+        defsym!(list_sym, "list", list);
+        defsym!(rest_sym, "&rest", rest);
         defsym!(quote_ptr, "quote", quote);
+        defsym!(user_package_ptr, "user", user_package);
+        defsym!(ok_key, ":ok", ok);
+        defsym!(out_of_fuel_key, ":out-of-fuel", out_of_fuel);
+        defsym!(error_key, ":error", error);
+        defsym!(unbound_var_key, ":unbound-var", unbound_var);
+        defsym!(invalid_form_key, ":invalid-form", invalid_form);
+        defsym!(invalid_call_key, ":invalid-call", invalid_call);
+        defsym!(invalid_arg_key, ":invalid-arg", invalid_arg);
+        defsym!(car_of_non_list_key, ":car-of-non-list", car_of_non_list);
+        defsym!(cdr_of_non_list_key, ":cdr-of-non-list", cdr_of_non_list);
+        defsym!(string_length_sym, "string-length", string_length);
+        defsym!(string_to_list_sym, "string->list", string_to_list);
+        defsym!(list_to_string_sym, "list->string", list_to_string);
+        defsym!(string_append_sym, "string-append", string_append);
+        defsym!(substring_sym, "substring", substring);
 
         let true_num = allocate_constant(&mut cs.namespace(|| "true"), F::ONE);
         let false_num = allocate_constant(&mut cs.namespace(|| "false"), F::ZERO);
@@ -209,26 +338,34 @@ impl<F: LurkField> GlobalAllocations<F> {
         let power2_32_ff = F::pow_vartime(&F::from_u64(2), [32]);
         let power2_32_num = allocate_constant(&mut cs.namespace(|| "pow(2,32)"), power2_32_ff);
 
+        let power2_63_ff = F::pow_vartime(&F::from_u64(2), [63]);
+        let power2_63_num = allocate_constant(&mut cs.namespace(|| "pow(2,63)"), power2_63_ff);
+
         let power2_64_ff = F::pow_vartime(&F::from_u64(2), [64]);
         let power2_64_num = allocate_constant(&mut cs.namespace(|| "pow(2,64)"), power2_64_ff);
 
         Ok(Self {
             terminal_ptr,
+            outermost_ptr,
             error_ptr_cont,
             error_ptr,
             dummy_ptr,
             nil_ptr,
             t_ptr,
-            dummy_arg_ptr,
             empty_str_ptr,
+            thrown_env_ptr,
+            empty_vec_ptr,
             thunk_tag,
             cons_tag,
             char_tag,
             str_tag,
+            sym_tag,
             num_tag,
             u64_tag,
+            i64_tag,
             comm_tag,
             fun_tag,
+            vec_tag,
             outermost_cont_tag,
             lookup_cont_tag,
             let_cont_tag,
@@ -242,17 +379,30 @@ impl<F: LurkField> GlobalAllocations<F> {
             binop_cont_tag,
             binop2_cont_tag,
             if_cont_tag,
+            vector_cont_tag,
+            fuel_cont_tag,
             op1_car_tag,
             op1_cdr_tag,
             op1_commit_tag,
             op1_num_tag,
             op1_char_tag,
             op1_u64_tag,
+            op1_i64_tag,
             op1_comm_tag,
             op1_open_tag,
             op1_secret_tag,
             op1_atom_tag,
             op1_emit_tag,
+            op1_lognot_tag,
+            op1_char_to_u64_tag,
+            op1_u64_to_char_tag,
+            op1_symbol_to_string_tag,
+            op1_string_to_symbol_tag,
+            op1_throw_tag,
+            op1_vector_length_tag,
+            op1_string_length_tag,
+            op1_string_to_list_tag,
+            op1_list_to_string_tag,
             op2_cons_tag,
             op2_strcons_tag,
             op2_hide_tag,
@@ -268,12 +418,43 @@ impl<F: LurkField> GlobalAllocations<F> {
             op2_less_equal_tag,
             op2_greater_tag,
             op2_greater_equal_tag,
+            op2_logand_tag,
+            op2_logior_tag,
+            op2_logxor_tag,
+            op2_shift_left_tag,
+            op2_shift_right_tag,
+            op2_catch_tag,
+            op2_list_tag,
+            op2_make_vector_tag,
+            op2_vector_ref_tag,
+            op2_vector_set_tag,
+            op2_eval_with_limit_tag,
+            op2_string_append_tag,
+            op2_substring_tag,
             lambda_sym,
+            list_sym,
+            rest_sym,
             quote_ptr,
+            user_package_ptr,
+            ok_key,
+            out_of_fuel_key,
+            error_key,
+            unbound_var_key,
+            invalid_form_key,
+            invalid_call_key,
+            invalid_arg_key,
+            car_of_non_list_key,
+            cdr_of_non_list_key,
+            string_length_sym,
+            string_to_list_sym,
+            list_to_string_sym,
+            string_append_sym,
+            substring_sym,
             true_num,
             false_num,
             default_num,
             power2_32_num,
+            power2_63_num,
             power2_64_num,
         })
     }
@@ -188,6 +188,7 @@ impl<'a, F: LurkField> HashConst<'a, F> {
             }};
         }
         match self {
+            HashConst::A2(c) => hash!(c),
             HashConst::A3(c) => hash!(c),
             HashConst::A4(c) => hash!(c),
             HashConst::A6(c) => hash!(c),
@@ -204,6 +205,7 @@ impl<'a, F: LurkField> HashConst<'a, F> {
             }};
         }
         match self {
+            HashConst::A2(c) => hash!(c),
             HashConst::A3(c) => hash!(c),
             HashConst::A4(c) => hash!(c),
             HashConst::A6(c) => hash!(c),
@@ -260,6 +262,7 @@ impl<'a, F: LurkField> HashConst<'a, F> {
             };
         }
         match self {
+            HashConst::A2(c) => hash!(c),
             HashConst::A3(c) => hash!(c),
             HashConst::A4(c) => hash!(c),
             HashConst::A6(c) => hash!(c),
@@ -12,7 +12,7 @@ use crate::{
     ptr::{ContPtr, Ptr},
     state::initial_lurk_state,
     store::Store,
-    tag::{ExprTag, Tag},
+    tag::{ContTag, ExprTag, Tag},
     writer::Write,
     z_ptr::{ZContPtr, ZExprPtr, ZPtr},
 };
@@ -225,6 +225,9 @@ impl<F: LurkField> AllocatedPtr<F> {
     pub fn is_u64<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Boolean, SynthesisError> {
         self.alloc_tag_equal(&mut cs.namespace(|| "is_u64"), ExprTag::U64.to_field())
     }
+    pub fn is_i64<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Boolean, SynthesisError> {
+        self.alloc_tag_equal(&mut cs.namespace(|| "is_i64"), ExprTag::I64.to_field())
+    }
     pub fn is_char<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Boolean, SynthesisError> {
         self.alloc_tag_equal(&mut cs.namespace(|| "is_char"), ExprTag::Char.to_field())
     }
@@ -237,6 +240,9 @@ impl<F: LurkField> AllocatedPtr<F> {
     pub fn is_fun<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Boolean, SynthesisError> {
         self.alloc_tag_equal(&mut cs.namespace(|| "is_fun"), ExprTag::Fun.to_field())
     }
+    pub fn is_vec<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<Boolean, SynthesisError> {
+        self.alloc_tag_equal(&mut cs.namespace(|| "is_vec"), ExprTag::Vec.to_field())
+    }
     pub fn is_thunk<CS: ConstraintSystem<F>>(
         &self,
         cs: &mut CS,
@@ -680,6 +686,76 @@ impl<F: LurkField> AllocatedContPtr<F> {
         store.fetch_cont(&ptr)
     }
 
+    /// Allocates the components of the `Fuel` continuation this points to, or dummies if it
+    /// isn't one, and hashes them.
+    /// NOTE: this allocation is unconstrained, so the hash must be checked against `self.hash()`.
+    #[allow(clippy::type_complexity)]
+    pub fn allocate_fuel_components_unconstrained<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        store: &Store<F>,
+    ) -> Result<
+        (
+            AllocatedNum<F>,
+            AllocatedContPtr<F>,
+            AllocatedPtr<F>,
+            AllocatedPtr<F>,
+            AllocatedContPtr<F>,
+        ),
+        SynthesisError,
+    > {
+        let dummy_expr = ZExprPtr::from_parts(ExprTag::Nil, F::ZERO);
+        let dummy_cont = ZContPtr::from_parts(ContTag::Dummy, F::ZERO);
+        let (inner, fuel, saved_env, continuation) = match self.get_cont(store) {
+            Some(Continuation::Fuel {
+                inner,
+                fuel,
+                saved_env,
+                continuation,
+            }) => (
+                store.hash_cont(&inner),
+                store.hash_expr(&fuel),
+                store.hash_expr(&saved_env),
+                store.hash_cont(&continuation),
+            ),
+            _ => (
+                Some(dummy_cont),
+                Some(dummy_expr),
+                Some(dummy_expr),
+                Some(dummy_cont),
+            ),
+        };
+
+        let inner = AllocatedContPtr::alloc(&mut cs.namespace(|| "Fuel component: inner"), || {
+            inner.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let fuel = AllocatedPtr::alloc(&mut cs.namespace(|| "Fuel component: fuel"), || {
+            fuel.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let saved_env =
+            AllocatedPtr::alloc(&mut cs.namespace(|| "Fuel component: saved_env"), || {
+                saved_env.ok_or(SynthesisError::AssignmentMissing)
+            })?;
+        let continuation =
+            AllocatedContPtr::alloc(&mut cs.namespace(|| "Fuel component: continuation"), || {
+                continuation.ok_or(SynthesisError::AssignmentMissing)
+            })?;
+
+        let components: [&dyn AsAllocatedHashComponents<F>; 4] =
+            [&inner, &fuel, &saved_env, &continuation];
+        let hash = hash_poseidon(
+            cs.namespace(|| "Fuel"),
+            components
+                .iter()
+                .flat_map(|c| c.as_allocated_hash_components())
+                .cloned()
+                .collect(),
+            store.poseidon_constants().c8(),
+        )?;
+
+        Ok((hash, inner, fuel, saved_env, continuation))
+    }
+
     pub fn get_cont_ptr(&self, store: &Store<F>) -> Option<ContPtr<F>> {
         let z_ptr = self.get_z_ptr_cont(store)?;
         store.fetch_z_cont_ptr(&z_ptr)
@@ -5,6 +5,7 @@ use crate::field::LanguageField;
 pub enum Backend {
     Nova,
     SnarkPackPlus,
+    SuperNova,
 }
 
 impl std::fmt::Display for Backend {
@@ -12,6 +13,7 @@ impl std::fmt::Display for Backend {
         match self {
             Self::Nova => write!(f, "Nova"),
             Self::SnarkPackPlus => write!(f, "SnarkPack+"),
+            Self::SuperNova => write!(f, "SuperNova"),
         }
     }
 }
@@ -19,7 +21,7 @@ impl std::fmt::Display for Backend {
 impl Backend {
     pub(crate) fn default_field(&self) -> LanguageField {
         match self {
-            Self::Nova => LanguageField::Pallas,
+            Self::Nova | Self::SuperNova => LanguageField::Pallas,
             Self::SnarkPackPlus => LanguageField::BLS12_381,
         }
     }
@@ -27,7 +29,7 @@ impl Backend {
     fn compatible_fields(&self) -> Vec<LanguageField> {
         use LanguageField::{Pallas, Vesta, BLS12_381};
         match self {
-            Self::Nova => vec![Pallas, Vesta],
+            Self::Nova | Self::SuperNova => vec![Pallas, Vesta],
             Self::SnarkPackPlus => vec![BLS12_381],
         }
     }
@@ -0,0 +1,245 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    coprocessor::{
+        blake2s::Blake2sCoprocessor,
+        digest::DigestOutput,
+        keccak::KeccakCoprocessor,
+        merkle::MerkleCoprocessor,
+        schnorr::SchnorrCoprocessor,
+        sha256::Sha256Coprocessor,
+        trie::{InsertCoprocessor, LookupCoprocessor, NewCoprocessor},
+    },
+    eval::lang::{Coproc, Lang},
+    field::LurkField,
+    state::State,
+    store::Store,
+    Symbol,
+};
+
+/// The names of the coprocessors that can be installed in the REPL
+const BUILTIN_NAMES: [&str; 8] = [
+    "trie-new",
+    "trie-lookup",
+    "trie-insert",
+    "sha256",
+    "keccak",
+    "blake2s",
+    "schnorr",
+    "merkle",
+];
+
+/// Returns the built-in coprocessor called `name`, configured with `params`,
+/// along with the path of the symbol it's bound to by default
+fn builtin<F: LurkField>(
+    name: &str,
+    params: &[usize],
+) -> Result<(Coproc<F>, &'static [&'static str])> {
+    match (name, params) {
+        ("trie-new", []) => Ok((NewCoprocessor::default().into(), &["lurk", "trie", "new"])),
+        ("trie-lookup", []) => Ok((
+            LookupCoprocessor::default().into(),
+            &["lurk", "trie", "lookup"],
+        )),
+        ("trie-insert", []) => Ok((
+            InsertCoprocessor::default().into(),
+            &["lurk", "trie", "insert"],
+        )),
+        ("sha256", [n]) => Ok((
+            Sha256Coprocessor::new(*n, DigestOutput::U64s).into(),
+            &["lurk", "hash", "sha256"],
+        )),
+        ("keccak", [n]) => Ok((
+            KeccakCoprocessor::new(*n, DigestOutput::U64s).into(),
+            &["lurk", "hash", "keccak"],
+        )),
+        ("blake2s", [n]) => Ok((
+            Blake2sCoprocessor::new(*n, [0; 8], DigestOutput::U64s).into(),
+            &["lurk", "hash", "blake2s"],
+        )),
+        ("schnorr", []) => {
+            if !SchnorrCoprocessor::<F>::supports_field() {
+                bail!("Coprocessor {name} doesn't support the {} field", F::FIELD)
+            }
+            Ok((
+                SchnorrCoprocessor::new().into(),
+                &["lurk", "schnorr", "verify"],
+            ))
+        }
+        ("merkle", [arity @ (2 | 4 | 8), depth])
+            if *depth > 0 && depth * arity.trailing_zeros() as usize <= F::CAPACITY as usize =>
+        {
+            Ok((
+                MerkleCoprocessor::new(*arity, *depth).into(),
+                &["lurk", "merkle", "verify"],
+            ))
+        }
+        _ => bail!("Invalid parameters for coprocessor {name}: {params:?}"),
+    }
+}
+
+/// A built-in coprocessor to be installed, specified as
+/// `<name>[:<param>,...][=<symbol>]`. The hash coprocessors take the number of
+/// bytes they hash, and `merkle` takes the arity and the depth of the trees.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CoprocessorSpec {
+    name: String,
+    params: Vec<usize>,
+    symbol: Option<String>,
+}
+
+impl CoprocessorSpec {
+    pub(crate) fn parse(spec: &str) -> Result<Self> {
+        let (name, symbol) = match spec.split_once('=') {
+            Some((name, symbol)) => (name.trim(), Some(symbol.trim())),
+            None => (spec.trim(), None),
+        };
+        Self::new(name, symbol)
+    }
+
+    fn new(name: &str, symbol: Option<&str>) -> Result<Self> {
+        let (name, params) = match name.split_once(':') {
+            Some((name, params)) => {
+                let params = params
+                    .split(',')
+                    .map(|param| param.trim().parse())
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(|| format!("Invalid parameters for coprocessor {name}"))?;
+                (name, params)
+            }
+            None => (name, vec![]),
+        };
+        if !BUILTIN_NAMES.contains(&name) {
+            bail!(
+                "Unknown coprocessor: {name}. The available ones are {}",
+                BUILTIN_NAMES.join(", ")
+            )
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            params,
+            symbol: symbol.filter(|s| !s.is_empty()).map(str::to_owned),
+        })
+    }
+
+    /// Reads the specs from the `coprocessors` section of a config file, which
+    /// maps names to symbols. Empty symbols stand for the default ones.
+    pub(crate) fn from_config(section: &HashMap<String, String>) -> Result<Vec<Self>> {
+        let mut specs = section
+            .iter()
+            .map(|(name, symbol)| Self::new(name, Some(symbol)))
+            .collect::<Result<Vec<_>>>()?;
+        // the order of the coprocessors affects the `Lang`, so it must be deterministic
+        specs.sort_by(|a, b| (&a.name, &a.params).cmp(&(&b.name, &b.params)));
+        Ok(specs)
+    }
+}
+
+/// Builds a `Lang` with the coprocessors in `specs`. Custom symbols are read
+/// relative to the current package of `state`, whereas the packages of default
+/// symbols are created in `state` if needed.
+pub(crate) fn build_lang<F: LurkField>(
+    specs: &[CoprocessorSpec],
+    store: &Store<F>,
+    state: &Rc<RefCell<State>>,
+) -> Result<Lang<F, Coproc<F>>> {
+    let mut lang = Lang::new();
+    for CoprocessorSpec {
+        name,
+        params,
+        symbol,
+    } in specs
+    {
+        let (coproc, default_path) = builtin(name, params)?;
+        let symbol: Symbol = match symbol {
+            None => (*state.borrow_mut().intern_path(default_path, false, true)?).clone(),
+            Some(symbol) => {
+                let ptr = store.read_with_state(state.clone(), symbol)?;
+                match store.fetch_sym(&ptr) {
+                    Some(symbol) => symbol,
+                    None => bail!("Coprocessor {name} must be bound to a symbol, not {symbol}"),
+                }
+            }
+        };
+        if lang.lookup_by_sym(&symbol).is_some() {
+            bail!("Symbol {symbol} is bound to more than one coprocessor")
+        }
+        lang.add_coprocessor(symbol, coproc, store);
+    }
+    Ok(lang)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blstrs::Scalar as S2;
+    use pasta_curves::pallas::Scalar as S1;
+
+    #[test]
+    fn test_parse_spec() {
+        assert_eq!(
+            CoprocessorSpec::parse("trie-new").unwrap(),
+            CoprocessorSpec {
+                name: "trie-new".into(),
+                params: vec![],
+                symbol: None
+            }
+        );
+        assert_eq!(
+            CoprocessorSpec::parse("trie-lookup=lookup").unwrap(),
+            CoprocessorSpec {
+                name: "trie-lookup".into(),
+                params: vec![],
+                symbol: Some("lookup".into())
+            }
+        );
+        assert_eq!(
+            CoprocessorSpec::parse("sha256:32=hash").unwrap(),
+            CoprocessorSpec {
+                name: "sha256".into(),
+                params: vec![32],
+                symbol: Some("hash".into())
+            }
+        );
+        assert!(CoprocessorSpec::parse("foo").is_err());
+        assert!(CoprocessorSpec::parse("sha256:x").is_err());
+    }
+
+    #[test]
+    fn test_build_lang() {
+        let store = Store::<S1>::default();
+        let state = State::init_lurk_state().rccell();
+        let specs = [
+            CoprocessorSpec::parse("trie-new").unwrap(),
+            CoprocessorSpec::parse("trie-insert=insert").unwrap(),
+        ];
+        let lang = build_lang(&specs, &store, &state).unwrap();
+        assert_eq!(lang.coprocessor_count(), 2);
+        assert!(lang
+            .lookup_by_sym(&Symbol::sym(&["lurk", "trie", "new"]))
+            .is_some());
+        assert!(lang
+            .lookup_by_sym(&Symbol::sym(&["lurk", "user", "insert"]))
+            .is_some());
+
+        let specs = [
+            CoprocessorSpec::parse("trie-new=foo").unwrap(),
+            CoprocessorSpec::parse("trie-insert=foo").unwrap(),
+        ];
+        assert!(build_lang(&specs, &store, &state).is_err());
+
+        // missing parameters
+        let specs = [CoprocessorSpec::parse("sha256").unwrap()];
+        assert!(build_lang(&specs, &store, &state).is_err());
+        // unsupported arity
+        let specs = [CoprocessorSpec::parse("merkle:3,10").unwrap()];
+        assert!(build_lang(&specs, &store, &state).is_err());
+
+        // the signature curve is only embedded in the Pallas and Vesta fields
+        let specs = [CoprocessorSpec::parse("schnorr").unwrap()];
+        assert!(build_lang(&specs, &store, &state).is_ok());
+        assert!(build_lang(&specs, &Store::<S2>::default(), &state).is_err());
+    }
+}
@@ -1,24 +1,51 @@
+use ::nova::traits::circuit::StepCircuit;
 use ::nova::traits::Group;
 use abomonation::Abomonation;
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use bellperson::groth16::{prepare_verifying_key, PreparedVerifyingKey};
+use blstrs::{Bls12, Scalar};
+use camino::Utf8Path;
+#[cfg(not(target_arch = "wasm32"))]
+use rand::rngs::OsRng;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
 
+#[cfg(not(target_arch = "wasm32"))]
 use crate::{
+    circuit::{MultiFrame, ToInputs},
+    proof::{
+        groth16::{Groth16Prover, INNER_PRODUCT_SRS},
+        Prover,
+    },
+};
+use crate::{
+    cli::commitment::Commitment,
     coprocessor::Coprocessor,
-    eval::lang::{Coproc, Lang},
+    eval::{
+        lang::{Coproc, Lang},
+        Frame, Witness, IO,
+    },
     field::LurkField,
     proof::{
-        nova::{self, CurveCycleEquipped, G1, G2},
-        MultiFrameTrait,
+        groth16,
+        nova::{self, CurveCycleEquipped, NovaVerifierKey, G1, G2},
+        supernova, MultiFrameTrait,
     },
+    ptr::Ptr,
     public_parameters::public_params,
+    state::initial_lurk_state,
+    store::Store,
+    writer::Write,
     z_ptr::{ZContPtr, ZExprPtr},
     z_store::ZStore,
+    Num,
 };
 
 use crate::cli::{
-    field_data::{dump, load},
-    paths::{proof_meta_path, proof_path, public_params_dir},
+    field_data::{de, dump, load, ser},
+    paths::{proof_meta_path, proof_path, public_params_dir, verifier_key_path},
 };
 
 use super::field_data::HasFieldModulus;
@@ -39,6 +66,17 @@ pub(crate) struct LurkProofMeta<F: LurkField> {
     pub(crate) env_out: ZExprPtr<F>,
     pub(crate) cont_out: ZContPtr<F>,
     pub(crate) zstore: ZStore<F>,
+    /// The verifier key that was persisted along with the proof, if any
+    pub(crate) vk: Option<VerifierKeyRef>,
+}
+
+/// Refers to a persisted verifier key, which is shared by the proofs with the
+/// same backend, field, reduction count and coprocessors. The digest of the key
+/// file binds a proof to the key it was produced with.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct VerifierKeyRef {
+    pub(crate) name: String,
+    pub(crate) digest: String,
 }
 
 impl<F: LurkField> HasFieldModulus for LurkProofMeta<F> {
@@ -68,6 +106,43 @@ pub(crate) enum LurkProof<
         rc: usize,
         lang: Lang<F, Coproc<F>>,
     },
+    SuperNova {
+        proof: supernova::Proof<F, C>,
+        public_inputs: Vec<F>,
+        public_outputs: Vec<F>,
+        num_steps: usize,
+        /// Index of the NIVC circuit that folded the last step
+        circuit_index: usize,
+        rc: usize,
+        lang: Lang<F, Coproc<F>>,
+    },
+}
+
+/// The verifier key of Nova proofs, which is enough to verify compressed proofs
+/// without generating the public parameters
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub(crate) struct LurkVerifierKey<F: CurveCycleEquipped, M: StepCircuit<F>>
+where
+    <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+    <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+{
+    pub(crate) vk: NovaVerifierKey<F, M>,
+}
+
+/// Minimal data structure containing just enough for the verification of proofs
+/// over BLS12-381. Those don't fit in `LurkProof` because BLS12-381 isn't part of
+/// a curve cycle.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LurkProofBls12 {
+    SnarkPackPlus {
+        proof: groth16::Proof<Bls12>,
+        public_inputs: Vec<Scalar>,
+        public_outputs: Vec<Scalar>,
+        rc: usize,
+        lang: Lang<Scalar, Coproc<Scalar>>,
+    },
 }
 
 impl<'a, F: CurveCycleEquipped, C: Coprocessor<F> + 'a, M: MultiFrameTrait<'a, F, C>>
@@ -81,6 +156,198 @@ where
     }
 }
 
+impl<F: CurveCycleEquipped, M: StepCircuit<F>> HasFieldModulus for LurkVerifierKey<F, M>
+where
+    <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+    <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+{
+    fn field_modulus() -> String {
+        F::MODULUS.to_owned()
+    }
+}
+
+impl<F: CurveCycleEquipped, M: StepCircuit<F>> LurkVerifierKey<F, M>
+where
+    <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+    <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+{
+    /// Persists the verifier key under `name` and returns a reference to it
+    pub(crate) fn persist(self, name: &str) -> Result<VerifierKeyRef> {
+        let bytes = ser(self)?;
+        let digest = hex::encode(Sha256::digest(&bytes));
+        std::fs::write(verifier_key_path(name), bytes)?;
+        Ok(VerifierKeyRef {
+            name: name.to_owned(),
+            digest,
+        })
+    }
+}
+
+impl HasFieldModulus for LurkProofBls12 {
+    fn field_modulus() -> String {
+        Scalar::MODULUS.to_owned()
+    }
+}
+
+/// Builds the claim of a Lurk proof: `expr`, when evaluated in the context of
+/// `env` and continuation `cont`, is reduced to `expr_out`, resulting on
+/// environment `env_out` and continuation `cont_out`.
+pub(crate) fn proof_claim<F: LurkField>(
+    store: &Store<F>,
+    exprs: (Ptr<F>, Ptr<F>),
+    envs: (Ptr<F>, Ptr<F>),
+    conts: ((F, F), (F, F)),
+) -> Ptr<F> {
+    let expr_key = store.key("expr");
+    let env_key = store.key("env");
+    let cont_key = store.key("cont");
+    let expr_out_key = store.key("expr-out");
+    let env_out_key = store.key("env-out");
+    let cont_out_key = store.key("cont-out");
+    let cont_tag = store.num(Num::Scalar(conts.0 .0));
+    let cont_val = store.num(Num::Scalar(conts.0 .1));
+    let cont = store.cons(cont_tag, cont_val);
+    let cont_out_tag = store.num(Num::Scalar(conts.1 .0));
+    let cont_out_val = store.num(Num::Scalar(conts.1 .1));
+    let cont_out = store.cons(cont_out_tag, cont_out_val);
+    store.list(&[
+        expr_key,
+        exprs.0,
+        env_key,
+        envs.0,
+        cont_key,
+        cont,
+        expr_out_key,
+        exprs.1,
+        env_out_key,
+        envs.1,
+        cont_out_key,
+        cont_out,
+    ])
+}
+
+impl<F: LurkField> LurkProofMeta<F> {
+    /// The public inputs and outputs that a proof of this claim must expose
+    fn public_io(&self) -> (Vec<F>, Vec<F>) {
+        let io = |expr: &ZExprPtr<F>, env: &ZExprPtr<F>, cont: &ZContPtr<F>| {
+            vec![
+                expr.tag_field(),
+                *expr.value(),
+                env.tag_field(),
+                *env.value(),
+                cont.tag_field(),
+                *cont.value(),
+            ]
+        };
+        (
+            io(&self.expr, &self.env, &self.cont),
+            io(&self.expr_out, &self.env_out, &self.cont_out),
+        )
+    }
+
+    /// Formats the expressions and continuations of the claim, in the order
+    /// `expr`, `env`, `cont`, `expr_out`, `env_out` and `cont_out`
+    fn fmt_claim(&self) -> [String; 6] {
+        let store = self.zstore.to_store();
+        let state = initial_lurk_state();
+        let fmt_expr = |z_ptr: &ZExprPtr<F>| match store.intern_z_expr_ptr(z_ptr, &self.zstore) {
+            Some(ptr) => ptr.fmt_to_string(&store, state),
+            None => format!("<opaque {z_ptr}>"),
+        };
+        let fmt_cont = |z_ptr: &ZContPtr<F>| match store.intern_z_cont_ptr(z_ptr, &self.zstore) {
+            Some(ptr) => ptr.fmt_to_string(&store, state),
+            None => format!("<opaque {z_ptr}>"),
+        };
+        [
+            fmt_expr(&self.expr),
+            fmt_expr(&self.env),
+            fmt_cont(&self.cont),
+            fmt_expr(&self.expr_out),
+            fmt_expr(&self.env_out),
+            fmt_cont(&self.cont_out),
+        ]
+    }
+
+    /// Recomputes the hash of the commitment to the claim described by this meta
+    fn claim_hash(&self) -> Result<F> {
+        let store = Store::default();
+        let intern = |z_ptr: &ZExprPtr<F>| {
+            store
+                .intern_z_expr_ptr(z_ptr, &self.zstore)
+                .ok_or_else(|| anyhow!("Claim data missing from the proof meta"))
+        };
+        let claim = proof_claim(
+            &store,
+            (intern(&self.expr)?, intern(&self.expr_out)?),
+            (intern(&self.env)?, intern(&self.env_out)?),
+            (self.cont.parts(), self.cont_out.parts()),
+        );
+        Ok(Commitment::new(None, claim, &store)?.hash)
+    }
+
+    /// Checks that the claim hash in `proof_key` and the public inputs/outputs
+    /// of its proof agree with this meta
+    pub(crate) fn check_claim(
+        &self,
+        proof_key: &str,
+        public_inputs: &[F],
+        public_outputs: &[F],
+    ) -> Result<()> {
+        let claim_hash = self.claim_hash()?.hex_digits();
+        if !proof_key.ends_with(&format!("_{claim_hash}")) {
+            bail!("Claim of proof \"{proof_key}\" doesn't match its commitment")
+        }
+        let (expected_inputs, expected_outputs) = self.public_io();
+        if expected_inputs != public_inputs || expected_outputs != public_outputs {
+            bail!("Public IO of proof \"{proof_key}\" doesn't match its claim")
+        }
+        Ok(())
+    }
+}
+
+impl<F: LurkField + DeserializeOwned> LurkProofMeta<F> {
+    /// Loads the meta persisted under `proof_key` and prints the claim it records
+    pub(crate) fn inspect(proof_key: &str) -> Result<()> {
+        let [backend, field, rc, claim_hash]: [&str; 4] = proof_key
+            .splitn(4, '_')
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| anyhow!("Malformed proof key: {proof_key}"))?;
+        let meta: LurkProofMeta<F> = load(proof_meta_path(proof_key))?;
+        let [expr, env, cont, expr_out, env_out, cont_out] = meta.fmt_claim();
+
+        println!("Proof \"{proof_key}\"");
+        println!("  Backend: {backend}");
+        println!("  Field: {field}");
+        println!("  Reduction count: {rc}");
+        println!("  Iterations: {}", meta.iterations);
+        println!("  Claim hash: 0x{claim_hash}");
+        println!("  Claim:");
+        println!("    Expr: {expr}");
+        println!("    Env: {env}");
+        println!("    Cont: {cont}");
+        println!("    Expr out: {expr_out}");
+        println!("    Env out: {env_out}");
+        println!("    Cont out: {cont_out}");
+        Ok(())
+    }
+
+    /// Loads the meta persisted under `proof_key` and summarizes its claim in
+    /// a single line
+    pub(crate) fn claim_summary(proof_key: &str) -> Result<String> {
+        let meta: LurkProofMeta<F> = load(proof_meta_path(proof_key))?;
+        let [expr, _, _, expr_out, ..] = meta.fmt_claim();
+        Ok(format!("{expr} => {expr_out}"))
+    }
+
+    /// Loads the meta persisted under `proof_key` and returns the name of the
+    /// verifier key persisted along with its proof, if any
+    pub(crate) fn vk_name(proof_key: &str) -> Result<Option<String>> {
+        let meta: LurkProofMeta<F> = load(proof_meta_path(proof_key))?;
+        Ok(meta.vk.map(|vk| vk.name))
+    }
+}
+
 impl<F: LurkField + Serialize> LurkProofMeta<F> {
     #[inline]
     pub(crate) fn persist(self, proof_key: &str) -> Result<()> {
@@ -107,19 +374,65 @@ impl<
         M: MultiFrameTrait<'static, F, Coproc<F>> + 'static,
     > LurkProof<'static, F, Coproc<F>, M>
 where
+    <F as ff::PrimeField>::Repr: Abomonation,
     <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
     <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
     <F as CurveCycleEquipped>::CK1: Sync + Send,
     <F as CurveCycleEquipped>::CK2: Sync + Send,
 {
-    pub(crate) fn verify_proof(proof_key: &str) -> Result<()> {
+    /// Loads the proof persisted under `proof_key`, checks it against the claim
+    /// in its meta and verifies it
+    pub(crate) fn check_proof(proof_key: &str) -> Result<bool> {
+        let lurk_proof: LurkProof<'_, F, Coproc<F>, M> = load(proof_path(proof_key))?;
+        let lurk_proof_meta: LurkProofMeta<F> = load(proof_meta_path(proof_key))?;
+        let (public_inputs, public_outputs) = lurk_proof.public_io();
+        lurk_proof_meta.check_claim(proof_key, public_inputs, public_outputs)?;
+        lurk_proof.verify()
+    }
+
+    /// Like `check_proof`, but verifies the proof with the verifier key persisted
+    /// at `vk_path` instead of the public parameters. The key must be the one the
+    /// proof was produced with, as recorded in its meta.
+    pub(crate) fn check_proof_with_vk(proof_key: &str, vk_path: &Utf8Path) -> Result<bool> {
         let lurk_proof: LurkProof<'_, F, Coproc<F>, M> = load(proof_path(proof_key))?;
-        if lurk_proof.verify()? {
-            println!("✓ Proof \"{proof_key}\" verified");
-        } else {
-            println!("✗ Proof \"{proof_key}\" failed on verification");
+        let lurk_proof_meta: LurkProofMeta<F> = load(proof_meta_path(proof_key))?;
+        let (public_inputs, public_outputs) = lurk_proof.public_io();
+        lurk_proof_meta.check_claim(proof_key, public_inputs, public_outputs)?;
+        let Some(vk_ref) = &lurk_proof_meta.vk else {
+            bail!("No verifier key was persisted for proof \"{proof_key}\"")
+        };
+        let bytes = std::fs::read(vk_path)?;
+        if hex::encode(Sha256::digest(&bytes)) != vk_ref.digest {
+            bail!("Verifier key at {vk_path} wasn't used for proof \"{proof_key}\"")
+        }
+        let LurkVerifierKey { vk } = de::<LurkVerifierKey<F, M>>(&bytes)?;
+        match lurk_proof {
+            Self::Nova {
+                proof,
+                public_inputs,
+                public_outputs,
+                num_steps,
+                ..
+            } => Ok(proof.verify_with_vk(&vk, num_steps, &public_inputs, &public_outputs)?),
+            Self::SuperNova { .. } => {
+                bail!("SuperNova proofs can't be verified with a verifier key yet")
+            }
+        }
+    }
+
+    fn public_io(&self) -> (&[F], &[F]) {
+        match self {
+            Self::Nova {
+                public_inputs,
+                public_outputs,
+                ..
+            }
+            | Self::SuperNova {
+                public_inputs,
+                public_outputs,
+                ..
+            } => (public_inputs, public_outputs),
         }
-        Ok(())
     }
 
     fn verify(self) -> Result<bool> {
@@ -133,9 +446,142 @@ where
                 lang,
             } => {
                 tracing::info!("Loading public parameters");
-                let pp = public_params(rc, true, std::sync::Arc::new(lang), &public_params_dir())?;
+                let pp = public_params(rc, true, Arc::new(lang), &public_params_dir())?;
                 Ok(proof.verify(&*pp, num_steps, &public_inputs, &public_outputs)?)
             }
+            Self::SuperNova {
+                proof,
+                public_inputs,
+                public_outputs,
+                num_steps,
+                circuit_index,
+                rc,
+                lang,
+            } => Ok(proof.verify_with_lang(
+                Arc::new(lang),
+                rc,
+                circuit_index,
+                num_steps,
+                &public_inputs,
+                &public_outputs,
+            )?),
+        }
+    }
+}
+
+impl LurkProofBls12 {
+    #[inline]
+    pub(crate) fn persist(self, proof_key: &str) -> Result<()> {
+        dump(self, proof_path(proof_key))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type SnarkPackProver<'a> =
+    Groth16Prover<'a, Bls12, Coproc<Scalar>, Scalar, MultiFrame<'a, Scalar, Coproc<Scalar>>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LurkProofBls12 {
+    pub(crate) fn prove_snarkpack_plus(
+        rc: usize,
+        lang: &Arc<Lang<Scalar, Coproc<Scalar>>>,
+        frames: &[Frame<IO<Scalar>, Witness<Scalar>, Scalar, Coproc<Scalar>>],
+        store: &Store<Scalar>,
+    ) -> Result<Self> {
+        tracing::info!("Generating Groth16 parameters");
+        let params = SnarkPackProver::create_groth_params(rc, lang.clone())?;
+        let prover = SnarkPackProver::new(rc, (**lang).clone());
+
+        tracing::info!("Proving and aggregating");
+        let (proof, public_inputs, public_outputs) = prover.prove_frames(
+            &params.0,
+            &INNER_PRODUCT_SRS,
+            frames,
+            store,
+            OsRng,
+            lang.clone(),
+        )?;
+        let public_inputs = public_inputs.to_inputs(store);
+        let public_outputs = public_outputs.to_inputs(store);
+
+        let pvk = prepare_verifying_key(&params.0.vk);
+        assert!(Self::verify_aggregate(
+            &pvk,
+            &proof,
+            &public_inputs,
+            &public_outputs
+        )?);
+
+        Ok(Self::SnarkPackPlus {
+            proof,
+            public_inputs,
+            public_outputs,
+            rc,
+            lang: (**lang).clone(),
+        })
+    }
+
+    /// Loads the proof persisted under `proof_key`, checks it against the claim
+    /// in its meta and verifies it
+    pub(crate) fn check_proof(proof_key: &str) -> Result<bool> {
+        let lurk_proof: LurkProofBls12 = load(proof_path(proof_key))?;
+        let lurk_proof_meta: LurkProofMeta<Scalar> = load(proof_meta_path(proof_key))?;
+        let Self::SnarkPackPlus {
+            public_inputs,
+            public_outputs,
+            ..
+        } = &lurk_proof;
+        lurk_proof_meta.check_claim(proof_key, public_inputs, public_outputs)?;
+        lurk_proof.verify()
+    }
+
+    fn verify(self) -> Result<bool> {
+        match self {
+            Self::SnarkPackPlus {
+                proof,
+                public_inputs,
+                public_outputs,
+                rc,
+                lang,
+            } => {
+                tracing::info!("Generating Groth16 parameters");
+                let params = SnarkPackProver::create_groth_params(rc, Arc::new(lang))?;
+                let pvk = prepare_verifying_key(&params.0.vk);
+                Self::verify_aggregate(&pvk, &proof, &public_inputs, &public_outputs)
+            }
         }
     }
+
+    fn verify_aggregate(
+        pvk: &PreparedVerifyingKey<Bls12>,
+        proof: &groth16::Proof<Bls12>,
+        public_inputs: &[Scalar],
+        public_outputs: &[Scalar],
+    ) -> Result<bool> {
+        let srs_vk = INNER_PRODUCT_SRS.specialize_vk(proof.proof_count);
+        Ok(SnarkPackProver::verify(
+            pvk,
+            &srs_vk,
+            public_inputs,
+            public_outputs,
+            &proof.proof,
+            &mut OsRng,
+        )?)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LurkProofBls12 {
+    pub(crate) fn prove_snarkpack_plus(
+        _rc: usize,
+        _lang: &Arc<Lang<Scalar, Coproc<Scalar>>>,
+        _frames: &[Frame<IO<Scalar>, Witness<Scalar>, Scalar, Coproc<Scalar>>],
+        _store: &Store<Scalar>,
+    ) -> Result<Self> {
+        anyhow::bail!("SnarkPack+ proofs aren't supported on wasm32")
+    }
+
+    pub(crate) fn check_proof(_proof_key: &str) -> Result<bool> {
+        anyhow::bail!("SnarkPack+ proofs aren't supported on wasm32")
+    }
 }
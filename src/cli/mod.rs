@@ -1,10 +1,13 @@
 pub mod backend;
 mod circom;
 mod commitment;
+mod coprocessors;
 mod field_data;
 mod lurk_proof;
+mod params;
 pub mod paths;
 pub mod repl;
+mod store;
 
 use anyhow::{bail, Context, Result};
 use camino::Utf8PathBuf;
@@ -15,8 +18,6 @@ use pasta_curves::pallas;
 use std::{collections::HashMap, fs};
 
 use crate::{
-    circuit::MultiFrame,
-    eval::lang::Coproc,
     field::{LanguageField, LurkField},
     store::Store,
     z_data::{from_z_data, ZData},
@@ -24,8 +25,9 @@ use crate::{
 };
 
 use crate::cli::{
+    coprocessors::CoprocessorSpec,
     paths::set_lurk_dirs,
-    repl::{validate_non_zero, Repl},
+    repl::{validate_non_zero, Repl, ReplField},
 };
 
 use self::backend::Backend;
@@ -47,8 +49,18 @@ enum Command {
     Load(LoadArgs),
     /// Enters Lurk's REPL environment ("repl" can be elided)
     Repl(ReplArgs),
+    /// Evaluates an expression or a file and prints the result as JSON
+    Eval(EvalArgs),
+    /// Evaluates and proves an expression or a file and prints the result as JSON
+    Prove(EvalArgs),
     /// Verifies a Lurk proof
     Verify(VerifyArgs),
+    /// Prints the claim of a Lurk proof without verifying it
+    Inspect(InspectArgs),
+    /// Manages the persisted proofs and commitments
+    Store(StoreArgs),
+    /// Manages the cached public parameters
+    Params(ParamsArgs),
     /// Instantiates a new circom gadget to interface with bellperson.
     ///
     /// See `lurk circom --help` for more details
@@ -90,6 +102,11 @@ struct LoadArgs {
     #[clap(long, value_parser)]
     field: Option<String>,
 
+    /// Built-in coprocessor to be installed, as `<name>[:<param>,...][=<symbol>]` (can
+    /// be repeated; overrides the `coprocessors` section of the config file)
+    #[clap(long, value_parser)]
+    coprocessor: Vec<String>,
+
     /// Path to public parameters directory
     #[clap(long, value_parser)]
     public_params_dir: Option<Utf8PathBuf>,
@@ -133,6 +150,9 @@ struct LoadCli {
     #[clap(long, value_parser)]
     field: Option<String>,
 
+    #[clap(long, value_parser)]
+    coprocessor: Vec<String>,
+
     #[clap(long, value_parser)]
     public_params_dir: Option<Utf8PathBuf>,
 
@@ -157,6 +177,7 @@ impl LoadArgs {
             limit: self.limit,
             backend: self.backend,
             field: self.field,
+            coprocessor: self.coprocessor,
             public_params_dir: self.public_params_dir,
             proofs_dir: self.proofs_dir,
             commits_dir: self.commits_dir,
@@ -175,6 +196,10 @@ struct ReplArgs {
     #[clap(long, value_parser)]
     load: Option<Utf8PathBuf>,
 
+    /// Session file to be resumed, if it exists, and saved back when exiting the REPL
+    #[clap(long, value_parser)]
+    session: Option<Utf8PathBuf>,
+
     /// Config file, containing the lowest precedence parameters
     #[clap(long, value_parser)]
     config: Option<Utf8PathBuf>,
@@ -195,6 +220,11 @@ struct ReplArgs {
     #[clap(long, value_parser)]
     field: Option<String>,
 
+    /// Built-in coprocessor to be installed, as `<name>[:<param>,...][=<symbol>]` (can
+    /// be repeated; overrides the `coprocessors` section of the config file)
+    #[clap(long, value_parser)]
+    coprocessor: Vec<String>,
+
     /// Path to public parameters directory
     #[clap(long, value_parser)]
     public_params_dir: Option<Utf8PathBuf>,
@@ -217,6 +247,9 @@ struct ReplCli {
     #[clap(long, value_parser)]
     load: Option<Utf8PathBuf>,
 
+    #[clap(long, value_parser)]
+    session: Option<Utf8PathBuf>,
+
     #[clap(long, value_parser)]
     zstore: Option<Utf8PathBuf>,
 
@@ -235,6 +268,9 @@ struct ReplCli {
     #[clap(long, value_parser)]
     field: Option<String>,
 
+    #[clap(long, value_parser)]
+    coprocessor: Vec<String>,
+
     #[clap(long, value_parser)]
     public_params_dir: Option<Utf8PathBuf>,
 
@@ -252,12 +288,14 @@ impl ReplArgs {
     fn into_cli(self) -> ReplCli {
         ReplCli {
             load: self.load,
+            session: self.session,
             zstore: self.zstore,
             config: self.config,
             rc: self.rc,
             limit: self.limit,
             backend: self.backend,
             field: self.field,
+            coprocessor: self.coprocessor,
             public_params_dir: self.public_params_dir,
             proofs_dir: self.proofs_dir,
             commits_dir: self.commits_dir,
@@ -270,6 +308,7 @@ fn parse_backend(backend_str: &String) -> Result<Backend> {
     match backend_str.to_lowercase().as_str() {
         "nova" => Ok(Backend::Nova),
         "snarkpack+" => Ok(Backend::SnarkPackPlus),
+        "supernova" => Ok(Backend::SuperNova),
         _ => bail!("Backend not supported: {backend_str}"),
     }
 }
@@ -283,6 +322,14 @@ fn parse_field(field_str: &String) -> Result<LanguageField> {
     }
 }
 
+/// Extracts the field from a proof key of the form `{backend}_{field}_{rc}_{hash}`
+fn parse_proof_key_field(proof_key: &str) -> Result<LanguageField> {
+    match proof_key.split('_').nth(1) {
+        Some(field_str) => parse_field(&field_str.to_owned()),
+        None => bail!("Malformed proof key: {proof_key}"),
+    }
+}
+
 fn parse_filename(file: &str) -> Result<Utf8PathBuf> {
     if file == "help" {
         bail!("help is not a valid filename. printing help console instead");
@@ -322,7 +369,14 @@ fn get_parsed<T>(
     }
 }
 
-pub fn get_config(config_path: &Option<Utf8PathBuf>) -> Result<HashMap<String, String>> {
+/// The contents of a config file
+struct ConfigFile {
+    vars: HashMap<String, String>,
+    /// Maps names of built-in coprocessors to the symbols they're bound to
+    coprocessors: HashMap<String, String>,
+}
+
+fn read_config(config_path: &Option<Utf8PathBuf>) -> Result<ConfigFile> {
     // First load from the config file
     let builder = match config_path {
         Some(config_path) if config_path.exists() => {
@@ -332,7 +386,35 @@ pub fn get_config(config_path: &Option<Utf8PathBuf>) -> Result<HashMap<String, S
     };
     // Then potentially overwrite with environment variables
     let builder = builder.add_source(Environment::with_prefix("LURK"));
-    Ok(builder.build()?.try_deserialize()?)
+    let mut table = builder.build()?.collect()?;
+    let coprocessors = match table.remove("coprocessors") {
+        Some(section) => section.try_deserialize()?,
+        None => HashMap::new(),
+    };
+    let vars = table
+        .into_iter()
+        .map(|(key, value)| Ok((key, value.into_string()?)))
+        .collect::<Result<_>>()?;
+    Ok(ConfigFile { vars, coprocessors })
+}
+
+pub fn get_config(config_path: &Option<Utf8PathBuf>) -> Result<HashMap<String, String>> {
+    Ok(read_config(config_path)?.vars)
+}
+
+/// Returns the coprocessors given with `--coprocessor` or, if there are none, the
+/// ones in the `coprocessors` section of the config file
+fn get_coprocessors(
+    args: &[String],
+    config_path: &Option<Utf8PathBuf>,
+) -> Result<Vec<CoprocessorSpec>> {
+    if args.is_empty() {
+        CoprocessorSpec::from_config(&read_config(config_path)?.coprocessors)
+    } else {
+        args.iter()
+            .map(|spec| CoprocessorSpec::parse(spec))
+            .collect()
+    }
 }
 
 fn get_store<F: LurkField + for<'a> serde::de::Deserialize<'a>>(
@@ -352,7 +434,10 @@ fn get_store<F: LurkField + for<'a> serde::de::Deserialize<'a>>(
 macro_rules! new_repl {
     ( $cli: expr, $rc: expr, $limit: expr, $field: path, $backend: expr ) => {{
         let store = get_store(&$cli.zstore).with_context(|| "reading store from file")?;
-        Repl::<$field>::new(store, $rc, $limit, $backend)
+        let mut repl = Repl::<$field>::from_store(store, $rc, $limit, $backend);
+        let coprocessors = get_coprocessors(&$cli.coprocessor, &$cli.config)?;
+        repl.install_coprocessors(&coprocessors)?;
+        repl
     }};
 }
 
@@ -361,10 +446,19 @@ impl ReplCli {
         macro_rules! repl {
             ( $rc: expr, $limit: expr, $field: path, $backend: expr ) => {{
                 let mut repl = new_repl!(self, $rc, $limit, $field, $backend);
+                if let Some(session) = &self.session {
+                    if session.exists() {
+                        repl.load_session(session)?;
+                    }
+                }
                 if let Some(lurk_file) = &self.load {
                     repl.load_file(lurk_file)?;
                 }
-                repl.start()
+                repl.start()?;
+                if let Some(session) = &self.session {
+                    repl.save_session(session)?;
+                }
+                Ok(())
             }};
         }
         let config = get_config(&self.config)?;
@@ -397,9 +491,8 @@ impl ReplCli {
         match field {
             LanguageField::Pallas => repl!(rc, limit, pallas::Scalar, backend),
             // LanguageField::Vesta => repl!(rc, limit, vesta::Scalar, backend),
-            // LanguageField::BLS12_381 => repl!(rc, limit, blstrs::Scalar, backend),
+            LanguageField::BLS12_381 => repl!(rc, limit, blstrs::Scalar, backend),
             LanguageField::Vesta => todo!(),
-            LanguageField::BLS12_381 => todo!(),
             LanguageField::BN256 => todo!(),
             LanguageField::Grumpkin => todo!(),
         }
@@ -448,15 +541,109 @@ impl LoadCli {
         match field {
             LanguageField::Pallas => load!(rc, limit, pallas::Scalar, backend),
             // LanguageField::Vesta => load!(rc, limit, vesta::Scalar, backend),
-            // LanguageField::BLS12_381 => load!(rc, limit, blstrs::Scalar, backend),
+            LanguageField::BLS12_381 => load!(rc, limit, blstrs::Scalar, backend),
             LanguageField::Vesta => todo!(),
-            LanguageField::BLS12_381 => todo!(),
             LanguageField::BN256 => todo!(),
             LanguageField::Grumpkin => todo!(),
         }
     }
 }
 
+#[derive(Args, Debug)]
+struct EvalArgs {
+    /// The Lurk expression or the path to a file with Lurk expressions
+    #[clap(value_parser)]
+    input: String,
+
+    /// ZStore to be preloaded before the evaluation
+    #[clap(long, value_parser)]
+    zstore: Option<Utf8PathBuf>,
+
+    /// Config file, containing the lowest precedence parameters
+    #[clap(long, value_parser)]
+    config: Option<Utf8PathBuf>,
+
+    /// Reduction count used for proofs (defaults to 10)
+    #[clap(long, value_parser)]
+    rc: Option<usize>,
+
+    /// Iterations allowed (defaults to 100_000_000; rounded up to the next multiple of rc)
+    #[clap(long, value_parser)]
+    limit: Option<usize>,
+
+    /// Prover backend (defaults to "Nova")
+    #[clap(long, value_parser)]
+    backend: Option<String>,
+
+    /// Arithmetic field (defaults to the backend's standard field)
+    #[clap(long, value_parser)]
+    field: Option<String>,
+
+    /// Built-in coprocessor to be installed, as `<name>[:<param>,...][=<symbol>]` (can
+    /// be repeated; overrides the `coprocessors` section of the config file)
+    #[clap(long, value_parser)]
+    coprocessor: Vec<String>,
+
+    /// Path to public parameters directory
+    #[clap(long, value_parser)]
+    public_params_dir: Option<Utf8PathBuf>,
+
+    /// Path to proofs directory
+    #[clap(long, value_parser)]
+    proofs_dir: Option<Utf8PathBuf>,
+
+    /// Path to commitments directory
+    #[clap(long, value_parser)]
+    commits_dir: Option<Utf8PathBuf>,
+}
+
+impl EvalArgs {
+    fn run(&self, prove: bool) -> Result<()> {
+        macro_rules! eval {
+            ( $rc: expr, $limit: expr, $field: path, $backend: expr ) => {{
+                let mut repl = new_repl!(self, $rc, $limit, $field, $backend);
+                let report = repl.eval_to_json(&self.input, prove)?;
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                Ok(())
+            }};
+        }
+        let config = get_config(&self.config)?;
+        tracing::info!("Configured variables: {:?}", config);
+        set_lurk_dirs(
+            &config,
+            &self.public_params_dir,
+            &self.proofs_dir,
+            &self.commits_dir,
+            &None,
+        );
+        let rc = get_parsed_usize("rc", &self.rc, &config, DEFAULT_RC)?;
+        let limit = get_parsed_usize("limit", &self.limit, &config, DEFAULT_LIMIT)?;
+        let backend = get_parsed(
+            "backend",
+            &self.backend,
+            &config,
+            parse_backend,
+            DEFAULT_BACKEND,
+        )?;
+        let field = get_parsed(
+            "field",
+            &self.field,
+            &config,
+            parse_field,
+            backend.default_field(),
+        )?;
+        validate_non_zero("rc", rc)?;
+        backend.validate_field(&field)?;
+        match field {
+            LanguageField::Pallas => eval!(rc, limit, pallas::Scalar, backend),
+            LanguageField::BLS12_381 => eval!(rc, limit, blstrs::Scalar, backend),
+            LanguageField::Vesta | LanguageField::BN256 | LanguageField::Grumpkin => {
+                bail!("field {field} is not supported yet")
+            }
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 struct VerifyArgs {
     /// ID of the proof to be verified
@@ -474,6 +661,146 @@ struct VerifyArgs {
     /// Path to proofs directory
     #[clap(long, value_parser)]
     proofs_dir: Option<Utf8PathBuf>,
+
+    /// Path to a verifier key file, used instead of the public parameters
+    #[clap(long, value_parser)]
+    vk: Option<Utf8PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct InspectArgs {
+    /// ID of the proof to be inspected
+    #[clap(value_parser)]
+    proof_id: String,
+
+    /// Config file, containing the lowest precedence parameters
+    #[clap(long, value_parser)]
+    config: Option<Utf8PathBuf>,
+
+    /// Path to proofs directory
+    #[clap(long, value_parser)]
+    proofs_dir: Option<Utf8PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct StoreArgs {
+    #[clap(subcommand)]
+    command: StoreCommand,
+
+    /// Config file, containing the lowest precedence parameters
+    #[clap(long, value_parser, global = true)]
+    config: Option<Utf8PathBuf>,
+
+    /// Path to proofs directory
+    #[clap(long, value_parser, global = true)]
+    proofs_dir: Option<Utf8PathBuf>,
+
+    /// Path to commitments directory
+    #[clap(long, value_parser, global = true)]
+    commits_dir: Option<Utf8PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum StoreCommand {
+    /// Lists the persisted proofs, with their claims, and commitments
+    List,
+    /// Deletes proofs, along with their claim commitments, that are older than a threshold
+    Gc {
+        /// Age threshold, in days
+        #[clap(long, value_parser)]
+        older_than: u64,
+
+        /// Also deletes the commitments that aren't claims of remaining proofs
+        #[clap(long, value_parser)]
+        commitments: bool,
+    },
+    /// Bundles proofs, their metas and their claim commitments into an archive file
+    Export {
+        /// Path to the archive file to be created
+        #[clap(value_parser)]
+        archive: Utf8PathBuf,
+
+        /// IDs of the proofs to be exported (defaults to all persisted proofs)
+        #[clap(value_parser)]
+        proof_ids: Vec<String>,
+    },
+    /// Imports the proofs bundled in an archive file
+    Import {
+        /// Path to the archive file
+        #[clap(value_parser)]
+        archive: Utf8PathBuf,
+    },
+}
+
+impl StoreArgs {
+    fn run(&self) -> Result<()> {
+        let config = get_config(&self.config)?;
+        tracing::info!("Configured variables: {:?}", config);
+        set_lurk_dirs(&config, &None, &self.proofs_dir, &self.commits_dir, &None);
+        match &self.command {
+            StoreCommand::List => store::list(),
+            StoreCommand::Gc {
+                older_than,
+                commitments,
+            } => store::gc(*older_than, *commitments),
+            StoreCommand::Export { archive, proof_ids } => store::export(archive, proof_ids),
+            StoreCommand::Import { archive } => store::import(archive),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+struct ParamsArgs {
+    #[clap(subcommand)]
+    command: ParamsCommand,
+
+    /// Config file, containing the lowest precedence parameters
+    #[clap(long, value_parser, global = true)]
+    config: Option<Utf8PathBuf>,
+
+    /// Path to public parameters directory
+    #[clap(long, value_parser, global = true)]
+    public_params_dir: Option<Utf8PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum ParamsCommand {
+    /// Lists the cached public parameters
+    List,
+    /// Generates and caches the public parameters for Nova proofs over Pallas, without
+    /// coprocessors. Those for other fields or with coprocessors are generated and cached
+    /// on the first proof that needs them
+    Generate {
+        /// Reduction count used for proofs (defaults to 10)
+        #[clap(long, value_parser)]
+        rc: Option<usize>,
+    },
+    /// Checks the integrity of the cached public parameters
+    Verify,
+    /// Deletes the cached public parameters
+    Clear {
+        /// Only delete the files that fail the integrity check
+        #[arg(long)]
+        invalid: bool,
+    },
+}
+
+impl ParamsArgs {
+    fn run(&self) -> Result<()> {
+        let config = get_config(&self.config)?;
+        tracing::info!("Configured variables: {:?}", config);
+        set_lurk_dirs(&config, &self.public_params_dir, &None, &None, &None);
+        match &self.command {
+            ParamsCommand::List => params::list(),
+            ParamsCommand::Generate { rc } => {
+                let rc = get_parsed_usize("rc", rc, &config, DEFAULT_RC)?;
+                validate_non_zero("rc", rc)?;
+                params::generate(rc)
+            }
+            ParamsCommand::Verify => params::verify(),
+            ParamsCommand::Clear { invalid } => params::clear(*invalid),
+        }
+    }
 }
 
 /// To setup a new circom gadget `<NAME>`, place your circom files in a designated folder and
@@ -509,9 +836,10 @@ impl Cli {
         match self.command {
             Command::Repl(repl_args) => repl_args.into_cli().run(),
             Command::Load(load_args) => load_args.into_cli().run(),
+            Command::Eval(eval_args) => eval_args.run(false),
+            Command::Prove(eval_args) => eval_args.run(true),
             #[allow(unused_variables)]
             Command::Verify(verify_args) => {
-                use crate::cli::lurk_proof::LurkProof;
                 let config = get_config(&verify_args.config)?;
                 tracing::info!("Configured variables: {:?}", config);
                 set_lurk_dirs(
@@ -521,11 +849,28 @@ impl Cli {
                     &None,
                     &None,
                 );
-                LurkProof::<_, _, MultiFrame<'_, _, Coproc<pallas::Scalar>>>::verify_proof(
-                    &verify_args.proof_id,
-                )?;
-                Ok(())
+                let proof_id = &verify_args.proof_id;
+                let vk_path = verify_args.vk.as_deref();
+                match parse_proof_key_field(proof_id)? {
+                    LanguageField::Pallas => pallas::Scalar::verify_proof(proof_id, vk_path),
+                    LanguageField::BLS12_381 => blstrs::Scalar::verify_proof(proof_id, vk_path),
+                    field => bail!("Verification over {field} is not supported yet"),
+                }
+            }
+            Command::Inspect(inspect_args) => {
+                use crate::cli::lurk_proof::LurkProofMeta;
+                let config = get_config(&inspect_args.config)?;
+                tracing::info!("Configured variables: {:?}", config);
+                set_lurk_dirs(&config, &None, &inspect_args.proofs_dir, &None, &None);
+                let proof_id = &inspect_args.proof_id;
+                match parse_proof_key_field(proof_id)? {
+                    LanguageField::Pallas => LurkProofMeta::<pallas::Scalar>::inspect(proof_id),
+                    LanguageField::BLS12_381 => LurkProofMeta::<blstrs::Scalar>::inspect(proof_id),
+                    field => bail!("Inspection over {field} is not supported yet"),
+                }
             }
+            Command::Store(store_args) => store_args.run(),
+            Command::Params(params_args) => params_args.run(),
             Command::Circom(circom_args) => {
                 use crate::cli::circom::create_circom_gadget;
                 if circom_args.name == "main" {
@@ -0,0 +1,104 @@
+use std::{fs, sync::Arc};
+
+use anyhow::{bail, Result};
+use camino::Utf8PathBuf;
+use pasta_curves::pallas;
+
+use crate::{
+    circuit::MultiFrame,
+    eval::lang::{Coproc, Lang},
+    public_parameters::{
+        disk_cache::{inspect_file, verify_file},
+        public_params,
+    },
+};
+
+use super::paths::public_params_dir;
+
+/// Suffix of the keys of abomonated public params, which are the only cache files
+/// with a header
+const ABOMONATED_SUFFIX: &str = "-abomonated";
+
+/// Returns the paths of the abomonated public params files in the public params
+/// directory, sorted. Other files, like the ones being written, are left alone.
+fn cache_files() -> Result<Vec<Utf8PathBuf>> {
+    let dir = public_params_dir();
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut paths = vec![];
+    for entry in dir.read_dir_utf8()? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && entry.file_name().ends_with(ABOMONATED_SUFFIX) {
+            paths.push(entry.into_path());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Prints the cached public params, reading only the headers of the files
+pub(crate) fn list() -> Result<()> {
+    let paths = cache_files()?;
+    println!("Public params ({}):", paths.len());
+    for path in &paths {
+        println!("  {}", path.file_name().unwrap_or(path.as_str()));
+        match inspect_file(path) {
+            Ok(header) => println!(
+                "    {} ({} bytes)",
+                header.type_name(),
+                header.payload_len()
+            ),
+            Err(e) => println!("    <unusable: {e}>"),
+        }
+    }
+    Ok(())
+}
+
+/// Generates and caches the public params for Nova proofs over Pallas with
+/// reduction count `rc`, unless they're already cached. Only the language without
+/// coprocessors is covered: other public params are generated on first use.
+pub(crate) fn generate(rc: usize) -> Result<()> {
+    let lang = Arc::new(Lang::<pallas::Scalar, Coproc<pallas::Scalar>>::new());
+    public_params::<_, _, MultiFrame<'static, _, _>>(rc, true, lang, &public_params_dir())?;
+    println!(
+        "Public params for rc = {rc} (Pallas, no coprocessors) are cached in {}",
+        public_params_dir()
+    );
+    Ok(())
+}
+
+/// Checks the headers and checksums of all cached public params
+pub(crate) fn verify() -> Result<()> {
+    let mut n_invalid = 0;
+    for path in cache_files()? {
+        let name = path.file_name().unwrap_or(path.as_str());
+        match verify_file(&path) {
+            Ok(_) => println!("✓ {name}"),
+            Err(e) => {
+                println!("✗ {name}: {e}");
+                n_invalid += 1;
+            }
+        }
+    }
+    if n_invalid > 0 {
+        bail!("{n_invalid} invalid file(s), remove with `lurk params clear --invalid`")
+    }
+    Ok(())
+}
+
+/// Deletes the cached public params, or only those that fail verification if
+/// `only_invalid` is set
+pub(crate) fn clear(only_invalid: bool) -> Result<()> {
+    let mut deleted = 0;
+    for path in cache_files()? {
+        if only_invalid && verify_file(&path).is_ok() {
+            continue;
+        }
+        fs::remove_file(&path)?;
+        println!("Deleted {path}");
+        deleted += 1;
+    }
+    println!("{deleted} file(s) deleted");
+    Ok(())
+}
@@ -169,6 +169,10 @@ pub(crate) fn proof_meta_path(name: &str) -> Utf8PathBuf {
         .with_extension("meta")
 }
 
+pub(crate) fn verifier_key_path(name: &str) -> Utf8PathBuf {
+    proofs_dir().join(Utf8Path::new(&format!("{name}.vk")))
+}
+
 pub(crate) fn circom_binary_path() -> Utf8PathBuf {
     circom_dir().join("circom")
 }
@@ -14,20 +14,33 @@ use rustyline::{
     Config, Editor,
 };
 use rustyline_derive::{Completer, Helper, Highlighter, Hinter};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::info;
 
-use super::{backend::Backend, commitment::Commitment, field_data::load, paths::commitment_path};
+use super::{
+    backend::Backend,
+    commitment::Commitment,
+    coprocessors::{build_lang, CoprocessorSpec},
+    field_data::{dump, load, HasFieldModulus},
+    paths::commitment_path,
+};
 
 use crate::{
     circuit::MultiFrame,
     cli::paths::{proof_path, public_params_dir},
     eval::{
         lang::{Coproc, Lang},
-        Evaluator, Frame, Witness, IO,
+        without_printing_emitted, Evaluator, Frame, Witness, IO,
     },
     field::LurkField,
-    lurk_sym_ptr, parser,
-    proof::{nova::NovaProver, Prover},
+    lurk_sym_ptr,
+    package::{Package, SymbolRef},
+    parser,
+    proof::{
+        nova::NovaProver,
+        supernova::{FoldingConfig, SuperNovaProver},
+        Prover,
+    },
     ptr::Ptr,
     public_parameters::public_params,
     state::State,
@@ -36,10 +49,12 @@ use crate::{
     writer::Write,
     z_ptr::ZExprPtr,
     z_store::ZStore,
-    Num, Symbol,
+    Symbol,
 };
 
-use super::lurk_proof::{LurkProof, LurkProofMeta};
+use super::lurk_proof::{
+    proof_claim, LurkProof, LurkProofBls12, LurkProofMeta, LurkVerifierKey, VerifierKeyRef,
+};
 
 use meta_cmd::MetaCmd;
 
@@ -60,6 +75,23 @@ struct Evaluation<F: LurkField, C> {
     iterations: usize,
 }
 
+/// A snapshot of the REPL's environment and packages, so that a session can be
+/// persisted and resumed later. The built-in packages that weren't changed aren't
+/// stored: they're rebuilt by the Lurk version that loads the session.
+#[derive(Serialize, Deserialize)]
+struct Session<F: LurkField> {
+    env: ZExprPtr<F>,
+    zstore: ZStore<F>,
+    current_package: SymbolRef,
+    packages: Vec<Package>,
+}
+
+impl<F: LurkField> HasFieldModulus for Session<F> {
+    fn field_modulus() -> String {
+        F::MODULUS.to_owned()
+    }
+}
+
 #[allow(dead_code)]
 pub struct Repl<F: LurkField> {
     store: Store<F>,
@@ -72,8 +104,13 @@ pub struct Repl<F: LurkField> {
     evaluation: Option<Evaluation<F, Coproc<F>>>,
     pwd_path: Utf8PathBuf,
     meta: std::collections::HashMap<&'static str, MetaCmd<F>>,
+    macros: std::collections::HashMap<Symbol, Ptr<F>>,
 }
 
+/// How many times the expansion of a macro call may itself be expanded, so
+/// that a macro expanding to a call of itself is reported instead of looping
+const MAX_MACRO_EXPANSION_DEPTH: usize = 256;
+
 pub(crate) fn validate_non_zero(name: &str, x: usize) -> Result<()> {
     if x == 0 {
         bail!("`{name}` can't be zero")
@@ -107,6 +144,16 @@ impl<F: LurkField> Repl<F> {
         Ok((first, second))
     }
 
+    fn peek3(&self, cmd: &str, args: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>, Ptr<F>)> {
+        let (first, rest) = self.store.car_cdr(args)?;
+        let (second, rest) = self.store.car_cdr(&rest)?;
+        let (third, rest) = self.store.car_cdr(&rest)?;
+        if !rest.is_nil() {
+            bail!("`{cmd}` accepts at most three arguments")
+        }
+        Ok((first, second, third))
+    }
+
     fn get_string(&self, ptr: &Ptr<F>) -> Result<String> {
         match self.store.fetch_string(ptr) {
             None => bail!(
@@ -128,10 +175,184 @@ impl<F: LurkField> Repl<F> {
     }
 }
 
-type F = pasta_curves::pallas::Scalar; // TODO: generalize this
+/// The fields that the REPL can operate on. Each field knows how to prove
+/// evaluations and verify persisted proofs with the backends it supports.
+pub trait ReplField: LurkField + Serialize + DeserializeOwned {
+    /// Proves `frames` with `backend` and persists the resulting proof, as well
+    /// as its verifier key if the backend has one, which is then returned
+    fn prove_and_persist(
+        backend: &Backend,
+        rc: usize,
+        lang: &Arc<Lang<Self, Coproc<Self>>>,
+        frames: &[Frame<IO<Self>, Witness<Self>, Self, Coproc<Self>>],
+        store: &Store<Self>,
+        proof_key: &str,
+    ) -> Result<Option<VerifierKeyRef>>;
+
+    /// Loads the proof persisted under `proof_key`, checks it against its claim
+    /// and verifies it
+    fn check_proof(proof_key: &str) -> Result<bool>;
+
+    /// Like `check_proof`, but verifies the proof with the verifier key persisted
+    /// at `vk_path` instead of the public parameters
+    fn check_proof_with_vk(_proof_key: &str, _vk_path: &Utf8Path) -> Result<bool> {
+        bail!(
+            "Verification with a verifier key isn't supported over {}",
+            Self::FIELD
+        )
+    }
+
+    /// Checks the proof persisted under `proof_key` and prints the outcome. The
+    /// verifier key at `vk_path` is used instead of the public parameters if
+    /// provided.
+    fn verify_proof(proof_key: &str, vk_path: Option<&Utf8Path>) -> Result<()> {
+        let verified = match vk_path {
+            None => Self::check_proof(proof_key)?,
+            Some(vk_path) => Self::check_proof_with_vk(proof_key, vk_path)?,
+        };
+        if verified {
+            println!("✓ Proof \"{proof_key}\" verified");
+        } else {
+            println!("✗ Proof \"{proof_key}\" failed on verification");
+        }
+        Ok(())
+    }
+}
+
+impl ReplField for pasta_curves::pallas::Scalar {
+    fn prove_and_persist(
+        backend: &Backend,
+        rc: usize,
+        lang: &Arc<Lang<Self, Coproc<Self>>>,
+        frames: &[Frame<IO<Self>, Witness<Self>, Self, Coproc<Self>>],
+        store: &Store<Self>,
+        proof_key: &str,
+    ) -> Result<Option<VerifierKeyRef>> {
+        match backend {
+            Backend::Nova => {
+                info!("Loading public parameters");
+                let pp = public_params(rc, true, lang.clone(), &public_params_dir())?;
+
+                let prover =
+                    NovaProver::<Self, Coproc<Self>, MultiFrame<'_, Self, Coproc<Self>>>::new(
+                        rc,
+                        (**lang).clone(),
+                    );
+
+                info!("Proving");
+                let (proof, public_inputs, public_outputs, num_steps) =
+                    prover.prove(&pp, frames, store, lang)?;
+                info!("Compressing proof");
+                let proof = proof.compress(&pp)?;
+                assert_eq!(rc * num_steps, pad(frames.len(), rc));
+                assert!(proof.verify(&pp, num_steps, &public_inputs, &public_outputs)?);
+
+                // the verifier key only depends on the reduction count and the
+                // coprocessors, so it's shared by the proofs with the same ones
+                let vk_name = format!("{backend}_{}_{rc}_{}", Self::FIELD, lang.key());
+                let vk_ref = LurkVerifierKey::<Self, MultiFrame<'_, Self, Coproc<Self>>> {
+                    vk: pp.vk().clone(),
+                }
+                .persist(&vk_name)?;
+                LurkProof::Nova {
+                    proof,
+                    public_inputs,
+                    public_outputs,
+                    num_steps,
+                    rc,
+                    lang: (**lang).clone(),
+                }
+                .persist(proof_key)?;
+                Ok(Some(vk_ref))
+            }
+            Backend::SuperNova => {
+                let prover = SuperNovaProver::<Self, Coproc<Self>>::new(rc, (**lang).clone());
+
+                info!("Proving");
+                let (proof, public_inputs, public_outputs, num_steps) =
+                    prover.prove(None, frames, store, lang.clone())?;
+                let circuit_index = FoldingConfig::new_nivc(lang.clone(), rc)
+                    .circuit_index(&frames[frames.len() - 1].meta);
+                assert!(proof.verify_with_lang(
+                    lang.clone(),
+                    rc,
+                    circuit_index,
+                    num_steps,
+                    &public_inputs,
+                    &public_outputs
+                )?);
+
+                let lurk_proof: LurkProof<'_, _, _, MultiFrame<'_, Self, Coproc<Self>>> =
+                    LurkProof::SuperNova {
+                        proof,
+                        public_inputs,
+                        public_outputs,
+                        num_steps,
+                        circuit_index,
+                        rc,
+                        lang: (**lang).clone(),
+                    };
+                lurk_proof.persist(proof_key)?;
+                Ok(None)
+            }
+            _ => bail!("Backend {backend} can't prove over {}", Self::FIELD),
+        }
+    }
+
+    fn check_proof(proof_key: &str) -> Result<bool> {
+        LurkProof::<'_, Self, Coproc<Self>, MultiFrame<'_, Self, Coproc<Self>>>::check_proof(
+            proof_key,
+        )
+    }
+
+    fn check_proof_with_vk(proof_key: &str, vk_path: &Utf8Path) -> Result<bool> {
+        LurkProof::<'_, Self, _, MultiFrame<'_, Self, Coproc<Self>>>::check_proof_with_vk(
+            proof_key, vk_path,
+        )
+    }
+}
+
+impl ReplField for blstrs::Scalar {
+    fn prove_and_persist(
+        backend: &Backend,
+        rc: usize,
+        lang: &Arc<Lang<Self, Coproc<Self>>>,
+        frames: &[Frame<IO<Self>, Witness<Self>, Self, Coproc<Self>>],
+        store: &Store<Self>,
+        proof_key: &str,
+    ) -> Result<Option<VerifierKeyRef>> {
+        match backend {
+            Backend::SnarkPackPlus => {
+                info!("Proving");
+                LurkProofBls12::prove_snarkpack_plus(rc, lang, frames, store)?
+                    .persist(proof_key)?;
+                Ok(None)
+            }
+            _ => bail!("Backend {backend} can't prove over {}", Self::FIELD),
+        }
+    }
+
+    #[inline]
+    fn check_proof(proof_key: &str) -> Result<bool> {
+        LurkProofBls12::check_proof(proof_key)
+    }
+}
+
+impl Repl<pasta_curves::pallas::Scalar> {
+    /// Creates a REPL over the Pallas scalar field, which is the default one
+    pub fn new(
+        store: Store<pasta_curves::pallas::Scalar>,
+        rc: usize,
+        limit: usize,
+        backend: Backend,
+    ) -> Self {
+        Self::from_store(store, rc, limit, backend)
+    }
+}
 
-impl Repl<F> {
-    pub fn new(store: Store<F>, rc: usize, limit: usize, backend: Backend) -> Repl<F> {
+impl<F: ReplField> Repl<F> {
+    /// Creates a REPL over the field of `store`
+    pub fn from_store(store: Store<F>, rc: usize, limit: usize, backend: Backend) -> Repl<F> {
         let limit = pad(limit, rc);
         info!(
             "Launching REPL with backend {backend}, field {}, rc {rc} and limit {limit}",
@@ -152,137 +373,220 @@ impl Repl<F> {
             evaluation: None,
             pwd_path,
             meta: MetaCmd::cmds(),
+            macros: Default::default(),
         }
     }
 
-    #[allow(dead_code)]
-    fn proof_claim(
-        store: &Store<F>,
-        exprs: (Ptr<F>, Ptr<F>),
-        envs: (Ptr<F>, Ptr<F>),
-        conts: ((F, F), (F, F)),
-    ) -> Ptr<F> {
-        let expr_key = store.key("expr");
-        let env_key = store.key("env");
-        let cont_key = store.key("cont");
-        let expr_out_key = store.key("expr-out");
-        let env_out_key = store.key("env-out");
-        let cont_out_key = store.key("cont-out");
-        let cont_tag = store.num(Num::Scalar(conts.0 .0));
-        let cont_val = store.num(Num::Scalar(conts.0 .1));
-        let cont = store.cons(cont_tag, cont_val);
-        let cont_out_tag = store.num(Num::Scalar(conts.1 .0));
-        let cont_out_val = store.num(Num::Scalar(conts.1 .1));
-        let cont_out = store.cons(cont_out_tag, cont_out_val);
-        store.list(&[
-            expr_key,
-            exprs.0,
-            env_key,
-            envs.0,
-            cont_key,
-            cont,
-            expr_out_key,
-            exprs.1,
-            env_out_key,
-            envs.1,
-            cont_out_key,
-            cont_out,
-        ])
-    }
-
     #[allow(dead_code)]
     fn proof_key(backend: &Backend, rc: &usize, claim_hash: &str) -> String {
         let field = F::FIELD;
         format!("{backend}_{field}_{rc}_{claim_hash}")
     }
 
+    /// The backend that actually proves evaluations. SuperNova falls back to
+    /// Nova when there are no coprocessors to fold separately, and proofs are
+    /// labeled accordingly.
+    fn proving_backend(&self) -> &Backend {
+        match self.backend {
+            Backend::SuperNova if self.lang.coprocessor_count() == 0 => {
+                info!("No coprocessors to fold separately, proving with Nova");
+                &Backend::Nova
+            }
+            _ => &self.backend,
+        }
+    }
+
+    /// Installs the built-in coprocessors in `specs`, replacing the current `Lang`
+    pub(crate) fn install_coprocessors(&mut self, specs: &[CoprocessorSpec]) -> Result<()> {
+        if !specs.is_empty() {
+            self.lang = Arc::new(build_lang(specs, &self.store, &self.state)?);
+        }
+        Ok(())
+    }
+
+    /// Persists the current environment and packages to `path`
+    pub(crate) fn save_session(&self, path: &Utf8Path) -> Result<()> {
+        let mut zstore = Some(ZStore::<F>::default());
+        let env = self.store.get_z_expr(&self.env, &mut zstore)?.0;
+        let state = self.state.borrow();
+        let session = Session {
+            env,
+            zstore: zstore.unwrap(),
+            current_package: state.get_current_package_name().clone(),
+            packages: state.user_packages().into_iter().cloned().collect(),
+        };
+        dump(session, path.to_owned())?;
+        println!("Session saved to {path}");
+        Ok(())
+    }
+
+    /// Restores the environment and packages persisted at `path`, replacing the
+    /// current ones
+    pub(crate) fn load_session(&mut self, path: &Utf8Path) -> Result<()> {
+        let session: Session<F> = load(path.to_owned())?;
+        let Some(env) = self.store.intern_z_expr_ptr(&session.env, &session.zstore) else {
+            bail!("Corrupted session file: missing environment data")
+        };
+        let state = State::from_user_packages(session.packages, session.current_package)
+            .with_context(|| "Corrupted session file")?;
+        self.env = env;
+        *self.state.borrow_mut() = state;
+        println!("Session loaded from {path}");
+        Ok(())
+    }
+
     pub(crate) fn prove_last_frames(&mut self) -> Result<()> {
+        let (claim_hash, proof_key) = self.prove_last_evaluation()?;
+        println!("Claim hash: 0x{claim_hash}");
+        println!("Proof key: \"{proof_key}\"");
+        Ok(())
+    }
+
+    /// Proves the last memoized evaluation, unless a valid proof is already
+    /// cached, and returns the claim hash and the proof key
+    fn prove_last_evaluation(&mut self) -> Result<(String, String)> {
         match self.evaluation.as_ref() {
             None => bail!("No evaluation to prove"),
-            Some(Evaluation { frames, iterations }) => match self.backend {
-                Backend::Nova => {
-                    info!("Hydrating the store");
-                    self.store.hydrate_scalar_cache();
-
-                    let n_frames = frames.len();
-
-                    // saving to avoid clones
-                    let input = &frames[0].input;
-                    let output = &frames[n_frames - 1].output;
-                    let mut zstore = Some(ZStore::<F>::default());
-                    let expr = self.store.get_z_expr(&input.expr, &mut zstore)?.0;
-                    let env = self.store.get_z_expr(&input.env, &mut zstore)?.0;
-                    let cont = self.store.get_z_cont(&input.cont, &mut zstore)?.0;
-                    let expr_out = self.store.get_z_expr(&output.expr, &mut zstore)?.0;
-                    let env_out = self.store.get_z_expr(&output.env, &mut zstore)?.0;
-                    let cont_out = self.store.get_z_cont(&output.cont, &mut zstore)?.0;
-
-                    let claim = Self::proof_claim(
+            Some(Evaluation { frames, iterations }) => {
+                info!("Hydrating the store");
+                self.store.hydrate_scalar_cache();
+
+                let n_frames = frames.len();
+
+                // saving to avoid clones
+                let input = &frames[0].input;
+                let output = &frames[n_frames - 1].output;
+                let mut zstore = Some(ZStore::<F>::default());
+                let expr = self.store.get_z_expr(&input.expr, &mut zstore)?.0;
+                let env = self.store.get_z_expr(&input.env, &mut zstore)?.0;
+                let cont = self.store.get_z_cont(&input.cont, &mut zstore)?.0;
+                let expr_out = self.store.get_z_expr(&output.expr, &mut zstore)?.0;
+                let env_out = self.store.get_z_expr(&output.env, &mut zstore)?.0;
+                let cont_out = self.store.get_z_cont(&output.cont, &mut zstore)?.0;
+
+                let claim = proof_claim(
+                    &self.store,
+                    (input.expr, output.expr),
+                    (input.env, output.env),
+                    (cont.parts(), cont_out.parts()),
+                );
+
+                let claim_comm = Commitment::new(None, claim, &self.store)?;
+                let claim_hash = claim_comm.hash.hex_digits();
+                let backend = self.proving_backend();
+                let proof_key = &Self::proof_key(backend, &self.rc, &claim_hash);
+
+                claim_comm.persist()?;
+
+                // a cached proof is checked against the meta persisted with it,
+                // whose claim must be the one that was just computed since the
+                // proof key carries its hash
+                let cached = proof_path(proof_key).exists()
+                    && match F::check_proof(proof_key) {
+                        Ok(true) => true,
+                        Ok(false) => {
+                            info!("Cached proof failed on verification");
+                            false
+                        }
+                        Err(e) => {
+                            info!("Cached proof is invalid: {e}");
+                            false
+                        }
+                    };
+
+                if cached {
+                    info!("Proof already cached");
+                } else {
+                    info!("Proof not cached");
+                    let vk = F::prove_and_persist(
+                        backend,
+                        self.rc,
+                        &self.lang,
+                        frames,
                         &self.store,
-                        (input.expr, output.expr),
-                        (input.env, output.env),
-                        (cont.parts(), cont_out.parts()),
-                    );
-
-                    let claim_comm = Commitment::new(None, claim, &self.store)?;
-                    let claim_hash = &claim_comm.hash.hex_digits();
-                    let proof_key = &Self::proof_key(&self.backend, &self.rc, claim_hash);
-                    let proof_path = proof_path(proof_key);
-
-                    if proof_path.exists() {
-                        info!("Proof already cached");
-                        // TODO: make sure that the proof file is not corrupted
-                    } else {
-                        info!("Proof not cached");
+                        proof_key,
+                    )?;
+                    LurkProofMeta {
+                        iterations: *iterations,
+                        expr,
+                        env,
+                        cont,
+                        expr_out,
+                        env_out,
+                        cont_out,
+                        zstore: zstore.unwrap(),
+                        vk,
+                    }
+                    .persist(proof_key)?;
+                }
+                Ok((claim_hash, proof_key.to_owned()))
+            }
+        }
+    }
 
-                        info!("Loading public parameters");
-                        let pp =
-                            public_params(self.rc, true, self.lang.clone(), &public_params_dir())?;
+    /// Evaluates the Lurk source in `input`, or in the file it points to,
+    /// without printing anything and reports the last evaluation as a JSON
+    /// document. The evaluation is also proven if `prove` is set.
+    pub(crate) fn eval_to_json(&mut self, input: &str, prove: bool) -> Result<serde_json::Value> {
+        let input_path = Utf8Path::new(input);
+        let source = if input_path.is_file() {
+            read_to_string(input_path)?
+        } else {
+            input.to_owned()
+        };
 
-                        let prover = NovaProver::<F, Coproc<F>, MultiFrame<'_, F, Coproc<F>>>::new(
-                            self.rc,
-                            (*self.lang).clone(),
+        let mut span = parser::Span::new(&source);
+        let mut last_evaluation = None;
+        let mut emitted = vec![];
+        loop {
+            match self
+                .store
+                .read_maybe_meta_with_state(self.state.clone(), span)
+            {
+                Ok((_, _, true)) => bail!("Meta commands aren't supported here, use `lurk load`"),
+                Ok((rest, expr_ptr, false)) => {
+                    // emitted values go in the report instead of polluting the JSON on stdout
+                    let (output, iterations) =
+                        without_printing_emitted(|| self.eval_expr_and_memoize(expr_ptr))?;
+                    if let Some(Evaluation { frames, .. }) = &self.evaluation {
+                        emitted.extend(
+                            frames[..iterations]
+                                .iter()
+                                .filter_map(|frame| {
+                                    frame.output.maybe_emitted_expression(&self.store)
+                                })
+                                .map(|ptr| ptr.fmt_to_string(&self.store, &self.state.borrow())),
                         );
-
-                        info!("Proving");
-                        let (proof, public_inputs, public_outputs, num_steps) =
-                            prover.prove(&pp, frames, &self.store, &self.lang)?;
-                        info!("Compressing proof");
-                        let proof = proof.compress(&pp)?;
-                        assert_eq!(self.rc * num_steps, pad(n_frames, self.rc));
-                        assert!(proof.verify(&pp, num_steps, &public_inputs, &public_outputs)?);
-
-                        let lurk_proof = LurkProof::Nova {
-                            proof,
-                            public_inputs,
-                            public_outputs,
-                            num_steps,
-                            rc: self.rc,
-                            lang: (*self.lang).clone(),
-                        };
-
-                        let lurk_proof_meta = LurkProofMeta {
-                            iterations: *iterations,
-                            expr,
-                            env,
-                            cont,
-                            expr_out,
-                            env_out,
-                            cont_out,
-                            zstore: zstore.unwrap(),
-                        };
-
-                        lurk_proof.persist(proof_key)?;
-                        lurk_proof_meta.persist(proof_key)?;
-                        claim_comm.persist()?;
                     }
-                    println!("Claim hash: 0x{claim_hash}");
-                    println!("Proof key: \"{proof_key}\"");
-                    Ok(())
+                    last_evaluation = Some((output, iterations));
+                    span = rest;
                 }
-                Backend::SnarkPackPlus => todo!(),
-            },
+                Err(parser::Error::NoInput) => break,
+                Err(e) => bail!("Read error: {e}"),
+            }
         }
+        let Some((output, iterations)) = last_evaluation else {
+            bail!("No expression to evaluate")
+        };
+
+        let status = match output.cont.tag {
+            ContTag::Terminal => "terminal",
+            ContTag::Error => "error",
+            _ => "limit-reached",
+        };
+        let mut report = serde_json::json!({
+            "result": output.expr.fmt_to_string(&self.store, &self.state.borrow()),
+            "status": status,
+            "iterations": iterations,
+            "emitted": emitted,
+        });
+
+        if prove {
+            let (claim_hash, proof_key) = self.prove_last_evaluation()?;
+            report["claim_hash"] = format!("0x{claim_hash}").into();
+            report["proof_key"] = proof_key.into();
+        }
+        Ok(report)
     }
 
     fn hide(&mut self, secret: F, payload: Ptr<F>) -> Result<()> {
@@ -327,7 +631,139 @@ impl Repl<F> {
         }
     }
 
+    /// Rewrites the macro calls in `expr_ptr` into their expansions until none
+    /// is left. Quoted data is left untouched and so are the calls of macros
+    /// whose names are bound by an enclosing `lambda`, `let` or `letrec`
+    fn expand_macros(&mut self, expr_ptr: Ptr<F>) -> Result<Ptr<F>> {
+        if self.macros.is_empty() {
+            return Ok(expr_ptr);
+        }
+        self.expand_macros_shadowed(expr_ptr, &[], 0)
+    }
+
+    fn expand_macros_shadowed(
+        &mut self,
+        expr_ptr: Ptr<F>,
+        shadowed: &[Symbol],
+        depth: usize,
+    ) -> Result<Ptr<F>> {
+        if expr_ptr.tag != ExprTag::Cons {
+            return Ok(expr_ptr);
+        }
+        let (head, args) = self.store.car_cdr(&expr_ptr)?;
+        if head == lurk_sym_ptr!(self.store, quote) {
+            return Ok(expr_ptr);
+        }
+        if let Some(expanded) = self.expand_macro_call(&head, &args, shadowed)? {
+            if depth == MAX_MACRO_EXPANSION_DEPTH {
+                bail!(
+                    "Expanding macro {} exceeded {MAX_MACRO_EXPANSION_DEPTH} nested expansions",
+                    head.fmt_to_string(&self.store, &self.state.borrow())
+                )
+            }
+            return self.expand_macros_shadowed(expanded, shadowed, depth + 1);
+        }
+        if head == lurk_sym_ptr!(self.store, lambda) && args.tag == ExprTag::Cons {
+            let (params, body) = self.store.car_cdr(&args)?;
+            let mut shadowed = shadowed.to_vec();
+            for param in self.store.fetch_list(&params).unwrap_or_default() {
+                self.shadow(&mut shadowed, &param);
+            }
+            let body = self.expand_macros_in_list(body, &shadowed, depth)?;
+            let args = self.store.cons(params, body);
+            return Ok(self.store.cons(head, args));
+        }
+        let is_letrec = head == lurk_sym_ptr!(self.store, letrec);
+        if (is_letrec || head == lurk_sym_ptr!(self.store, let_)) && args.tag == ExprTag::Cons {
+            let (bindings, body) = self.store.car_cdr(&args)?;
+            if let Some(bindings) = self.store.fetch_list(&bindings) {
+                let mut shadowed = shadowed.to_vec();
+                let mut expanded_bindings = Vec::with_capacity(bindings.len());
+                for binding in bindings {
+                    if binding.tag != ExprTag::Cons {
+                        expanded_bindings.push(binding);
+                        continue;
+                    }
+                    let (var, values) = self.store.car_cdr(&binding)?;
+                    if is_letrec {
+                        self.shadow(&mut shadowed, &var);
+                    }
+                    let values = self.expand_macros_in_list(values, &shadowed, depth)?;
+                    if !is_letrec {
+                        self.shadow(&mut shadowed, &var);
+                    }
+                    expanded_bindings.push(self.store.cons(var, values));
+                }
+                let bindings = self.store.list(&expanded_bindings);
+                let body = self.expand_macros_in_list(body, &shadowed, depth)?;
+                let args = self.store.cons(bindings, body);
+                return Ok(self.store.cons(head, args));
+            }
+        }
+        self.expand_macros_in_list(expr_ptr, shadowed, depth)
+    }
+
+    fn expand_macros_in_list(
+        &mut self,
+        list: Ptr<F>,
+        shadowed: &[Symbol],
+        depth: usize,
+    ) -> Result<Ptr<F>> {
+        if list.tag != ExprTag::Cons {
+            return Ok(list);
+        }
+        let (head, tail) = self.store.car_cdr(&list)?;
+        let head = self.expand_macros_shadowed(head, shadowed, depth)?;
+        let tail = self.expand_macros_in_list(tail, shadowed, depth)?;
+        Ok(self.store.cons(head, tail))
+    }
+
+    /// Extends `shadowed` with `var` if it's the name of a macro
+    fn shadow(&self, shadowed: &mut Vec<Symbol>, var: &Ptr<F>) {
+        if let Some(sym) = self.store.fetch_sym(var) {
+            if self.macros.contains_key(&sym) && !shadowed.contains(&sym) {
+                shadowed.push(sym)
+            }
+        }
+    }
+
+    /// Expands `(head . args)` once if `head` names a macro, by applying the
+    /// macro's function to the quoted `args`
+    fn expand_macro_call(
+        &mut self,
+        head: &Ptr<F>,
+        args: &Ptr<F>,
+        shadowed: &[Symbol],
+    ) -> Result<Option<Ptr<F>>> {
+        let Some(macro_fun) = self
+            .store
+            .fetch_sym(head)
+            .filter(|sym| !shadowed.contains(sym))
+            .and_then(|sym| self.macros.get(&sym).copied())
+        else {
+            return Ok(None);
+        };
+        let Some(args) = self.store.fetch_list(args) else {
+            bail!(
+                "Arguments of macro {} must be a proper list",
+                head.fmt_to_string(&self.store, &self.state.borrow())
+            )
+        };
+        let quote = lurk_sym_ptr!(self.store, quote);
+        let mut call = vec![macro_fun];
+        call.extend(args.into_iter().map(|arg| self.store.list(&[quote, arg])));
+        let call = self.store.list(&call);
+        let (expanded_io, ..) = self.eval_expr(call).with_context(|| {
+            format!(
+                "expanding macro {}",
+                head.fmt_to_string(&self.store, &self.state.borrow())
+            )
+        })?;
+        Ok(Some(expanded_io.expr))
+    }
+
     fn eval_expr(&mut self, expr_ptr: Ptr<F>) -> Result<(IO<F>, usize, Vec<Ptr<F>>)> {
+        let expr_ptr = self.expand_macros(expr_ptr)?;
         let ret = Evaluator::new(expr_ptr, self.env, &self.store, self.limit, &self.lang).eval()?;
         match ret.0.cont.tag {
             ContTag::Terminal => Ok(ret),
@@ -347,6 +783,7 @@ impl Repl<F> {
         &mut self,
         expr_ptr: Ptr<F>,
     ) -> Result<(IO<F>, usize, Vec<Ptr<F>>)> {
+        let expr_ptr = self.expand_macros(expr_ptr)?;
         let ret = Evaluator::new(expr_ptr, self.env, &self.store, self.limit, &self.lang).eval()?;
         if matches!(ret.0.cont.tag, ContTag::Terminal | ContTag::Error) {
             Ok(ret)
@@ -359,6 +796,7 @@ impl Repl<F> {
     }
 
     fn eval_expr_and_memoize(&mut self, expr_ptr: Ptr<F>) -> Result<(IO<F>, usize)> {
+        let expr_ptr = self.expand_macros(expr_ptr)?;
         let frames =
             Evaluator::new(expr_ptr, self.env, &self.store, self.limit, &self.lang).get_frames()?;
 
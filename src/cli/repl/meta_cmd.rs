@@ -1,24 +1,18 @@
-use abomonation::Abomonation;
 use anyhow::{bail, Context, Result};
 use camino::Utf8Path;
-use nova::traits::Group;
-use serde::de::DeserializeOwned;
 use std::process;
 
 use crate::{
-    circuit::MultiFrame,
-    cli::lurk_proof::LurkProof,
-    eval::lang::Coproc,
+    cli::lurk_proof::LurkProofMeta,
     field::LurkField,
     lurk_sym_ptr,
     package::{Package, SymbolRef},
-    proof::nova::{CurveCycleEquipped, G1, G2},
     ptr::Ptr,
     tag::{ContTag, ExprTag},
     writer::Write,
 };
 
-use super::Repl;
+use super::{Repl, ReplField};
 
 pub(super) struct MetaCmd<F: LurkField> {
     name: &'static str,
@@ -29,9 +23,7 @@ pub(super) struct MetaCmd<F: LurkField> {
     pub(super) run: fn(repl: &mut Repl<F>, cmd: &str, args: &Ptr<F>) -> Result<()>,
 }
 
-type F = pasta_curves::pallas::Scalar; // TODO: generalize this
-
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const LOAD: MetaCmd<F> = MetaCmd {
         name: "load",
         summary: "Load lurk expressions from a file path.",
@@ -52,7 +44,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const DEF: MetaCmd<F> = MetaCmd {
         name: "def",
         summary: "Extends env with a non-recursive binding.",
@@ -83,7 +75,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const DEFREC: MetaCmd<F> = MetaCmd {
         name: "defrec",
         summary: "Extends the env with a recursive binding.",
@@ -120,7 +112,60 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
+    const DEFMACRO: MetaCmd<F> = MetaCmd {
+        name: "defmacro",
+        summary: "Define a macro, expanded before evaluation.",
+        format: "!(defmacro <symbol> <params> <body>)",
+        description: &[
+            "Calls to the macro are replaced by the result of evaluating <body> with <params> bound",
+            "to the unevaluated arguments. The expansion happens before evaluating or proving.",
+            "Quoted data isn't expanded, and neither is a call within a `lambda`, `let` or",
+            "`letrec` that binds <symbol>.",
+        ],
+        example: &[
+            "!(defmacro if-not (c x y) (cons 'if (cons c (cons y (cons x nil)))))",
+            "(if-not (= 1 2) 3 4)",
+        ],
+        run: |repl, cmd, args| {
+            let (name, params, body) = repl.peek3(cmd, args)?;
+            let Some(sym) = repl.store.fetch_sym(&name) else {
+                bail!(
+                    "Macro name must be a symbol. Got {}",
+                    name.fmt_to_string(&repl.store, &repl.state.borrow())
+                )
+            };
+            let lambda = lurk_sym_ptr!(&repl.store, lambda);
+            let macro_fun = repl.store.list(&[lambda, params, body]);
+            let (macro_fun_io, ..) = repl.eval_expr(macro_fun)?;
+            repl.macros.insert(sym, macro_fun_io.expr);
+            println!("{}", name.fmt_to_string(&repl.store, &repl.state.borrow()));
+            Ok(())
+        },
+    };
+
+    const MACROEXPAND: MetaCmd<F> = MetaCmd {
+        name: "macroexpand",
+        summary: "Print <expr> with its macro calls expanded.",
+        format: "!(macroexpand <expr>)",
+        description: &[],
+        example: &[
+            "!(defmacro if-not (c x y) (cons 'if (cons c (cons y (cons x nil)))))",
+            "!(macroexpand (if-not (= 1 2) 3 4))",
+        ],
+        run: |repl, cmd, args| {
+            let first = repl.peek1(cmd, args)?;
+            let expanded = repl.expand_macros(first)?;
+            println!(
+                "{}",
+                expanded.fmt_to_string(&repl.store, &repl.state.borrow())
+            );
+            Ok(())
+        },
+    };
+}
+
+impl<F: ReplField> MetaCmd<F> {
     const ASSERT: MetaCmd<F> = MetaCmd {
         name: "assert",
         summary: "Assert that an expression evaluates to true.",
@@ -142,7 +187,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const ASSERT_EQ: MetaCmd<F> = MetaCmd {
         name: "assert-eq",
         summary: "Assert that two expressions evaluate to the same value.",
@@ -176,7 +221,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const ASSERT_EMITTED: MetaCmd<F> = MetaCmd {
         name:
             "assert-emitted",
@@ -215,7 +260,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const ASSERT_ERROR: MetaCmd<F> = MetaCmd {
         name: "assert-error",
         summary: "Assert that a evaluation of <expr> fails.",
@@ -237,7 +282,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const COMMIT: MetaCmd<F> = MetaCmd {
         name:
             "commit",
@@ -259,7 +304,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const HIDE: MetaCmd<F> = MetaCmd {
         name: "hide",
         summary: "Return and persist the commitment of <exp> using secret <secret>.",
@@ -292,7 +337,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const FETCH: MetaCmd<F> = MetaCmd {
         name: "fetch",
         summary: "Add data from a commitment to the repl store.",
@@ -310,7 +355,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const OPEN: MetaCmd<F> = MetaCmd {
         name: "open",
         summary: "Open a commitment.",
@@ -342,7 +387,7 @@ impl<F: LurkField> MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const SET_ENV: MetaCmd<F> = MetaCmd {
         name: "set-env",
         summary: "Set the env to the result of evaluating the first argument.",
@@ -359,7 +404,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const PROVE: MetaCmd<F> = MetaCmd {
         name:
             "prove",
@@ -385,14 +430,7 @@ impl MetaCmd<F> {
     };
 }
 
-impl<F: CurveCycleEquipped + DeserializeOwned> MetaCmd<F>
-where
-    // TODO(huitseeker): this is a bit pedantic, revisit later.
-    <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
-    <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
-    <F as CurveCycleEquipped>::CK1: Sync + Send,
-    <F as CurveCycleEquipped>::CK2: Sync + Send,
-{
+impl<F: ReplField> MetaCmd<F> {
     const VERIFY: MetaCmd<F> = MetaCmd {
         name:
             "verify",
@@ -411,14 +449,71 @@ where
         run: |repl, cmd, args| {
             let first = repl.peek1(cmd, args)?;
             let proof_id = repl.get_string(&first)?;
-            LurkProof::<_, _, MultiFrame<'_, _, Coproc<F>>>::verify_proof(
-                &proof_id,
-            )?;
+            F::verify_proof(&proof_id, None)?;
             Ok(())
         }
     };
 }
 
+impl<F: ReplField> MetaCmd<F> {
+    const INSPECT: MetaCmd<F> = MetaCmd {
+        name:
+            "inspect",
+        summary:
+            "Print the claim of a proof",
+        format:
+            "!(inspect <string>)",
+        description: &[
+            "Print the claim, iterations, reduction count, backend and field of proof id <string>.",
+        ],
+        example: &[
+            "!(prove '(1 2 3))",
+            "!(inspect \"Nova_Pallas_10_166fafef9d86d1ddd29e7b62fa5e4fb2d7f4d885baf28e23187860d0720f74ca\")",
+        ],
+        run: |repl, cmd, args| {
+            let first = repl.peek1(cmd, args)?;
+            let proof_id = repl.get_string(&first)?;
+            LurkProofMeta::<F>::inspect(&proof_id)?;
+            Ok(())
+        }
+    };
+}
+
+impl<F: ReplField> MetaCmd<F> {
+    const SAVE_SESSION: MetaCmd<F> = MetaCmd {
+        name: "save-session",
+        summary: "Save the current env and packages to a file path.",
+        format: "!(save-session <string>)",
+        description: &["The session can be resumed later with `load-session`."],
+        example: &["!(def foo 123)", "!(save-session \"my-session\")"],
+        run: |repl, cmd, args| {
+            let first = repl.peek1(cmd, args)?;
+            match repl.store.fetch_string(&first) {
+                Some(path) => repl.save_session(&repl.pwd_path.join(Utf8Path::new(&path))),
+                _ => bail!("Argument of `save-session` must be a string."),
+            }
+        },
+    };
+
+    const LOAD_SESSION: MetaCmd<F> = MetaCmd {
+        name: "load-session",
+        summary: "Restore the env and packages saved to a file path.",
+        format: "!(load-session <string>)",
+        description: &["The current env and packages are replaced by the saved ones."],
+        example: &["!(load-session \"my-session\")", "foo"],
+        run: |repl, cmd, args| {
+            let first = repl.peek1(cmd, args)?;
+            match repl.store.fetch_string(&first) {
+                Some(path) => {
+                    let joined = repl.pwd_path.join(Utf8Path::new(&path));
+                    repl.load_session(&joined)
+                }
+                _ => bail!("Argument of `load-session` must be a string."),
+            }
+        },
+    };
+}
+
 impl<F: LurkField> MetaCmd<F> {
     const DEFPACKAGE: MetaCmd<F> = MetaCmd {
         name: "defpackage",
@@ -512,7 +607,7 @@ impl<F: LurkField> MetaCmd<F> {
     };
 }
 
-impl<F: LurkField> MetaCmd<F> {
+impl<F: ReplField> MetaCmd<F> {
     const HELP: MetaCmd<F> = MetaCmd {
         name: "help",
         summary: "Print help message.",
@@ -537,7 +632,7 @@ impl<F: LurkField> MetaCmd<F> {
                 ExprTag::Nil => {
                     use itertools::Itertools;
                     println!("Available commands:");
-                    for (_, i) in MetaCmd::cmds().iter().sorted_by_key(|x| x.0) {
+                    for (_, i) in Self::cmds().iter().sorted_by_key(|x| x.0) {
                         println!("  {} - {}", i.name, i.summary);
                     }
                 }
@@ -548,7 +643,7 @@ impl<F: LurkField> MetaCmd<F> {
     };
 
     fn meta_help(cmd: &str) {
-        match MetaCmd::cmds().get(cmd) {
+        match Self::cmds().get(cmd) {
             Some(i) => {
                 println!("{} - {}", i.name, i.summary);
                 for &e in i.description.iter() {
@@ -567,11 +662,15 @@ impl<F: LurkField> MetaCmd<F> {
     }
 }
 
-impl MetaCmd<F> {
-    const CMDS: [MetaCmd<F>; 19] = [
+impl<F: ReplField> MetaCmd<F> {
+    const CMDS: [MetaCmd<F>; 24] = [
         MetaCmd::LOAD,
+        MetaCmd::SAVE_SESSION,
+        MetaCmd::LOAD_SESSION,
         MetaCmd::DEF,
         MetaCmd::DEFREC,
+        MetaCmd::DEFMACRO,
+        MetaCmd::MACROEXPAND,
         MetaCmd::ASSERT,
         MetaCmd::ASSERT_EQ,
         MetaCmd::ASSERT_EMITTED,
@@ -584,6 +683,7 @@ impl MetaCmd<F> {
         MetaCmd::SET_ENV,
         MetaCmd::PROVE,
         MetaCmd::VERIFY,
+        MetaCmd::INSPECT,
         MetaCmd::DEFPACKAGE,
         MetaCmd::IMPORT,
         MetaCmd::IN_PACKAGE,
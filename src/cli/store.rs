@@ -0,0 +1,291 @@
+use std::{
+    fs,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{bail, Result};
+use camino::Utf8Path;
+use pasta_curves::pallas;
+use serde::{Deserialize, Serialize};
+
+use crate::field::LanguageField;
+
+use super::{
+    lurk_proof::LurkProofMeta,
+    parse_proof_key_field,
+    paths::{
+        commitment_path, commits_dir, proof_meta_path, proof_path, proofs_dir, verifier_key_path,
+    },
+};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A portable bundle of proofs along with their metas, claim commitments and
+/// verifier keys.
+///
+/// The files are kept as they were persisted, so the field checks performed when
+/// loading them still apply after an import.
+#[derive(Serialize, Deserialize)]
+struct Archive {
+    entries: Vec<ArchiveEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchiveEntry {
+    proof_key: String,
+    proof: Vec<u8>,
+    meta: Vec<u8>,
+    commitment: Option<Vec<u8>>,
+    /// The name and contents of the verifier key of the proof, if any
+    vk: Option<(String, Vec<u8>)>,
+}
+
+/// Reads the file at `path`, if it exists
+fn read_if_exists(path: &Utf8Path) -> Result<Option<Vec<u8>>> {
+    if path.exists() {
+        Ok(Some(fs::read(path)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the stems of the files in `dir` with extension `ext`, sorted
+fn file_stems(dir: &Utf8Path, ext: &str) -> Result<Vec<String>> {
+    let mut stems = vec![];
+    for entry in dir.read_dir_utf8()? {
+        let path = entry?.into_path();
+        if path.extension() == Some(ext) {
+            if let Some(stem) = path.file_stem() {
+                stems.push(stem.to_owned());
+            }
+        }
+    }
+    stems.sort();
+    Ok(stems)
+}
+
+/// The claim hash is the last component of a proof key
+fn claim_hash(proof_key: &str) -> &str {
+    proof_key.rsplit('_').next().unwrap_or(proof_key)
+}
+
+/// Proof keys end up in file names, so they can't be used to escape the proofs
+/// directory
+fn validate_proof_key(proof_key: &str) -> Result<()> {
+    if proof_key.split('_').count() != 4 || proof_key.contains(['/', '\\', '.']) {
+        bail!("Malformed proof key: {proof_key}")
+    }
+    Ok(())
+}
+
+fn claim_summary(proof_key: &str) -> Result<String> {
+    match parse_proof_key_field(proof_key)? {
+        LanguageField::Pallas => LurkProofMeta::<pallas::Scalar>::claim_summary(proof_key),
+        LanguageField::BLS12_381 => LurkProofMeta::<blstrs::Scalar>::claim_summary(proof_key),
+        field => bail!("Proofs over {field} are not supported yet"),
+    }
+}
+
+fn vk_name(proof_key: &str) -> Result<Option<String>> {
+    match parse_proof_key_field(proof_key)? {
+        LanguageField::Pallas => LurkProofMeta::<pallas::Scalar>::vk_name(proof_key),
+        LanguageField::BLS12_381 => LurkProofMeta::<blstrs::Scalar>::vk_name(proof_key),
+        field => bail!("Proofs over {field} are not supported yet"),
+    }
+}
+
+/// Verifier key names end up in file names too
+fn validate_vk_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.contains(['/', '\\', '.']) {
+        bail!("Malformed verifier key name: {name}")
+    }
+    Ok(())
+}
+
+/// Prints the persisted proofs, with their claims, and commitments
+pub(crate) fn list() -> Result<()> {
+    let proof_keys = file_stems(&proofs_dir(), "proof")?;
+    println!("Proofs ({}):", proof_keys.len());
+    for proof_key in &proof_keys {
+        match claim_summary(proof_key) {
+            Ok(summary) => println!("  {proof_key}\n    {summary}"),
+            Err(e) => println!("  {proof_key}\n    <unreadable meta: {e}>"),
+        }
+    }
+
+    let hashes = file_stems(&commits_dir(), "commit")?;
+    println!("Commitments ({}):", hashes.len());
+    for hash in &hashes {
+        if proof_keys.iter().any(|key| claim_hash(key) == hash) {
+            println!("  0x{hash} (claim)");
+        } else {
+            println!("  0x{hash}");
+        }
+    }
+    Ok(())
+}
+
+/// Whether the file at `path` exists and was last modified before `threshold`
+fn modified_before(path: &Utf8Path, threshold: SystemTime) -> Result<bool> {
+    if !path.exists() {
+        return Ok(false);
+    }
+    Ok(fs::metadata(path)?.modified()? < threshold)
+}
+
+/// Deletes the file at `path`, if it exists, and returns whether it did
+fn remove_if_exists(path: &Utf8Path) -> Result<bool> {
+    if !path.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(path)?;
+    println!("Deleted {path}");
+    Ok(true)
+}
+
+/// Deletes the proofs that were last modified more than `days` days ago, along
+/// with their metas and claim commitments, and the verifier keys that no longer
+/// serve any proof. Other commitments may be referenced by users, so they're
+/// only deleted if `commitments` is set.
+pub(crate) fn gc(days: u64, commitments: bool) -> Result<()> {
+    let Some(threshold) = days
+        .checked_mul(SECONDS_PER_DAY)
+        .and_then(|secs| SystemTime::now().checked_sub(Duration::from_secs(secs)))
+    else {
+        bail!("Age threshold is too large: {days} days")
+    };
+    let proofs_dir = proofs_dir();
+    let mut deleted = 0;
+
+    // a proof and its meta are deleted together, even if only one of them
+    // remains
+    let mut proof_keys = file_stems(&proofs_dir, "proof")?;
+    proof_keys.extend(file_stems(&proofs_dir, "meta")?);
+    proof_keys.sort();
+    proof_keys.dedup();
+    let mut stale_keys = vec![];
+    let mut kept_claims = vec![];
+    let mut kept_vks = vec![];
+    // if a remaining meta can't be read, the verifier keys it may refer to are kept
+    let mut keep_all_vks = false;
+    for proof_key in proof_keys {
+        let proof = proof_path(&proof_key);
+        let stale = if proof.exists() {
+            modified_before(&proof, threshold)?
+        } else {
+            modified_before(&proof_meta_path(&proof_key), threshold)?
+        };
+        if stale {
+            stale_keys.push(proof_key);
+        } else {
+            match vk_name(&proof_key) {
+                Ok(Some(name)) => kept_vks.push(name),
+                Ok(None) => (),
+                Err(_) => keep_all_vks = true,
+            }
+            kept_claims.push(claim_hash(&proof_key).to_owned());
+        }
+    }
+
+    // claim commitments are kept as long as some proof of the claim remains
+    for proof_key in &stale_keys {
+        let hash = claim_hash(proof_key);
+        let mut paths = vec![proof_path(proof_key), proof_meta_path(proof_key)];
+        if !kept_claims.iter().any(|kept| kept == hash) {
+            paths.push(commitment_path(hash));
+        }
+        for path in paths {
+            if remove_if_exists(&path)? {
+                deleted += 1;
+            }
+        }
+    }
+
+    if !keep_all_vks {
+        for name in file_stems(&proofs_dir, "vk")? {
+            let path = verifier_key_path(&name);
+            if !kept_vks.contains(&name)
+                && modified_before(&path, threshold)?
+                && remove_if_exists(&path)?
+            {
+                deleted += 1;
+            }
+        }
+    }
+
+    if commitments {
+        for hash in file_stems(&commits_dir(), "commit")? {
+            let path = commitment_path(&hash);
+            if !kept_claims.contains(&hash)
+                && modified_before(&path, threshold)?
+                && remove_if_exists(&path)?
+            {
+                deleted += 1;
+            }
+        }
+    }
+    println!("{deleted} file(s) deleted");
+    Ok(())
+}
+
+/// Bundles the proofs with keys in `proof_keys`, or all the persisted proofs if
+/// none is provided, into the archive file at `archive_path`
+pub(crate) fn export(archive_path: &Utf8Path, proof_keys: &[String]) -> Result<()> {
+    let proof_keys = if proof_keys.is_empty() {
+        file_stems(&proofs_dir(), "proof")?
+    } else {
+        proof_keys.to_vec()
+    };
+    let mut entries = Vec::with_capacity(proof_keys.len());
+    for proof_key in proof_keys {
+        validate_proof_key(&proof_key)?;
+        // a meta that can't be read is still exported, without a verifier key
+        let vk = match vk_name(&proof_key) {
+            Ok(Some(name)) => read_if_exists(&verifier_key_path(&name))?.map(|vk| (name, vk)),
+            Ok(None) | Err(_) => None,
+        };
+        entries.push(ArchiveEntry {
+            proof: fs::read(proof_path(&proof_key))?,
+            meta: fs::read(proof_meta_path(&proof_key))?,
+            commitment: read_if_exists(&commitment_path(claim_hash(&proof_key)))?,
+            vk,
+            proof_key,
+        });
+    }
+    let n_entries = entries.len();
+    fs::write(archive_path, bincode::serialize(&Archive { entries })?)?;
+    println!("Exported {n_entries} proof(s) to {archive_path}");
+    Ok(())
+}
+
+/// Unpacks the archive file at `archive_path` into the proofs and commits
+/// directories, skipping proofs that are already persisted
+pub(crate) fn import(archive_path: &Utf8Path) -> Result<()> {
+    let Archive { entries } = bincode::deserialize(&fs::read(archive_path)?)?;
+    for entry in entries {
+        let proof_key = &entry.proof_key;
+        validate_proof_key(proof_key)?;
+        if let Some((name, _)) = &entry.vk {
+            validate_vk_name(name)?;
+        }
+        let path = proof_path(proof_key);
+        if path.exists() {
+            println!("Skipping \"{proof_key}\": already persisted");
+            continue;
+        }
+        fs::write(path, entry.proof)?;
+        fs::write(proof_meta_path(proof_key), entry.meta)?;
+        if let Some(commitment) = entry.commitment {
+            fs::write(commitment_path(claim_hash(proof_key)), commitment)?;
+        }
+        if let Some((name, vk)) = entry.vk {
+            // verifier keys are shared, so one that's already persisted is kept
+            let path = verifier_key_path(&name);
+            if !path.exists() {
+                fs::write(path, vk)?;
+            }
+        }
+        println!("Imported \"{proof_key}\"");
+    }
+    Ok(())
+}
@@ -12,13 +12,14 @@ pub enum Continuation<F: LurkField> {
         continuation: ContPtr<F>,
     },
     Call {
-        unevaled_arg: Ptr<F>,
+        unevaled_args: Ptr<F>,
         saved_env: Ptr<F>,
         continuation: ContPtr<F>,
     },
     Call2 {
         saved_env: Ptr<F>,
         function: Ptr<F>,
+        unevaled_args: Ptr<F>,
         continuation: ContPtr<F>,
     },
     Tail {
@@ -64,6 +65,25 @@ pub enum Continuation<F: LurkField> {
     Emit {
         continuation: ContPtr<F>,
     },
+    /// A step of a vector operation. `vector` is the node being read or updated, or the length
+    /// of the vector being made, and `evaled_arg` is the element it's made of or the index of the
+    /// element being updated.
+    Vector {
+        operator: Op2,
+        vector: Ptr<F>,
+        evaled_arg: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
+    /// Evaluation bounded by `eval-with-limit`. `inner` is the continuation of the sandboxed
+    /// evaluation, which has `fuel` (a u64) reduction steps left, and `saved_env` is the
+    /// environment to restore when it returns to `continuation`. A sandboxed evaluation can't
+    /// open another one, see `notes/reduction-notes.md`.
+    Fuel {
+        inner: ContPtr<F>,
+        fuel: Ptr<F>,
+        saved_env: Ptr<F>,
+        continuation: ContPtr<F>,
+    },
     Dummy,
     Terminal,
 }
@@ -96,21 +116,25 @@ impl<F: LurkField> Continuation<F> {
                 .call0_store
                 .insert_probe(Box::new((*saved_env, *continuation))),
             Self::Call {
-                unevaled_arg,
+                unevaled_args,
                 saved_env,
                 continuation,
             } => {
                 store
                     .call_store
-                    .insert_probe(Box::new((*unevaled_arg, *saved_env, *continuation)))
+                    .insert_probe(Box::new((*unevaled_args, *saved_env, *continuation)))
             }
             Self::Call2 {
                 function,
                 saved_env,
+                unevaled_args,
                 continuation,
-            } => store
-                .call2_store
-                .insert_probe(Box::new((*function, *saved_env, *continuation))),
+            } => store.call2_store.insert_probe(Box::new((
+                *function,
+                *saved_env,
+                *unevaled_args,
+                *continuation,
+            ))),
             Self::Tail {
                 saved_env,
                 continuation,
@@ -172,6 +196,27 @@ impl<F: LurkField> Continuation<F> {
                     .insert_probe(Box::new((*var, *body, *saved_env, *continuation)))
             }
             Self::Emit { continuation } => store.emit_store.insert_probe(Box::new(*continuation)),
+            Self::Vector {
+                operator,
+                vector,
+                evaled_arg,
+                continuation,
+            } => store.vector_store.insert_probe(Box::new((
+                *operator,
+                *vector,
+                *evaled_arg,
+                *continuation,
+            ))),
+            Self::Fuel {
+                inner,
+                fuel,
+                saved_env,
+                continuation,
+            } => {
+                store
+                    .fuel_store
+                    .insert_probe(Box::new((*inner, *fuel, *saved_env, *continuation)))
+            }
         }
     }
 
@@ -186,13 +231,14 @@ impl<F: LurkField> Continuation<F> {
                 continuation: _,
             } => ContTag::Call0,
             Self::Call {
-                unevaled_arg: _,
+                unevaled_args: _,
                 saved_env: _,
                 continuation: _,
             } => ContTag::Call,
             Self::Call2 {
                 function: _,
                 saved_env: _,
+                unevaled_args: _,
                 continuation: _,
             } => ContTag::Call2,
             Self::Tail {
@@ -235,8 +281,41 @@ impl<F: LurkField> Continuation<F> {
                 continuation: _,
             } => ContTag::LetRec,
             Self::Emit { continuation: _ } => ContTag::Emit,
+            Self::Vector {
+                operator: _,
+                vector: _,
+                evaled_arg: _,
+                continuation: _,
+            } => ContTag::Vector,
+            Self::Fuel {
+                inner: _,
+                fuel: _,
+                saved_env: _,
+                continuation: _,
+            } => ContTag::Fuel,
         }
     }
+    /// The continuation this one returns to, if any
+    pub fn continuation(&self) -> Option<ContPtr<F>> {
+        match self {
+            Self::Outermost | Self::Dummy | Self::Error | Self::Terminal => None,
+            Self::Call0 { continuation, .. }
+            | Self::Call { continuation, .. }
+            | Self::Call2 { continuation, .. }
+            | Self::Tail { continuation, .. }
+            | Self::Lookup { continuation, .. }
+            | Self::Unop { continuation, .. }
+            | Self::Binop { continuation, .. }
+            | Self::Binop2 { continuation, .. }
+            | Self::If { continuation, .. }
+            | Self::Let { continuation, .. }
+            | Self::LetRec { continuation, .. }
+            | Self::Vector { continuation, .. }
+            | Self::Fuel { continuation, .. }
+            | Self::Emit { continuation } => Some(*continuation),
+        }
+    }
+
     pub fn get_simple_cont(&self) -> ContPtr<F> {
         match self {
             Self::Outermost | Self::Dummy | Self::Error | Self::Terminal => {
@@ -0,0 +1,148 @@
+//! A Blake2s-256 coprocessor over inputs of a fixed number of bytes, given as described in `coprocessor::digest`.
+//!
+//! The hash is unkeyed, but may be personalized.
+
+use std::marker::PhantomData;
+
+use bellpepper::gadgets::blake2s::blake2s;
+use bellpepper_core::{boolean::Boolean, ConstraintSystem, SynthesisError};
+use blake2s_simd::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::circuit::gadgets::data::GlobalAllocations;
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use crate::coprocessor::digest::{self, DigestOutput};
+use crate::coprocessor::{CoCircuit, Coprocessor};
+use crate::eval::IO;
+use crate::field::LurkField;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Blake2sCoprocessor<F: LurkField> {
+    n: usize,
+    personalization: [u8; 8],
+    output: DigestOutput,
+    _p: PhantomData<F>,
+}
+
+impl<F: LurkField> Blake2sCoprocessor<F> {
+    /// Creates a coprocessor hashing inputs of exactly `n` bytes with the given `personalization`
+    pub fn new(n: usize, personalization: [u8; 8], output: DigestOutput) -> Self {
+        Self {
+            n,
+            personalization,
+            output,
+            _p: Default::default(),
+        }
+    }
+
+    fn hash(&self, bytes: &[u8]) -> Vec<u8> {
+        Params::new()
+            .hash_length(32)
+            .personal(&self.personalization)
+            .hash(bytes)
+            .as_bytes()
+            .to_vec()
+    }
+}
+
+impl<F: LurkField> Coprocessor<F> for Blake2sCoprocessor<F> {
+    fn eval_arity(&self) -> usize {
+        1
+    }
+
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        digest::evaluate(s, args, env, cont, self.n, self.output, |bytes| {
+            self.hash(bytes)
+        })
+    }
+
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        let bytes = digest::input_bytes(s, &args[0], self.n)
+            .expect("invalid input for the Blake2s coprocessor");
+        self.output.intern(s, &self.hash(&bytes))
+    }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
+}
+
+impl<F: LurkField> CoCircuit<F> for Blake2sCoprocessor<F> {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let (result, valid) = digest::synthesize(
+            cs,
+            g,
+            store,
+            not_dummy,
+            &input_exprs[0],
+            self.n,
+            self.output,
+            |cs, bytes| {
+                // the gadget already works on the little-endian bits of each byte
+                let digest = blake2s(
+                    cs.namespace(|| "blake2s"),
+                    &bytes.concat(),
+                    &self.personalization,
+                )?;
+                Ok(digest.chunks(8).map(|bits| bits.to_vec()).collect())
+            },
+        )?;
+        let cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "blake2s cont"),
+            &valid,
+            input_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        Ok((result, input_env.clone(), cont))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coprocessor::digest::test::check_circuit;
+    use pasta_curves::pallas::Scalar as Fr;
+
+    #[test]
+    fn test_blake2s_evaluation() {
+        let s = &Store::<Fr>::default();
+        let coproc = Blake2sCoprocessor::<Fr>::new(3, [0; 8], DigestOutput::U64s);
+        // blake2s-256("abc")
+        let expected = s.list(&[
+            s.intern_u64(0x508c5e8c327c14e2),
+            s.intern_u64(0xe1a72ba34eeb452f),
+            s.intern_u64(0x37458b209ed63a29),
+            s.intern_u64(0x4d999b4c86675982),
+        ]);
+        let string = s.intern_string("abc");
+        let bytes = s.list(&[s.num(97), s.num(98), s.num(99)]);
+        assert_eq!(coproc.simple_evaluate(s, &[string]), expected);
+        assert_eq!(coproc.simple_evaluate(s, &[bytes]), expected);
+
+        let personalized = Blake2sCoprocessor::<Fr>::new(3, *b"Lurk_abc", DigestOutput::U64s);
+        assert_ne!(personalized.simple_evaluate(s, &[string]), expected);
+    }
+
+    #[test]
+    fn test_blake2s_circuit() {
+        for output in [DigestOutput::U64s, DigestOutput::Num] {
+            check_circuit(&Blake2sCoprocessor::<Fr>::new(3, *b"Lurk_abc", output));
+        }
+    }
+}
@@ -10,7 +10,7 @@ pub mod non_wasm {
 
     use ansi_term::Colour::Red;
     use anyhow::{bail, Result};
-    use bellpepper_core::{ConstraintSystem, SynthesisError};
+    use bellpepper_core::{boolean::Boolean, ConstraintSystem, SynthesisError};
     use circom_scotia::r1cs::CircomConfig;
 
     use crate::{
@@ -116,6 +116,7 @@ Then run `lurk coprocessor --name {name} <{}_FOLDER>` to instantiate a new gadge
             cs: &mut CS,
             g: &GlobalAllocations<F>,
             _store: &Store<F>,
+            _not_dummy: &Boolean,
             input_exprs: &[AllocatedPtr<F>],
             input_env: &AllocatedPtr<F>,
             input_cont: &AllocatedContPtr<F>,
@@ -0,0 +1,395 @@
+//! Helpers for the coprocessors computing digests of byte strings, like `Sha256Coprocessor`.
+//!
+//! These coprocessors take a single argument of a fixed number of bytes: either a string, whose characters must all be
+//! bytes, or a proper list of nums in the `0..256` range. Like all coprocessor arguments, it isn't evaluated. The
+//! digest is returned as specified by a `DigestOutput`, and invalid inputs evaluate to an error.
+//!
+//! In circuits, bytes are represented by their little-endian bits.
+
+use bellpepper::gadgets::multipack::pack_bits;
+use bellpepper_core::boolean::Boolean;
+use bellpepper_core::num::AllocatedNum;
+use bellpepper_core::{ConstraintSystem, SynthesisError};
+use serde::{Deserialize, Serialize};
+
+use crate::circuit::gadgets::constraints::{alloc_equal, alloc_is_zero, implies_equal, pick};
+use crate::circuit::gadgets::data::GlobalAllocations;
+use crate::circuit::gadgets::pointer::AllocatedPtr;
+use crate::eval::IO;
+use crate::expr::Expression;
+use crate::field::LurkField;
+use crate::lurk_sym_ptr;
+use crate::num::Num;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+use crate::tag::{ExprTag, Tag};
+
+/// How a digest is returned to Lurk
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DigestOutput {
+    /// A list of u64s, the first one holding the most significant bytes
+    #[default]
+    U64s,
+    /// A num holding the `F::CAPACITY` least significant bits of the digest
+    Num,
+}
+
+impl DigestOutput {
+    /// Interns `digest`, whose length must be a multiple of 8 bytes
+    pub(crate) fn intern<F: LurkField>(self, s: &Store<F>, digest: &[u8]) -> Ptr<F> {
+        match self {
+            Self::U64s => {
+                let words = digest
+                    .chunks(8)
+                    .map(|word| s.intern_u64(u64::from_be_bytes(word.try_into().unwrap())))
+                    .collect::<Vec<_>>();
+                s.list(&words)
+            }
+            Self::Num => {
+                let bits = digest
+                    .iter()
+                    .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+                    .collect::<Vec<_>>();
+                let capacity = bits.len().min(F::CAPACITY as usize);
+                let scalar = bits[bits.len() - capacity..]
+                    .iter()
+                    .fold(F::ZERO, |acc, bit| {
+                        if *bit {
+                            acc.double() + F::ONE
+                        } else {
+                            acc.double()
+                        }
+                    });
+                s.intern_num(Num::Scalar(scalar))
+            }
+        }
+    }
+
+    /// Allocates the pointer interned by `intern` for the allocated `digest`
+    pub(crate) fn synthesize<F: LurkField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        digest: &[Vec<Boolean>],
+    ) -> Result<AllocatedPtr<F>, SynthesisError> {
+        match self {
+            Self::U64s => {
+                let words = digest
+                    .chunks(8)
+                    .enumerate()
+                    .map(|(i, word)| {
+                        let bits = little_endian_bits(word);
+                        let num = pack_bits(cs.namespace(|| format!("word {i}")), &bits)?;
+                        AllocatedPtr::alloc_tag(
+                            &mut cs.namespace(|| format!("word {i} ptr")),
+                            ExprTag::U64.to_field(),
+                            num,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                AllocatedPtr::construct_list(
+                    cs.namespace(|| "words"),
+                    g,
+                    store,
+                    &words.iter().collect::<Vec<_>>(),
+                )
+            }
+            Self::Num => {
+                let mut bits = little_endian_bits(digest);
+                bits.truncate(F::CAPACITY as usize);
+                let num = pack_bits(cs.namespace(|| "num"), &bits)?;
+                AllocatedPtr::alloc_tag(cs, ExprTag::Num.to_field(), num)
+            }
+        }
+    }
+}
+
+/// The little-endian bits of the big-endian integer made of `bytes`
+fn little_endian_bits(bytes: &[Vec<Boolean>]) -> Vec<Boolean> {
+    bytes.iter().rev().flatten().cloned().collect()
+}
+
+/// Returns the bytes of `input`, if it's a valid input of `n` bytes
+pub(crate) fn input_bytes<F: LurkField>(s: &Store<F>, input: &Ptr<F>, n: usize) -> Option<Vec<u8>> {
+    let bytes = match input.tag {
+        ExprTag::Str => s
+            .fetch_string(input)?
+            .chars()
+            .map(|c| u8::try_from(c).ok())
+            .collect::<Option<Vec<_>>>()?,
+        ExprTag::Cons | ExprTag::Nil => s
+            .fetch_list(input)?
+            .iter()
+            .map(|elt| match elt.tag {
+                ExprTag::Num => u8::try_from(s.fetch_num(elt)?.into_scalar().to_u64()?).ok(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    (bytes.len() == n).then_some(bytes)
+}
+
+/// Evaluates a call, with arguments `args`, to a coprocessor computing the digest of `n` bytes with `hash`
+pub(crate) fn evaluate<F: LurkField>(
+    s: &Store<F>,
+    args: Ptr<F>,
+    env: Ptr<F>,
+    cont: ContPtr<F>,
+    n: usize,
+    output: DigestOutput,
+    hash: impl FnOnce(&[u8]) -> Vec<u8>,
+) -> IO<F> {
+    let bytes = s
+        .fetch_list(&args)
+        .filter(|argv| argv.len() == 1)
+        .and_then(|argv| input_bytes(s, &argv[0], n));
+    match bytes {
+        Some(bytes) => IO {
+            expr: output.intern(s, &hash(&bytes)),
+            env,
+            cont,
+        },
+        None => IO {
+            expr: args,
+            env,
+            cont: s.intern_cont_error(),
+        },
+    }
+}
+
+/// Synthesizes a call, on the allocated `input`, to a coprocessor computing the digest of `n` bytes with the `hash`
+/// gadget. Returns the result along with whether `input` is valid, for callers to select the error continuation
+/// otherwise. Coprocessor circuits are synthesized for frames that don't call them, in which case their inputs are
+/// arbitrary, so the decomposition of `input` is only enforced under `not_dummy`. Validity then only depends on the
+/// tags of the decomposed nodes and elements and on the range of the elements.
+pub(crate) fn synthesize<F, CS, H>(
+    cs: &mut CS,
+    g: &GlobalAllocations<F>,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    input: &AllocatedPtr<F>,
+    n: usize,
+    output: DigestOutput,
+    hash: H,
+) -> Result<(AllocatedPtr<F>, Boolean), SynthesisError>
+where
+    F: LurkField,
+    CS: ConstraintSystem<F>,
+    H: FnOnce(&mut CS, &[Vec<Boolean>]) -> Result<Vec<Vec<Boolean>>, SynthesisError>,
+{
+    let (bytes, valid) =
+        synthesize_input(&mut cs.namespace(|| "input"), g, store, not_dummy, input, n)?;
+    let digest = hash(cs, &bytes)?;
+    let result = output.synthesize(&mut cs.namespace(|| "output"), g, store, &digest)?;
+    Ok((result, valid))
+}
+
+/// Destructures `input` into `n` bytes, returning them along with whether `input` is valid. Each node whose tag matches
+/// is enforced under `not_dummy` to be the cons of the allocated element and rest.
+fn synthesize_input<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    g: &GlobalAllocations<F>,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    input: &AllocatedPtr<F>,
+    n: usize,
+) -> Result<(Vec<Vec<Boolean>>, Boolean), SynthesisError> {
+    let is_str = alloc_equal(cs.namespace(|| "is str"), input.tag(), &g.str_tag)?;
+    let node_tag = pick(
+        cs.namespace(|| "node tag"),
+        &is_str,
+        &g.str_tag,
+        &g.cons_tag,
+    )?;
+    let element_tag = pick(
+        cs.namespace(|| "element tag"),
+        &is_str,
+        &g.char_tag,
+        &g.num_tag,
+    )?;
+    let end = AllocatedPtr::pick(
+        cs.namespace(|| "end"),
+        &is_str,
+        &g.empty_str_ptr,
+        &g.nil_ptr,
+    )?;
+    let nil = lurk_sym_ptr!(store, nil);
+
+    let mut checks = Vec::with_capacity(3 * n + 1);
+    let mut bytes = Vec::with_capacity(n);
+    let mut node = input.clone();
+    let mut decomposing = not_dummy.clone();
+    for i in 0..n {
+        let cs = &mut cs.namespace(|| format!("element {i}"));
+        let (car, cdr) = node
+            .ptr(store)
+            .and_then(|ptr| match store.fetch(&ptr) {
+                Some(Expression::Cons(car, cdr) | Expression::Str(car, cdr)) => Some((car, cdr)),
+                _ => None,
+            })
+            .unwrap_or((nil, nil));
+        let element = AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "car"), store, || Ok(&car))?;
+        let rest = AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "cdr"), store, || Ok(&cdr))?;
+        // strings are hashed just like conses
+        let constructed =
+            AllocatedPtr::construct_cons(cs.namespace(|| "node"), g, store, &element, &rest)?;
+
+        // the empty string has the tag of a node, but no element
+        let tag_matches = alloc_equal(cs.namespace(|| "node tag matches"), node.tag(), &node_tag)?;
+        let is_end = node.alloc_equal(&mut cs.namespace(|| "node is end"), &end)?;
+        let is_node = Boolean::and(cs.namespace(|| "is node"), &tag_matches, &is_end.not())?;
+        decomposing = Boolean::and(cs.namespace(|| "decomposing"), &decomposing, &is_node)?;
+        implies_equal(
+            &mut cs.namespace(|| "node hash matches"),
+            &decomposing,
+            node.hash(),
+            constructed.hash(),
+        );
+
+        checks.push(is_node);
+        checks.push(alloc_equal(
+            cs.namespace(|| "element tag matches"),
+            element.tag(),
+            &element_tag,
+        )?);
+        let (bits, is_byte) = alloc_byte(&mut cs.namespace(|| "byte"), element.hash())?;
+        checks.push(is_byte);
+        bytes.push(bits);
+        node = rest;
+    }
+    checks.push(node.alloc_equal(&mut cs.namespace(|| "end matches"), &end)?);
+
+    let mut valid = Boolean::Constant(true);
+    for (i, check) in checks.iter().enumerate() {
+        valid = Boolean::and(cs.namespace(|| format!("valid {i}")), &valid, check)?;
+    }
+    Ok((bytes, valid))
+}
+
+/// Allocates the bits of the least significant byte of `num`, returning them along with whether they make up all of
+/// `num`. The decomposition of `num` is enforced, so that a byte can't be claimed not to be one.
+fn alloc_byte<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    num: &AllocatedNum<F>,
+) -> Result<(Vec<Boolean>, Boolean), SynthesisError> {
+    let mut bits = num.to_bits_le_strict(cs.namespace(|| "bits"))?;
+    let high = pack_bits(cs.namespace(|| "high bits"), &bits[8..])?;
+    let is_byte = alloc_is_zero(cs.namespace(|| "is byte"), &high)?;
+    bits.truncate(8);
+    Ok((bits, is_byte))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use bellpepper_core::test_cs::TestConstraintSystem;
+    use ff::Field;
+    use pasta_curves::pallas::Scalar as Fr;
+
+    use super::*;
+    use crate::coprocessor::{CoCircuit, Coprocessor};
+
+    /// Inputs of 3 bytes that must be rejected
+    pub(crate) fn invalid_inputs(s: &Store<Fr>) -> Vec<Ptr<Fr>> {
+        vec![
+            s.intern_string("ab"),
+            s.intern_string("abλ"),
+            s.list(&[s.num(97), s.num(98), s.num(256)]),
+            s.list(&[s.num(97), s.num(98), s.uint64(99)]),
+            s.num(97),
+        ]
+    }
+
+    /// Checks that the circuit of `coproc`, which must hash inputs of 3 bytes, agrees with its evaluation on valid and
+    /// invalid inputs
+    pub(crate) fn check_circuit<C: Coprocessor<Fr>>(coproc: &C) {
+        let s = &Store::<Fr>::default();
+        for input in [
+            s.intern_string("abc"),
+            s.list(&[s.num(97), s.num(98), s.num(99)]),
+        ] {
+            let expected = coproc.simple_evaluate(s, &[input]);
+            let expected = s.hash_expr(&expected).unwrap();
+            let (result, is_error) = synthesize_call(coproc, s, &input, true).unwrap();
+            assert!(!is_error);
+            assert_eq!(result, Some((expected.tag_field(), *expected.value())));
+        }
+        for input in invalid_inputs(s) {
+            let (_, is_error) = synthesize_call(coproc, s, &input, true).unwrap();
+            assert!(is_error);
+        }
+        // the hash of a node must open to its element and rest, so an input can't be rejected as invalid by choosing
+        // another decomposition, and one without a known decomposition can't be proven when actually called
+        let opaque = s.intern_opaque_cons(Fr::from(42));
+        assert!(synthesize_call(coproc, s, &opaque, false).is_some());
+        assert!(synthesize_call(coproc, s, &opaque, true).is_none());
+    }
+
+    /// Synthesizes a call on `input`. Returns the tag and hash of the result, along with whether the continuation is
+    /// the error one, or `None` if the circuit isn't satisfied.
+    fn synthesize_call<C: CoCircuit<Fr>>(
+        coproc: &C,
+        s: &Store<Fr>,
+        input: &Ptr<Fr>,
+        not_dummy: bool,
+    ) -> Option<(Option<(Fr, Fr)>, bool)> {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let g = GlobalAllocations::new(&mut cs.namespace(|| "global_allocations"), s).unwrap();
+        let input = AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "arg"), s, || Ok(input)).unwrap();
+        let (result, _, cont) = coproc
+            .synthesize(
+                &mut cs,
+                &g,
+                s,
+                &Boolean::Constant(not_dummy),
+                &[input],
+                &g.nil_ptr,
+                &g.terminal_ptr,
+            )
+            .unwrap();
+        if !cs.is_satisfied() {
+            return None;
+        }
+        let result = result.tag().get_value().zip(result.hash().get_value());
+        let is_error = cont.tag().get_value() == g.error_ptr_cont.tag().get_value()
+            && cont.hash().get_value() == g.error_ptr_cont.hash().get_value();
+        Some((result, is_error))
+    }
+
+    #[test]
+    fn test_input_bytes() {
+        let s = &Store::<Fr>::default();
+        let expected = Some(vec![97, 98, 99]);
+        assert_eq!(input_bytes(s, &s.intern_string("abc"), 3), expected);
+        let list = s.list(&[s.num(97), s.num(98), s.num(99)]);
+        assert_eq!(input_bytes(s, &list, 3), expected);
+        assert_eq!(input_bytes(s, &s.intern_string(""), 0), Some(vec![]));
+        for input in invalid_inputs(s) {
+            assert_eq!(input_bytes(s, &input, 3), None);
+        }
+    }
+
+    #[test]
+    fn test_digest_output() {
+        let s = &Store::<Fr>::default();
+        let mut digest = [0u8; 32];
+        digest[0] = 0xff;
+        digest[31] = 1;
+        assert_eq!(
+            DigestOutput::U64s.intern(s, &digest),
+            s.list(&[
+                s.uint64(0xff00_0000_0000_0000),
+                s.uint64(0),
+                s.uint64(0),
+                s.uint64(1)
+            ])
+        );
+        // the most significant bits don't fit
+        let expected = Fr::from(2).pow_vartime([254]) - Fr::from(2).pow_vartime([248]) + Fr::ONE;
+        assert_eq!(
+            DigestOutput::Num.intern(s, &digest),
+            s.num(Num::Scalar(expected))
+        );
+    }
+}
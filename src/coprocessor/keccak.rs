@@ -0,0 +1,315 @@
+//! A Keccak-256 coprocessor over inputs of a fixed number of bytes, given as described in `coprocessor::digest`.
+//!
+//! This is the original Keccak padding, as used by Ethereum, rather than the SHA3-256 one.
+
+use std::marker::PhantomData;
+
+use bellpepper_core::boolean::Boolean;
+use bellpepper_core::{ConstraintSystem, SynthesisError};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::circuit::gadgets::data::GlobalAllocations;
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use crate::coprocessor::digest::{self, DigestOutput};
+use crate::coprocessor::{CoCircuit, Coprocessor};
+use crate::eval::IO;
+use crate::field::LurkField;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+
+/// The number of bytes absorbed per permutation
+const RATE: usize = 136;
+
+/// The rotation offsets of the lanes, indexed by `x + 5 * y`
+const ROTATIONS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeccakCoprocessor<F: LurkField> {
+    n: usize,
+    output: DigestOutput,
+    _p: PhantomData<F>,
+}
+
+impl<F: LurkField> KeccakCoprocessor<F> {
+    /// Creates a coprocessor hashing inputs of exactly `n` bytes
+    pub fn new(n: usize, output: DigestOutput) -> Self {
+        Self {
+            n,
+            output,
+            _p: Default::default(),
+        }
+    }
+}
+
+fn hash(bytes: &[u8]) -> Vec<u8> {
+    Keccak256::digest(bytes).to_vec()
+}
+
+/// The bytes appended to a message of `n` bytes to fill its last block
+fn padding(n: usize) -> Vec<u8> {
+    let mut padding = vec![0; RATE - n % RATE];
+    padding[0] |= 0x01;
+    *padding.last_mut().unwrap() |= 0x80;
+    padding
+}
+
+/// XORs two lanes given by their little-endian bits
+fn xor_lanes<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    a: &[Boolean],
+    b: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError> {
+    a.iter()
+        .zip(b)
+        .enumerate()
+        .map(|(i, (a, b))| Boolean::xor(cs.namespace(|| format!("bit {i}")), a, b))
+        .collect()
+}
+
+/// Rotates a lane given by its little-endian bits to the left
+fn rotate_lane(lane: &[Boolean], by: usize) -> Vec<Boolean> {
+    let mut lane = lane.to_vec();
+    lane.rotate_right(by);
+    lane
+}
+
+/// The Keccak-f[1600] permutation over 25 lanes, each given by its 64 little-endian bits
+fn keccak_f<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    mut state: Vec<Vec<Boolean>>,
+) -> Result<Vec<Vec<Boolean>>, SynthesisError> {
+    for (round, round_constant) in ROUND_CONSTANTS.iter().enumerate() {
+        let cs = &mut cs.namespace(|| format!("round {round}"));
+
+        // theta
+        let mut columns = Vec::with_capacity(5);
+        for x in 0..5 {
+            let mut column = state[x].clone();
+            for y in 1..5 {
+                column = xor_lanes(
+                    &mut cs.namespace(|| format!("column {x} {y}")),
+                    &column,
+                    &state[x + 5 * y],
+                )?;
+            }
+            columns.push(column);
+        }
+        for x in 0..5 {
+            let d = xor_lanes(
+                &mut cs.namespace(|| format!("d {x}")),
+                &columns[(x + 4) % 5],
+                &rotate_lane(&columns[(x + 1) % 5], 1),
+            )?;
+            for y in 0..5 {
+                state[x + 5 * y] = xor_lanes(
+                    &mut cs.namespace(|| format!("theta {x} {y}")),
+                    &state[x + 5 * y],
+                    &d,
+                )?;
+            }
+        }
+
+        // rho and pi
+        let mut moved = vec![vec![]; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                moved[y + 5 * ((2 * x + 3 * y) % 5)] =
+                    rotate_lane(&state[x + 5 * y], ROTATIONS[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for x in 0..5 {
+            for y in 0..5 {
+                let cs = &mut cs.namespace(|| format!("chi {x} {y}"));
+                let next = &moved[(x + 1) % 5 + 5 * y];
+                let after_next = &moved[(x + 2) % 5 + 5 * y];
+                let masked = next
+                    .iter()
+                    .zip(after_next)
+                    .enumerate()
+                    .map(|(i, (a, b))| {
+                        Boolean::and(cs.namespace(|| format!("and {i}")), &a.not(), b)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                state[x + 5 * y] =
+                    xor_lanes(&mut cs.namespace(|| "xor"), &moved[x + 5 * y], &masked)?;
+            }
+        }
+
+        // iota
+        let constant = (0..64)
+            .map(|i| Boolean::Constant((round_constant >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+        state[0] = xor_lanes(&mut cs.namespace(|| "iota"), &state[0], &constant)?;
+    }
+    Ok(state)
+}
+
+/// Hashes `bytes`, each given by its little-endian bits, into the little-endian bits of the 32 bytes of the digest
+fn keccak256<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    bytes: &[Vec<Boolean>],
+) -> Result<Vec<Vec<Boolean>>, SynthesisError> {
+    let padding = padding(bytes.len()).into_iter().map(|byte| {
+        (0..8)
+            .map(|i| Boolean::Constant((byte >> i) & 1 == 1))
+            .collect::<Vec<_>>()
+    });
+    let message = bytes.iter().cloned().chain(padding).collect::<Vec<_>>();
+
+    let mut state = vec![vec![Boolean::Constant(false); 64]; 25];
+    for (i, block) in message.chunks(RATE).enumerate() {
+        let cs = &mut cs.namespace(|| format!("block {i}"));
+        // lanes are read from their least significant byte
+        for (j, lane) in block.chunks(8).enumerate() {
+            let lane = lane.concat();
+            state[j] = xor_lanes(
+                &mut cs.namespace(|| format!("absorb {j}")),
+                &state[j],
+                &lane,
+            )?;
+        }
+        state = keccak_f(&mut cs.namespace(|| "permutation"), state)?;
+    }
+    Ok(state[..4]
+        .iter()
+        .flat_map(|lane| lane.chunks(8).map(<[Boolean]>::to_vec))
+        .collect())
+}
+
+impl<F: LurkField> Coprocessor<F> for KeccakCoprocessor<F> {
+    fn eval_arity(&self) -> usize {
+        1
+    }
+
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        digest::evaluate(s, args, env, cont, self.n, self.output, hash)
+    }
+
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        let bytes = digest::input_bytes(s, &args[0], self.n)
+            .expect("invalid input for the Keccak-256 coprocessor");
+        self.output.intern(s, &hash(&bytes))
+    }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
+}
+
+impl<F: LurkField> CoCircuit<F> for KeccakCoprocessor<F> {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let (result, valid) = digest::synthesize(
+            cs,
+            g,
+            store,
+            not_dummy,
+            &input_exprs[0],
+            self.n,
+            self.output,
+            |cs, bytes| keccak256(&mut cs.namespace(|| "keccak256"), bytes),
+        )?;
+        let cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "keccak cont"),
+            &valid,
+            input_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        Ok((result, input_env.clone(), cont))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coprocessor::digest::test::check_circuit;
+    use pasta_curves::pallas::Scalar as Fr;
+
+    #[test]
+    fn test_padding() {
+        assert_eq!(padding(RATE - 1), vec![0x81]);
+        assert_eq!(padding(RATE - 2), vec![0x01, 0x80]);
+        let full = padding(RATE);
+        assert_eq!(full.len(), RATE);
+        assert_eq!((full[0], full[RATE - 1]), (0x01, 0x80));
+    }
+
+    #[test]
+    fn test_keccak_evaluation() {
+        let s = &Store::<Fr>::default();
+        let coproc = KeccakCoprocessor::<Fr>::new(3, DigestOutput::U64s);
+        // keccak256("abc")
+        let expected = s.list(&[
+            s.intern_u64(0x4e03657aea45a94f),
+            s.intern_u64(0xc7d47ba826c8d667),
+            s.intern_u64(0xc0d1e6e33a64a036),
+            s.intern_u64(0xec44f58fa12d6c45),
+        ]);
+        let string = s.intern_string("abc");
+        let bytes = s.list(&[s.num(97), s.num(98), s.num(99)]);
+        assert_eq!(coproc.simple_evaluate(s, &[string]), expected);
+        assert_eq!(coproc.simple_evaluate(s, &[bytes]), expected);
+
+        // keccak256("")
+        let coproc = KeccakCoprocessor::<Fr>::new(0, DigestOutput::U64s);
+        let expected = s.list(&[
+            s.intern_u64(0xc5d2460186f7233c),
+            s.intern_u64(0x927e7db2dcc703c0),
+            s.intern_u64(0xe500b653ca82273b),
+            s.intern_u64(0x7bfad8045d85a470),
+        ]);
+        assert_eq!(coproc.simple_evaluate(s, &[s.intern_string("")]), expected);
+    }
+
+    #[test]
+    fn test_keccak_circuit() {
+        for output in [DigestOutput::U64s, DigestOutput::Num] {
+            check_circuit(&KeccakCoprocessor::<Fr>::new(3, output));
+        }
+    }
+}
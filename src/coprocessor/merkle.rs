@@ -0,0 +1,562 @@
+//! A coprocessor checking membership proofs in Poseidon Merkle trees built outside of Lurk, unlike the ones managed by
+//! `coprocessor::trie::Trie`.
+//!
+//! Trees have a fixed arity, among 2, 4 and 8, and a fixed depth. Each node is the Poseidon hash, with the constants of
+//! `hash::PoseidonCache`, of its children. `(verify root leaf path siblings)` returns `t` when `leaf` is a member of the
+//! tree with root `root`, and `nil` otherwise:
+//! - `root` and `leaf` are nums.
+//! - `path` is a num whose little-endian bits give the position of the leaf: its lowest `log2(arity)` bits give the
+//!   position of the leaf among its siblings, and so on up to the root. It must fit in `depth * log2(arity)` bits.
+//! - `siblings` is a proper list of nums holding the `arity - 1` siblings of each node along the path, in the order of
+//!   their positions, from the leaf up to the root.
+//!
+//! Other inputs evaluate to an error.
+
+use std::marker::PhantomData;
+
+use bellpepper::gadgets::multipack::pack_bits;
+use bellpepper_core::boolean::Boolean;
+use bellpepper_core::num::AllocatedNum;
+use bellpepper_core::{ConstraintSystem, SynthesisError};
+use serde::{Deserialize, Serialize};
+
+use crate::circuit::gadgets::constraints::{
+    alloc_equal, alloc_is_zero, and_v, implies_equal, pick,
+};
+use crate::circuit::gadgets::data::{hash_poseidon, GlobalAllocations};
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use crate::coprocessor::{CoCircuit, Coprocessor};
+use crate::eval::IO;
+use crate::expr::Expression;
+use crate::field::LurkField;
+use crate::hash::{HashArity, PoseidonCache};
+use crate::lurk_sym_ptr;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+use crate::tag::ExprTag;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleCoprocessor<F: LurkField> {
+    arity: usize,
+    depth: usize,
+    _p: PhantomData<F>,
+}
+
+/// The inputs of a call, once validated
+struct MerkleProof<F> {
+    root: F,
+    leaf: F,
+    path: Vec<bool>,
+    siblings: Vec<F>,
+}
+
+impl<F: LurkField> MerkleCoprocessor<F> {
+    /// Creates a coprocessor for trees of the given `arity`, which must be 2, 4 or 8, and `depth`, which must be
+    /// positive
+    pub fn new(arity: usize, depth: usize) -> Self {
+        assert!(
+            matches!(
+                HashArity::from(arity),
+                HashArity::A2 | HashArity::A4 | HashArity::A8
+            ),
+            "unsupported Merkle tree arity: {arity}"
+        );
+        assert!(depth > 0, "Merkle trees must have a positive depth");
+        let coproc = Self {
+            arity,
+            depth,
+            _p: Default::default(),
+        };
+        assert!(
+            coproc.path_len() <= F::CAPACITY as usize,
+            "Merkle tree too deep for the field"
+        );
+        coproc
+    }
+
+    fn arity_bits(&self) -> usize {
+        self.arity.trailing_zeros() as usize
+    }
+
+    /// The number of bits of a path
+    fn path_len(&self) -> usize {
+        self.depth * self.arity_bits()
+    }
+
+    /// The number of siblings of a path
+    fn siblings_len(&self) -> usize {
+        self.depth * (self.arity - 1)
+    }
+
+    fn fetch_proof(&self, s: &Store<F>, args: &[Ptr<F>]) -> Option<MerkleProof<F>> {
+        let [root, leaf, path, siblings] = args else {
+            return None;
+        };
+        let num = |ptr: &Ptr<F>| match ptr.tag {
+            ExprTag::Num => Some(s.fetch_num(ptr)?.into_scalar()),
+            _ => None,
+        };
+        let (root, leaf) = (num(root)?, num(leaf)?);
+
+        let path_bits = num(path)?.to_le_bits();
+        if path_bits.iter().skip(self.path_len()).any(|bit| *bit) {
+            return None;
+        }
+        let path = (0..self.path_len()).map(|i| path_bits[i]).collect();
+
+        let siblings = match siblings.tag {
+            ExprTag::Cons | ExprTag::Nil => s
+                .fetch_list(siblings)?
+                .iter()
+                .map(num)
+                .collect::<Option<Vec<_>>>()?,
+            _ => return None,
+        };
+        if siblings.len() != self.siblings_len() {
+            return None;
+        }
+        Some(MerkleProof {
+            root,
+            leaf,
+            path,
+            siblings,
+        })
+    }
+
+    /// Computes the root of the tree in which `leaf` is at `path`, with the given `siblings`
+    fn compute_root(&self, cache: &PoseidonCache<F>, leaf: F, path: &[bool], siblings: &[F]) -> F {
+        let positions = path.chunks(self.arity_bits()).map(|bits| {
+            bits.iter()
+                .rev()
+                .fold(0, |acc, bit| (acc << 1) | usize::from(*bit))
+        });
+        positions
+            .zip(siblings.chunks(self.arity - 1))
+            .fold(leaf, |node, (position, siblings)| {
+                let mut preimage = siblings.to_vec();
+                preimage.insert(position, node);
+                match preimage.len() {
+                    2 => cache.hash2(&preimage.try_into().unwrap()),
+                    4 => cache.hash4(&preimage.try_into().unwrap()),
+                    8 => cache.hash8(&preimage.try_into().unwrap()),
+                    _ => unreachable!(),
+                }
+            })
+    }
+
+    fn hash_preimage<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        store: &Store<F>,
+        preimage: Vec<AllocatedNum<F>>,
+    ) -> Result<AllocatedNum<F>, SynthesisError> {
+        let constants = store.poseidon_constants();
+        match self.arity {
+            2 => hash_poseidon(cs, preimage, constants.c2()),
+            4 => hash_poseidon(cs, preimage, constants.c4()),
+            8 => hash_poseidon(cs, preimage, constants.c8()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Mirrors `compute_root`, with `path` given by the little-endian bits of each position
+    fn synthesize_root<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        store: &Store<F>,
+        leaf: &AllocatedNum<F>,
+        path: &[Boolean],
+        siblings: &[AllocatedNum<F>],
+    ) -> Result<AllocatedNum<F>, SynthesisError> {
+        let mut node = leaf.clone();
+        for (i, (bits, siblings)) in path
+            .chunks(self.arity_bits())
+            .zip(siblings.chunks(self.arity - 1))
+            .enumerate()
+        {
+            let cs = &mut cs.namespace(|| format!("level {i}"));
+            let flags = position_flags(&mut cs.namespace(|| "position flags"), self.arity, bits)?;
+
+            // the node is inserted at its position, shifting the siblings after it
+            let mut preimage = Vec::with_capacity(self.arity);
+            let mut is_before = Boolean::Constant(false);
+            for (j, flag) in flags.iter().enumerate() {
+                let cs = &mut cs.namespace(|| format!("element {j}"));
+                let sibling = if j == 0 {
+                    siblings[0].clone()
+                } else if j == self.arity - 1 {
+                    siblings[j - 1].clone()
+                } else {
+                    pick(
+                        cs.namespace(|| "sibling"),
+                        &is_before,
+                        &siblings[j - 1],
+                        &siblings[j],
+                    )?
+                };
+                preimage.push(pick(cs.namespace(|| "element"), flag, &node, &sibling)?);
+                is_before =
+                    Boolean::and(cs.namespace(|| "is before"), &is_before.not(), &flag.not())?
+                        .not();
+            }
+            node = self.hash_preimage(cs.namespace(|| "hash"), store, preimage)?;
+        }
+        Ok(node)
+    }
+}
+
+/// Returns one flag per position among `arity` ones, which is only set for the position given by its little-endian
+/// `bits`
+fn position_flags<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    arity: usize,
+    bits: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let mut flags = Vec::with_capacity(arity);
+    for position in 0..arity {
+        let mut flag = Boolean::Constant(true);
+        for (i, bit) in bits.iter().enumerate() {
+            let literal = if (position >> i) & 1 == 1 {
+                bit.clone()
+            } else {
+                bit.not()
+            };
+            flag = Boolean::and(
+                cs.namespace(|| format!("position {position} bit {i}")),
+                &flag,
+                &literal,
+            )?;
+        }
+        flags.push(flag);
+    }
+    Ok(flags)
+}
+
+/// Allocates the `n` least significant bits of `num`, returning them along with whether they make up all of `num`. The
+/// decomposition of `num` is enforced, so that whether it fits can't be forged.
+fn alloc_bits<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    num: &AllocatedNum<F>,
+    n: usize,
+) -> Result<(Vec<Boolean>, Boolean), SynthesisError> {
+    let mut bits = num.to_bits_le_strict(cs.namespace(|| "bits"))?;
+    let high = pack_bits(cs.namespace(|| "high bits"), &bits[n..])?;
+    let fits = alloc_is_zero(cs.namespace(|| "fits"), &high)?;
+    bits.truncate(n);
+    Ok((bits, fits))
+}
+
+/// Destructures `list` into `n` elements, returning their values along with checks that `list` is actually a proper
+/// list of `n` nums. Each node tagged as a cons is enforced under `not_dummy` to be the cons of the allocated element
+/// and rest, so the checks only depend on tags. Missing witnesses are replaced by `nil`.
+fn destructure_nums<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    g: &GlobalAllocations<F>,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    list: &AllocatedPtr<F>,
+    n: usize,
+) -> Result<(Vec<AllocatedNum<F>>, Vec<Boolean>), SynthesisError> {
+    let nil = lurk_sym_ptr!(store, nil);
+    let mut checks = Vec::with_capacity(2 * n + 1);
+    let mut nums = Vec::with_capacity(n);
+    let mut node = list.clone();
+    let mut decomposing = not_dummy.clone();
+    for i in 0..n {
+        let cs = &mut cs.namespace(|| format!("element {i}"));
+        let (car, cdr) = node
+            .ptr(store)
+            .and_then(|ptr| match store.fetch(&ptr) {
+                Some(Expression::Cons(car, cdr)) => Some((car, cdr)),
+                _ => None,
+            })
+            .unwrap_or((nil, nil));
+        let element = AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "car"), store, || Ok(&car))?;
+        let rest = AllocatedPtr::alloc_ptr(&mut cs.namespace(|| "cdr"), store, || Ok(&cdr))?;
+        let constructed =
+            AllocatedPtr::construct_cons(cs.namespace(|| "cons"), g, store, &element, &rest)?;
+
+        let is_cons = alloc_equal(cs.namespace(|| "is cons"), node.tag(), &g.cons_tag)?;
+        decomposing = Boolean::and(cs.namespace(|| "decomposing"), &decomposing, &is_cons)?;
+        implies_equal(
+            &mut cs.namespace(|| "hash matches"),
+            &decomposing,
+            node.hash(),
+            constructed.hash(),
+        );
+
+        checks.push(is_cons);
+        checks.push(alloc_equal(
+            cs.namespace(|| "is num"),
+            element.tag(),
+            &g.num_tag,
+        )?);
+        nums.push(element.hash().clone());
+        node = rest;
+    }
+    checks.push(node.alloc_equal(&mut cs.namespace(|| "ends with nil"), &g.nil_ptr)?);
+    Ok((nums, checks))
+}
+
+impl<F: LurkField> Coprocessor<F> for MerkleCoprocessor<F> {
+    fn eval_arity(&self) -> usize {
+        4
+    }
+
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        match s.fetch_list(&args) {
+            Some(argv) if self.fetch_proof(s, &argv).is_some() => IO {
+                expr: self.simple_evaluate(s, &argv),
+                env,
+                cont,
+            },
+            _ => IO {
+                expr: args,
+                env,
+                cont: s.intern_cont_error(),
+            },
+        }
+    }
+
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        let MerkleProof {
+            root,
+            leaf,
+            path,
+            siblings,
+        } = self
+            .fetch_proof(s, args)
+            .expect("invalid input for the Merkle coprocessor");
+        if self.compute_root(&s.poseidon_cache, leaf, &path, &siblings) == root {
+            lurk_sym_ptr!(s, t)
+        } else {
+            lurk_sym_ptr!(s, nil)
+        }
+    }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
+}
+
+impl<F: LurkField> CoCircuit<F> for MerkleCoprocessor<F> {
+    fn arity(&self) -> usize {
+        4
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let (root, leaf, path, siblings) = (
+            &input_exprs[0],
+            &input_exprs[1],
+            &input_exprs[2],
+            &input_exprs[3],
+        );
+
+        let mut checks = Vec::with_capacity(2 * self.siblings_len() + 5);
+        for (name, input) in [("root", root), ("leaf", leaf), ("path", path)] {
+            checks.push(alloc_equal(
+                cs.namespace(|| format!("{name} is num")),
+                input.tag(),
+                &g.num_tag,
+            )?);
+        }
+        let (path_bits, path_fits) = alloc_bits(
+            &mut cs.namespace(|| "path bits"),
+            path.hash(),
+            self.path_len(),
+        )?;
+        checks.push(path_fits);
+        let (siblings, siblings_checks) = destructure_nums(
+            &mut cs.namespace(|| "siblings"),
+            g,
+            store,
+            not_dummy,
+            siblings,
+            self.siblings_len(),
+        )?;
+        checks.extend(siblings_checks);
+        let valid = and_v(cs.namespace(|| "valid"), &checks.iter().collect::<Vec<_>>())?;
+
+        let computed_root = self.synthesize_root(
+            &mut cs.namespace(|| "computed root"),
+            store,
+            leaf.hash(),
+            &path_bits,
+            &siblings,
+        )?;
+        let is_member = alloc_equal(cs.namespace(|| "is member"), &computed_root, root.hash())?;
+
+        let result = AllocatedPtr::pick(
+            cs.namespace(|| "merkle result"),
+            &is_member,
+            &g.t_ptr,
+            &g.nil_ptr,
+        )?;
+        let cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "merkle cont"),
+            &valid,
+            input_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        Ok((result, input_env.clone(), cont))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bellpepper_core::test_cs::TestConstraintSystem;
+    use ff::Field;
+    use pasta_curves::pallas::Scalar as Fr;
+    use proptest::prelude::*;
+
+    use crate::field::FWrap;
+    use crate::num::Num;
+
+    const DEPTH: usize = 3;
+
+    /// Synthesizes a call on `inputs`. Returns the tag and hash of the result, along with whether the continuation is
+    /// the error one, or `None` if the circuit isn't satisfied.
+    fn synthesize_call(
+        coproc: &MerkleCoprocessor<Fr>,
+        s: &Store<Fr>,
+        inputs: &[Ptr<Fr>],
+        not_dummy: bool,
+    ) -> Option<(Option<(Fr, Fr)>, bool)> {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let g = GlobalAllocations::new(&mut cs.namespace(|| "global_allocations"), s).unwrap();
+        let inputs = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                AllocatedPtr::alloc_ptr(&mut cs.namespace(|| format!("input {i}")), s, || Ok(input))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let (result, _, cont) = coproc
+            .synthesize(
+                &mut cs,
+                &g,
+                s,
+                &Boolean::Constant(not_dummy),
+                &inputs,
+                &g.nil_ptr,
+                &g.terminal_ptr,
+            )
+            .unwrap();
+        if !cs.is_satisfied() {
+            return None;
+        }
+        let result = result.tag().get_value().zip(result.hash().get_value());
+        let is_error = cont.tag().get_value() == g.error_ptr_cont.tag().get_value()
+            && cont.hash().get_value() == g.error_ptr_cont.hash().get_value();
+        Some((result, is_error))
+    }
+
+    /// Checks that evaluating and synthesizing a call on `inputs` agree, returning whether the call succeeded
+    fn check_call(coproc: &MerkleCoprocessor<Fr>, s: &Store<Fr>, inputs: [Ptr<Fr>; 4]) -> bool {
+        let args = s.list(&inputs);
+        let io = coproc.evaluate(s, args, lurk_sym_ptr!(s, nil), s.intern_cont_outermost());
+        let (result, is_error) = synthesize_call(coproc, s, &inputs, true).unwrap();
+        assert_eq!(is_error, io.cont == s.intern_cont_error());
+        if is_error {
+            return false;
+        }
+        let expected = s.hash_expr(&io.expr).unwrap();
+        assert_eq!(result, Some((expected.tag_field(), *expected.value())));
+        io.expr == lurk_sym_ptr!(s, t)
+    }
+
+    fn inputs(s: &Store<Fr>, root: Fr, leaf: Fr, path: u64, siblings: &[Fr]) -> [Ptr<Fr>; 4] {
+        let siblings = siblings
+            .iter()
+            .map(|sibling| s.num(Num::Scalar(*sibling)))
+            .collect::<Vec<_>>();
+        [
+            s.num(Num::Scalar(root)),
+            s.num(Num::Scalar(leaf)),
+            s.num(path),
+            s.list(&siblings),
+        ]
+    }
+
+    #[test]
+    fn test_compute_root() {
+        let s = &Store::<Fr>::default();
+        let coproc = MerkleCoprocessor::<Fr>::new(4, 1);
+        let siblings = [Fr::from(1), Fr::from(2), Fr::from(3)];
+        let leaf = Fr::from(42);
+        // position 2 is given by the bits [0, 1]
+        let root = coproc.compute_root(&s.poseidon_cache, leaf, &[false, true], &siblings);
+        assert_eq!(
+            root,
+            s.poseidon_cache
+                .hash4(&[siblings[0], siblings[1], leaf, siblings[2]])
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let s = &Store::<Fr>::default();
+        let coproc = MerkleCoprocessor::<Fr>::new(2, DEPTH);
+        let siblings = [Fr::ONE; DEPTH];
+        let valid = inputs(s, Fr::ZERO, Fr::ZERO, 0, &siblings);
+        assert!(!check_call(&coproc, s, valid));
+
+        // the path doesn't fit
+        let invalid = inputs(s, Fr::ZERO, Fr::ZERO, 1 << DEPTH, &siblings);
+        assert!(!check_call(&coproc, s, invalid));
+        // too few siblings
+        let invalid = inputs(s, Fr::ZERO, Fr::ZERO, 0, &siblings[1..]);
+        assert!(!check_call(&coproc, s, invalid));
+        // not a num
+        let mut invalid = valid;
+        invalid[1] = s.intern_string("leaf");
+        assert!(!check_call(&coproc, s, invalid));
+
+        // siblings without a known decomposition can't be claimed invalid, so the call can't be proven
+        let mut opaque = valid;
+        opaque[3] = s.intern_opaque_cons(Fr::from(42));
+        assert!(synthesize_call(&coproc, s, &opaque, false).is_some());
+        assert!(synthesize_call(&coproc, s, &opaque, true).is_none());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(8))]
+
+        #[test]
+        fn test_membership(
+            arity_bits in 1usize..4,
+            path in any::<u64>(),
+            leaf in any::<FWrap<Fr>>(),
+            siblings in prop::collection::vec(any::<FWrap<Fr>>(), 7 * DEPTH),
+        ) {
+            let s = &Store::<Fr>::default();
+            let arity = 1 << arity_bits;
+            let coproc = MerkleCoprocessor::<Fr>::new(arity, DEPTH);
+            let path = path % (1 << coproc.path_len());
+            let path_bits = (0..coproc.path_len()).map(|i| (path >> i) & 1 == 1).collect::<Vec<_>>();
+            let siblings = siblings[..coproc.siblings_len()]
+                .iter()
+                .map(|sibling| sibling.0)
+                .collect::<Vec<_>>();
+            let root = coproc.compute_root(&s.poseidon_cache, leaf.0, &path_bits, &siblings);
+
+            prop_assert!(check_call(&coproc, s, inputs(s, root, leaf.0, path, &siblings)));
+            let other_leaf = leaf.0 + Fr::ONE;
+            prop_assert!(!check_call(&coproc, s, inputs(s, root, other_leaf, path, &siblings)));
+            let other_path = path ^ 1;
+            prop_assert!(!check_call(&coproc, s, inputs(s, root, leaf.0, other_path, &siblings)));
+        }
+    }
+}
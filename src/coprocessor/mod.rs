@@ -15,7 +15,13 @@ use crate::store::Store;
 use crate::tag::Tag;
 use crate::z_data::z_ptr::ZExprPtr;
 
+pub mod blake2s;
 pub mod circom;
+pub mod digest;
+pub mod keccak;
+pub mod merkle;
+pub mod schnorr;
+pub mod sha256;
 pub mod trie;
 
 /// `Coprocessor` is a trait that represents a generalized interface for coprocessors.
@@ -124,8 +130,15 @@ pub trait Coprocessor<F: LurkField>: Clone + Debug + Sync + Send + CoCircuit<F>
             F::from(arity as u64),
         )?;
 
-        let (result_expr, result_env, result_cont) =
-            self.synthesize(cs, g, store, &inputs, input_env, input_cont)?;
+        let (result_expr, result_env, result_cont) = self.synthesize(
+            cs,
+            g,
+            store,
+            &arity_is_correct,
+            &inputs,
+            input_env,
+            input_cont,
+        )?;
 
         let quoted_expr = AllocatedPtr::construct_list(
             &mut cs.namespace(|| "quote coprocessor result"),
@@ -179,11 +192,15 @@ pub trait CoCircuit<F: LurkField>: Send + Sync + Clone {
         todo!()
     }
 
+    /// Synthesizes the circuit of a call to the coprocessor. Since this circuit is also synthesized for frames that don't
+    /// call the coprocessor, checks on the inputs must only be enforced under `not_dummy`, which is set exactly when the
+    /// coprocessor is invoked.
     fn synthesize<CS: ConstraintSystem<F>>(
         &self,
         _cs: &mut CS,
         _g: &GlobalAllocations<F>,
         _store: &Store<F>,
+        _not_dummy: &Boolean,
         _input_exprs: &[AllocatedPtr<F>],
         _input_env: &AllocatedPtr<F>,
         _input_cont: &AllocatedContPtr<F>,
@@ -262,6 +279,7 @@ pub(crate) mod test {
             cs: &mut CS,
             _g: &GlobalAllocations<F>,
             _store: &Store<F>,
+            _not_dummy: &Boolean,
             input_exprs: &[AllocatedPtr<F>],
             input_env: &AllocatedPtr<F>,
             input_cont: &AllocatedContPtr<F>,
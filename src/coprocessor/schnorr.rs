@@ -0,0 +1,632 @@
+//! A Schnorr signature verification coprocessor over the curve embedded in the Lurk field.
+//!
+//! The signatures are over the short Weierstrass curve `y² = x³ + 5` over the Lurk field, with generator `G = (-1, 2)`:
+//! that's Vesta for the Pallas scalar field, and Pallas for the Vesta one. Other fields are not supported, and the
+//! coprocessor can't be created over them. A signature `(R, s)` of the message `m` by the public key `A` is valid when
+//! `[s]G = R + [c]A`, where the challenge `c` is the Poseidon hash of `(R.x, R.y, A.x, A.y, m, 0)`, read as an
+//! integer. Signers must therefore reduce `s` modulo the order of the curve, which may exceed the modulus of the Lurk
+//! field (as is the case for Pallas), with negligible probability.
+//!
+//! `(verify a-x a-y m r-x r-y s)` returns `t` when the signature is valid and `nil` otherwise. Its arguments must all be
+//! nums, otherwise it evaluates to an error.
+//!
+//! Points are added with the complete formulas for projective coordinates of Renes, Costello and Batina (2016,
+//! algorithm 7), so the circuit has no exceptional cases to handle.
+
+use std::marker::PhantomData;
+
+use bellpepper_core::boolean::Boolean;
+use bellpepper_core::num::AllocatedNum;
+use bellpepper_core::{ConstraintSystem, SynthesisError};
+use serde::{Deserialize, Serialize};
+
+use crate::circuit::gadgets::constraints::{add, alloc_equal, and_v, mul, pick, sub};
+use crate::circuit::gadgets::data::{allocate_constant, hash_poseidon, GlobalAllocations};
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use crate::coprocessor::{CoCircuit, Coprocessor};
+use crate::eval::IO;
+use crate::field::{LanguageField, LurkField};
+use crate::lurk_sym_ptr;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+use crate::tag::ExprTag;
+
+/// The `b` coefficient of the curve `y² = x³ + b`
+const CURVE_B: u64 = 5;
+
+/// A point of the curve in projective coordinates
+#[derive(Clone, Copy, Debug)]
+struct Point<F: LurkField> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+impl<F: LurkField> Point<F> {
+    fn identity() -> Self {
+        Self {
+            x: F::ZERO,
+            y: F::ONE,
+            z: F::ZERO,
+        }
+    }
+
+    fn generator() -> Self {
+        Self::from_affine(-F::ONE, F::from(2))
+    }
+
+    fn from_affine(x: F, y: F) -> Self {
+        Self { x, y, z: F::ONE }
+    }
+
+    fn to_affine(self) -> Option<(F, F)> {
+        let z_inv = Option::<F>::from(self.z.invert())?;
+        Some((self.x * z_inv, self.y * z_inv))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let b3 = F::from(3 * CURVE_B);
+        let t0 = self.x * other.x;
+        let t1 = self.y * other.y;
+        let t2 = self.z * other.z;
+        let t3 = (self.x + self.y) * (other.x + other.y) - (t0 + t1);
+        let t4 = (self.y + self.z) * (other.y + other.z) - (t1 + t2);
+        let y3 = ((self.x + self.z) * (other.x + other.z) - (t0 + t2)) * b3;
+        let t0 = t0 + t0 + t0;
+        let t2 = t2 * b3;
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        Self {
+            x: t3 * t1 - t4 * y3,
+            y: t1 * z3 + y3 * t0,
+            z: z3 * t4 + t0 * t3,
+        }
+    }
+
+    /// Multiplies by the integer given by its little-endian `bits`
+    fn mul(&self, bits: &[bool]) -> Self {
+        bits.iter().rev().fold(Self::identity(), |acc, bit| {
+            let acc = acc.add(&acc);
+            if *bit {
+                acc.add(self)
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl<F: LurkField> PartialEq for Point<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+fn le_bits<F: LurkField>(f: &F) -> Vec<bool> {
+    let bits = f.to_le_bits();
+    (0..bits.len()).map(|i| bits[i]).collect()
+}
+
+fn is_on_curve<F: LurkField>(x: F, y: F) -> bool {
+    y.square() == x.square() * x + F::from(CURVE_B)
+}
+
+fn challenge<F: LurkField>(store: &Store<F>, a: (F, F), m: F, r: (F, F)) -> F {
+    store
+        .poseidon_cache
+        .hash6(&[r.0, r.1, a.0, a.1, m, F::ZERO])
+}
+
+/// Checks the signature `(r, s)` of `m` by the public key `a`
+fn verify<F: LurkField>(store: &Store<F>, a: (F, F), m: F, r: (F, F), s: F) -> bool {
+    if !is_on_curve(a.0, a.1) || !is_on_curve(r.0, r.1) {
+        return false;
+    }
+    let c = challenge(store, a, m, r);
+    let lhs = Point::generator().mul(&le_bits(&s));
+    let rhs = Point::from_affine(r.0, r.1).add(&Point::from_affine(a.0, a.1).mul(&le_bits(&c)));
+    lhs == rhs
+}
+
+#[derive(Clone)]
+struct AllocatedPoint<F: LurkField> {
+    x: AllocatedNum<F>,
+    y: AllocatedNum<F>,
+    z: AllocatedNum<F>,
+}
+
+impl<F: LurkField> AllocatedPoint<F> {
+    fn constant<CS: ConstraintSystem<F>>(cs: &mut CS, point: Point<F>) -> Self {
+        let x = allocate_constant(&mut cs.namespace(|| "x"), point.x);
+        let y = allocate_constant(&mut cs.namespace(|| "y"), point.y);
+        let z = allocate_constant(&mut cs.namespace(|| "z"), point.z);
+        Self { x, y, z }
+    }
+
+    fn from_affine<CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        x: &AllocatedNum<F>,
+        y: &AllocatedNum<F>,
+    ) -> Self {
+        Self {
+            x: x.clone(),
+            y: y.clone(),
+            z: allocate_constant(&mut cs.namespace(|| "z"), F::ONE),
+        }
+    }
+
+    /// Mirrors `Point::add`
+    fn add<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let b3 = F::from(3 * CURVE_B);
+        let t0 = mul(cs.namespace(|| "t0"), &self.x, &other.x)?;
+        let t1 = mul(cs.namespace(|| "t1"), &self.y, &other.y)?;
+        let t2 = mul(cs.namespace(|| "t2"), &self.z, &other.z)?;
+        let t3 = cross_term(
+            &mut cs.namespace(|| "t3"),
+            (&self.x, &self.y),
+            (&other.x, &other.y),
+            (&t0, &t1),
+        )?;
+        let t4 = cross_term(
+            &mut cs.namespace(|| "t4"),
+            (&self.y, &self.z),
+            (&other.y, &other.z),
+            (&t1, &t2),
+        )?;
+        let y3 = cross_term(
+            &mut cs.namespace(|| "xz"),
+            (&self.x, &self.z),
+            (&other.x, &other.z),
+            (&t0, &t2),
+        )?;
+        let y3 = scale(&mut cs.namespace(|| "y3 * b3"), &y3, b3)?;
+        let t0 = scale(&mut cs.namespace(|| "t0 * 3"), &t0, F::from(3))?;
+        let t2 = scale(&mut cs.namespace(|| "t2 * b3"), &t2, b3)?;
+        let z3 = add(cs.namespace(|| "t1 + t2"), &t1, &t2)?;
+        let t1 = sub(cs.namespace(|| "t1 - t2"), &t1, &t2)?;
+
+        let t3_t1 = mul(cs.namespace(|| "t3 * t1"), &t3, &t1)?;
+        let t4_y3 = mul(cs.namespace(|| "t4 * y3"), &t4, &y3)?;
+        let t1_z3 = mul(cs.namespace(|| "t1 * z3"), &t1, &z3)?;
+        let y3_t0 = mul(cs.namespace(|| "y3 * t0"), &y3, &t0)?;
+        let z3_t4 = mul(cs.namespace(|| "z3 * t4"), &z3, &t4)?;
+        let t0_t3 = mul(cs.namespace(|| "t0 * t3"), &t0, &t3)?;
+        let x = sub(cs.namespace(|| "x"), &t3_t1, &t4_y3)?;
+        let y = add(cs.namespace(|| "y"), &t1_z3, &y3_t0)?;
+        let z = add(cs.namespace(|| "z"), &z3_t4, &t0_t3)?;
+        Ok(Self { x, y, z })
+    }
+
+    fn pick<CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        condition: &Boolean,
+        a: &Self,
+        b: &Self,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            x: pick(cs.namespace(|| "x"), condition, &a.x, &b.x)?,
+            y: pick(cs.namespace(|| "y"), condition, &a.y, &b.y)?,
+            z: pick(cs.namespace(|| "z"), condition, &a.z, &b.z)?,
+        })
+    }
+
+    /// Mirrors `Point::mul`
+    fn mul<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let mut acc = Self::constant(&mut cs.namespace(|| "identity"), Point::identity());
+        for (i, bit) in bits.iter().enumerate().rev() {
+            let cs = &mut cs.namespace(|| format!("bit {i}"));
+            let doubled = acc.add(&mut cs.namespace(|| "double"), &acc)?;
+            let sum = doubled.add(&mut cs.namespace(|| "add"), self)?;
+            acc = Self::pick(&mut cs.namespace(|| "pick"), bit, &sum, &doubled)?;
+        }
+        Ok(acc)
+    }
+
+    /// Mirrors `Point::eq`
+    fn alloc_equal<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        other: &Self,
+    ) -> Result<Boolean, SynthesisError> {
+        let x1_z2 = mul(cs.namespace(|| "x1 * z2"), &self.x, &other.z)?;
+        let x2_z1 = mul(cs.namespace(|| "x2 * z1"), &other.x, &self.z)?;
+        let y1_z2 = mul(cs.namespace(|| "y1 * z2"), &self.y, &other.z)?;
+        let y2_z1 = mul(cs.namespace(|| "y2 * z1"), &other.y, &self.z)?;
+        let x_equal = alloc_equal(cs.namespace(|| "x equal"), &x1_z2, &x2_z1)?;
+        let y_equal = alloc_equal(cs.namespace(|| "y equal"), &y1_z2, &y2_z1)?;
+        Boolean::and(cs.namespace(|| "equal"), &x_equal, &y_equal)
+    }
+}
+
+/// Computes `(a.0 + a.1) * (b.0 + b.1) - (products.0 + products.1)`
+fn cross_term<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    a: (&AllocatedNum<F>, &AllocatedNum<F>),
+    b: (&AllocatedNum<F>, &AllocatedNum<F>),
+    products: (&AllocatedNum<F>, &AllocatedNum<F>),
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let a = add(cs.namespace(|| "a"), a.0, a.1)?;
+    let b = add(cs.namespace(|| "b"), b.0, b.1)?;
+    let products = add(cs.namespace(|| "products"), products.0, products.1)?;
+    let product = mul(cs.namespace(|| "a * b"), &a, &b)?;
+    sub(cs.namespace(|| "difference"), &product, &products)
+}
+
+/// Multiplies `a` by the constant `k`
+fn scale<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    a: &AllocatedNum<F>,
+    k: F,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let res = AllocatedNum::alloc(cs.namespace(|| "scaled"), || {
+        Ok(a.get_value().ok_or(SynthesisError::AssignmentMissing)? * k)
+    })?;
+    cs.enforce(
+        || "scale",
+        |lc| lc + a.get_variable(),
+        |lc| lc + (k, CS::one()),
+        |lc| lc + res.get_variable(),
+    );
+    Ok(res)
+}
+
+fn alloc_is_on_curve<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    x: &AllocatedNum<F>,
+    y: &AllocatedNum<F>,
+) -> Result<Boolean, SynthesisError> {
+    let y2 = y.square(cs.namespace(|| "y^2"))?;
+    let x2 = x.square(cs.namespace(|| "x^2"))?;
+    let x3 = mul(cs.namespace(|| "x^3"), &x2, x)?;
+    let b = allocate_constant(&mut cs.namespace(|| "b"), F::from(CURVE_B));
+    let rhs = add(cs.namespace(|| "x^3 + b"), &x3, &b)?;
+    alloc_equal(cs.namespace(|| "on curve"), &y2, &rhs)
+}
+
+/// Mirrors `verify`
+fn synthesize_verify<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    store: &Store<F>,
+    a: (&AllocatedNum<F>, &AllocatedNum<F>),
+    m: &AllocatedNum<F>,
+    r: (&AllocatedNum<F>, &AllocatedNum<F>),
+    s: &AllocatedNum<F>,
+) -> Result<Boolean, SynthesisError> {
+    let a_on_curve = alloc_is_on_curve(&mut cs.namespace(|| "a on curve"), a.0, a.1)?;
+    let r_on_curve = alloc_is_on_curve(&mut cs.namespace(|| "r on curve"), r.0, r.1)?;
+
+    let zero = allocate_constant(&mut cs.namespace(|| "zero"), F::ZERO);
+    let c = hash_poseidon(
+        cs.namespace(|| "challenge"),
+        vec![
+            r.0.clone(),
+            r.1.clone(),
+            a.0.clone(),
+            a.1.clone(),
+            m.clone(),
+            zero,
+        ],
+        store.poseidon_constants().c6(),
+    )?;
+    let s_bits = s.to_bits_le_strict(cs.namespace(|| "s bits"))?;
+    let c_bits = c.to_bits_le_strict(cs.namespace(|| "c bits"))?;
+
+    let g = AllocatedPoint::constant(&mut cs.namespace(|| "generator"), Point::generator());
+    let a = AllocatedPoint::from_affine(&mut cs.namespace(|| "a"), a.0, a.1);
+    let r = AllocatedPoint::from_affine(&mut cs.namespace(|| "r"), r.0, r.1);
+    let lhs = g.mul(&mut cs.namespace(|| "[s]G"), &s_bits)?;
+    let ca = a.mul(&mut cs.namespace(|| "[c]A"), &c_bits)?;
+    let rhs = r.add(&mut cs.namespace(|| "R + [c]A"), &ca)?;
+    let equation_holds = lhs.alloc_equal(&mut cs.namespace(|| "equation holds"), &rhs)?;
+
+    let on_curve = Boolean::and(cs.namespace(|| "on curve"), &a_on_curve, &r_on_curve)?;
+    Boolean::and(cs.namespace(|| "valid"), &on_curve, &equation_holds)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchnorrCoprocessor<F: LurkField> {
+    _p: PhantomData<F>,
+}
+
+impl<F: LurkField> SchnorrCoprocessor<F> {
+    /// Creates a coprocessor over the Lurk field `F`, which must be supported (see `supports_field`)
+    pub fn new() -> Self {
+        assert!(
+            Self::supports_field(),
+            "unsupported field for signature verification: {}",
+            F::FIELD
+        );
+        Self {
+            _p: Default::default(),
+        }
+    }
+
+    /// Whether the curve of the signatures is embedded in the Lurk field `F`, which is only the case for the Pallas
+    /// and Vesta fields
+    pub fn supports_field() -> bool {
+        matches!(F::FIELD, LanguageField::Pallas | LanguageField::Vesta)
+    }
+}
+
+impl<F: LurkField> Default for SchnorrCoprocessor<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: LurkField> Coprocessor<F> for SchnorrCoprocessor<F> {
+    fn eval_arity(&self) -> usize {
+        6
+    }
+
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        match s.fetch_list(&args) {
+            Some(argv)
+                if argv.len() == self.eval_arity()
+                    && argv.iter().all(|arg| arg.tag == ExprTag::Num) =>
+            {
+                IO {
+                    expr: self.simple_evaluate(s, &argv),
+                    env,
+                    cont,
+                }
+            }
+            _ => IO {
+                expr: args,
+                env,
+                cont: s.intern_cont_error(),
+            },
+        }
+    }
+
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        let nums = args
+            .iter()
+            .map(|arg| {
+                s.fetch_num(arg)
+                    .expect("the Schnorr coprocessor expects nums")
+                    .into_scalar()
+            })
+            .collect::<Vec<_>>();
+        if verify(s, (nums[0], nums[1]), nums[2], (nums[3], nums[4]), nums[5]) {
+            lurk_sym_ptr!(s, t)
+        } else {
+            lurk_sym_ptr!(s, nil)
+        }
+    }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
+}
+
+impl<F: LurkField> CoCircuit<F> for SchnorrCoprocessor<F> {
+    fn arity(&self) -> usize {
+        6
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        _not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let are_nums = input_exprs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                alloc_equal(
+                    cs.namespace(|| format!("input {i} is num")),
+                    input.tag(),
+                    &g.num_tag,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let are_nums = and_v(
+            cs.namespace(|| "inputs are nums"),
+            &are_nums.iter().collect::<Vec<_>>(),
+        )?;
+
+        let nums = input_exprs
+            .iter()
+            .map(AllocatedPtr::hash)
+            .collect::<Vec<_>>();
+        let verified = synthesize_verify(
+            &mut cs.namespace(|| "verify"),
+            store,
+            (nums[0], nums[1]),
+            nums[2],
+            (nums[3], nums[4]),
+            nums[5],
+        )?;
+
+        let result = AllocatedPtr::pick(
+            cs.namespace(|| "schnorr result"),
+            &verified,
+            &g.t_ptr,
+            &g.nil_ptr,
+        )?;
+        let cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "schnorr cont"),
+            &are_nums,
+            input_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        Ok((result, input_env.clone(), cont))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bellpepper_core::test_cs::TestConstraintSystem;
+    use ff::{Field, PrimeField};
+    use pasta_curves::{pallas, vesta};
+    use proptest::prelude::*;
+
+    use crate::field::FWrap;
+    use crate::num::Num;
+
+    // Over the Pallas scalar field, the curve is Vesta, whose scalars are the Vesta scalar field
+    type Fr = pallas::Scalar;
+    type Scalar = vesta::Scalar;
+
+    /// Returns the public key, the message and the signature `(r, s)`, as inputs of the coprocessor
+    fn sign(store: &Store<Fr>, secret: Scalar, nonce: Scalar, m: Fr) -> [Fr; 6] {
+        let a = Point::generator()
+            .mul(&le_bits(&secret))
+            .to_affine()
+            .unwrap();
+        let r = Point::generator()
+            .mul(&le_bits(&nonce))
+            .to_affine()
+            .unwrap();
+        let c = challenge(store, a, m, r);
+        let c = le_bits(&c).iter().rev().fold(Scalar::ZERO, |acc, bit| {
+            if *bit {
+                acc.double() + Scalar::ONE
+            } else {
+                acc.double()
+            }
+        });
+        let s = Fr::from_repr((nonce + c * secret).to_repr()).unwrap();
+        [a.0, a.1, m, r.0, r.1, s]
+    }
+
+    /// Synthesizes a call on `inputs`, checking that the circuit is satisfied. Returns the tag and hash of the result,
+    /// along with whether the continuation is the error one.
+    fn synthesize_call(s: &Store<Fr>, inputs: &[Ptr<Fr>]) -> (Option<(Fr, Fr)>, bool) {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let g = GlobalAllocations::new(&mut cs.namespace(|| "global_allocations"), s).unwrap();
+        let inputs = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                AllocatedPtr::alloc_ptr(&mut cs.namespace(|| format!("input {i}")), s, || Ok(input))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let (result, _, cont) = SchnorrCoprocessor::default()
+            .synthesize(
+                &mut cs,
+                &g,
+                s,
+                &Boolean::Constant(true),
+                &inputs,
+                &g.nil_ptr,
+                &g.terminal_ptr,
+            )
+            .unwrap();
+        assert!(cs.is_satisfied());
+        let result = result.tag().get_value().zip(result.hash().get_value());
+        let is_error = cont.tag().get_value() == g.error_ptr_cont.tag().get_value()
+            && cont.hash().get_value() == g.error_ptr_cont.hash().get_value();
+        (result, is_error)
+    }
+
+    /// Checks that evaluating and synthesizing a call on `inputs` both return `expected`
+    fn check_call(s: &Store<Fr>, inputs: &[Fr; 6], expected: bool) {
+        let inputs = inputs.map(|input| s.num(Num::Scalar(input)));
+        let coproc = SchnorrCoprocessor::default();
+        let result = coproc.simple_evaluate(s, &inputs);
+        let expected = if expected {
+            lurk_sym_ptr!(s, t)
+        } else {
+            lurk_sym_ptr!(s, nil)
+        };
+        assert_eq!(result, expected);
+
+        let expected = s.hash_expr(&expected).unwrap();
+        let (result, is_error) = synthesize_call(s, &inputs);
+        assert!(!is_error);
+        assert_eq!(result, Some((expected.tag_field(), *expected.value())));
+    }
+
+    #[test]
+    fn test_group_law() {
+        let g = Point::<Fr>::generator();
+        assert!(is_on_curve(-Fr::ONE, Fr::from(2)));
+        assert_eq!(g.mul(&[true]), g);
+        assert_eq!(g.add(&Point::identity()), g);
+
+        let (a, b) = (Scalar::from(123456789), -Scalar::from(42));
+        assert_eq!(
+            g.mul(&le_bits(&b)).mul(&le_bits(&a)),
+            g.mul(&le_bits(&(a * b)))
+        );
+        // the order of the curve is the modulus of the scalar field
+        assert_eq!(g.mul(&le_bits(&-Scalar::ONE)).add(&g), Point::identity());
+    }
+
+    #[test]
+    fn test_non_num_inputs() {
+        let s = &Store::<Fr>::default();
+        let mut inputs = [Fr::ONE; 6].map(|input| s.num(Num::Scalar(input)));
+        inputs[2] = s.intern_string("hello");
+        let args = s.list(&inputs);
+        let io = SchnorrCoprocessor::default().evaluate(
+            s,
+            args,
+            lurk_sym_ptr!(s, nil),
+            s.intern_cont_outermost(),
+        );
+        assert_eq!(io.cont, s.intern_cont_error());
+
+        let (_, is_error) = synthesize_call(s, &inputs);
+        assert!(is_error);
+    }
+
+    #[test]
+    fn test_supported_fields() {
+        assert!(SchnorrCoprocessor::<pallas::Scalar>::supports_field());
+        assert!(SchnorrCoprocessor::<vesta::Scalar>::supports_field());
+        assert!(!SchnorrCoprocessor::<blstrs::Scalar>::supports_field());
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported field")]
+    fn test_unsupported_field() {
+        SchnorrCoprocessor::<blstrs::Scalar>::new();
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(3))]
+
+        #[test]
+        fn test_signatures(
+            secret in any::<FWrap<Scalar>>(),
+            nonce in any::<FWrap<Scalar>>(),
+            m in any::<FWrap<Fr>>(),
+        ) {
+            prop_assume!(secret.0 != Scalar::ZERO && nonce.0 != Scalar::ZERO);
+            let s = &Store::<Fr>::default();
+            let inputs = sign(s, secret.0, nonce.0, m.0);
+            check_call(s, &inputs, true);
+
+            let mut tampered = inputs;
+            tampered[2] += Fr::ONE;
+            check_call(s, &tampered, false);
+        }
+
+        #[test]
+        fn test_arbitrary_inputs(inputs in any::<[FWrap<Fr>; 6]>()) {
+            let s = &Store::<Fr>::default();
+            let inputs = inputs.map(|input| input.0);
+            let expected = verify(s, (inputs[0], inputs[1]), inputs[2], (inputs[3], inputs[4]), inputs[5]);
+            check_call(s, &inputs, expected);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! A SHA-256 coprocessor over inputs of a fixed number of bytes, given as described in `coprocessor::digest`.
+
+use std::marker::PhantomData;
+
+use bellpepper::gadgets::sha256::sha256;
+use bellpepper_core::boolean::Boolean;
+use bellpepper_core::{ConstraintSystem, SynthesisError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::circuit::gadgets::data::GlobalAllocations;
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
+use crate::coprocessor::digest::{self, DigestOutput};
+use crate::coprocessor::{CoCircuit, Coprocessor};
+use crate::eval::IO;
+use crate::field::LurkField;
+use crate::ptr::{ContPtr, Ptr};
+use crate::store::Store;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Sha256Coprocessor<F: LurkField> {
+    n: usize,
+    output: DigestOutput,
+    _p: PhantomData<F>,
+}
+
+impl<F: LurkField> Sha256Coprocessor<F> {
+    /// Creates a coprocessor hashing inputs of exactly `n` bytes
+    pub fn new(n: usize, output: DigestOutput) -> Self {
+        Self {
+            n,
+            output,
+            _p: Default::default(),
+        }
+    }
+}
+
+fn hash(bytes: &[u8]) -> Vec<u8> {
+    Sha256::digest(bytes).to_vec()
+}
+
+impl<F: LurkField> Coprocessor<F> for Sha256Coprocessor<F> {
+    fn eval_arity(&self) -> usize {
+        1
+    }
+
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        digest::evaluate(s, args, env, cont, self.n, self.output, hash)
+    }
+
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        let bytes = digest::input_bytes(s, &args[0], self.n)
+            .expect("invalid input for the SHA-256 coprocessor");
+        self.output.intern(s, &hash(&bytes))
+    }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
+}
+
+impl<F: LurkField> CoCircuit<F> for Sha256Coprocessor<F> {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let (result, valid) = digest::synthesize(
+            cs,
+            g,
+            store,
+            not_dummy,
+            &input_exprs[0],
+            self.n,
+            self.output,
+            |cs, bytes| {
+                // the gadget works on bits ordered from the most significant one of each byte
+                let preimage = bytes
+                    .iter()
+                    .flat_map(|bits| bits.iter().rev().cloned())
+                    .collect::<Vec<_>>();
+                let digest = sha256(cs.namespace(|| "sha256"), &preimage)?;
+                Ok(digest
+                    .chunks(8)
+                    .map(|bits| bits.iter().rev().cloned().collect::<Vec<Boolean>>())
+                    .collect())
+            },
+        )?;
+        let cont = AllocatedContPtr::pick(
+            &mut cs.namespace(|| "sha256 cont"),
+            &valid,
+            input_cont,
+            &g.error_ptr_cont,
+        )?;
+
+        Ok((result, input_env.clone(), cont))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coprocessor::digest::test::check_circuit;
+    use pasta_curves::pallas::Scalar as Fr;
+
+    #[test]
+    fn test_sha256_evaluation() {
+        let s = &Store::<Fr>::default();
+        let coproc = Sha256Coprocessor::<Fr>::new(3, DigestOutput::U64s);
+        // sha256("abc")
+        let expected = s.list(&[
+            s.intern_u64(0xba7816bf8f01cfea),
+            s.intern_u64(0x414140de5dae2223),
+            s.intern_u64(0xb00361a396177a9c),
+            s.intern_u64(0xb410ff61f20015ad),
+        ]);
+
+        let string = s.intern_string("abc");
+        let bytes = s.list(&[s.num(97), s.num(98), s.num(99)]);
+        assert_eq!(coproc.simple_evaluate(s, &[string]), expected);
+        assert_eq!(coproc.simple_evaluate(s, &[bytes]), expected);
+    }
+
+    #[test]
+    fn test_sha256_circuit() {
+        for output in [DigestOutput::U64s, DigestOutput::Num] {
+            check_circuit(&Sha256Coprocessor::<Fr>::new(3, output));
+        }
+    }
+}
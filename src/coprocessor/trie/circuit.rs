@@ -0,0 +1,330 @@
+//! Circuits for the `Trie` coprocessors. They prove exactly what `LookupProof::verify` checks: that the preimages
+//! along the path of a key hash up to the root of the trie. An insertion additionally recomputes the root from the same
+//! preimages, with the leaf replaced by the new value.
+//!
+//! Coprocessor circuits are synthesized for every frame of an IVC proof, whether or not the frame actually calls the
+//! coprocessor, so the hashes are only enforced under `not_dummy`, which is set exactly when the coprocessor is invoked.
+//! A call whose path can't be opened therefore can't be proven at all.
+
+use bellpepper_core::{boolean::Boolean, num::AllocatedNum, ConstraintSystem, SynthesisError};
+
+use crate::circuit::gadgets::constraints::{implies_equal, pick};
+use crate::circuit::gadgets::data::hash_poseidon;
+use crate::field::LurkField;
+use crate::store::Store;
+
+use super::{StandardTrie, TRIE_ARITY, TRIE_HEIGHT};
+
+const TRIE_ARITY_BITS: usize = TRIE_ARITY.trailing_zeros() as usize;
+
+/// Fetches from `store` the preimages along the path of `key` in the trie with root `root`. Inputs without such a path
+/// can't be opened anyway, so any preimages will do for them. Returns `None` when the inputs have no value, as is the
+/// case when synthesizing blank circuits.
+fn preimage_path_witness<F: LurkField>(
+    store: &Store<F>,
+    root: Option<F>,
+    key: Option<F>,
+) -> Option<Vec<[F; TRIE_ARITY]>> {
+    let (root, key) = (root?, key?);
+    let trie = StandardTrie::new_with_root(store, root);
+    let preimage_path = trie.prove_lookup(key).map_or_else(
+        |_| vec![[F::ZERO; TRIE_ARITY]; TRIE_HEIGHT],
+        |proof| proof.preimage_path,
+    );
+    Some(preimage_path)
+}
+
+fn alloc_preimage_path<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    witness: Option<&[[F; TRIE_ARITY]]>,
+) -> Result<Vec<Vec<AllocatedNum<F>>>, SynthesisError> {
+    let mut preimage_path = Vec::with_capacity(TRIE_HEIGHT);
+    for i in 0..TRIE_HEIGHT {
+        let mut preimage = Vec::with_capacity(TRIE_ARITY);
+        for j in 0..TRIE_ARITY {
+            preimage.push(AllocatedNum::alloc(
+                cs.namespace(|| format!("preimage {i} element {j}")),
+                || {
+                    witness
+                        .map(|w| w[i][j])
+                        .ok_or(SynthesisError::AssignmentMissing)
+                },
+            )?);
+        }
+        preimage_path.push(preimage);
+    }
+    Ok(preimage_path)
+}
+
+/// Decomposes `key` into the same path as `Trie::path`, but with each step given by its little-endian bits.
+fn alloc_path<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    key: &AllocatedNum<F>,
+) -> Result<Vec<Vec<Boolean>>, SynthesisError> {
+    let mut bits = key.to_bits_le_strict(cs.namespace(|| "key bits"))?;
+    // The path may need more bits than the field has, in which case the most significant ones are zero.
+    bits.resize(TRIE_ARITY_BITS * TRIE_HEIGHT, Boolean::Constant(false));
+    Ok(bits
+        .chunks(TRIE_ARITY_BITS)
+        .rev()
+        .map(<[Boolean]>::to_vec)
+        .collect())
+}
+
+/// Selects `preimage[k]`, where `k` is given by its little-endian `bits`.
+fn select<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    preimage: &[AllocatedNum<F>],
+    bits: &[Boolean],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let mut candidates = preimage.to_vec();
+    for (i, bit) in bits.iter().enumerate() {
+        candidates = candidates
+            .chunks(2)
+            .enumerate()
+            .map(|(j, pair)| {
+                pick(
+                    cs.namespace(|| format!("pick {i} {j}")),
+                    bit,
+                    &pair[1],
+                    &pair[0],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+    debug_assert_eq!(candidates.len(), 1);
+    Ok(candidates.swap_remove(0))
+}
+
+/// Returns one flag per position of a preimage, which is only set for the position `k` given by its little-endian
+/// `bits`.
+fn position_flags<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    bits: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let mut flags = Vec::with_capacity(TRIE_ARITY);
+    for position in 0..TRIE_ARITY {
+        let mut flag = Boolean::Constant(true);
+        for (i, bit) in bits.iter().enumerate() {
+            let literal = if (position >> i) & 1 == 1 {
+                bit.clone()
+            } else {
+                bit.not()
+            };
+            flag = Boolean::and(
+                cs.namespace(|| format!("position {position} bit {i}")),
+                &flag,
+                &literal,
+            )?;
+        }
+        flags.push(flag);
+    }
+    Ok(flags)
+}
+
+/// Descends from `root` along `path`, enforcing under `not_dummy` that each preimage hashes to the node it opens.
+/// Returns the leaf at the end of the path.
+fn open_path<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    root: &AllocatedNum<F>,
+    path: &[Vec<Boolean>],
+    preimage_path: &[Vec<AllocatedNum<F>>],
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let mut node = root.clone();
+    for (i, (bits, preimage)) in path.iter().zip(preimage_path).enumerate() {
+        let cs = &mut cs.namespace(|| format!("open level {i}"));
+        let hash = hash_poseidon(
+            cs.namespace(|| "hash"),
+            preimage.clone(),
+            store.poseidon_constants().c8(),
+        )?;
+        implies_equal(
+            &mut cs.namespace(|| "hash matches"),
+            not_dummy,
+            &hash,
+            &node,
+        );
+        node = select(&mut cs.namespace(|| "select"), preimage, bits)?;
+    }
+    Ok(node)
+}
+
+/// Replaces the leaf at the end of `path` with `value` and rehashes `preimage_path` from the bottom up, returning the
+/// new root.
+fn update_path<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    store: &Store<F>,
+    path: &[Vec<Boolean>],
+    preimage_path: &[Vec<AllocatedNum<F>>],
+    value: &AllocatedNum<F>,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let mut node = value.clone();
+    for (i, (bits, preimage)) in path.iter().zip(preimage_path).enumerate().rev() {
+        let cs = &mut cs.namespace(|| format!("update level {i}"));
+        let flags = position_flags(&mut cs.namespace(|| "position flags"), bits)?;
+        let new_preimage = preimage
+            .iter()
+            .zip(&flags)
+            .enumerate()
+            .map(|(j, (elt, flag))| pick(cs.namespace(|| format!("element {j}")), flag, &node, elt))
+            .collect::<Result<Vec<_>, _>>()?;
+        node = hash_poseidon(
+            cs.namespace(|| "hash"),
+            new_preimage,
+            store.poseidon_constants().c8(),
+        )?;
+    }
+    Ok(node)
+}
+
+/// Synthesizes the lookup of `key` in the trie with root `root` and returns the value found. The path to it is only
+/// checked under `not_dummy`.
+pub(crate) fn synthesize_lookup<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    root: &AllocatedNum<F>,
+    key: &AllocatedNum<F>,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let witness = preimage_path_witness(store, root.get_value(), key.get_value());
+    let preimage_path =
+        alloc_preimage_path(&mut cs.namespace(|| "preimage path"), witness.as_deref())?;
+    let path = alloc_path(&mut cs.namespace(|| "path"), key)?;
+    open_path(
+        &mut cs.namespace(|| "open"),
+        store,
+        not_dummy,
+        root,
+        &path,
+        &preimage_path,
+    )
+}
+
+/// Synthesizes the insertion of `value` under `key` in the trie with root `root` and returns the new root. The path to
+/// the old value is only checked under `not_dummy`.
+pub(crate) fn synthesize_insert<F: LurkField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    store: &Store<F>,
+    not_dummy: &Boolean,
+    root: &AllocatedNum<F>,
+    key: &AllocatedNum<F>,
+    value: &AllocatedNum<F>,
+) -> Result<AllocatedNum<F>, SynthesisError> {
+    let witness = preimage_path_witness(store, root.get_value(), key.get_value());
+    let preimage_path =
+        alloc_preimage_path(&mut cs.namespace(|| "preimage path"), witness.as_deref())?;
+    let path = alloc_path(&mut cs.namespace(|| "path"), key)?;
+    open_path(
+        &mut cs.namespace(|| "open"),
+        store,
+        not_dummy,
+        root,
+        &path,
+        &preimage_path,
+    )?;
+    let new_root = update_path(
+        &mut cs.namespace(|| "update"),
+        store,
+        &path,
+        &preimage_path,
+        value,
+    )?;
+    Ok(new_root)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bellpepper_core::test_cs::TestConstraintSystem;
+    use ff::Field;
+    use pasta_curves::pallas::Scalar as Fr;
+
+    fn alloc_nums<CS: ConstraintSystem<Fr>>(cs: &mut CS, values: &[Fr]) -> Vec<AllocatedNum<Fr>> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                AllocatedNum::alloc_infallible(cs.namespace(|| format!("input {i}")), || *v)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_and_lookup_circuits() {
+        let store = Store::<Fr>::default();
+        let mut trie = StandardTrie::new(&store);
+        let empty_root = trie.root();
+        let (key, value) = (Fr::from(123), Fr::from(456));
+        trie.insert(key, value).unwrap();
+        let root = trie.root();
+        let not_dummy = Boolean::Constant(true);
+
+        {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let inputs = alloc_nums(&mut cs, &[empty_root, key, value]);
+            let new_root = synthesize_insert(
+                &mut cs, &store, &not_dummy, &inputs[0], &inputs[1], &inputs[2],
+            )
+            .unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(new_root.get_value(), Some(root));
+        }
+
+        for (key, expected) in [(key, value), (Fr::from(789), Fr::ZERO)] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let inputs = alloc_nums(&mut cs, &[root, key]);
+            let found =
+                synthesize_lookup(&mut cs, &store, &not_dummy, &inputs[0], &inputs[1]).unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(found.get_value(), Some(expected));
+        }
+
+        // a root with no known preimage can't be opened, which only matters when the coprocessor is invoked
+        for (not_dummy, satisfied) in [(Boolean::Constant(false), true), (not_dummy, false)] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let inputs = alloc_nums(&mut cs, &[Fr::from(42), key]);
+            synthesize_lookup(&mut cs, &store, &not_dummy, &inputs[0], &inputs[1]).unwrap();
+            assert_eq!(cs.is_satisfied(), satisfied);
+        }
+    }
+
+    #[test]
+    fn test_wrong_preimage_is_unsatisfied() {
+        let store = Store::<Fr>::default();
+        let mut trie = StandardTrie::new(&store);
+        let (key, value) = (Fr::from(123), Fr::from(456));
+        trie.insert(key, value).unwrap();
+        let root = trie.root();
+
+        let mut preimage_path = trie.prove_lookup(key).unwrap().preimage_path;
+        // claim another value is stored under `key`
+        let leaf_level = preimage_path.last_mut().unwrap();
+        for elt in leaf_level.iter_mut() {
+            if *elt == value {
+                *elt = Fr::from(789);
+            }
+        }
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let inputs = alloc_nums(&mut cs, &[root, key]);
+        let preimages = alloc_preimage_path(
+            &mut cs.namespace(|| "preimage path"),
+            Some(preimage_path.as_slice()),
+        )
+        .unwrap();
+        let path = alloc_path(&mut cs.namespace(|| "path"), &inputs[1]).unwrap();
+        let found = open_path(
+            &mut cs.namespace(|| "open"),
+            &store,
+            &Boolean::Constant(true),
+            &inputs[0],
+            &path,
+            &preimages,
+        )
+        .unwrap();
+        assert_eq!(found.get_value(), Some(Fr::from(789)));
+        assert!(!cs.is_satisfied());
+    }
+}
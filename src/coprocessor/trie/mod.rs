@@ -12,13 +12,13 @@
 
 use std::cell::RefCell;
 // TODO:
-//  - Implement circuit (https://github.com/lurk-lab/lurk-rs/issues/421).
 //  - Adapt to ongoing changes to general coprocessor API, most importantly, absorb
 //    https://github.com/lurk-lab/lurk-rs/issues/398. - If #398 is smooth enough, no actual implementation changes
 //    should be required here, but the test in src/eval/tests/trie.rs can and should be updated.
 use std::marker::PhantomData;
 use std::rc::Rc;
 
+use bellpepper_core::{boolean::Boolean, ConstraintSystem, SynthesisError};
 use lurk_macros::Coproc;
 use serde::{Deserialize, Serialize};
 
@@ -26,13 +26,19 @@ use crate::package::Package;
 use crate::state::State;
 use crate::{self as lurk, Symbol};
 
+use crate::circuit::gadgets::data::{allocate_constant, GlobalAllocations};
+use crate::circuit::gadgets::pointer::{AllocatedContPtr, AllocatedPtr};
 use crate::coprocessor::{CoCircuit, Coprocessor};
 use crate::eval::lang::Lang;
+use crate::eval::IO;
 use crate::field::{FWrap, LurkField};
 use crate::hash::{HashArity, InversePoseidonCache, PoseidonCache};
 use crate::num::Num;
-use crate::ptr::Ptr;
+use crate::ptr::{ContPtr, Ptr};
 use crate::store::Store;
+use crate::tag::{ExprTag, Tag};
+
+mod circuit;
 
 #[derive(Debug)]
 pub enum Error<F> {
@@ -45,6 +51,14 @@ pub type PreimagePath<F, const ARITY: usize> = Vec<[F; ARITY]>;
 
 pub type HashPreimagePath<F, const ARITY: usize> = Vec<(F, [F; ARITY])>;
 
+const TRIE_ARITY: usize = 8;
+
+/// The minimal height for every element of a 255-bit field to have its own path.
+const TRIE_HEIGHT: usize = 85;
+
+/// The shape of the tries handled by the coprocessors.
+type StandardTrie<'a, F> = Trie<'a, F, TRIE_ARITY, TRIE_HEIGHT>;
+
 #[derive(Clone, Coproc, Debug)]
 pub enum TrieCoproc<F: LurkField> {
     New(NewCoprocessor<F>),
@@ -63,68 +77,211 @@ impl<F: LurkField> Coprocessor<F> for NewCoprocessor<F> {
     }
 
     fn simple_evaluate(&self, s: &Store<F>, _args: &[Ptr<F>]) -> Ptr<F> {
-        let trie: Trie<'_, F, 8, 85> = Trie::new(s);
+        let trie: StandardTrie<'_, F> = Trie::new(s);
 
         let root = trie.root;
 
         // FIXME: Use a custom type.
         s.intern_num(Num::Scalar(root))
     }
+
+    fn has_circuit(&self) -> bool {
+        true
+    }
 }
 
-impl<F: LurkField> CoCircuit<F> for NewCoprocessor<F> {}
+impl<F: LurkField> CoCircuit<F> for NewCoprocessor<F> {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        _g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        _not_dummy: &Boolean,
+        _input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let trie: StandardTrie<'_, F> = Trie::new(store);
+        let root = allocate_constant(&mut cs.namespace(|| "empty root"), trie.root);
+        let root_ptr = AllocatedPtr::alloc_tag(cs, ExprTag::Num.to_field(), root)?;
+
+        Ok((root_ptr, input_env.clone(), input_cont.clone()))
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Default, Deserialize)]
 pub struct LookupCoprocessor<F: LurkField> {
     _p: PhantomData<F>,
 }
 
+impl<F: LurkField> LookupCoprocessor<F> {
+    /// Looks up the key `args[1]` in the trie with root `args[0]`. Returns `None` if the preimages along the path of
+    /// the key aren't known, in which case the circuit can't open it either.
+    fn lookup(&self, s: &Store<F>, args: &[Ptr<F>]) -> Option<Ptr<F>> {
+        let root_scalar = *s.hash_expr(&args[0])?.value();
+        let key_scalar = *s.hash_expr(&args[1])?.value();
+        let trie: StandardTrie<'_, F> = Trie::new_with_root(s, root_scalar);
+
+        let found = trie.lookup_aux(key_scalar).ok()?;
+
+        Some(s.intern_maybe_opaque_comm(found))
+    }
+}
+
 impl<F: LurkField> Coprocessor<F> for LookupCoprocessor<F> {
     fn eval_arity(&self) -> usize {
         2
     }
 
-    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
-        let root_ptr = args[0];
-        let key_ptr = args[1];
-        let root_scalar = *s.hash_expr(&root_ptr).unwrap().value();
-        let key_scalar = *s.hash_expr(&key_ptr).unwrap().value();
-        let trie: Trie<'_, F, 8, 85> = Trie::new_with_root(s, root_scalar);
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        match s.fetch_list(&args) {
+            Some(argv) if argv.len() == 2 => match self.lookup(s, &argv) {
+                Some(expr) => IO { expr, env, cont },
+                None => IO {
+                    expr: args,
+                    env,
+                    cont: s.intern_cont_error(),
+                },
+            },
+            _ => IO {
+                expr: args,
+                env,
+                cont: s.intern_cont_error(),
+            },
+        }
+    }
 
-        let found = trie.lookup_aux(key_scalar).unwrap();
+    fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
+        self.lookup(s, args)
+            .expect("invalid input for the trie lookup coprocessor")
+    }
 
-        s.intern_maybe_opaque_comm(found)
+    fn has_circuit(&self) -> bool {
+        true
     }
 }
 
-impl<F: LurkField> CoCircuit<F> for LookupCoprocessor<F> {}
+impl<F: LurkField> CoCircuit<F> for LookupCoprocessor<F> {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        _g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let root = &input_exprs[0];
+        let key = &input_exprs[1];
+
+        let found = circuit::synthesize_lookup(
+            &mut cs.namespace(|| "lookup"),
+            store,
+            not_dummy,
+            root.hash(),
+            key.hash(),
+        )?;
+        let found_ptr = AllocatedPtr::alloc_tag(cs, ExprTag::Comm.to_field(), found)?;
+
+        Ok((found_ptr, input_env.clone(), input_cont.clone()))
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Default, Deserialize)]
 pub struct InsertCoprocessor<F: LurkField> {
     _p: PhantomData<F>,
 }
 
+impl<F: LurkField> InsertCoprocessor<F> {
+    /// Inserts the value `args[2]` at the key `args[1]` in the trie with root `args[0]` and returns the new root.
+    /// Returns `None` if the preimages along the path of the key aren't known, in which case the circuit can't open
+    /// it either.
+    fn insert(&self, s: &Store<F>, args: &[Ptr<F>]) -> Option<Ptr<F>> {
+        let root_scalar = *s.hash_expr(&args[0])?.value();
+        let key_scalar = *s.hash_expr(&args[1])?.value();
+        let val_scalar = *s.hash_expr(&args[2])?.value();
+        let mut trie: StandardTrie<'_, F> = Trie::new_with_root(s, root_scalar);
+        trie.insert(key_scalar, val_scalar).ok()?;
+
+        let new_root = trie.root;
+        Some(s.intern_num(Num::Scalar(new_root)))
+    }
+}
+
 impl<F: LurkField> Coprocessor<F> for InsertCoprocessor<F> {
     fn eval_arity(&self) -> usize {
         3
     }
 
+    fn evaluate(&self, s: &Store<F>, args: Ptr<F>, env: Ptr<F>, cont: ContPtr<F>) -> IO<F> {
+        match s.fetch_list(&args) {
+            Some(argv) if argv.len() == 3 => match self.insert(s, &argv) {
+                Some(expr) => IO { expr, env, cont },
+                None => IO {
+                    expr: args,
+                    env,
+                    cont: s.intern_cont_error(),
+                },
+            },
+            _ => IO {
+                expr: args,
+                env,
+                cont: s.intern_cont_error(),
+            },
+        }
+    }
+
     fn simple_evaluate(&self, s: &Store<F>, args: &[Ptr<F>]) -> Ptr<F> {
-        let root_ptr = args[0];
-        let key_ptr = args[1];
-        let val_ptr = args[2];
-        let root_scalar = *s.hash_expr(&root_ptr).unwrap().value();
-        let key_scalar = *s.hash_expr(&key_ptr).unwrap().value();
-        let val_scalar = *s.hash_expr(&val_ptr).unwrap().value();
-        let mut trie: Trie<'_, F, 8, 85> = Trie::new_with_root(s, root_scalar);
-        trie.insert(key_scalar, val_scalar).unwrap();
+        self.insert(s, args)
+            .expect("invalid input for the trie insert coprocessor")
+    }
 
-        let new_root = trie.root;
-        s.intern_num(Num::Scalar(new_root))
+    fn has_circuit(&self) -> bool {
+        true
     }
 }
 
-impl<F: LurkField> CoCircuit<F> for InsertCoprocessor<F> {}
+impl<F: LurkField> CoCircuit<F> for InsertCoprocessor<F> {
+    fn arity(&self) -> usize {
+        3
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        _g: &GlobalAllocations<F>,
+        store: &Store<F>,
+        not_dummy: &Boolean,
+        input_exprs: &[AllocatedPtr<F>],
+        input_env: &AllocatedPtr<F>,
+        input_cont: &AllocatedContPtr<F>,
+    ) -> Result<(AllocatedPtr<F>, AllocatedPtr<F>, AllocatedContPtr<F>), SynthesisError> {
+        let root = &input_exprs[0];
+        let key = &input_exprs[1];
+        let val = &input_exprs[2];
+
+        let new_root = circuit::synthesize_insert(
+            &mut cs.namespace(|| "insert"),
+            store,
+            not_dummy,
+            root.hash(),
+            key.hash(),
+            val.hash(),
+        )?;
+        let new_root_ptr = AllocatedPtr::alloc_tag(cs, ExprTag::Num.to_field(), new_root)?;
+
+        Ok((new_root_ptr, input_env.clone(), input_cont.clone()))
+    }
+}
 
 /// Add the `Trie`-associated functions to a `Lang` with standard bindings.
 // TODO: define standard patterns for such modularity.
@@ -531,9 +688,71 @@ impl<'a, F: LurkField, const ARITY: usize, const HEIGHT: usize> Default
 #[cfg(test)]
 mod test {
     use super::*;
+    use bellpepper_core::test_cs::TestConstraintSystem;
     use ff::PrimeField;
     use pasta_curves::pallas::Scalar as Fr;
 
+    use crate::lurk_sym_ptr;
+
+    /// Checks that evaluating and synthesizing a call of `coproc` agree, returning whether the call succeeded. A
+    /// call the evaluator rejects must leave the circuit unsatisfied, so that it can't be proven.
+    fn check_call<C: Coprocessor<Fr>>(coproc: &C, s: &Store<Fr>, inputs: &[Ptr<Fr>]) -> bool {
+        let args = s.list(inputs);
+        let io = coproc.evaluate(s, args, lurk_sym_ptr!(s, nil), s.intern_cont_outermost());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let g = GlobalAllocations::new(&mut cs.namespace(|| "global_allocations"), s).unwrap();
+        let inputs = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                AllocatedPtr::alloc_ptr(&mut cs.namespace(|| format!("input {i}")), s, || Ok(input))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let (result, _, _) = coproc
+            .synthesize(
+                &mut cs,
+                &g,
+                s,
+                &Boolean::Constant(true),
+                &inputs,
+                &g.nil_ptr,
+                &g.terminal_ptr,
+            )
+            .unwrap();
+
+        let is_error = io.cont == s.intern_cont_error();
+        assert_eq!(cs.is_satisfied(), !is_error);
+        if is_error {
+            return false;
+        }
+        let expected = s.hash_expr(&io.expr).unwrap();
+        assert_eq!(
+            result.tag().get_value().zip(result.hash().get_value()),
+            Some((expected.tag_field(), *expected.value()))
+        );
+        true
+    }
+
+    #[test]
+    fn test_evaluate_agrees_with_circuit() {
+        let s = &Store::<Fr>::default();
+        let lookup = LookupCoprocessor::default();
+        let insert = InsertCoprocessor::default();
+        let root = s.intern_num(Num::Scalar(StandardTrie::new(s).root()));
+        let key = s.num(123u64);
+        let val = s.num(456u64);
+
+        assert!(check_call(&insert, s, &[root, key, val]));
+        assert!(check_call(&lookup, s, &[root, key]));
+
+        // roots with no known preimages can't be opened
+        let unknown_root = s.num(42u64);
+        assert!(!check_call(&insert, s, &[unknown_root, key, val]));
+        assert!(!check_call(&lookup, s, &[unknown_root, key]));
+    }
+
     #[test]
     fn test_empty_roots() {
         let s = &mut Store::new();
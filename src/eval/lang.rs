@@ -6,6 +6,12 @@ use indexmap::IndexMap;
 use lurk_macros::Coproc;
 use serde::{Deserialize, Serialize};
 
+use crate::coprocessor::blake2s::Blake2sCoprocessor;
+use crate::coprocessor::keccak::KeccakCoprocessor;
+use crate::coprocessor::merkle::MerkleCoprocessor;
+use crate::coprocessor::schnorr::SchnorrCoprocessor;
+use crate::coprocessor::sha256::Sha256Coprocessor;
+use crate::coprocessor::trie::{InsertCoprocessor, LookupCoprocessor, NewCoprocessor};
 use crate::coprocessor::{CoCircuit, Coprocessor};
 use crate::field::LurkField;
 use crate::lem::store::Store as LEMStore;
@@ -65,6 +71,14 @@ impl<F: LurkField> DummyCoprocessor<F> {
 #[derive(Clone, Debug, Deserialize, Serialize, Coproc)]
 pub enum Coproc<F: LurkField> {
     Dummy(DummyCoprocessor<F>),
+    TrieNew(NewCoprocessor<F>),
+    TrieLookup(LookupCoprocessor<F>),
+    TrieInsert(InsertCoprocessor<F>),
+    Sha256(Sha256Coprocessor<F>),
+    Keccak(KeccakCoprocessor<F>),
+    Blake2s(Blake2sCoprocessor<F>),
+    Schnorr(SchnorrCoprocessor<F>),
+    Merkle(MerkleCoprocessor<F>),
 }
 
 /// `Lang` is a struct that represents a language with coprocessors.
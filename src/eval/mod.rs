@@ -26,6 +26,8 @@ pub mod lang;
 
 mod reduction;
 
+pub(crate) use reduction::without_printing_emitted;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
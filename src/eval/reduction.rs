@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use super::{empty_sym_env, Meta, Witness};
 use crate::cont::Continuation;
 use crate::coprocessor::Coprocessor;
@@ -14,6 +16,19 @@ use crate::tag::{ContTag, ExprTag, Op1, Op2};
 use crate::writer::Write;
 use crate::{lurk_sym_ptr, store};
 
+thread_local! {
+    static PRINT_EMITTED: Cell<bool> = Cell::new(true);
+}
+
+/// Runs `f` without printing the values passed to `emit`. They are still recorded in the
+/// frames, so callers that report them some other way (e.g. as JSON) can collect them there.
+pub(crate) fn without_printing_emitted<T>(f: impl FnOnce() -> T) -> T {
+    let previous = PRINT_EMITTED.with(|print| print.replace(false));
+    let result = f();
+    PRINT_EMITTED.with(|print| print.set(previous));
+    result
+}
+
 pub(crate) fn reduce<F: LurkField, C: Coprocessor<F>>(
     expr: Ptr<F>,
     env: Ptr<F>,
@@ -85,6 +100,8 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
                 | ExprTag::Char
                 | ExprTag::Comm
                 | ExprTag::U64
+                | ExprTag::I64
+                | ExprTag::Vec
                 | ExprTag::Key => {
                     debug_assert!(expr.tag.is_self_evaluating());
                     Control::ApplyContinuation(expr, env, cont)
@@ -273,7 +290,6 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
 
                     let lambda = c.lambda.ptr();
                     let quote = c.quote.ptr();
-                    let dummy_arg = c.dummy.ptr();
 
                     macro_rules! car_cdr_named {
                         ($cons_name:expr, $cons:expr) => {{
@@ -294,12 +310,23 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
                         (c.commit.ptr(), Op1::Commit),
                         (c.num.ptr(), Op1::Num),
                         (c.u64.ptr(), Op1::U64),
+                        (c.i64.ptr(), Op1::I64),
                         (c.comm.ptr(), Op1::Comm),
                         (c.char.ptr(), Op1::Char),
                         (c.open.ptr(), Op1::Open),
                         (c.secret.ptr(), Op1::Secret),
                         (c.atom.ptr(), Op1::Atom),
                         (c.emit.ptr(), Op1::Emit),
+                        (c.lognot.ptr(), Op1::LogNot),
+                        (c.char_to_u64.ptr(), Op1::CharToU64),
+                        (c.u64_to_char.ptr(), Op1::U64ToChar),
+                        (c.symbol_to_string.ptr(), Op1::SymbolToString),
+                        (c.string_to_symbol.ptr(), Op1::StringToSymbol),
+                        (c.string_length.ptr(), Op1::StringLength),
+                        (c.string_to_list.ptr(), Op1::StringToList),
+                        (c.list_to_string.ptr(), Op1::ListToString),
+                        (c.throw.ptr(), Op1::Throw),
+                        (c.vector_length.ptr(), Op1::VectorLength),
                     ];
 
                     // An array, for performance reasons
@@ -318,39 +345,25 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
                         (c.greater.ptr(), Op2::Greater),
                         (c.less_equal.ptr(), Op2::LessEqual),
                         (c.greater_equal.ptr(), Op2::GreaterEqual),
+                        (c.logand.ptr(), Op2::LogAnd),
+                        (c.logior.ptr(), Op2::LogIor),
+                        (c.logxor.ptr(), Op2::LogXor),
+                        (c.shift_left.ptr(), Op2::ShiftLeft),
+                        (c.shift_right.ptr(), Op2::ShiftRight),
+                        (c.string_append.ptr(), Op2::StringAppend),
+                        (c.catch.ptr(), Op2::Catch),
+                        (c.make_vector.ptr(), Op2::MakeVector),
+                        (c.vector_ref.ptr(), Op2::VectorRef),
                     ];
 
                     if head == lambda {
-                        let (args, body) = car_cdr_named!(ConsName::ExprCdr, &rest)?;
-                        let (arg, _rest) = if args.is_nil() {
-                            // (LAMBDA () STUFF)
-                            // becomes (LAMBDA (DUMMY) STUFF)
-                            (dummy_arg, lurk_sym_ptr!(store, nil))
-                        } else {
-                            cons_witness.car_cdr_named(ConsName::ExprCadr, store, &args)?
-                        };
-                        if arg.tag != ExprTag::Sym {
+                        let (params, body) = car_cdr_named!(ConsName::ExprCdr, &rest)?;
+                        // The parameters are kept as they are, and they are bound one by one as
+                        // the arguments of a call are evaluated
+                        if !matches!(params.tag, ExprTag::Nil | ExprTag::Cons) {
                             Control::Error(expr, env)
                         } else {
-                            let (_, cdr_args) =
-                                cons_witness.car_cdr_named(ConsName::ExprCadr, store, &args)?;
-                            let inner_body = if cdr_args.is_nil() {
-                                body
-                            } else {
-                                // (LAMBDA (A B) STUFF)
-                                // becomes (LAMBDA (A) (LAMBDA (B) STUFF))
-                                let inner = cons_witness.cons_named(
-                                    ConsName::InnerLambda,
-                                    store,
-                                    cdr_args,
-                                    body,
-                                );
-                                let l =
-                                    cons_witness.cons_named(ConsName::Lambda, store, lambda, inner);
-                                let nil = lurk_sym_ptr!(store, nil);
-                                cons_witness.cons_named(ConsName::InnerBody, store, l, nil)
-                            };
-                            let function = store.intern_fun(arg, inner_body, env);
+                            let function = store.intern_fun(params, body, env);
 
                             Control::ApplyContinuation(function, env, cont)
                         }
@@ -474,6 +487,66 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
                                 ),
                             )
                         }
+                    } else if head == c.list.ptr() {
+                        let (arg1, more) = car_cdr_named!(ConsName::ExprCdr, &rest)?;
+
+                        // (LIST) is nil, which is what `arg1` is in that case
+                        if rest.is_nil() {
+                            Control::Return(arg1, env, cont)
+                        } else {
+                            Control::Return(
+                                arg1,
+                                env,
+                                cont_witness.intern_named_cont(
+                                    ContName::NewerCont,
+                                    store,
+                                    Continuation::Binop {
+                                        operator: Op2::List,
+                                        saved_env: env,
+                                        unevaled_args: more,
+                                        continuation: cont,
+                                    },
+                                ),
+                            )
+                        }
+                    } else if head == c.substring.ptr()
+                        || head == c.vector_set.ptr()
+                        || head == c.eval_with_limit.ptr()
+                    {
+                        let operator = if head == c.substring.ptr() {
+                            Op2::Substring
+                        } else if head == c.vector_set.ptr() {
+                            Op2::VectorSet
+                        } else {
+                            Op2::EvalWithLimit
+                        };
+                        let (arg1, more) = car_cdr_named!(ConsName::ExprCdr, &rest)?;
+                        if rest.is_nil() || more.tag != ExprTag::Cons {
+                            Control::Error(expr, env)
+                        } else {
+                            let (_, more_args) =
+                                cons_witness.car_cdr_named(ConsName::ExprCddr, store, &more)?;
+                            if more_args.is_nil() {
+                                Control::Error(expr, env)
+                            } else {
+                                // The second and third arguments are evaluated as a list, like
+                                // the arguments of `list`
+                                Control::Return(
+                                    arg1,
+                                    env,
+                                    cont_witness.intern_named_cont(
+                                        ContName::NewerCont,
+                                        store,
+                                        Continuation::Binop {
+                                            operator,
+                                            saved_env: env,
+                                            unevaled_args: more,
+                                            continuation: cont,
+                                        },
+                                    ),
+                                )
+                            }
+                        }
                     } else if let Some((_, op)) = unops_map.iter().find(|(ptr, _)| head == *ptr) {
                         let (arg1, end) = car_cdr_named!(ConsName::ExprCdr, &rest)?;
                         if rest.is_nil() || !end.is_nil() {
@@ -601,49 +674,24 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
                                     },
                                 ),
                             )
+                        } else if args.tag != ExprTag::Cons {
+                            Control::Error(expr, env)
                         } else {
-                            let (arg, more_args) = car_cdr_named!(ConsName::ExprCdr, &args)?;
-                            match more_args.tag {
-                                // (fn arg)
-                                // Interpreting as call.
-                                ExprTag::Nil => Control::Return(
-                                    fun_form,
-                                    env,
-                                    cont_witness.intern_named_cont(
-                                        ContName::NewerCont,
-                                        store,
-                                        Continuation::Call {
-                                            unevaled_arg: arg,
-                                            saved_env: env,
-                                            continuation: cont,
-                                        },
-                                    ),
+                            // (fn arg . more_args)
+                            // All arguments are evaluated in the `Call` and `Call2` continuations.
+                            Control::Return(
+                                fun_form,
+                                env,
+                                cont_witness.intern_named_cont(
+                                    ContName::NewerCont,
+                                    store,
+                                    Continuation::Call {
+                                        unevaled_args: args,
+                                        saved_env: env,
+                                        continuation: cont,
+                                    },
                                 ),
-                                _ => {
-                                    // Interpreting as multi-arg call.
-                                    // (fn arg . more_args) => ((fn arg) . more_args)
-                                    let nil = lurk_sym_ptr!(store, nil);
-                                    let expanded_inner0 = cons_witness.cons_named(
-                                        ConsName::ExpandedInner0,
-                                        store,
-                                        arg,
-                                        nil,
-                                    );
-                                    let expanded_inner = cons_witness.cons_named(
-                                        ConsName::ExpandedInner,
-                                        store,
-                                        fun_form,
-                                        expanded_inner0,
-                                    );
-                                    let expanded = cons_witness.cons_named(
-                                        ConsName::FunExpanded,
-                                        store,
-                                        expanded_inner,
-                                        more_args,
-                                    );
-                                    Control::Return(expanded, env, cont)
-                                }
-                            }
+                            )
                         }
                     }
                 }
@@ -654,6 +702,9 @@ fn reduce_with_witness_inner<F: LurkField, C: Coprocessor<F>>(
     ))
 }
 
+/// Reduces as `reduce_unfueled_with_witness()`, but for the `Fuel` continuation of an
+/// `eval-with-limit`, whose evaluation is stepped under its inner continuation with one unit of
+/// fuel less, until it terminates, fails or its fuel runs out.
 fn reduce_with_witness<F: LurkField, C: Coprocessor<F>>(
     expr: Ptr<F>,
     env: Ptr<F>,
@@ -661,12 +712,91 @@ fn reduce_with_witness<F: LurkField, C: Coprocessor<F>>(
     store: &Store<F>,
     c: &NamedConstants<F>,
     lang: &Lang<F, C>,
+) -> Result<(Control<F>, Witness<F>, Meta<F>), ReductionError> {
+    if cont.tag != ContTag::Fuel {
+        return reduce_unfueled_with_witness(expr, env, cont, false, store, c, lang);
+    }
+    let (inner, fuel, saved_env, continuation) = match store
+        .fetch_cont(&cont)
+        .ok_or_else(|| store::Error("Fetch failed".into()))?
+    {
+        Continuation::Fuel {
+            inner,
+            fuel,
+            saved_env,
+            continuation,
+        } => (inner, fetch_u64(store, &fuel)?, saved_env, continuation),
+        _ => unreachable!(),
+    };
+
+    // Out of fuel, the evaluation takes the step of a terminated one, which leaves it as it is
+    let (step_cont, outcome) = if fuel == 0 {
+        (store.intern_cont_terminal(), c.out_of_fuel.ptr())
+    } else {
+        (inner, c.ok.ptr())
+    };
+
+    let (control, witness, _) =
+        reduce_unfueled_with_witness(expr, env, step_cont, true, store, c, lang)?;
+    let (new_expr, new_env, new_cont) = control.into_results(store);
+
+    // An error the evaluation doesn't catch, which includes a nested `eval-with-limit`, ends it
+    // with the `:error` outcome
+    let outcome = match new_cont.tag {
+        ContTag::Error | ContTag::Fuel => c.error.ptr(),
+        _ => outcome,
+    };
+
+    let control = match new_cont.tag {
+        ContTag::Terminal | ContTag::Error | ContTag::Fuel => {
+            let value = match store.fetch(&new_expr) {
+                Some(Expression::Thunk(thunk)) => thunk.value,
+                _ => new_expr,
+            };
+            let result = store.list(&[outcome, value]);
+            let thunk = store.intern_thunk(Thunk {
+                value: result,
+                continuation,
+            });
+            Control::Return(thunk, saved_env, store.intern_cont_dummy())
+        }
+        _ => {
+            let fuel = store.intern_u64(fuel - 1);
+            let cont = Continuation::Fuel {
+                inner: new_cont,
+                fuel,
+                saved_env,
+                continuation,
+            }
+            .intern_aux(store);
+            Control::Return(new_expr, new_env, cont)
+        }
+    };
+
+    Ok((control, witness, Meta::Lurk))
+}
+
+fn reduce_unfueled_with_witness<F: LurkField, C: Coprocessor<F>>(
+    expr: Ptr<F>,
+    env: Ptr<F>,
+    cont: ContPtr<F>,
+    sandboxed: bool,
+    store: &Store<F>,
+    c: &NamedConstants<F>,
+    lang: &Lang<F, C>,
 ) -> Result<(Control<F>, Witness<F>, Meta<F>), ReductionError> {
     let cons_witness = &mut ConsWitness::<F>::new_dummy();
     let cont_witness = &mut ContWitness::<F>::new_dummy();
 
     let (control, closure_to_extend, meta) =
         reduce_with_witness_inner(expr, env, cont, store, cons_witness, cont_witness, c, lang)?;
+    // Coprocessors aren't available to the evaluation of an `eval-with-limit`, whose steps are
+    // all Lurk steps
+    let (control, meta) = if sandboxed && !meta.is_lurk() {
+        (Control::Error(expr, env), Meta::Lurk)
+    } else {
+        (control, meta)
+    };
 
     let (new_expr, new_env, new_cont) = control.clone().into_results(store);
 
@@ -683,6 +813,13 @@ fn reduce_with_witness<F: LurkField, C: Coprocessor<F>>(
 
     let control = apply_continuation(control, store, &mut witness, c)?;
 
+    // The error of a coprocessor is the output of its own step, so it isn't thrown
+    let control = if meta.is_lurk() {
+        raise(control, expr, cont, store, &mut witness, c)?
+    } else {
+        control
+    };
+
     let ctrl = make_thunk(control, store, &mut witness)?;
 
     witness.conses.assert_invariants(store);
@@ -691,6 +828,65 @@ fn reduce_with_witness<F: LurkField, C: Coprocessor<F>>(
     Ok((ctrl, witness, meta))
 }
 
+/// An error is thrown, as a keyword naming it consed onto the expression it's raised on. An
+/// error of the reduction is thrown to the continuation of the reduction, and an error of an
+/// applied continuation to the continuation this one returns to.
+///
+/// An error nothing catches is unwound like any thrown value, one pending continuation per step,
+/// before ending the evaluation with the error continuation. An error raised right under the
+/// outermost continuation still ends it at once. Ending it at once whenever no `catch` is pending
+/// would take walking the continuation chain, which a step can't do with a constant number of
+/// constraints, or recording in every continuation whether a `catch` encloses it, which would
+/// change the hash of all of them. The extra steps are thus the price of catchable errors, and
+/// each of them only takes the skipping branch of `apply_continuation()`.
+fn raise<F: LurkField>(
+    control: Control<F>,
+    expr: Ptr<F>,
+    cont: ContPtr<F>,
+    store: &Store<F>,
+    witness: &mut Witness<F>,
+    c: &NamedConstants<F>,
+) -> Result<Control<F>, ReductionError> {
+    let Control::Error(result, _) = control else {
+        return Ok(control);
+    };
+
+    let (key, continuation) = match witness.apply_continuation_cont {
+        None if expr.tag == ExprTag::Sym => (c.unbound_var.ptr(), cont),
+        None => (c.invalid_form.ptr(), cont),
+        Some(applied) => {
+            let applied = witness
+                .conts
+                .fetch_named_cont(ContName::ApplyContinuation, store, &applied)
+                .ok_or_else(|| store::Error("Fetch failed".into()))?;
+            let key = match applied {
+                Continuation::Call0 { .. }
+                | Continuation::Call { .. }
+                | Continuation::Call2 { .. } => c.invalid_call.ptr(),
+                Continuation::If { .. } | Continuation::Binop { .. } => c.invalid_form.ptr(),
+                Continuation::Unop {
+                    operator: Op1::Car, ..
+                } => c.car_of_non_list.ptr(),
+                Continuation::Unop {
+                    operator: Op1::Cdr, ..
+                } => c.cdr_of_non_list.ptr(),
+                _ => c.invalid_arg.ptr(),
+            };
+            let continuation = applied
+                .continuation()
+                .ok_or_else(|| store::Error("Fetch failed".into()))?;
+            (key, continuation)
+        }
+    };
+    let thrown = store.cons(key, result);
+
+    Ok(Control::MakeThunk(
+        thrown,
+        store.intern_thrown_env(),
+        continuation,
+    ))
+}
+
 fn apply_continuation<F: LurkField>(
     control: Control<F>,
     store: &Store<F>,
@@ -707,10 +903,38 @@ fn apply_continuation<F: LurkField>(
     let cons_witness = &mut witness.conses;
     let cont_witness = &mut witness.conts;
 
+    // While a value is being thrown, which the env being a thunk tells, every continuation
+    // but the `Binop` of a `catch` is skipped
+    if env.tag == ExprTag::Thunk {
+        match cont.tag {
+            ContTag::Terminal | ContTag::Error => (),
+            ContTag::Outermost => {
+                return Ok(Control::Return(result, env, store.intern_cont_error()))
+            }
+            _ => {
+                let catching = matches!(
+                    store.fetch_cont(&cont),
+                    Some(Continuation::Binop {
+                        operator: Op2::Catch,
+                        ..
+                    })
+                );
+                if !catching {
+                    let continuation = cont_witness
+                        .fetch_named_cont(ContName::ApplyContinuation, store, &cont)
+                        .and_then(|cont| cont.continuation())
+                        .ok_or_else(|| store::Error("Fetch failed".into()))?;
+                    return Ok(Control::MakeThunk(result, env, continuation));
+                }
+            }
+        }
+    }
+
     let control = match cont.tag {
         ContTag::Cproc => unreachable!("Lurk Alpha doesn't produce such continuations"),
         ContTag::Terminal | ContTag::Error => Control::Return(result, env, cont),
         ContTag::Dummy => unreachable!("Dummy Continuation should never be applied."),
+        ContTag::Fuel => unreachable!("Fuel Continuation should never be applied."),
         ContTag::Outermost => Control::Return(result, env, store.intern_cont_terminal()),
         ContTag::Emit => match cont_witness
             .fetch_named_cont(ContName::ApplyContinuation, store, &cont)
@@ -733,16 +957,35 @@ fn apply_continuation<F: LurkField>(
                     .fetch(&result)
                     .ok_or_else(|| store::Error("Fetch failed".into()))?
                 {
-                    Expression::Fun(arg, body, closed_env) => {
-                        if arg == c.dummy.ptr() {
-                            if body.is_nil() {
-                                Control::Error(result, env)
+                    Expression::Fun(params, body, closed_env) => {
+                        // A function of no parameters, or of a `&rest` one only, which is bound to
+                        // nil, is entered
+                        let body_env = if params.is_nil() {
+                            Some(closed_env)
+                        } else {
+                            let (param, _, next_param) =
+                                fun_params(&params, store, cons_witness, c)?;
+                            if param != c.rest.ptr() {
+                                None
+                            } else if next_param.tag != ExprTag::Sym {
+                                return Ok(Control::Error(result, env));
                             } else {
-                                let (body_form, end) =
-                                    cons_witness.car_cdr_named(ConsName::FunBody, store, &body)?;
-                                if !end.is_nil() {
-                                    Control::Error(result, env)
-                                } else {
+                                Some(cons_witness.extend_named(
+                                    ConsName::ClosedEnv,
+                                    closed_env,
+                                    next_param,
+                                    c.nil.ptr(),
+                                    store,
+                                ))
+                            }
+                        };
+                        match body_env {
+                            // // Applying zero args to a non-zero arg function leaves it unchanged.
+                            // // This is arguably consistent with auto-currying.
+                            None => Control::Return(result, env, continuation),
+                            Some(body_env) => match fun_body_form(&body, store, cons_witness)? {
+                                None => Control::Error(result, env),
+                                Some(body_form) => {
                                     let cont = make_tail_continuation(
                                         saved_env,
                                         continuation,
@@ -750,13 +993,9 @@ fn apply_continuation<F: LurkField>(
                                         cont_witness,
                                     );
 
-                                    Control::Return(body_form, closed_env, cont)
+                                    Control::Return(body_form, body_env, cont)
                                 }
-                            }
-                        } else {
-                            // // Applying zero args to a non-zero arg function leaves it unchanged.
-                            // // This is arguably consistent with auto-currying.
-                            Control::Return(result, env, continuation)
+                            },
                         }
                     }
                     _ => unreachable!(),
@@ -771,23 +1010,34 @@ fn apply_continuation<F: LurkField>(
                 .ok_or_else(|| store::Error("Fetch failed".into()))?
             {
                 Continuation::Call {
-                    unevaled_arg,
+                    unevaled_args,
                     saved_env,
                     continuation,
                 } => {
-                    let function = result;
-                    let next_expr = unevaled_arg;
-
-                    let newer_cont = cont_witness.intern_named_cont(
-                        ContName::NewerCont2,
-                        store,
-                        Continuation::Call2 {
-                            function,
+                    let Some(Expression::Fun(params, _, _)) = store.fetch(&result) else {
+                        unreachable!()
+                    };
+                    if params.is_nil() {
+                        // A function of no parameters takes no arguments
+                        return Ok(Control::Error(result, env));
+                    }
+                    let (param, _) =
+                        cons_witness.car_cdr_named(ConsName::FunParams, store, &params)?;
+                    if unevaled_args.tag != ExprTag::Cons {
+                        Control::Error(result, env)
+                    } else {
+                        call_next_arg(
+                            result,
+                            param,
+                            unevaled_args,
                             saved_env,
                             continuation,
-                        },
-                    );
-                    Control::Return(next_expr, env, newer_cont)
+                            store,
+                            cons_witness,
+                            cont_witness,
+                            c,
+                        )?
+                    }
                 }
                 _ => unreachable!(),
             },
@@ -803,39 +1053,84 @@ fn apply_continuation<F: LurkField>(
             Continuation::Call2 {
                 function,
                 saved_env,
+                unevaled_args,
                 continuation,
             } => match function.tag {
                 ExprTag::Fun => match store
                     .fetch(&function)
                     .ok_or_else(|| store::Error("Fetch failed".into()))?
                 {
-                    Expression::Fun(arg, body, closed_env) => {
-                        if arg == c.dummy.ptr() {
+                    Expression::Fun(params, body, closed_env) => {
+                        if params.is_nil() {
                             return Ok(Control::Error(result, env));
                         }
-                        if body.is_nil() {
-                            Control::Error(result, env)
+                        let (param, more_params, next_param) =
+                            fun_params(&params, store, cons_witness, c)?;
+                        // A `&rest` parameter is bound to the list of all the arguments left
+                        let (var, remaining_params) = if param == c.rest.ptr() {
+                            (next_param, c.nil.ptr())
                         } else {
-                            let (body_form, end) =
-                                cons_witness.car_cdr_named(ConsName::FunBody, store, &body)?;
-
-                            if !end.is_nil() {
+                            (param, more_params)
+                        };
+                        if var.tag != ExprTag::Sym
+                            || !matches!(remaining_params.tag, ExprTag::Nil | ExprTag::Cons)
+                        {
+                            return Ok(Control::Error(result, env));
+                        }
+                        let newer_env = cons_witness.extend_named(
+                            ConsName::ClosedEnv,
+                            closed_env,
+                            var,
+                            result,
+                            store,
+                        );
+                        if remaining_params.is_nil() {
+                            match fun_body_form(&body, store, cons_witness)? {
+                                None => Control::Error(result, env),
+                                Some(body_form) => {
+                                    let cont = if unevaled_args.is_nil() {
+                                        make_tail_continuation(
+                                            saved_env,
+                                            continuation,
+                                            store,
+                                            cont_witness,
+                                        )
+                                    } else {
+                                        // The body evaluates to the function taking the remaining
+                                        // arguments
+                                        cont_witness.intern_named_cont(
+                                            ContName::NewerCont2,
+                                            store,
+                                            Continuation::Call {
+                                                unevaled_args,
+                                                saved_env,
+                                                continuation,
+                                            },
+                                        )
+                                    };
+                                    Control::Return(body_form, newer_env, cont)
+                                }
+                            }
+                        } else {
+                            // The parameters left are bound in the same way, without entering the
+                            // body in between
+                            let function = store.intern_fun(remaining_params, body, newer_env);
+                            if unevaled_args.is_nil() {
+                                Control::MakeThunk(function, env, continuation)
+                            } else if unevaled_args.tag != ExprTag::Cons {
                                 Control::Error(result, env)
                             } else {
-                                let newer_env = cons_witness.extend_named(
-                                    ConsName::ClosedEnv,
-                                    closed_env,
-                                    arg,
-                                    result,
-                                    store,
-                                );
-                                let cont = make_tail_continuation(
+                                call_next_arg(
+                                    function,
+                                    next_param,
+                                    unevaled_args,
                                     saved_env,
                                     continuation,
                                     store,
+                                    cons_witness,
                                     cont_witness,
-                                );
-                                Control::Return(body_form, newer_env, cont)
+                                    c,
+                                )?
                             }
                         }
                     }
@@ -912,7 +1207,9 @@ fn apply_continuation<F: LurkField>(
                         _ => lurk_sym_ptr!(store, t),
                     },
                     Op1::Emit => {
-                        println!("{}", result.fmt_to_string(store, initial_lurk_state()));
+                        if PRINT_EMITTED.with(Cell::get) {
+                            println!("{}", result.fmt_to_string(store, initial_lurk_state()));
+                        }
                         return Ok(Control::MakeThunk(
                             result,
                             env,
@@ -939,6 +1236,13 @@ fn apply_continuation<F: LurkField>(
                                 .ok_or_else(|| store::Error("expr hash missing".into()))?;
                             store.intern_num(crate::Num::Scalar::<F>(*z_ptr.value()))
                         }
+                        ExprTag::I64 => {
+                            let n = store
+                                .fetch_i64(&result)
+                                .ok_or_else(|| store::Error("Fetch failed".into()))?;
+                            let x = F::from_u64(n.unsigned_abs());
+                            store.intern_num(crate::Num::Scalar(if n < 0 { -x } else { x }))
+                        }
                         _ => return Ok(Control::Error(result, env)),
                     },
                     Op1::U64 => match result.tag {
@@ -950,6 +1254,12 @@ fn apply_continuation<F: LurkField>(
                             store.intern_u64(z_ptr.value().to_u64_unchecked())
                         }
                         ExprTag::U64 => result,
+                        ExprTag::I64 => {
+                            let n = store
+                                .fetch_i64(&result)
+                                .ok_or_else(|| store::Error("Fetch failed".into()))?;
+                            store.intern_u64(n as u64)
+                        }
                         _ => return Ok(Control::Error(result, env)),
                     },
                     Op1::Comm => match result.tag {
@@ -973,6 +1283,171 @@ fn apply_continuation<F: LurkField>(
                     Op1::Eval => {
                         return Ok(Control::Return(result, empty_sym_env(store), continuation));
                     }
+                    Op1::LogNot => match result.tag {
+                        ExprTag::U64 => {
+                            let z_ptr = store
+                                .hash_expr(&result)
+                                .ok_or_else(|| store::Error("expr hash missing".into()))?;
+                            store.intern_u64(!z_ptr.value().to_u64_unchecked())
+                        }
+                        _ => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::I64 => match result.tag {
+                        ExprTag::Num => {
+                            let z_ptr = store
+                                .hash_expr(&result)
+                                .ok_or_else(|| store::Error("expr hash missing".into()))?;
+                            // Shifting by 2^63 maps the range of i64s onto the range of u64s, so
+                            // that nums are wrapped the same way as by `u64`, but around zero
+                            let shifted = *z_ptr.value() + F::from_u64(1 << 63);
+                            store.intern_i64((shifted.to_u64_unchecked() ^ (1 << 63)) as i64)
+                        }
+                        ExprTag::U64 => {
+                            let n = store
+                                .fetch_uint(&result)
+                                .ok_or_else(|| store::Error("Fetch failed".into()))?;
+                            store.intern_i64(u64::from(n) as i64)
+                        }
+                        ExprTag::I64 => result,
+                        _ => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::CharToU64 => match result.tag {
+                        ExprTag::Char => {
+                            let z_ptr = store
+                                .hash_expr(&result)
+                                .ok_or_else(|| store::Error("expr hash missing".into()))?;
+                            store.intern_u64(z_ptr.value().to_u64_unchecked())
+                        }
+                        _ => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::U64ToChar => match result.tag {
+                        ExprTag::U64 => {
+                            let z_ptr = store
+                                .hash_expr(&result)
+                                .ok_or_else(|| store::Error("expr hash missing".into()))?;
+                            match u32::try_from(z_ptr.value().to_u64_unchecked()) {
+                                Ok(x) => Ptr::index(ExprTag::Char, x as usize),
+                                Err(_) => return Ok(Control::Error(result, env)),
+                            }
+                        }
+                        _ => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::SymbolToString => match cons_witness.symbol_car_cdr_named(
+                        ConsName::UnopConsLike,
+                        store,
+                        &result,
+                    ) {
+                        Ok((car, _)) => car,
+                        Err(_) => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::StringToSymbol => match result.tag {
+                        ExprTag::Str => cons_witness.symcons_named(
+                            ConsName::UnopConsLike,
+                            store,
+                            result,
+                            lurk_sym_ptr!(store, user_package),
+                        ),
+                        _ => return Ok(Control::Error(result, env)),
+                    },
+                    Op1::Throw => {
+                        return Ok(Control::MakeThunk(
+                            result,
+                            store.intern_thrown_env(),
+                            continuation,
+                        ));
+                    }
+                    Op1::VectorLength => match cons_witness.vec_len_content_named(
+                        ConsName::UnopConsLike,
+                        store,
+                        &result,
+                    ) {
+                        Ok((len, _)) => len,
+                        Err(_) => return Ok(Control::Error(result, env)),
+                    },
+                    // A call on a non-empty string or list becomes a call on its tail, whose
+                    // result its head is then added to by a `Binop2`
+                    Op1::StringLength | Op1::StringToList => {
+                        if result.tag != ExprTag::Str {
+                            return Ok(Control::Error(result, env));
+                        }
+                        if result == store.strnil() {
+                            if operator == Op1::StringLength {
+                                store.intern_u64(0)
+                            } else {
+                                c.nil.ptr()
+                            }
+                        } else {
+                            let (car, cdr) = cons_witness.car_cdr_mut_named(
+                                ConsName::UnopConsLike,
+                                store,
+                                &result,
+                            )?;
+                            let (sym, op2, head) = if operator == Op1::StringLength {
+                                (c.string_length.ptr(), Op2::Sum, store.intern_u64(1))
+                            } else {
+                                (c.string_to_list.ptr(), Op2::Cons, car)
+                            };
+                            let nil = c.nil.ptr();
+                            let args =
+                                cons_witness.cons_named(ConsName::StrCallArgs, store, cdr, nil);
+                            let call = cons_witness.cons_named(ConsName::StrCall, store, sym, args);
+                            return Ok(Control::Return(
+                                call,
+                                env,
+                                cont_witness.intern_named_cont(
+                                    ContName::NewerCont2,
+                                    store,
+                                    Continuation::Binop2 {
+                                        operator: op2,
+                                        evaled_arg: head,
+                                        continuation,
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+                    Op1::ListToString => match result.tag {
+                        ExprTag::Nil => store.strnil(),
+                        ExprTag::Cons => {
+                            let (car, cdr) = cons_witness.car_cdr_mut_named(
+                                ConsName::UnopConsLike,
+                                store,
+                                &result,
+                            )?;
+                            // The tail is quoted, and `strcons` errors if the head isn't a char
+                            let nil = c.nil.ptr();
+                            let quoted =
+                                cons_witness.cons_named(ConsName::StrQuoted, store, cdr, nil);
+                            let quoted = cons_witness.cons_named(
+                                ConsName::StrQuote,
+                                store,
+                                c.quote.ptr(),
+                                quoted,
+                            );
+                            let args =
+                                cons_witness.cons_named(ConsName::StrCallArgs, store, quoted, nil);
+                            let call = cons_witness.cons_named(
+                                ConsName::StrCall,
+                                store,
+                                c.list_to_string.ptr(),
+                                args,
+                            );
+                            return Ok(Control::Return(
+                                call,
+                                env,
+                                cont_witness.intern_named_cont(
+                                    ContName::NewerCont2,
+                                    store,
+                                    Continuation::Binop2 {
+                                        operator: Op2::StrCons,
+                                        evaled_arg: car,
+                                        continuation,
+                                    },
+                                ),
+                            ));
+                        }
+                        _ => return Ok(Control::Error(result, env)),
+                    },
                 };
                 Control::MakeThunk(val, env, continuation)
             }
@@ -999,8 +1474,40 @@ fn apply_continuation<F: LurkField>(
                             cons_witness.cons_named(ConsName::Begin, store, begin, unevaled_args);
                         Control::Return(begin_again, saved_env, continuation)
                     }
+                } else if matches!(
+                    operator,
+                    Op2::List | Op2::Substring | Op2::VectorSet | Op2::EvalWithLimit
+                ) {
+                    if unevaled_args.is_nil() {
+                        let nil = c.nil.ptr();
+                        let list = cons_witness.cons_named(ConsName::TheCons, store, result, nil);
+                        Control::MakeThunk(list, env, continuation)
+                    } else {
+                        // The remaining arguments are evaluated as a list, which `result` is consed
+                        // onto in `Binop2`, or which holds the bounds of `substring`, the index and
+                        // the element of `vector-set` or the env and the fuel of `eval-with-limit`
+                        let list = c.list.ptr();
+                        let list_again =
+                            cons_witness.cons_named(ConsName::ListArgs, store, list, unevaled_args);
+                        Control::Return(
+                            list_again,
+                            saved_env,
+                            cont_witness.intern_named_cont(
+                                ContName::NewerCont2,
+                                store,
+                                Continuation::Binop2 {
+                                    operator,
+                                    evaled_arg: result,
+                                    continuation,
+                                },
+                            ),
+                        )
+                    }
                 } else if !rest.is_nil() {
                     return Ok(Control::Error(result, env));
+                } else if operator == Op2::Catch && env.tag != ExprTag::Thunk {
+                    // Nothing was thrown, so the handler is left unevaluated
+                    Control::MakeThunk(result, saved_env, continuation)
                 } else {
                     Control::Return(
                         arg2,
@@ -1079,12 +1586,214 @@ fn apply_continuation<F: LurkField>(
                 // from the store
                 let result = match operator {
                     Op2::Equal => store.as_lurk_boolean(store.ptr_eq(&evaled_arg, &arg2)?),
-                    Op2::Cons => {
+                    Op2::Cons | Op2::List => {
                         cons_witness.cons_named(ConsName::TheCons, store, evaled_arg, arg2)
                     }
                     Op2::Eval => {
                         return Ok(Control::Return(evaled_arg, arg2, continuation));
                     }
+                    Op2::Catch => {
+                        // The handler is called on the thrown value
+                        return Ok(Control::MakeThunk(
+                            evaled_arg,
+                            env,
+                            cont_witness.intern_named_cont(
+                                ContName::NewerCont2,
+                                store,
+                                Continuation::Call2 {
+                                    function: arg2,
+                                    saved_env: env,
+                                    unevaled_args: c.nil.ptr(),
+                                    continuation,
+                                },
+                            ),
+                        ));
+                    }
+                    Op2::VectorRef => {
+                        return Ok(vector_ref_step(
+                            evaled_arg,
+                            arg2,
+                            env,
+                            continuation,
+                            store,
+                            cons_witness,
+                            cont_witness,
+                        )?
+                        .unwrap_or(Control::Error(result, env)));
+                    }
+                    Op2::MakeVector => {
+                        return Ok(make_vector_step(
+                            evaled_arg,
+                            arg2,
+                            env,
+                            continuation,
+                            store,
+                            cont_witness,
+                        )
+                        .unwrap_or(Control::Error(result, env)));
+                    }
+                    Op2::VectorSet => {
+                        let (i, more) =
+                            cons_witness.car_cdr_named(ConsName::VecArgs, store, &arg2)?;
+                        let (elt, end) =
+                            cons_witness.car_cdr_named(ConsName::VecArgsCdr, store, &more)?;
+                        if !end.is_nil() {
+                            return Ok(Control::Error(result, env));
+                        }
+                        return Ok(vector_set_step(
+                            evaled_arg,
+                            i,
+                            elt,
+                            env,
+                            continuation,
+                            store,
+                            cons_witness,
+                            cont_witness,
+                        )?
+                        .unwrap_or(Control::Error(result, env)));
+                    }
+                    Op2::EvalWithLimit => {
+                        let (eval_env, more) =
+                            cons_witness.car_cdr_named(ConsName::EvalArgs, store, &arg2)?;
+                        let (fuel, end) =
+                            cons_witness.car_cdr_named(ConsName::EvalArgsCdr, store, &more)?;
+                        if !end.is_nil() || fuel.tag != ExprTag::U64 {
+                            return Ok(Control::Error(result, env));
+                        }
+                        // The evaluation starts anew under `Fuel`, which counts its steps
+                        return Ok(Control::Return(
+                            evaled_arg,
+                            eval_env,
+                            cont_witness.intern_named_cont(
+                                ContName::NewerCont2,
+                                store,
+                                Continuation::Fuel {
+                                    inner: store.intern_cont_outermost(),
+                                    fuel,
+                                    saved_env: env,
+                                    continuation,
+                                },
+                            ),
+                        ));
+                    }
+                    // Like the unary string built-ins, a call on a non-empty string becomes a
+                    // call on its tail, whose result its head is then consed onto by a `Binop2`
+                    // unless it's dropped by `substring`
+                    Op2::StringAppend => {
+                        if evaled_arg.tag != ExprTag::Str || arg2.tag != ExprTag::Str {
+                            return Ok(Control::Error(result, env));
+                        }
+                        if evaled_arg == store.strnil() {
+                            return Ok(Control::MakeThunk(arg2, env, continuation));
+                        }
+                        let (car, cdr) = cons_witness.car_cdr_mut_named(
+                            ConsName::StrCarCdr,
+                            store,
+                            &evaled_arg,
+                        )?;
+                        let nil = c.nil.ptr();
+                        let args =
+                            cons_witness.cons_named(ConsName::StrCallArgsCddr, store, arg2, nil);
+                        let args = cons_witness.cons_named(ConsName::StrCallArgs, store, cdr, args);
+                        let call = cons_witness.cons_named(
+                            ConsName::StrCall,
+                            store,
+                            c.string_append.ptr(),
+                            args,
+                        );
+                        return Ok(Control::Return(
+                            call,
+                            env,
+                            cont_witness.intern_named_cont(
+                                ContName::NewerCont2,
+                                store,
+                                Continuation::Binop2 {
+                                    operator: Op2::StrCons,
+                                    evaled_arg: car,
+                                    continuation,
+                                },
+                            ),
+                        ));
+                    }
+                    Op2::Substring => {
+                        let (start, more) =
+                            cons_witness.car_cdr_named(ConsName::StrArgs, store, &arg2)?;
+                        let (end, rest) =
+                            cons_witness.car_cdr_named(ConsName::StrArgsCdr, store, &more)?;
+                        let is_index =
+                            |ptr: &Ptr<F>| matches!(ptr.tag, ExprTag::Num | ExprTag::U64);
+                        if evaled_arg.tag != ExprTag::Str
+                            || !is_index(&start)
+                            || !is_index(&end)
+                            || !rest.is_nil()
+                        {
+                            return Ok(Control::Error(result, env));
+                        }
+                        let start_hash = *store
+                            .hash_expr(&start)
+                            .ok_or_else(|| store::Error("expr hash missing".into()))?
+                            .value();
+                        let end_hash = *store
+                            .hash_expr(&end)
+                            .ok_or_else(|| store::Error("expr hash missing".into()))?
+                            .value();
+                        if start_hash == F::ZERO && end_hash == F::ZERO {
+                            return Ok(Control::MakeThunk(store.strnil(), env, continuation));
+                        }
+                        // Both bounds are decremented until the start is reached, and then only
+                        // the end is, so the end can't be reached first
+                        if end_hash == F::ZERO || evaled_arg == store.strnil() {
+                            return Ok(Control::Error(result, env));
+                        }
+                        let decrement = |ptr: &Ptr<F>, hash: F| {
+                            if hash == F::ZERO {
+                                *ptr
+                            } else if ptr.tag == ExprTag::U64 {
+                                store.intern_u64((hash - F::ONE).to_u64_unchecked())
+                            } else {
+                                store.intern_num(crate::Num::Scalar(hash - F::ONE))
+                            }
+                        };
+                        let (car, cdr) = cons_witness.car_cdr_mut_named(
+                            ConsName::StrCarCdr,
+                            store,
+                            &evaled_arg,
+                        )?;
+                        let nil = c.nil.ptr();
+                        let args = cons_witness.cons_named(
+                            ConsName::StrCallArgsCddr,
+                            store,
+                            decrement(&end, end_hash),
+                            nil,
+                        );
+                        let args = cons_witness.cons_named(
+                            ConsName::StrCallArgsCdr,
+                            store,
+                            decrement(&start, start_hash),
+                            args,
+                        );
+                        let args = cons_witness.cons_named(ConsName::StrCallArgs, store, cdr, args);
+                        let call = cons_witness.cons_named(
+                            ConsName::StrCall,
+                            store,
+                            c.substring.ptr(),
+                            args,
+                        );
+                        let continuation = if start_hash == F::ZERO {
+                            cont_witness.intern_named_cont(
+                                ContName::NewerCont2,
+                                store,
+                                Continuation::Binop2 {
+                                    operator: Op2::StrCons,
+                                    evaled_arg: car,
+                                    continuation,
+                                },
+                            )
+                        } else {
+                            continuation
+                        };
+                        return Ok(Control::Return(call, env, continuation));
+                    }
                     _ => match (
                         store
                             .fetch(&evaled_arg)
@@ -1109,22 +1818,14 @@ fn apply_continuation<F: LurkField>(
                                 Op2::Product => store.intern_u64((a * b).into()),
                                 Op2::Quotient => {
                                     if b.is_zero() {
-                                        return Ok(Control::Return(
-                                            result,
-                                            env,
-                                            store.intern_cont_error(),
-                                        ));
+                                        return Ok(Control::Error(result, env));
                                     } else {
                                         store.intern_u64((a / b).into())
                                     }
                                 }
                                 Op2::Modulo => {
                                     if b.is_zero() {
-                                        return Ok(Control::Return(
-                                            result,
-                                            env,
-                                            store.intern_cont_error(),
-                                        ));
+                                        return Ok(Control::Error(result, env));
                                     } else {
                                         store.intern_u64((a % b).into())
                                     }
@@ -1137,6 +1838,37 @@ fn apply_continuation<F: LurkField>(
                                 _ => unreachable!(),
                             }
                         }
+                        (Expression::UInt(a), Expression::UInt(b)) if operator.is_bitwise() => {
+                            let val = match operator {
+                                Op2::LogAnd => a & b,
+                                Op2::LogIor => a | b,
+                                Op2::LogXor => a ^ b,
+                                Op2::ShiftLeft => a << b,
+                                Op2::ShiftRight => a >> b,
+                                _ => unreachable!(),
+                            };
+                            store.intern_u64(val.into())
+                        }
+                        (Expression::I64(a), Expression::I64(b)) if operator.is_numeric() => {
+                            match operator {
+                                Op2::Sum => store.intern_i64(a.wrapping_add(b)),
+                                Op2::Diff => store.intern_i64(a.wrapping_sub(b)),
+                                Op2::Product => store.intern_i64(a.wrapping_mul(b)),
+                                Op2::Quotient | Op2::Modulo if b == 0 => {
+                                    return Ok(Control::Error(result, env));
+                                }
+                                // Like Rust's, the quotient is rounded towards zero and the
+                                // remainder has the sign of the dividend
+                                Op2::Quotient => store.intern_i64(a.wrapping_div(b)),
+                                Op2::Modulo => store.intern_i64(a.wrapping_rem(b)),
+                                Op2::Equal | Op2::NumEqual => store.as_lurk_boolean(a == b),
+                                Op2::Less => store.as_lurk_boolean(a < b),
+                                Op2::Greater => store.as_lurk_boolean(a > b),
+                                Op2::LessEqual => store.as_lurk_boolean(a <= b),
+                                Op2::GreaterEqual => store.as_lurk_boolean(a >= b),
+                                _ => unreachable!(),
+                            }
+                        }
                         (Expression::Num(a), Expression::UInt(b)) if operator.is_numeric() => {
                             match num_num(store, operator, a, b.into()) {
                                 Ok(x) => x,
@@ -1155,7 +1887,7 @@ fn apply_continuation<F: LurkField>(
                             cons_witness.strcons_named(ConsName::TheCons, store, evaled_arg, arg2)
                         }
                         _ => {
-                            return Ok(Control::Return(result, env, store.intern_cont_error()));
+                            return Ok(Control::Error(result, env));
                         }
                     },
                 };
@@ -1163,6 +1895,74 @@ fn apply_continuation<F: LurkField>(
             }
             _ => unreachable!(),
         },
+        ContTag::Vector => match cont_witness
+            .fetch_named_cont(ContName::ApplyContinuation, store, &cont)
+            .ok_or_else(|| store::Error("Fetch failed".into()))?
+        {
+            Continuation::Vector {
+                operator,
+                vector,
+                evaled_arg,
+                continuation,
+            } => match operator {
+                Op2::VectorRef => vector_ref_step(
+                    vector,
+                    result,
+                    env,
+                    continuation,
+                    store,
+                    cons_witness,
+                    cont_witness,
+                )?
+                .unwrap_or(Control::Error(result, env)),
+                // A length is passed down until the empty vector is reached, and then the vector
+                // made of each length is passed up
+                Op2::MakeVector if result.tag == ExprTag::U64 => {
+                    make_vector_step(result, evaled_arg, env, cont, store, cont_witness)
+                        .unwrap_or(Control::Error(result, env))
+                }
+                Op2::MakeVector => {
+                    let content = if fetch_u64(store, &vector)? % 2 == 1 {
+                        cons_witness.cons_named(ConsName::VecNewContent, store, evaled_arg, result)
+                    } else {
+                        cons_witness.cons_named(ConsName::VecNewContent, store, result, result)
+                    };
+                    let made =
+                        cons_witness.vec_node_named(ConsName::VecNew, store, vector, content);
+                    Control::MakeThunk(made, env, continuation)
+                }
+                // The child to update, the index in it and the element are passed down until the
+                // element is reached, and then each updated node is passed up
+                Op2::VectorSet if result.tag == ExprTag::Cons => {
+                    let (child, more) =
+                        cons_witness.car_cdr_named(ConsName::VecArgs, store, &result)?;
+                    let (i, elt) =
+                        cons_witness.car_cdr_named(ConsName::VecArgsCdr, store, &more)?;
+                    vector_set_step(child, i, elt, env, cont, store, cons_witness, cont_witness)?
+                        .unwrap_or(Control::Error(result, env))
+                }
+                Op2::VectorSet => {
+                    let (len, content) =
+                        cons_witness.vec_len_content_named(ConsName::VecNode, store, &vector)?;
+                    let (first, rest) =
+                        cons_witness.car_cdr_named(ConsName::VecContent, store, &content)?;
+                    // `result` is the updated child, which is the cdr of the content if the
+                    // length or the index is odd
+                    let content = if fetch_u64(store, &len)? % 2 == 1
+                        || fetch_u64(store, &evaled_arg)? % 2 == 1
+                    {
+                        cons_witness.cons_named(ConsName::VecNewContent, store, first, result)
+                    } else {
+                        cons_witness.cons_named(ConsName::VecNewContent, store, result, rest)
+                    };
+                    let updated =
+                        cons_witness.vec_node_named(ConsName::VecNew, store, len, content);
+                    Control::MakeThunk(updated, env, continuation)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
         ContTag::If => match cont_witness
             .fetch_named_cont(ContName::ApplyContinuation, store, &cont)
             .ok_or_else(|| store::Error("Fetch failed".into()))?
@@ -1206,7 +2006,7 @@ fn apply_continuation<F: LurkField>(
                 let (arg2, end) =
                     cons_witness.car_cdr_named(ConsName::UnevaledArgsCdr, store, &more)?;
                 if !end.is_nil() {
-                    Control::Return(arg1, env, store.intern_cont_error())
+                    Control::Error(arg1, env)
                 } else {
                     Control::Return(
                         if condition.is_nil() { arg2 } else { arg1 },
@@ -1282,13 +2082,27 @@ fn make_thunk<F: LurkField>(
                     value: result,
                     continuation,
                 });
-                Ok(Control::Return(thunk, saved_env, store.intern_cont_dummy()))
+                // A thrown value keeps its env until it's caught
+                let env = if env.tag == ExprTag::Thunk {
+                    env
+                } else {
+                    saved_env
+                };
+                Ok(Control::Return(thunk, env, store.intern_cont_dummy()))
             }
             _ => unreachable!(),
         },
         // If continuation is outermost, we don't actually make a thunk. Instead, we signal
-        // that this is the terminal result by returning a Terminal continuation.
-        ContTag::Outermost => Ok(Control::Return(result, env, store.intern_cont_terminal())),
+        // that this is the terminal result by returning a Terminal continuation, or an Error
+        // one for a value thrown but never caught.
+        ContTag::Outermost => {
+            let cont = if env.tag == ExprTag::Thunk {
+                store.intern_cont_error()
+            } else {
+                store.intern_cont_terminal()
+            };
+            Ok(Control::Return(result, env, cont))
+        }
         _ => {
             let thunk = store.intern_thunk(Thunk {
                 value: result,
@@ -1299,6 +2113,240 @@ fn make_thunk<F: LurkField>(
     }
 }
 
+fn fetch_u64<F: LurkField>(store: &Store<F>, ptr: &Ptr<F>) -> Result<u64, ReductionError> {
+    if ptr.tag != ExprTag::U64 {
+        return Err(store::Error("Expected a u64".into()).into());
+    }
+    store
+        .fetch_uint(ptr)
+        .map(u64::from)
+        .ok_or_else(|| store::Error("Fetch failed".into()).into())
+}
+
+/// Where an element of a vector is found from one of its nodes
+enum VectorStep<F: LurkField> {
+    /// The element is the first of the content of the node, which has length `len`
+    Here {
+        len: Ptr<F>,
+        first: Ptr<F>,
+        rest: Ptr<F>,
+    },
+    /// The element is at index `index` of the vector `child`, a part of the content of the node
+    Child { child: Ptr<F>, index: Ptr<F> },
+}
+
+/// Finds where the element at index `i` of the vector `node` is. Returns `None` if `node` isn't a
+/// vector or `i` isn't one of its indices.
+fn vector_step<F: LurkField>(
+    node: &Ptr<F>,
+    i: &Ptr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+) -> Result<Option<VectorStep<F>>, ReductionError> {
+    if node.tag != ExprTag::Vec || i.tag != ExprTag::U64 {
+        return Ok(None);
+    }
+    let (len, content) = cons_witness.vec_len_content_named(ConsName::VecNode, store, node)?;
+    if len.tag != ExprTag::U64 {
+        return Ok(None);
+    }
+    let (n, j) = (fetch_u64(store, &len)?, fetch_u64(store, i)?);
+    if j >= n {
+        return Ok(None);
+    }
+    let (first, rest) = cons_witness.car_cdr_named(ConsName::VecContent, store, &content)?;
+    // See `Store::intern_vec_node()` for the layout of the content
+    let step = if n % 2 == 1 {
+        if j == 0 {
+            VectorStep::Here { len, first, rest }
+        } else {
+            VectorStep::Child {
+                child: rest,
+                index: store.intern_u64(j - 1),
+            }
+        }
+    } else {
+        VectorStep::Child {
+            child: if j % 2 == 1 { rest } else { first },
+            index: store.intern_u64(j / 2),
+        }
+    };
+    Ok(Some(step))
+}
+
+/// Takes a step towards the element at index `i` of the vector `node`: returns it if it's at hand,
+/// or else passes its index in the child holding it to a `Vector` continuation reading that child
+fn vector_ref_step<F: LurkField>(
+    node: Ptr<F>,
+    i: Ptr<F>,
+    env: Ptr<F>,
+    continuation: ContPtr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+    cont_witness: &mut ContWitness<F>,
+) -> Result<Option<Control<F>>, ReductionError> {
+    Ok(match vector_step(&node, &i, store, cons_witness)? {
+        None => None,
+        Some(VectorStep::Here { first, .. }) => Some(Control::MakeThunk(first, env, continuation)),
+        Some(VectorStep::Child { child, index }) => Some(Control::MakeThunk(
+            index,
+            env,
+            cont_witness.intern_named_cont(
+                ContName::NewerCont2,
+                store,
+                Continuation::Vector {
+                    operator: Op2::VectorRef,
+                    vector: child,
+                    evaled_arg: lurk_sym_ptr!(store, nil),
+                    continuation,
+                },
+            ),
+        )),
+    })
+}
+
+/// Takes a step towards making the vector of length `len` whose elements are all `elt`: returns
+/// the empty vector if `len` is zero, or else passes the length of the vectors its content is
+/// made of to a `Vector` continuation making it
+fn make_vector_step<F: LurkField>(
+    len: Ptr<F>,
+    elt: Ptr<F>,
+    env: Ptr<F>,
+    continuation: ContPtr<F>,
+    store: &Store<F>,
+    cont_witness: &mut ContWitness<F>,
+) -> Option<Control<F>> {
+    if len.tag != ExprTag::U64 {
+        return None;
+    }
+    let n = u64::from(store.fetch_uint(&len)?);
+    if n == 0 {
+        return Some(Control::MakeThunk(store.intern_vec(&[]), env, continuation));
+    }
+    let half = if n % 2 == 1 { n - 1 } else { n / 2 };
+    Some(Control::MakeThunk(
+        store.intern_u64(half),
+        env,
+        cont_witness.intern_named_cont(
+            ContName::NewerCont2,
+            store,
+            Continuation::Vector {
+                operator: Op2::MakeVector,
+                vector: len,
+                evaled_arg: elt,
+                continuation,
+            },
+        ),
+    ))
+}
+
+/// Takes a step towards setting the element at index `i` of the vector `node` to `elt`: returns
+/// the updated node if the element is at hand, or else passes the child holding it, its index in
+/// it and `elt` to a `Vector` continuation updating `node` with the updated child
+fn vector_set_step<F: LurkField>(
+    node: Ptr<F>,
+    i: Ptr<F>,
+    elt: Ptr<F>,
+    env: Ptr<F>,
+    continuation: ContPtr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+    cont_witness: &mut ContWitness<F>,
+) -> Result<Option<Control<F>>, ReductionError> {
+    Ok(match vector_step(&node, &i, store, cons_witness)? {
+        None => None,
+        Some(VectorStep::Here { len, rest, .. }) => {
+            let content = cons_witness.cons_named(ConsName::VecNewContent, store, elt, rest);
+            let updated = cons_witness.vec_node_named(ConsName::VecNew, store, len, content);
+            Some(Control::MakeThunk(updated, env, continuation))
+        }
+        Some(VectorStep::Child { child, index }) => {
+            let args = cons_witness.cons_named(ConsName::VecNewContent, store, index, elt);
+            let args = cons_witness.cons_named(ConsName::VecNew, store, child, args);
+            Some(Control::MakeThunk(
+                args,
+                env,
+                cont_witness.intern_named_cont(
+                    ContName::NewerCont2,
+                    store,
+                    Continuation::Vector {
+                        operator: Op2::VectorSet,
+                        vector: node,
+                        evaled_arg: i,
+                        continuation,
+                    },
+                ),
+            ))
+        }
+    })
+}
+
+/// The first parameter of the function of parameters `params`, the ones after it, and the first
+/// of those, which is nil if there are none
+fn fun_params<F: LurkField>(
+    params: &Ptr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+    c: &NamedConstants<F>,
+) -> Result<(Ptr<F>, Ptr<F>, Ptr<F>), ReductionError> {
+    let (param, more_params) = cons_witness.car_cdr_named(ConsName::FunParams, store, params)?;
+    let next_param = if more_params.tag == ExprTag::Cons {
+        let (next_param, _) =
+            cons_witness.car_cdr_named(ConsName::FunMoreParams, store, &more_params)?;
+        next_param
+    } else {
+        c.nil.ptr()
+    };
+    Ok((param, more_params, next_param))
+}
+
+/// The form of the body of a function, or `None` if the body isn't a single form
+fn fun_body_form<F: LurkField>(
+    body: &Ptr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+) -> Result<Option<Ptr<F>>, ReductionError> {
+    if body.tag != ExprTag::Cons {
+        return Ok(None);
+    }
+    let (body_form, end) = cons_witness.car_cdr_named(ConsName::FunBody, store, body)?;
+    Ok(end.is_nil().then_some(body_form))
+}
+
+/// Evaluates the next argument of a call to `function` under a `Call2` continuation, or all the
+/// arguments left as `(list ...)` when `param`, the parameter they are for, is `&rest`
+#[allow(clippy::too_many_arguments)]
+fn call_next_arg<F: LurkField>(
+    function: Ptr<F>,
+    param: Ptr<F>,
+    unevaled_args: Ptr<F>,
+    saved_env: Ptr<F>,
+    continuation: ContPtr<F>,
+    store: &Store<F>,
+    cons_witness: &mut ConsWitness<F>,
+    cont_witness: &mut ContWitness<F>,
+    c: &NamedConstants<F>,
+) -> Result<Control<F>, ReductionError> {
+    let (next_expr, more_args) = if param == c.rest.ptr() {
+        let list_args =
+            cons_witness.cons_named(ConsName::CallListArgs, store, c.list.ptr(), unevaled_args);
+        (list_args, c.nil.ptr())
+    } else {
+        cons_witness.car_cdr_named(ConsName::CallArgs, store, &unevaled_args)?
+    };
+    let newer_cont = cont_witness.intern_named_cont(
+        ContName::NewerCont2,
+        store,
+        Continuation::Call2 {
+            function,
+            saved_env,
+            unevaled_args: more_args,
+            continuation,
+        },
+    );
+    Ok(Control::Return(next_expr, saved_env, newer_cont))
+}
+
 fn make_tail_continuation<F: LurkField>(
     env: Ptr<F>,
     continuation: ContPtr<F>,
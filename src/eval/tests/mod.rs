@@ -746,7 +746,7 @@ fn evaluate_recursion_multiarg() {
         None,
         Some(terminal),
         None,
-        95,
+        91,
         None,
     );
 }
@@ -899,7 +899,7 @@ fn evaluate_multiple_letrec_bindings_recursive() {
         None,
         Some(terminal),
         None,
-        242,
+        232,
         None,
     );
 }
@@ -994,11 +994,11 @@ fn evaluate_zero_arg_lambda() {
     }
     {
         let expected = {
-            let arg = s.user_sym("x");
+            let params = s.list(&[s.user_sym("x")]);
             let num = s.num(123);
             let body = s.list(&[num]);
             let env = lurk_sym_ptr!(s, nil);
-            s.intern_fun(arg, body, env)
+            s.intern_fun(params, body, env)
         };
 
         // One arg expected but zero supplied.
@@ -1051,7 +1051,7 @@ fn evaluate_zero_arg_lambda_variants() {
         let expr = "((lambda () 123) 1)";
 
         let error = s.get_cont_error();
-        test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 3, None);
+        test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 2, None);
     }
     {
         let s = &mut Store::<Fr>::default();
@@ -1160,7 +1160,7 @@ fn evaluate_map_tree_bug() {
             None,
             Some(terminal),
             None,
-            170,
+            163,
             None,
         );
     }
@@ -1178,9 +1178,9 @@ fn evaluate_map_tree_numequal_bug() {
                                                (= (map-tree f (car tree))
                                                   (map-tree f (cdr tree)))))))
                        (map-tree (lambda (x) (+ 1 x)) '((1 . 2) . (3 . 4))))";
-        let expected = lurk_sym_ptr!(s, nil);
+        let expected = s.read("(:invalid-arg)").unwrap();
         let error = s.get_cont_error();
-        test_aux::<Coproc<Fr>>(s, expr, Some(expected), None, Some(error), None, 169, None);
+        test_aux::<Coproc<Fr>>(s, expr, Some(expected), None, Some(error), None, 142, None);
     }
 }
 
@@ -1471,7 +1471,7 @@ fn go_translate() {
         None,
         None,
         None,
-        1114,
+        1079,
         None,
     );
 }
@@ -2106,7 +2106,7 @@ fn test_eval_env_regression() {
     let error = s.get_cont_error();
     let terminal = s.get_cont_terminal();
 
-    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 5, None);
+    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 6, None);
     test_aux::<Coproc<Fr>>(s, expr2, Some(res), None, Some(terminal), None, 6, None);
 }
 
@@ -2221,6 +2221,66 @@ fn test_num_mod() {
     test_aux::<Coproc<Fr>>(s, expr3, None, None, Some(error), None, 3, None);
 }
 
+#[test]
+fn test_u64_bitwise() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(logand 12u64 10u64)";
+    let expr2 = "(logior 12u64 10u64)";
+    let expr3 = "(logxor 12u64 10u64)";
+    let expr4 = "(lognot 0u64)";
+    let expr5 = "(lognot 18446744073709551614u64)";
+    let expr6 = "(logand 12 10)";
+    let expr7 = "(logior 12u64 10)";
+    let expr8 = "(lognot 0)";
+
+    let res = s.uint64(8);
+    let res2 = s.uint64(14);
+    let res3 = s.uint64(6);
+    let res4 = s.uint64(18446744073709551615);
+    let res5 = s.uint64(1);
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr6, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr7, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr8, None, None, Some(error), None, 2, None);
+}
+
+#[test]
+fn test_u64_shift() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(shift-left 1u64 3u64)";
+    let expr2 = "(shift-right 1024u64 3u64)";
+    let expr3 = "(shift-left 3u64 63u64)";
+    let expr4 = "(shift-right 18446744073709551615u64 63u64)";
+    let expr5 = "(shift-left 1u64 64u64)";
+    let expr6 = "(shift-right 1u64 18446744073709551615u64)";
+    let expr7 = "(shift-left 1 3u64)";
+
+    let res = s.uint64(8);
+    let res2 = s.uint64(128);
+    let res3 = s.uint64(9223372036854775808);
+    let res4 = s.uint64(1);
+    let res5 = s.uint64(0);
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res5), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr7, None, None, Some(error), None, 3, None);
+}
+
 #[test]
 fn test_u64_comp() {
     let s = &mut Store::<Fr>::default();
@@ -2314,6 +2374,11 @@ fn test_numeric_type_error() {
     test(">=");
     test("<=");
     test("=");
+    test("logand");
+    test("logior");
+    test("logxor");
+    test("shift-left");
+    test("shift-right");
 }
 
 #[test]
@@ -2354,6 +2419,377 @@ fn test_hide_u64_secret() {
     test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 3, None);
 }
 
+#[test]
+fn test_i64_self_evaluating() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "-123i64";
+    let res = s.intern_i64(-123);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 1, None);
+}
+
+#[test]
+fn test_i64_arithmetic() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(+ -5i64 3i64)";
+    let expr2 = "(- -5i64 3i64)";
+    let expr3 = "(* -5i64 3i64)";
+    let expr4 = "(+ 9223372036854775807i64 1i64)";
+    let expr5 = "(- -9223372036854775808i64 1i64)";
+    let expr6 = "(* 4611686018427387904i64 2i64)";
+
+    let res = s.intern_i64(-2);
+    let res2 = s.intern_i64(-8);
+    let res3 = s.intern_i64(-15);
+    let res4 = s.intern_i64(i64::MIN);
+    let res5 = s.intern_i64(i64::MAX);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res4), None, Some(terminal), None, 3, None);
+}
+
+#[test]
+fn test_i64_div_mod() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(/ -7i64 2i64)";
+    let expr2 = "(% -7i64 2i64)";
+    let expr3 = "(/ 7i64 -2i64)";
+    let expr4 = "(% 7i64 -2i64)";
+    let expr5 = "(/ -8i64 -2i64)";
+    let expr6 = "(/ -9223372036854775808i64 -1i64)";
+    let expr7 = "(/ 1i64 0i64)";
+    let expr8 = "(% 1i64 0i64)";
+
+    let res = s.intern_i64(-3);
+    let res2 = s.intern_i64(-1);
+    let res4 = s.intern_i64(1);
+    let res5 = s.intern_i64(4);
+    let res6 = s.intern_i64(i64::MIN);
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res6), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr7, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr8, None, None, Some(error), None, 3, None);
+}
+
+#[test]
+fn test_i64_comp() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(< -1i64 1i64)";
+    let expr2 = "(> -1i64 1i64)";
+    let expr3 = "(<= -9223372036854775808i64 9223372036854775807i64)";
+    let expr4 = "(>= -2i64 -1i64)";
+    let expr5 = "(= -1i64 -1i64)";
+
+    let t = lurk_sym_ptr!(s, t);
+    let nil = lurk_sym_ptr!(s, nil);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(t), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(nil), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(t), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(nil), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(t), None, Some(terminal), None, 3, None);
+}
+
+#[test]
+fn test_i64_conversion() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(i64 -5)";
+    let expr2 = "(num -5i64)";
+    let expr3 = "(u64 -1i64)";
+    let expr4 = "(i64 18446744073709551615u64)";
+    let expr5 = "(i64 9223372036854775808)";
+    let expr6 = "(i64 -5i64)";
+
+    let res = s.intern_i64(-5);
+    let res2 = s.read("-5").unwrap();
+    let res3 = s.intern_u64(u64::MAX);
+    let res4 = s.intern_i64(-1);
+    let res5 = s.intern_i64(i64::MIN);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res), None, Some(terminal), None, 2, None);
+}
+
+#[test]
+fn test_i64_type_error() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(+ 1i64 1)";
+    let expr2 = "(+ 1u64 1i64)";
+    let expr3 = "(logand 1i64 1i64)";
+    let expr4 = "(lognot 1i64)";
+    let expr5 = "(i64 'a)";
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr3, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr4, None, None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr5, None, None, Some(error), None, 2, None);
+}
+
+#[test]
+fn test_char_u64_conversions() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = r"(char->u64 #\a)";
+    let expr2 = "(u64->char 97u64)";
+    let expr3 = "(char->u64 97)";
+    let expr4 = "(u64->char 4294967296u64)";
+
+    let res = s.uint64(97);
+    let res2 = s.read(r"#\a").unwrap();
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr3, None, None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr4, None, None, Some(error), None, 2, None);
+}
+
+#[test]
+fn test_symbol_string_conversions() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(symbol->string 'foo)";
+    let expr2 = r#"(string->symbol "foo")"#;
+    let expr3 = r#"(symbol->string "foo")"#;
+    let expr4 = "(string->symbol 'foo)";
+
+    let res = s.intern_string("foo");
+    let res2 = s.user_sym("foo");
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr3, None, None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr4, None, None, Some(error), None, 2, None);
+}
+
+#[test]
+fn test_string_builtins() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = r#"(string-length "")"#;
+    let expr2 = r#"(string-length "abc")"#;
+    let expr3 = "(string-length 'foo)";
+    let expr4 = r#"(string-append "ab" "cd")"#;
+    let expr5 = r#"(string-append "ab" 1)"#;
+    let expr6 = r#"(substring "hello" 1 3)"#;
+    let expr7 = r#"(substring "hello" 3 1)"#;
+    let expr8 = r#"(string->list "ab")"#;
+    let expr9 = r#"(list->string '(#\a #\b))"#;
+    let expr10 = "(list->string '(1 2))";
+
+    let res = s.uint64(0);
+    let res2 = s.uint64(3);
+    let res4 = s.str("abcd");
+    let res6 = s.str("el");
+    let res8 = s.read("(#\\a #\\b)").unwrap();
+    let res9 = s.str("ab");
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 11, None);
+    test_aux::<Coproc<Fr>>(s, expr3, None, None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 11, None);
+    test_aux::<Coproc<Fr>>(s, expr5, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res6), None, Some(terminal), None, 34, None);
+    test_aux::<Coproc<Fr>>(s, expr7, None, None, Some(error), None, 16, None);
+    test_aux::<Coproc<Fr>>(s, expr8, Some(res8), None, Some(terminal), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr9, Some(res9), None, Some(terminal), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr10, None, None, Some(error), None, 8, None);
+}
+
+#[test]
+fn test_catch_throw() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(catch 1 (lambda (e) e))";
+    let expr2 = "(catch (throw 1) (lambda (e) e))";
+    let expr3 = "(catch (+ 1 (throw 41)) (lambda (e) (+ e 1)))";
+    let expr4 = "(throw 1)";
+    let expr5 = "(+ 1 (throw 2))";
+
+    let res = s.num(1);
+    let res3 = s.num(42);
+    let res5 = s.num(2);
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res), None, Some(terminal), None, 7, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 13, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res), None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(error), None, 5, None);
+}
+
+#[test]
+fn test_catch_errors() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(catch (car 1) (lambda (e) e))";
+    let expr2 = "(catch (+ 1 (cdr 'x)) (lambda (e) (car e)))";
+    let expr3 = "(catch (foo) (lambda (e) e))";
+    let expr4 = "(car 1)";
+    let expr5 = "(catch 1 2 3)";
+
+    let res = s.read("(:car-of-non-list . 1)").unwrap();
+    let res2 = s.key("cdr-of-non-list");
+    let res3 = s.read("(:unbound-var . foo)").unwrap();
+    let res5 = s.read("(:invalid-form . 1)").unwrap();
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 7, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 12, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res), None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(error), None, 2, None);
+}
+
+#[test]
+fn test_derived_forms() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(and 1 2 3)";
+    let expr2 = "(and 1 nil 3)";
+    let expr3 = "(or nil 2)";
+    let expr4 = "(when t 1)";
+    let expr5 = "(unless t 1)";
+    let expr6 = "(cond ((= 1 2) 1) ((= 1 1) 2))";
+    let expr7 = "(let* ((a 1) (b a)) b)";
+
+    let res = s.num(3);
+    let res2 = lurk_sym_ptr!(s, nil);
+    let res3 = s.num(2);
+    let res4 = s.num(1);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 5, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 5, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 5, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res2), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res3), None, Some(terminal), None, 11, None);
+    test_aux::<Coproc<Fr>>(s, expr7, Some(res4), None, Some(terminal), None, 5, None);
+}
+
+#[test]
+fn test_list_and_rest_params() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(list)";
+    let expr2 = "(list 1 2 3)";
+    let expr3 = "((lambda (a &rest r) r) 1 2 3)";
+    let expr4 = "((lambda (&rest r) r))";
+    let expr5 = "(((lambda (a &rest r) r) 1))";
+    let expr6 = "(let ((f (lambda (x &rest ys) (cons x ys)))) (f 1 2 3))";
+    let expr7 = "((lambda (x y) (+ x y)) 1 2)";
+
+    let nil = lurk_sym_ptr!(s, nil);
+    let res2 = s.list(&[s.num(1), s.num(2), s.num(3)]);
+    let res3 = s.list(&[s.num(2), s.num(3)]);
+    let res7 = s.num(3);
+    let terminal = s.get_cont_terminal();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(nil), None, Some(terminal), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 10, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(nil), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(nil), None, Some(terminal), None, 6, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res2), None, Some(terminal), None, 17, None);
+    test_aux::<Coproc<Fr>>(s, expr7, Some(res7), None, Some(terminal), None, 10, None);
+}
+
+#[test]
+fn test_vectors() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(make-vector 0u64 nil)";
+    let expr2 = "(make-vector 3u64 0)";
+    let expr3 = "(vector-length (make-vector 3u64 0))";
+    let expr4 = "(vector-set (make-vector 2u64 0) 1u64 7)";
+    let expr5 = "(vector-ref (vector-set (make-vector 2u64 0) 1u64 7) 1u64)";
+    let expr6 = "(vector-ref (make-vector 2u64 0) 2u64)";
+    let expr7 = "(vector-length 1)";
+    let expr8 = "(vector-set (make-vector 1u64 0) 0u64)";
+
+    let res = s.intern_vec(&[]);
+    let res2 = s.intern_vec(&[s.num(0), s.num(0), s.num(0)]);
+    let res3 = s.uint64(3);
+    let res4 = s.intern_vec(&[s.num(0), s.num(7)]);
+    let res5 = s.num(7);
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 9, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 10, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 17, None);
+    test_aux::<Coproc<Fr>>(s, expr5, Some(res5), None, Some(terminal), None, 21, None);
+    test_aux::<Coproc<Fr>>(s, expr6, None, None, Some(error), None, 10, None);
+    test_aux::<Coproc<Fr>>(s, expr7, None, None, Some(error), None, 2, None);
+    test_aux::<Coproc<Fr>>(s, expr8, None, None, Some(error), None, 1, None);
+}
+
+#[test]
+fn test_eval_with_limit() {
+    let s = &mut Store::<Fr>::default();
+
+    let expr = "(eval-with-limit (quote (+ 1 2)) nil 10u64)";
+    let expr2 = "(eval-with-limit (quote (+ 1 2)) nil 1u64)";
+    let expr3 = "(eval-with-limit (quote (+ 1 2)) nil 0u64)";
+    let expr4 = "(car (eval-with-limit (quote (+ 1 2)) nil 10u64))";
+    let expr5 = "(eval-with-limit 1 nil 1)";
+    let expr6 = "(eval-with-limit (quote (car 1)) nil 10u64)";
+    let expr7 = "(eval-with-limit (quote (eval-with-limit 1 nil 1u64)) nil 10u64)";
+
+    let res = s.read("(:ok 3)").unwrap();
+    let res2 = s.read("(:out-of-fuel 1)").unwrap();
+    let res3 = s.read("(:out-of-fuel (+ 1 2))").unwrap();
+    let res4 = s.key("ok");
+    let res6 = s.read("(:error (:car-of-non-list . 1))").unwrap();
+    let res7 = s.read("(:error 1)").unwrap();
+    let terminal = s.get_cont_terminal();
+    let error = s.get_cont_error();
+
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 12, None);
+    test_aux::<Coproc<Fr>>(s, expr2, Some(res2), None, Some(terminal), None, 11, None);
+    test_aux::<Coproc<Fr>>(s, expr3, Some(res3), None, Some(terminal), None, 10, None);
+    test_aux::<Coproc<Fr>>(s, expr4, Some(res4), None, Some(terminal), None, 13, None);
+    test_aux::<Coproc<Fr>>(s, expr5, None, None, Some(error), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr6, Some(res6), None, Some(terminal), None, 11, None);
+    test_aux::<Coproc<Fr>>(s, expr7, Some(res7), None, Some(terminal), None, 17, None);
+}
+
 #[test]
 fn test_keyword() {
     let s = &mut Store::<Fr>::default();
@@ -2465,7 +2901,7 @@ fn test_fold_cons_regression() {
     let res = s.num(6);
     let terminal = s.get_cont_terminal();
 
-    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 152, None);
+    test_aux::<Coproc<Fr>>(s, expr, Some(res), None, Some(terminal), None, 108, None);
 }
 
 #[test]
@@ -2484,7 +2920,7 @@ fn test_eval_bad_form() {
     let expr = "(* 5 (eval '(+ 1 a) '((0 . 3))))"; // two-arg eval, optional second arg is env.
     let error = s.get_cont_error();
 
-    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 8, None);
+    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 10, None);
 }
 
 #[test]
@@ -2504,7 +2940,7 @@ fn test_eval_dotted_syntax_error() {
     let expr = "(let ((a (lambda (x) (+ x 1)))) (a . 1))";
     let error = s.get_cont_error();
 
-    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 3, None);
+    test_aux::<Coproc<Fr>>(s, expr, None, None, Some(error), None, 4, None);
 }
 
 fn op_syntax_error<T: Op + Copy>() {
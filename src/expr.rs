@@ -21,7 +21,7 @@ pub enum Expression<F: LurkField> {
     Nil,
     Cons(Ptr<F>, Ptr<F>),
     Comm(F, Ptr<F>),
-    /// arg, body, closed env
+    /// params, body, closed env
     Fun(Ptr<F>, Ptr<F>, Ptr<F>),
     Num(Num<F>),
     EmptyStr,
@@ -33,6 +33,9 @@ pub enum Expression<F: LurkField> {
     Key(Ptr<F>, Ptr<F>),
     Char(char),
     UInt(UInt),
+    I64(i64),
+    /// length, content
+    Vec(Ptr<F>, Ptr<F>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
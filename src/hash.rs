@@ -4,12 +4,13 @@ use std::sync::Arc;
 use crate::field::{FWrap, LurkField};
 use elsa::{sync::FrozenMap, sync_index_map::FrozenIndexMap};
 
-use generic_array::typenum::{U3, U4, U6, U8};
+use generic_array::typenum::{U2, U3, U4, U6, U8};
 use neptune::{poseidon::PoseidonConstants, Poseidon};
 use once_cell::sync::OnceCell;
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashArity {
+    A2,
     A3,
     A4,
     A6,
@@ -19,6 +20,7 @@ pub enum HashArity {
 impl From<usize> for HashArity {
     fn from(n: usize) -> Self {
         match n {
+            2 => Self::A2,
             3 => Self::A3,
             4 => Self::A4,
             6 => Self::A6,
@@ -30,6 +32,7 @@ impl From<usize> for HashArity {
 
 #[derive(Debug)]
 pub enum HashConst<'a, F: LurkField> {
+    A2(&'a PoseidonConstants<F, U2>),
     A3(&'a PoseidonConstants<F, U3>),
     A4(&'a PoseidonConstants<F, U4>),
     A6(&'a PoseidonConstants<F, U6>),
@@ -39,6 +42,7 @@ pub enum HashConst<'a, F: LurkField> {
 /// Holds the constants needed for poseidon hashing.
 #[derive(Clone, Debug)]
 pub struct HashConstants<F: LurkField> {
+    c2: OnceCell<PoseidonConstants<F, U2>>,
     c3: OnceCell<PoseidonConstants<F, U3>>,
     c4: OnceCell<PoseidonConstants<F, U4>>,
     c6: OnceCell<PoseidonConstants<F, U6>>,
@@ -48,6 +52,7 @@ pub struct HashConstants<F: LurkField> {
 impl<F: LurkField> Default for HashConstants<F> {
     fn default() -> Self {
         Self {
+            c2: OnceCell::new(),
             c3: OnceCell::new(),
             c4: OnceCell::new(),
             c6: OnceCell::new(),
@@ -57,6 +62,10 @@ impl<F: LurkField> Default for HashConstants<F> {
 }
 
 impl<F: LurkField> HashConstants<F> {
+    pub fn c2(&self) -> &PoseidonConstants<F, U2> {
+        self.c2.get_or_init(|| PoseidonConstants::new())
+    }
+
     pub fn c3(&self) -> &PoseidonConstants<F, U3> {
         self.c3.get_or_init(|| PoseidonConstants::new())
     }
@@ -75,6 +84,7 @@ impl<F: LurkField> HashConstants<F> {
 
     pub fn constants(&self, arity: HashArity) -> HashConst<'_, F> {
         match arity {
+            HashArity::A2 => HashConst::A2(self.c2.get_or_init(|| PoseidonConstants::new())),
             HashArity::A3 => HashConst::A3(self.c3.get_or_init(|| PoseidonConstants::new())),
             HashArity::A4 => HashConst::A4(self.c4.get_or_init(|| PoseidonConstants::new())),
             HashArity::A6 => HashConst::A6(self.c6.get_or_init(|| PoseidonConstants::new())),
@@ -85,6 +95,7 @@ impl<F: LurkField> HashConstants<F> {
 
 #[derive(Clone, Default, Debug)]
 pub struct PoseidonCache<F: LurkField> {
+    a2: Arc<FrozenMap<CacheKey<F, 2>, F>>,
     a3: Arc<FrozenMap<CacheKey<F, 3>, F>>,
     a4: Arc<FrozenMap<CacheKey<F, 4>, F>>,
     a6: Arc<FrozenMap<CacheKey<F, 6>, F>>,
@@ -104,6 +115,7 @@ impl<F: LurkField> PoseidonCache<F> {
             }};
         }
         match ARITY {
+            2 => hash!(hash2, 2),
             3 => hash!(hash3, 3),
             4 => hash!(hash4, 4),
             6 => hash!(hash6, 6),
@@ -115,6 +127,7 @@ impl<F: LurkField> PoseidonCache<F> {
 
 #[derive(Default, Debug)]
 pub struct InversePoseidonCache<F: LurkField> {
+    a2: FrozenIndexMap<FWrap<F>, Box<[F; 2]>>,
     a3: FrozenIndexMap<FWrap<F>, Box<[F; 3]>>,
     a4: FrozenIndexMap<FWrap<F>, Box<[F; 4]>>,
     a6: FrozenIndexMap<FWrap<F>, Box<[F; 6]>>,
@@ -140,6 +153,7 @@ impl<F: LurkField> InversePoseidonCache<F> {
         }
 
         match ARITY {
+            2 => get!(a2, 2),
             3 => get!(a3, 3),
             4 => get!(a4, 4),
             6 => get!(a6, 6),
@@ -160,6 +174,7 @@ impl<F: LurkField> InversePoseidonCache<F> {
         }
 
         match ARITY {
+            2 => insert!(a2, 2),
             3 => insert!(a3, 3),
             4 => insert!(a4, 4),
             6 => insert!(a6, 6),
@@ -182,6 +197,12 @@ impl<F: LurkField, const N: usize> Hash for CacheKey<F, N> {
 }
 
 impl<F: LurkField> PoseidonCache<F> {
+    pub fn hash2(&self, preimage: &[F; 2]) -> F {
+        self.a2.get_copy_or_insert_with(CacheKey(*preimage), || {
+            Poseidon::new_with_preimage(preimage, self.constants.c2()).hash()
+        })
+    }
+
     pub fn hash3(&self, preimage: &[F; 3]) -> F {
         self.a3.get_copy_or_insert_with(CacheKey(*preimage), || {
             Poseidon::new_with_preimage(preimage, self.constants.c3()).hash()
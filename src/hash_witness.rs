@@ -183,6 +183,8 @@ pub enum ConsName {
     ExprCaaadr,
     ExprCddr,
     UnopConsLike,
+    FunParams,
+    FunMoreParams,
     FunBody,
     NewRec,
     NewRecCadr,
@@ -191,17 +193,32 @@ pub enum ConsName {
     UnevaledArgsCdr,
     Begin,
     EnvToUse,
-    InnerBody,
-    Lambda,
-    InnerLambda,
     TheCons,
-    FunExpanded,
     ExtendedClosureEnv,
     Binding,
     ClosedEnv,
-    ExpandedInner0,
     ExpandedInner,
     Expanded,
+    ListArgs,
+    CallArgs,
+    CallListArgs,
+    VecArgs,
+    VecArgsCdr,
+    VecNode,
+    VecContent,
+    VecNewContent,
+    VecNew,
+    EvalArgs,
+    EvalArgsCdr,
+    StrArgs,
+    StrArgsCdr,
+    StrCarCdr,
+    StrQuoted,
+    StrQuote,
+    StrCallArgs,
+    StrCallArgsCdr,
+    StrCallArgsCddr,
+    StrCall,
 }
 
 pub trait HashName: Copy {
@@ -217,31 +234,48 @@ impl HashName for ConsName {
             Self::ExprCdr => 1,
             Self::UnevaledArgsCdr => 1,
             Self::ExprCadr => 2,
+            Self::FunParams => 2,
+            Self::VecArgs => 2,
+            Self::EvalArgs => 2,
+            Self::StrArgs => 2,
             Self::ExprCddr => 3,
             Self::UnopConsLike => 3,
-            Self::Lambda => 3,
+            Self::FunMoreParams => 3,
+            Self::VecArgsCdr => 3,
+            Self::EvalArgsCdr => 3,
+            Self::StrArgsCdr => 3,
             Self::ExprCaadr => 4,
             Self::Begin => 4,
-            Self::InnerBody => 4,
             Self::ExtendedClosureEnv => 4,
+            Self::StrCall => 4,
             Self::UnevaledArgs => 5,
             Self::ExprCaaadr => 5,
             Self::ExtendedRec => 5,
             Self::EnvToUse => 5,
             Self::Binding => 5,
+            Self::VecNode => 5,
+            Self::StrCarCdr => 5,
+            Self::StrQuoted => 5,
             Self::FunBody => 6,
+            Self::CallArgs => 6,
+            Self::CallListArgs => 6,
             Self::NewRecCadr => 6,
+            Self::VecContent => 6,
+            Self::StrQuote => 6,
+            Self::StrCallArgsCddr => 6,
             Self::NewRec => 7,
             Self::ClosedEnv => 7,
+            Self::ListArgs => 7,
+            Self::VecNewContent => 7,
+            Self::StrCallArgs => 7,
             Self::Env => 8,
-            Self::ExpandedInner0 => 8,
-            Self::FunExpanded => 9,
+            Self::StrCallArgsCdr => 8,
             Self::Expanded => 9,
             Self::EnvCar => 9,
-            Self::InnerLambda => 10,
             Self::TheCons => 10,
             Self::EnvCaar => 10,
             Self::ExpandedInner => 10,
+            Self::VecNew => 10,
         }
     }
 }
@@ -296,6 +330,50 @@ impl<F: LurkField> ConsStub<F> {
         }
     }
 
+    pub fn symbol_car_cdr(
+        &mut self,
+        s: &Store<F>,
+        sym: &Ptr<F>,
+    ) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        match self {
+            Self::Dummy => {
+                let (car, cdr) = Cons::get_symbol_car_cdr(s, sym)?;
+
+                *self = Self::Value(Cons {
+                    car,
+                    cdr,
+                    cons: *sym,
+                });
+
+                Ok((car, cdr))
+            }
+            Self::Blank => unreachable!("Blank ConsStub should be used only in blank circuits."),
+            Self::Value(h) => Ok(h.car_cdr(sym)),
+        }
+    }
+
+    pub fn vec_len_content(
+        &mut self,
+        s: &Store<F>,
+        vec: &Ptr<F>,
+    ) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        match self {
+            Self::Dummy => {
+                let (car, cdr) = Cons::get_vec_len_content(s, vec)?;
+
+                *self = Self::Value(Cons {
+                    car,
+                    cdr,
+                    cons: *vec,
+                });
+
+                Ok((car, cdr))
+            }
+            Self::Blank => unreachable!("Blank ConsStub should be used only in blank circuits."),
+            Self::Value(h) => Ok(h.car_cdr(vec)),
+        }
+    }
+
     pub fn cons(&mut self, store: &Store<F>, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
         match self {
             Self::Dummy => {
@@ -322,6 +400,32 @@ impl<F: LurkField> ConsStub<F> {
             Self::Value(_) => Cons::strcons(store, car, cdr),
         }
     }
+    pub fn symcons(&mut self, store: &Store<F>, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
+        match self {
+            Self::Dummy => {
+                let cons = Cons::symcons(store, car, cdr);
+
+                *self = Self::Value(Cons { car, cdr, cons });
+
+                cons
+            }
+            Self::Blank => unreachable!("Blank ConsStub should be used only in blank circuits."),
+            Self::Value(_) => Cons::symcons(store, car, cdr),
+        }
+    }
+    pub fn vec_node(&mut self, store: &Store<F>, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
+        match self {
+            Self::Dummy => {
+                let cons = Cons::vec_node(store, car, cdr);
+
+                *self = Self::Value(Cons { car, cdr, cons });
+
+                cons
+            }
+            Self::Blank => unreachable!("Blank ConsStub should be used only in blank circuits."),
+            Self::Value(_) => Cons::vec_node(store, car, cdr),
+        }
+    }
 }
 
 impl<F: LurkField> ContStub<F> {}
@@ -547,6 +651,26 @@ impl<F: LurkField> ConsWitness<F> {
         self.get_assigned_slot(name).strcons(store, car, cdr)
     }
 
+    pub fn symcons_named(
+        &mut self,
+        name: ConsName,
+        store: &Store<F>,
+        car: Ptr<F>,
+        cdr: Ptr<F>,
+    ) -> Ptr<F> {
+        self.get_assigned_slot(name).symcons(store, car, cdr)
+    }
+
+    pub fn vec_node_named(
+        &mut self,
+        name: ConsName,
+        store: &Store<F>,
+        len: Ptr<F>,
+        content: Ptr<F>,
+    ) -> Ptr<F> {
+        self.get_assigned_slot(name).vec_node(store, len, content)
+    }
+
     pub fn car_cdr_mut_named(
         &mut self,
         name: ConsName,
@@ -556,6 +680,24 @@ impl<F: LurkField> ConsWitness<F> {
         self.get_assigned_slot(name).car_cdr(store, cons)
     }
 
+    pub fn symbol_car_cdr_named(
+        &mut self,
+        name: ConsName,
+        store: &Store<F>,
+        sym: &Ptr<F>,
+    ) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        self.get_assigned_slot(name).symbol_car_cdr(store, sym)
+    }
+
+    pub fn vec_len_content_named(
+        &mut self,
+        name: ConsName,
+        store: &Store<F>,
+        vec: &Ptr<F>,
+    ) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        self.get_assigned_slot(name).vec_len_content(store, vec)
+    }
+
     pub fn extend_named(
         &mut self,
         name: ConsName,
@@ -579,6 +721,14 @@ impl<F: LurkField> Cons<F> {
         store.strcons(car, cdr)
     }
 
+    fn symcons(store: &Store<F>, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
+        store.intern_symcons(car, cdr)
+    }
+
+    fn vec_node(store: &Store<F>, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
+        store.intern_vec_node(car, cdr)
+    }
+
     fn car_cdr(&self, cons: &Ptr<F>) -> (Ptr<F>, Ptr<F>) {
         assert_eq!(cons, &self.cons, "wrong cons found when destructuring");
 
@@ -588,6 +738,27 @@ impl<F: LurkField> Cons<F> {
     fn get_car_cdr(s: &Store<F>, cons: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
         s.car_cdr(cons)
     }
+
+    fn get_symbol_car_cdr(s: &Store<F>, sym: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        match sym.tag {
+            ExprTag::Sym => s
+                .fetch_symcons(sym)
+                .ok_or_else(|| store::Error("Can only extract car_cdr from known Sym".into())),
+            _ => Err(store::Error(
+                "Can only extract symbol car_cdr from Sym".into(),
+            )),
+        }
+    }
+
+    fn get_vec_len_content(s: &Store<F>, vec: &Ptr<F>) -> Result<(Ptr<F>, Ptr<F>), store::Error> {
+        match vec.tag {
+            ExprTag::Vec => s
+                .fetch_vec_node(vec)
+                .copied()
+                .ok_or_else(|| store::Error("Can only extract len_content from known Vec".into())),
+            _ => Err(store::Error("Can only extract len_content from Vec".into())),
+        }
+    }
 }
 
 impl<F: LurkField> ContWitness<F> {
@@ -36,7 +36,8 @@ use crate::{
         constraints::{
             add, alloc_equal, alloc_is_zero, allocate_is_negative, and, div, enforce_pack,
             enforce_product_and_sum, enforce_selector_with_premise, implies_equal,
-            implies_equal_const, implies_u64, implies_unequal_const, mul, or, pick, sub,
+            implies_equal_const, implies_u64, implies_u64_shift, implies_unequal_const, mul, or,
+            pick, sub, u64_and,
         },
         data::{allocate_constant, hash_poseidon},
         pointer::AllocatedPtr,
@@ -337,7 +338,10 @@ impl Block {
                 | Op::Mul(..)
                 | Op::Lt(..)
                 | Op::Trunc(..)
-                | Op::DivRem64(..) => {
+                | Op::DivRem64(..)
+                | Op::BitAnd64(..)
+                | Op::Shl64(..)
+                | Op::Shr64(..) => {
                     g.new_const(cs, Tag::Expr(Num).to_field());
                 }
                 Op::Div(..) => {
@@ -893,6 +897,37 @@ impl Func {
                         bound_allocations.insert_ptr(tgt[0].clone(), div_ptr);
                         bound_allocations.insert_ptr(tgt[1].clone(), rem_ptr);
                     }
+                    Op::BitAnd64(tgt, a, b) => {
+                        let a = bound_allocations.get_ptr(a)?.hash();
+                        let b = bound_allocations.get_ptr(b)?.hash();
+                        let a_bits = implies_u64(cs.namespace(|| "a_u64"), not_dummy, a)?;
+                        let b_bits = implies_u64(cs.namespace(|| "b_u64"), not_dummy, b)?;
+                        let and = u64_and(cs.namespace(|| "and"), &a_bits, &b_bits)?;
+                        let tag = g
+                            .global_allocator
+                            .get_allocated_const_cloned(Tag::Expr(Num).to_field())?;
+                        let c = AllocatedPtr::from_parts(tag, and);
+                        bound_allocations.insert_ptr(tgt.clone(), c);
+                    }
+                    Op::Shl64(tgt, a, b) | Op::Shr64(tgt, a, b) => {
+                        let right = Boolean::Constant(matches!(op, Op::Shr64(..)));
+                        let a = bound_allocations.get_ptr(a)?.hash();
+                        let b = bound_allocations.get_ptr(b)?.hash();
+                        implies_u64(cs.namespace(|| "a_u64"), not_dummy, a)?;
+                        let b_bits = implies_u64(cs.namespace(|| "b_u64"), not_dummy, b)?;
+                        let shifted = implies_u64_shift(
+                            cs.namespace(|| "shift"),
+                            not_dummy,
+                            &right,
+                            a,
+                            &b_bits,
+                        )?;
+                        let tag = g
+                            .global_allocator
+                            .get_allocated_const_cloned(Tag::Expr(Num).to_field())?;
+                        let c = AllocatedPtr::from_parts(tag, shifted);
+                        bound_allocations.insert_ptr(tgt.clone(), c);
+                    }
                     Op::Emit(_) => (),
                     Op::Hide(tgt, sec, pay) => {
                         let sec = bound_allocations.get_ptr(sec)?;
@@ -1261,6 +1296,16 @@ impl Func {
                         // three implies_u64, one sub and one linear
                         num_constraints += 197;
                     }
+                    Op::BitAnd64(..) => {
+                        globals.insert(FWrap(Tag::Expr(Num).to_field()));
+                        // two implies_u64 and 64 ands + enforce_pack
+                        num_constraints += 195;
+                    }
+                    Op::Shl64(..) | Op::Shr64(..) => {
+                        globals.insert(FWrap(Tag::Expr(Num).to_field()));
+                        // two implies_u64 and the shift gadget
+                        num_constraints += 277;
+                    }
                     Op::Not(..) | Op::Emit(_) | Op::Cproc(..) => (),
                     Op::Cons2(_, tag, _) => {
                         // tag for the image
@@ -238,12 +238,18 @@ pub fn make_eval_step_from_lang<F: LurkField, C: Coprocessor<F>>(
 pub fn make_eval_step(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
     let reduce = reduce(cprocs);
     let apply_cont = apply_cont(cprocs, ivc);
+    let raise = raise();
     let make_thunk = make_thunk();
+    let unfuel = unfuel();
+    let refuel = refuel();
 
     func!(step(expr, env, cont): 3 => {
-        let (expr, env, cont, ctrl) = reduce(expr, env, cont);
-        let (expr, env, cont, ctrl) = apply_cont(expr, env, cont, ctrl);
-        let (expr, env, cont, _ctrl) = make_thunk(expr, env, cont, ctrl);
+        let (step_cont, sandboxed, fuel, saved_env, continuation) = unfuel(cont);
+        let (result, result_env, applied, reduce_ctrl) = reduce(expr, env, step_cont);
+        let (result, result_env, result_cont, ctrl) = apply_cont(result, result_env, applied, reduce_ctrl, sandboxed);
+        let (result, result_env, result_cont, ctrl) = raise(expr, step_cont, applied, reduce_ctrl, result, result_env, result_cont, ctrl);
+        let (result, result_env, result_cont, _ctrl) = make_thunk(result, result_env, result_cont, ctrl);
+        let (expr, env, cont) = refuel(result, result_env, result_cont, sandboxed, fuel, saved_env, continuation);
         return (expr, env, cont)
     })
 }
@@ -550,20 +556,6 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
         let env: Expr::Cons = cons2(smaller_rec_env, smaller_env);
         return (env)
     });
-    let extract_arg = func!(extract_arg(args): 2 => {
-        match args.tag {
-            Expr::Nil => {
-                let dummy = Symbol("dummy");
-                let nil = Symbol("nil");
-                let nil = cast(nil, Expr::Nil);
-                return (dummy, nil)
-            }
-            Expr::Cons => {
-                let (arg, rest) = decons2(args);
-                return (arg, rest)
-            }
-        }
-    });
     let expand_bindings = func!(expand_bindings(head, body, body1, rest_bindings): 1 => {
         match rest_bindings.tag {
             Expr::Nil => {
@@ -610,6 +602,10 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                 let op: Op1::U64;
                 return (op);
             }
+            "i64" => {
+                let op: Op1::I64;
+                return (op);
+            }
             "comm" => {
                 let op: Op1::Comm;
                 return (op);
@@ -634,6 +630,46 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                 let op: Op1::Emit;
                 return (op);
             }
+            "lognot" => {
+                let op: Op1::LogNot;
+                return (op);
+            }
+            "char->u64" => {
+                let op: Op1::CharToU64;
+                return (op);
+            }
+            "u64->char" => {
+                let op: Op1::U64ToChar;
+                return (op);
+            }
+            "symbol->string" => {
+                let op: Op1::SymbolToString;
+                return (op);
+            }
+            "string->symbol" => {
+                let op: Op1::StringToSymbol;
+                return (op);
+            }
+            "string-length" => {
+                let op: Op1::StringLength;
+                return (op);
+            }
+            "string->list" => {
+                let op: Op1::StringToList;
+                return (op);
+            }
+            "list->string" => {
+                let op: Op1::ListToString;
+                return (op);
+            }
+            "throw" => {
+                let op: Op1::Throw;
+                return (op);
+            }
+            "vector-length" => {
+                let op: Op1::VectorLength;
+                return (op);
+            }
         };
         return (nil)
     });
@@ -697,6 +733,42 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                 let op: Op2::GreaterEqual;
                 return (op);
             }
+            "logand" => {
+                let op: Op2::LogAnd;
+                return (op);
+            }
+            "logior" => {
+                let op: Op2::LogIor;
+                return (op);
+            }
+            "logxor" => {
+                let op: Op2::LogXor;
+                return (op);
+            }
+            "shift-left" => {
+                let op: Op2::ShiftLeft;
+                return (op);
+            }
+            "shift-right" => {
+                let op: Op2::ShiftRight;
+                return (op);
+            }
+            "string-append" => {
+                let op: Op2::StringAppend;
+                return (op);
+            }
+            "catch" => {
+                let op: Op2::Catch;
+                return (op);
+            }
+            "make-vector" => {
+                let op: Op2::MakeVector;
+                return (op);
+            }
+            "vector-ref" => {
+                let op: Op2::VectorRef;
+                return (op);
+            }
         };
         return (nil)
     });
@@ -709,19 +781,9 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                 return (head, env, cont, ret)
             }
             Expr::Cons => {
-                let (arg, more_args) = decons2(rest);
-                match more_args.tag {
-                    Expr::Nil => {
-                        let cont: Cont::Call = cons4(arg, env, cont, foo);
-                        return (head, env, cont, ret)
-                    }
-                };
-                let nil = Symbol("nil");
-                let nil = cast(nil, Expr::Nil);
-                let expanded_inner0: Expr::Cons = cons2(arg, nil);
-                let expanded_inner: Expr::Cons = cons2(head, expanded_inner0);
-                let expanded: Expr::Cons = cons2(expanded_inner, more_args);
-                return (expanded, env, cont, ret)
+                // The arguments are evaluated one at a time, after the function
+                let cont: Cont::Call = cons4(rest, env, cont, foo);
+                return (head, env, cont, ret)
             }
         }
     });
@@ -760,7 +822,7 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
             Expr::Cproc => {
                 return (expr, env, cont, ret)
             }
-            Expr::Nil | Expr::Fun | Expr::Num | Expr::Str | Expr::Char | Expr::Comm | Expr::U64 | Expr::Key => {
+            Expr::Nil | Expr::Fun | Expr::Num | Expr::Str | Expr::Char | Expr::Comm | Expr::U64 | Expr::I64 | Expr::Vec | Expr::Key => {
                 return (expr, env, cont, apply)
             }
             Expr::Thunk => {
@@ -840,7 +902,7 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                 match rest.tag {
                     // rest's tag can only be Nil or Cons
                     Expr::Sym | Expr::Fun | Expr::Num | Expr::Thunk | Expr::Str
-                    | Expr::Char | Expr::Comm | Expr::U64 | Expr::Key => {
+                    | Expr::Char | Expr::Comm | Expr::U64 | Expr::I64 | Expr::Vec | Expr::Key => {
                         return (expr, env, err, errctrl);
                     }
                 };
@@ -848,21 +910,12 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                     Expr::Sym => {
                         match symbol head {
                             "lambda" => {
-                                let (args, body) = car_cdr(rest);
-                                let (arg, cdr_args) = extract_arg(args);
-
-                                match arg.tag {
-                                    Expr::Sym => {
-                                        match cdr_args.tag {
-                                            Expr::Nil => {
-                                                let function: Expr::Fun = cons3(arg, body, env);
-                                                return (function, env, cont, apply)
-                                            }
-                                        };
-                                        let inner: Expr::Cons = cons2(cdr_args, body);
-                                        let l: Expr::Cons = cons2(head, inner);
-                                        let inner_body: Expr::Cons = cons2(l, nil);
-                                        let function: Expr::Fun = cons3(arg, inner_body, env);
+                                let (params, body) = car_cdr(rest);
+                                // The parameters are kept as they are, and they are bound one by
+                                // one as the arguments of a call are evaluated
+                                match params.tag {
+                                    Expr::Nil | Expr::Cons => {
+                                        let function: Expr::Fun = cons3(params, body, env);
                                         return (function, env, cont, apply)
                                     }
                                 };
@@ -925,6 +978,74 @@ fn reduce(cprocs: &[(&Symbol, usize)]) -> Func {
                                 let cont: Cont::Binop = cons4(op, env, more, cont);
                                 return (arg1, env, cont, ret)
                             }
+                            "list" => {
+                                match rest.tag {
+                                    Expr::Nil => {
+                                        return (nil, env, cont, ret)
+                                    }
+                                };
+                                let (arg1, more) = decons2(rest);
+                                let op: Op2::List;
+                                let cont: Cont::Binop = cons4(op, env, more, cont);
+                                return (arg1, env, cont, ret)
+                            }
+                            "substring" => {
+                                let (arg1, more) = car_cdr(rest);
+                                match more.tag {
+                                    Expr::Cons => {
+                                        let (_arg2, more_args) = decons2(more);
+                                        match more_args.tag {
+                                            Expr::Nil => {
+                                                return (expr, env, err, errctrl)
+                                            }
+                                        };
+                                        // The bounds are evaluated as a list, like the arguments
+                                        // of `list`
+                                        let op: Op2::Substring;
+                                        let cont: Cont::Binop = cons4(op, env, more, cont);
+                                        return (arg1, env, cont, ret)
+                                    }
+                                };
+                                return (expr, env, err, errctrl)
+                            }
+                            "vector-set" => {
+                                let (arg1, more) = car_cdr(rest);
+                                match more.tag {
+                                    Expr::Cons => {
+                                        let (_arg2, more_args) = decons2(more);
+                                        match more_args.tag {
+                                            Expr::Nil => {
+                                                return (expr, env, err, errctrl)
+                                            }
+                                        };
+                                        // The index and the element are evaluated as a list, like
+                                        // the arguments of `list`
+                                        let op: Op2::VectorSet;
+                                        let cont: Cont::Binop = cons4(op, env, more, cont);
+                                        return (arg1, env, cont, ret)
+                                    }
+                                };
+                                return (expr, env, err, errctrl)
+                            }
+                            "eval-with-limit" => {
+                                let (arg1, more) = car_cdr(rest);
+                                match more.tag {
+                                    Expr::Cons => {
+                                        let (_arg2, more_args) = decons2(more);
+                                        match more_args.tag {
+                                            Expr::Nil => {
+                                                return (expr, env, err, errctrl)
+                                            }
+                                        };
+                                        // The environment and the fuel are evaluated as a list,
+                                        // like the arguments of `list`
+                                        let op: Op2::EvalWithLimit;
+                                        let cont: Cont::Binop = cons4(op, env, more, cont);
+                                        return (arg1, env, cont, ret)
+                                    }
+                                };
+                                return (expr, env, err, errctrl)
+                            }
                             "eval" => {
                                 match rest.tag {
                                     Expr::Nil => {
@@ -1086,8 +1207,8 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
             }
         }
     });
-    // Returns 0u64 if both arguments are U64, 0 (num) if the arguments are some kind of number (either U64 or Num),
-    // and nil otherwise
+    // Returns 0u64 if both arguments are U64, 0i64 if both arguments are I64, 0 (num) if the arguments are some kind
+    // of number (either U64 or Num), and nil otherwise
     let args_num_type = func!(args_num_type(arg1, arg2): 1 => {
         let nil = Symbol("nil");
         let nil = cast(nil, Expr::Nil);
@@ -1118,11 +1239,288 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                 };
                 return (nil)
             }
+            Expr::I64 => {
+                match arg2.tag {
+                    Expr::I64 => {
+                        let ret: Expr::I64;
+                        return (ret)
+                    }
+                };
+                return (nil)
+            }
+        };
+        return (nil)
+    });
+    // Returns the signs of both arguments, as 1 for negative and 0 otherwise, and their signed
+    // values, with negative ones brought back 2**64 below so that they can be compared, if they
+    // are I64s (as told by the result of `args_num_type`). Otherwise returns zero signs and the
+    // arguments themselves
+    let i64_signed = func!(i64_signed(args_num_type, arg1, arg2): 4 => {
+        let zero = Num(0);
+        match args_num_type.tag {
+            Expr::I64 => {
+                // The sign of an i64 is its most significant bit
+                let half_u64 = Num(9223372036854775808);
+                let size_u64 = Num(18446744073709551616);
+                let (sign1, _rem1) = div_rem64(arg1, half_u64);
+                let (sign2, _rem2) = div_rem64(arg2, half_u64);
+                let shift1 = mul(sign1, size_u64);
+                let signed1 = sub(arg1, shift1);
+                let shift2 = mul(sign2, size_u64);
+                let signed2 = sub(arg2, shift2);
+                return (sign1, sign2, signed1, signed2)
+            }
+        };
+        return (zero, zero, arg1, arg2)
+    });
+    // Returns the operands of the divisions and of the bitwise operators, which are computed once
+    // for all the operators: the absolute values of the arguments (the arguments themselves for
+    // u64s) if they are u64s or i64s and the second isn't zero, and 0 and 1 otherwise, so that
+    // they can always be divided
+    let u64_operands = func!(u64_operands(args_num_type, sign1, sign2, signed1, signed2): 2 => {
+        let zero = Num(0);
+        let one = Num(1);
+        match args_num_type.tag {
+            Expr::Nil | Expr::Num => {
+                return (zero, one)
+            }
+        };
+        let is_z = eq_val(signed2, zero);
+        if is_z {
+            return (zero, one)
+        }
+        let neg1 = mul(sign1, signed1);
+        let abs1 = sub(signed1, neg1);
+        let abs1 = sub(abs1, neg1);
+        let neg2 = mul(sign2, signed2);
+        let abs2 = sub(signed2, neg2);
+        let abs2 = sub(abs2, neg2);
+        return (abs1, abs2)
+    });
+    // Returns the first parameter of a function, the ones after it, and the first of those, which
+    // is nil if there are none
+    let fun_params = func!(fun_params(params): 3 => {
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        let (param, more_params) = decons2(params);
+        match more_params.tag {
+            Expr::Cons => {
+                let (next_param, _rest) = decons2(more_params);
+                return (param, more_params, next_param)
+            }
+        };
+        return (param, more_params, nil)
+    });
+    // Returns the variable bound to the next argument of a function and the parameters left after
+    // it, which are none for a `&rest` parameter, bound to the list of all the arguments left
+    let param_var = func!(param_var(param, more_params, next_param): 2 => {
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        let rest = Symbol("&rest");
+        let param_is_rest = eq_val(param, rest);
+        if param_is_rest {
+            return (next_param, nil)
+        }
+        return (param, more_params)
+    });
+    // Returns the form of the body of a function and t, or nil and nil if the body isn't a single
+    // form
+    let fun_body_form = func!(fun_body_form(body): 2 => {
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        match body.tag {
+            Expr::Cons => {
+                let (body_form, end) = decons2(body);
+                match end.tag {
+                    Expr::Nil => {
+                        let t = Symbol("t");
+                        return (body_form, t)
+                    }
+                };
+                return (nil, nil)
+            }
+        };
+        return (nil, nil)
+    });
+    // Returns the next argument of a call to `function` and the `Call2` continuation evaluating
+    // it, or all the arguments left as `(list ...)` if `param`, the parameter they are for, is
+    // `&rest`
+    let call_next_arg = func!(call_next_arg(function, param, unevaled_args, saved_env, continuation): 2 => {
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        let rest = Symbol("&rest");
+        let param_is_rest = eq_val(param, rest);
+        if param_is_rest {
+            let list = Symbol("list");
+            let list_args: Expr::Cons = cons2(list, unevaled_args);
+            let newer_cont: Cont::Call2 = cons4(function, saved_env, continuation, nil);
+            return (list_args, newer_cont)
+        }
+        let (arg, more_args) = decons2(unevaled_args);
+        let newer_cont: Cont::Call2 = cons4(function, saved_env, continuation, more_args);
+        return (arg, newer_cont)
+    });
+    // Returns where the element at index `i` of the vector `node` is: `here` and the element if
+    // it's the first of the content of `node`, `child` and the child holding it and its index in
+    // that child, or `error` if `node` isn't a vector or `i` isn't one of its indices
+    let vector_step = func!(vector_step(node, i): 3 => {
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        let errctrl = Symbol("error");
+        match node.tag {
+            Expr::Vec => {
+                match i.tag {
+                    Expr::U64 => {
+                        let (len, content) = decons2(node);
+                        let in_range = lt(i, len);
+                        if in_range {
+                            // See `Store::intern_vec_node()` for the layout of the content
+                            let (first, rest) = decons2(content);
+                            let one = Num(1);
+                            let two = Num(2);
+                            let child = Symbol("child");
+                            let (_len_half, len_parity) = div_rem64(len, two);
+                            let len_odd = eq_val(len_parity, one);
+                            if len_odd {
+                                let zero = Num(0);
+                                let is_first = eq_val(i, zero);
+                                if is_first {
+                                    let here = Symbol("here");
+                                    return (here, first, nil)
+                                }
+                                let index = sub(i, one);
+                                let index = cast(index, Expr::U64);
+                                return (child, rest, index)
+                            }
+                            let (half, parity) = div_rem64(i, two);
+                            let index = cast(half, Expr::U64);
+                            let odd = eq_val(parity, one);
+                            if odd {
+                                return (child, rest, index)
+                            }
+                            return (child, first, index)
+                        }
+                        return (errctrl, nil, nil)
+                    }
+                };
+                return (errctrl, nil, nil)
+            }
+        };
+        return (errctrl, nil, nil)
+    });
+    // Takes a step towards the element at index `i` of the vector `node`: returns it if it's at
+    // hand, or else its index in the child holding it and a `Vector` continuation reading that
+    // child. The continuation is an error if there's no such element
+    let vector_ref_step = func!(vector_ref_step(node, i, continuation): 2 => {
+        let (status, found, index) = vector_step(node, i);
+        match symbol status {
+            "here" => {
+                return (found, continuation)
+            }
+            "child" => {
+                let op: Op2::VectorRef;
+                let nil = Symbol("nil");
+                let nil = cast(nil, Expr::Nil);
+                let newer_cont: Cont::Vector = cons4(op, found, nil, continuation);
+                return (index, newer_cont)
+            }
+        };
+        let err: Cont::Error;
+        return (node, err)
+    });
+    // Takes a step towards making the vector of length `len` whose elements are all `elt`: returns
+    // the empty vector if `len` is zero, or else the length of the vectors its content is made of
+    // and a `Vector` continuation making it
+    let make_vector_step = func!(make_vector_step(len, elt, continuation): 2 => {
+        let zero = Num(0);
+        let is_empty = eq_val(len, zero);
+        if is_empty {
+            let nil = Symbol("nil");
+            let nil = cast(nil, Expr::Nil);
+            let empty: Expr::Vec = cons2(len, nil);
+            return (empty, continuation)
+        }
+        let one = Num(1);
+        let two = Num(2);
+        let op: Op2::MakeVector;
+        let newer_cont: Cont::Vector = cons4(op, len, elt, continuation);
+        let (half, parity) = div_rem64(len, two);
+        let odd = eq_val(parity, one);
+        if odd {
+            let half = sub(len, one);
+            let half = cast(half, Expr::U64);
+            return (half, newer_cont)
+        }
+        let half = cast(half, Expr::U64);
+        return (half, newer_cont)
+    });
+    // Takes a step towards setting the element at index `i` of the vector `node` to `elt`: returns
+    // the updated node if the element is at hand, or else the child holding it, its index in it and
+    // `elt`, and a `Vector` continuation updating `node` with the updated child. The continuation
+    // is an error if there's no such element
+    let vector_set_step = func!(vector_set_step(node, i, elt, continuation): 2 => {
+        let (status, found, index) = vector_step(node, i);
+        match symbol status {
+            "here" => {
+                let (len, content) = decons2(node);
+                let (_first, rest) = decons2(content);
+                let content: Expr::Cons = cons2(elt, rest);
+                let updated: Expr::Vec = cons2(len, content);
+                return (updated, continuation)
+            }
+            "child" => {
+                let args: Expr::Cons = cons2(index, elt);
+                let args: Expr::Cons = cons2(found, args);
+                let op: Op2::VectorSet;
+                let newer_cont: Cont::Vector = cons4(op, node, i, continuation);
+                return (args, newer_cont)
+            }
+        };
+        let err: Cont::Error;
+        return (node, err)
+    });
+    // Whether `s` is a string and the bounds of `substring` are a num or u64 each, with no more
+    // arguments
+    let substring_args = func!(substring_args(s, start, end, rest): 1 => {
+        let t = Symbol("t");
+        let nil = Symbol("nil");
+        let nil = cast(nil, Expr::Nil);
+        match rest.tag {
+            Expr::Nil => {
+                match s.tag {
+                    Expr::Str => {
+                        match start.tag {
+                            Expr::Num | Expr::U64 => {
+                                match end.tag {
+                                    Expr::Num | Expr::U64 => {
+                                        return (t)
+                                    }
+                                };
+                                return (nil)
+                            }
+                        };
+                        return (nil)
+                    }
+                };
+                return (nil)
+            }
         };
         return (nil)
     });
+    // Decrements a non-zero bound of `substring`, keeping its tag
+    let decrement_bound = func!(decrement_bound(bound): 1 => {
+        let one = Num(1);
+        let val = sub(bound, one);
+        match bound.tag {
+            Expr::U64 => {
+                let val = cast(val, Expr::U64);
+                return (val)
+            }
+        };
+        return (val)
+    });
     let choose_cproc_call = choose_cproc_call(cprocs, ivc);
-    func!(apply_cont(result, env, cont, ctrl): 4 => {
+    func!(apply_cont(result, env, cont, ctrl, sandboxed): 4 => {
         // Useful constants
         let ret = Symbol("return");
         let makethunk = Symbol("make-thunk");
@@ -1133,11 +1531,60 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
         let t = Symbol("t");
         let zero = Num(0);
         let size_u64 = Num(18446744073709551616);
+        let max_u64 = Num(18446744073709551615);
+        let half_u64 = Num(9223372036854775808);
         let empty_str = String("");
         let foo: Expr::Nil;
 
         match symbol ctrl {
             "apply-continuation" => {
+                // While a value is being thrown, which the env being a thunk tells, every
+                // continuation but the `Binop` of a `catch` is skipped
+                match env.tag {
+                    Expr::Thunk => {
+                        match cont.tag {
+                            Cont::Terminal | Cont::Error => {
+                                return (result, env, cont, ret)
+                            }
+                            Cont::Outermost => {
+                                return (result, env, err, ret)
+                            }
+                            Cont::Emit => {
+                                let (continuation, _foo, _foo, _foo) = decons4(cont);
+                                return (result, env, continuation, makethunk)
+                            }
+                            Cont::Call0 | Cont::Unop | Cont::If | Cont::Lookup | Cont::Tail => {
+                                let (_foo, continuation, _foo, _foo) = decons4(cont);
+                                return (result, env, continuation, makethunk)
+                            }
+                            Cont::Call | Cont::Call2 | Cont::Binop2 => {
+                                let (_foo, _foo, continuation, _foo) = decons4(cont);
+                                return (result, env, continuation, makethunk)
+                            }
+                            Cont::Let | Cont::LetRec | Cont::Cproc | Cont::Vector => {
+                                let (_foo, _foo, _foo, continuation) = decons4(cont);
+                                return (result, env, continuation, makethunk)
+                            }
+                            Cont::Binop => {
+                                let (operator, saved_env, unevaled_args, continuation) = decons4(cont);
+                                match operator.tag {
+                                    Op2::Catch => {
+                                        // The handler is evaluated, to be called on the thrown value
+                                        let (handler, rest) = car_cdr(unevaled_args);
+                                        match rest.tag {
+                                            Expr::Nil => {
+                                                let cont: Cont::Binop2 = cons4(operator, result, continuation, foo);
+                                                return (handler, saved_env, cont, ret)
+                                            }
+                                        };
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (result, env, continuation, makethunk)
+                            }
+                        }
+                    }
+                };
                 match cont.tag {
                     Cont::Terminal | Cont::Error => {
                         return (result, env, cont, ret)
@@ -1154,24 +1601,39 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                         let (saved_env, continuation, _foo, _foo) = decons4(cont);
                         match result.tag {
                             Expr::Fun => {
-                                let (arg, body, closed_env) = decons3(result);
-                                match symbol arg {
-                                    "dummy" => {
-                                        match body.tag {
-                                            Expr::Nil => {
-                                                return (result, env, err, errctrl)
-                                            }
-                                        };
-                                        let (body_form, end) = car_cdr(body);
-                                        match end.tag {
-                                            Expr::Nil => {
-                                                let (cont) = make_tail_continuation(saved_env, continuation);
-                                                return (body_form, closed_env, cont, ret)
-                                            }
-                                        };
+                                let (params, body, closed_env) = decons3(result);
+                                // A function of no parameters, or of a `&rest` one only, which is
+                                // bound to nil, is entered
+                                match params.tag {
+                                    Expr::Nil => {
+                                        let (body_form, body_is_valid) = fun_body_form(body);
+                                        let body_is_valid = eq_val(body_is_valid, t);
+                                        if body_is_valid {
+                                            let (cont) = make_tail_continuation(saved_env, continuation);
+                                            return (body_form, closed_env, cont, ret)
+                                        }
                                         return (result, env, err, errctrl)
                                     }
                                 };
+                                let (param, _more_params, next_param) = fun_params(params);
+                                let rest = Symbol("&rest");
+                                let param_is_rest = eq_val(param, rest);
+                                if param_is_rest {
+                                    match next_param.tag {
+                                        Expr::Sym => {
+                                            let (body_form, body_is_valid) = fun_body_form(body);
+                                            let body_is_valid = eq_val(body_is_valid, t);
+                                            if body_is_valid {
+                                                let binding: Expr::Cons = cons2(next_param, nil);
+                                                let newer_env: Expr::Cons = cons2(binding, closed_env);
+                                                let (cont) = make_tail_continuation(saved_env, continuation);
+                                                return (body_form, newer_env, cont, ret)
+                                            }
+                                            return (result, env, err, errctrl)
+                                        }
+                                    };
+                                    return (result, env, err, errctrl)
+                                }
                                 return (result, env, continuation, ret)
                             }
                         };
@@ -1180,37 +1642,78 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                     Cont::Call => {
                         match result.tag {
                             Expr::Fun => {
-                                let (unevaled_arg, saved_env, continuation, _foo) = decons4(cont);
-                                let newer_cont: Cont::Call2 = cons4(result, saved_env, continuation, foo);
-                                return (unevaled_arg, env, newer_cont, ret)
+                                let (unevaled_args, saved_env, continuation, _foo) = decons4(cont);
+                                let (params, _body, _closed_env) = decons3(result);
+                                match params.tag {
+                                    Expr::Cons => {
+                                        let (param, _more_params) = decons2(params);
+                                        match unevaled_args.tag {
+                                            Expr::Cons => {
+                                                let (arg, newer_cont) = call_next_arg(result, param, unevaled_args, saved_env, continuation);
+                                                return (arg, saved_env, newer_cont, ret)
+                                            }
+                                        };
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
                             }
                         };
                         return (result, env, err, errctrl)
                     }
                     Cont::Call2 => {
-                        let (function, saved_env, continuation, _foo) = decons4(cont);
+                        let (function, saved_env, continuation, unevaled_args) = decons4(cont);
                         match function.tag {
                             Expr::Fun => {
-                                let (arg, body, closed_env) = decons3(function);
-                                match symbol arg {
-                                    "dummy" => {
-                                        return (result, env, err, errctrl)
-                                    }
-                                };
-                                match body.tag {
-                                    Expr::Nil => {
+                                let (params, body, closed_env) = decons3(function);
+                                match params.tag {
+                                    Expr::Cons => {
+                                        let (param, more_params, next_param) = fun_params(params);
+                                        let (var, remaining_params) = param_var(param, more_params, next_param);
+                                        match var.tag {
+                                            Expr::Sym => {
+                                                let binding: Expr::Cons = cons2(var, result);
+                                                let newer_env: Expr::Cons = cons2(binding, closed_env);
+                                                match remaining_params.tag {
+                                                    Expr::Nil => {
+                                                        let (body_form, body_is_valid) = fun_body_form(body);
+                                                        let body_is_valid = eq_val(body_is_valid, t);
+                                                        if body_is_valid {
+                                                            match unevaled_args.tag {
+                                                                Expr::Nil => {
+                                                                    let (cont) = make_tail_continuation(saved_env, continuation);
+                                                                    return (body_form, newer_env, cont, ret)
+                                                                }
+                                                            };
+                                                            // The body evaluates to the function taking
+                                                            // the remaining arguments
+                                                            let cont: Cont::Call = cons4(unevaled_args, saved_env, continuation, foo);
+                                                            return (body_form, newer_env, cont, ret)
+                                                        }
+                                                        return (result, env, err, errctrl)
+                                                    }
+                                                    Expr::Cons => {
+                                                        // The parameters left are bound in the same way,
+                                                        // without entering the body in between
+                                                        let function: Expr::Fun = cons3(remaining_params, body, newer_env);
+                                                        match unevaled_args.tag {
+                                                            Expr::Nil => {
+                                                                return (function, env, continuation, makethunk)
+                                                            }
+                                                            Expr::Cons => {
+                                                                let (arg, newer_cont) = call_next_arg(function, next_param, unevaled_args, saved_env, continuation);
+                                                                return (arg, saved_env, newer_cont, ret)
+                                                            }
+                                                        };
+                                                        return (result, env, err, errctrl)
+                                                    }
+                                                };
+                                                return (result, env, err, errctrl)
+                                            }
+                                        };
                                         return (result, env, err, errctrl)
                                     }
                                 };
-                                let (body_form, end) = decons2(body);
-                                match end.tag {
-                                    Expr::Nil => {
-                                        let binding: Expr::Cons = cons2(arg, result);
-                                        let newer_env: Expr::Cons = cons2(binding, closed_env);
-                                        let (cont) = make_tail_continuation(saved_env, continuation);
-                                        return (body_form, newer_env, cont, ret)
-                                    }
-                                };
                                 return (result, env, err, errctrl)
                             }
                         };
@@ -1325,6 +1828,13 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                         let cast = cast(result, Expr::Num);
                                         return(cast, env, continuation, makethunk)
                                     }
+                                    Expr::I64 => {
+                                        // Negative i64s are 2**64 above their num
+                                        let (sign, _rem) = div_rem64(result, half_u64);
+                                        let shift = mul(sign, size_u64);
+                                        let val = sub(result, shift);
+                                        return(val, env, continuation, makethunk)
+                                    }
                                 };
                                 return(result, env, err, errctrl)
                             }
@@ -1339,6 +1849,37 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                     Expr::U64 => {
                                         return(result, env, continuation, makethunk)
                                     }
+                                    Expr::I64 => {
+                                        let cast = cast(result, Expr::U64);
+                                        return(cast, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::I64 => {
+                                match result.tag {
+                                    Expr::Num => {
+                                        // Shifting by 2**63 maps the range of i64s onto the range of u64s,
+                                        // so that nums are truncated around zero
+                                        let val = add(result, half_u64);
+                                        let val = truncate(val, 64);
+                                        let below_half = lt(val, half_u64);
+                                        if below_half {
+                                            let val = add(val, half_u64);
+                                            let val = cast(val, Expr::I64);
+                                            return(val, env, continuation, makethunk)
+                                        }
+                                        let val = sub(val, half_u64);
+                                        let val = cast(val, Expr::I64);
+                                        return(val, env, continuation, makethunk)
+                                    }
+                                    Expr::U64 => {
+                                        let cast = cast(result, Expr::I64);
+                                        return(cast, env, continuation, makethunk)
+                                    }
+                                    Expr::I64 => {
+                                        return(result, env, continuation, makethunk)
+                                    }
                                 };
                                 return(result, env, err, errctrl)
                             }
@@ -1368,6 +1909,142 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                             Op1::Eval => {
                                 return(result, nil, continuation, ret)
                             }
+                            Op1::LogNot => {
+                                match result.tag {
+                                    Expr::U64 => {
+                                        // The complement of a u64 is its difference to 2**64 - 1
+                                        let val = sub(max_u64, result);
+                                        let val = cast(val, Expr::U64);
+                                        return(val, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::CharToU64 => {
+                                match result.tag {
+                                    Expr::Char => {
+                                        let cast = cast(result, Expr::U64);
+                                        return(cast, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::U64ToChar => {
+                                match result.tag {
+                                    Expr::U64 => {
+                                        // Only u64s below 2**32 are chars
+                                        let size_u32 = Num(4294967296);
+                                        let is_char = lt(result, size_u32);
+                                        if is_char {
+                                            let cast = cast(result, Expr::Char);
+                                            return(cast, env, continuation, makethunk)
+                                        }
+                                        return(result, env, err, errctrl)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::SymbolToString => {
+                                match result.tag {
+                                    Expr::Sym => {
+                                        // The root symbol has no name
+                                        let root: Expr::Sym;
+                                        let is_root = eq_val(result, root);
+                                        if is_root {
+                                            return(result, env, err, errctrl)
+                                        }
+                                        let (name, _parent) = decons2(result);
+                                        return(name, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::StringToSymbol => {
+                                match result.tag {
+                                    Expr::Str => {
+                                        let user = Symbol("user");
+                                        let sym: Expr::Sym = cons2(result, user);
+                                        return(sym, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            // A call on a non-empty string or list becomes a call on its tail,
+                            // whose result its head is then added to by a `Binop2`
+                            Op1::StringLength => {
+                                match result.tag {
+                                    Expr::Str => {
+                                        let is_empty = eq_val(result, empty_str);
+                                        if is_empty {
+                                            let zero = cast(zero, Expr::U64);
+                                            return(zero, env, continuation, makethunk)
+                                        }
+                                        let (_car, cdr) = decons2(result);
+                                        let sym = Symbol("string-length");
+                                        let args: Expr::Cons = cons2(cdr, nil);
+                                        let call: Expr::Cons = cons2(sym, args);
+                                        let one = Num(1);
+                                        let one = cast(one, Expr::U64);
+                                        let op: Op2::Sum;
+                                        let newer_cont: Cont::Binop2 = cons4(op, one, continuation, foo);
+                                        return(call, env, newer_cont, ret)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::StringToList => {
+                                match result.tag {
+                                    Expr::Str => {
+                                        let is_empty = eq_val(result, empty_str);
+                                        if is_empty {
+                                            return(nil, env, continuation, makethunk)
+                                        }
+                                        let (car, cdr) = decons2(result);
+                                        let sym = Symbol("string->list");
+                                        let args: Expr::Cons = cons2(cdr, nil);
+                                        let call: Expr::Cons = cons2(sym, args);
+                                        let op: Op2::Cons;
+                                        let newer_cont: Cont::Binop2 = cons4(op, car, continuation, foo);
+                                        return(call, env, newer_cont, ret)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::ListToString => {
+                                match result.tag {
+                                    Expr::Nil => {
+                                        return(empty_str, env, continuation, makethunk)
+                                    }
+                                    Expr::Cons => {
+                                        // The tail is quoted, and `strcons` errors if the head
+                                        // isn't a char
+                                        let (car, cdr) = decons2(result);
+                                        let quote = Symbol("quote");
+                                        let quoted: Expr::Cons = cons2(cdr, nil);
+                                        let quoted: Expr::Cons = cons2(quote, quoted);
+                                        let sym = Symbol("list->string");
+                                        let args: Expr::Cons = cons2(quoted, nil);
+                                        let call: Expr::Cons = cons2(sym, args);
+                                        let op: Op2::StrCons;
+                                        let newer_cont: Cont::Binop2 = cons4(op, car, continuation, foo);
+                                        return(call, env, newer_cont, ret)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
+                            Op1::Throw => {
+                                let thrown: Expr::Thunk = cons2(nil, err);
+                                return (result, thrown, continuation, makethunk)
+                            }
+                            Op1::VectorLength => {
+                                match result.tag {
+                                    Expr::Vec => {
+                                        let (len, _content) = decons2(result);
+                                        return(len, env, continuation, makethunk)
+                                    }
+                                };
+                                return(result, env, err, errctrl)
+                            }
                         };
                         return (result, env, err, errctrl)
                     }
@@ -1384,6 +2061,31 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                 let begin_again: Expr::Cons = cons2(operator, unevaled_args);
                                 return (begin_again, saved_env, continuation, ctrl)
                             }
+                            Op2::Catch => {
+                                // Nothing was thrown, so the handler is left unevaluated
+                                match rest.tag {
+                                    Expr::Nil => {
+                                        return (result, saved_env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::List | Op2::Substring | Op2::VectorSet | Op2::EvalWithLimit => {
+                                match unevaled_args.tag {
+                                    Expr::Nil => {
+                                        let list: Expr::Cons = cons2(result, nil);
+                                        return (list, env, continuation, makethunk)
+                                    }
+                                };
+                                // The remaining arguments are evaluated as a list, which `result`
+                                // is consed onto in `Binop2`, or which holds the bounds of
+                                // `substring`, the index and the element of `vector-set`, or the
+                                // environment and the fuel of `eval-with-limit`
+                                let list = Symbol("list");
+                                let list_again: Expr::Cons = cons2(list, unevaled_args);
+                                let cont: Cont::Binop2 = cons4(operator, result, continuation, foo);
+                                return (list_again, saved_env, cont, ret)
+                            }
                         };
                         match rest.tag {
                             Expr::Nil => {
@@ -1396,14 +2098,147 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                     Cont::Binop2 => {
                         let (operator, evaled_arg, continuation, _foo) = decons4(cont);
                         let (args_num_type) = args_num_type(evaled_arg, result);
+                        let (sign1, sign2, signed1, signed2) =
+                            i64_signed(args_num_type, evaled_arg, result);
+                        // Shared by `Quotient`, `Modulo`, `LogAnd`, `LogIor` and `LogXor`
+                        let (operand1, operand2) =
+                            u64_operands(args_num_type, sign1, sign2, signed1, signed2);
+                        let (quotient, remainder) = div_rem64(operand1, operand2);
+                        let conjunction = and64(operand1, operand2);
                         match operator.tag {
                             Op2::Eval => {
                                 return (evaled_arg, result, continuation, ret)
                             }
-                            Op2::Cons => {
+                            Op2::Catch => {
+                                // The handler is called on the thrown value
+                                let cont: Cont::Call2 = cons4(result, env, continuation, nil);
+                                return (evaled_arg, env, cont, makethunk)
+                            }
+                            Op2::Cons | Op2::List => {
                                 let val: Expr::Cons = cons2(evaled_arg, result);
                                 return (val, env, continuation, makethunk)
                             }
+                            Op2::VectorRef => {
+                                let (val, newer_cont) = vector_ref_step(evaled_arg, result, continuation);
+                                match newer_cont.tag {
+                                    Cont::Error => {
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (val, env, newer_cont, makethunk)
+                            }
+                            Op2::MakeVector => {
+                                match evaled_arg.tag {
+                                    Expr::U64 => {
+                                        let (val, newer_cont) = make_vector_step(evaled_arg, result, continuation);
+                                        return (val, env, newer_cont, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::VectorSet => {
+                                let (i, more) = car_cdr(result);
+                                let (elt, end) = car_cdr(more);
+                                match end.tag {
+                                    Expr::Nil => {
+                                        let (val, newer_cont) = vector_set_step(evaled_arg, i, elt, continuation);
+                                        match newer_cont.tag {
+                                            Cont::Error => {
+                                                return (result, env, err, errctrl)
+                                            }
+                                        };
+                                        return (val, env, newer_cont, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::EvalWithLimit => {
+                                let (eval_env, more) = car_cdr(result);
+                                let (fuel, end) = car_cdr(more);
+                                match end.tag {
+                                    Expr::Nil => {
+                                        match fuel.tag {
+                                            Expr::U64 => {
+                                                // The evaluation starts anew under `Fuel`, which
+                                                // counts its steps
+                                                let inner: Cont::Outermost;
+                                                let newer_cont: Cont::Fuel = cons4(inner, fuel, env, continuation);
+                                                return (evaled_arg, eval_env, newer_cont, ret)
+                                            }
+                                        };
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            // Like the unary string built-ins, a call on a non-empty string
+                            // becomes a call on its tail, whose result its head is then consed
+                            // onto by a `Binop2` unless it's dropped by `substring`
+                            Op2::StringAppend => {
+                                match evaled_arg.tag {
+                                    Expr::Str => {
+                                        match result.tag {
+                                            Expr::Str => {
+                                                let is_empty = eq_val(evaled_arg, empty_str);
+                                                if is_empty {
+                                                    return (result, env, continuation, makethunk)
+                                                }
+                                                let (car, cdr) = decons2(evaled_arg);
+                                                let sym = Symbol("string-append");
+                                                let args: Expr::Cons = cons2(result, nil);
+                                                let args: Expr::Cons = cons2(cdr, args);
+                                                let call: Expr::Cons = cons2(sym, args);
+                                                let op: Op2::StrCons;
+                                                let newer_cont: Cont::Binop2 = cons4(op, car, continuation, foo);
+                                                return (call, env, newer_cont, ret)
+                                            }
+                                        };
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::Substring => {
+                                let (start, more) = car_cdr(result);
+                                let (end, rest) = car_cdr(more);
+                                let (valid) = substring_args(evaled_arg, start, end, rest);
+                                let valid = eq_val(valid, t);
+                                if !valid {
+                                    return (result, env, err, errctrl)
+                                }
+                                let start_is_zero = eq_val(start, zero);
+                                let end_is_zero = eq_val(end, zero);
+                                let both_zero = and(start_is_zero, end_is_zero);
+                                if both_zero {
+                                    return (empty_str, env, continuation, makethunk)
+                                }
+                                // Both bounds are decremented until the start is reached, and
+                                // then only the end is, so the end can't be reached first
+                                if end_is_zero {
+                                    return (result, env, err, errctrl)
+                                }
+                                let is_empty = eq_val(evaled_arg, empty_str);
+                                if is_empty {
+                                    return (result, env, err, errctrl)
+                                }
+                                let (car, cdr) = decons2(evaled_arg);
+                                let sym = Symbol("substring");
+                                let (end) = decrement_bound(end);
+                                let args: Expr::Cons = cons2(end, nil);
+                                if start_is_zero {
+                                    let args: Expr::Cons = cons2(start, args);
+                                    let args: Expr::Cons = cons2(cdr, args);
+                                    let call: Expr::Cons = cons2(sym, args);
+                                    let op: Op2::StrCons;
+                                    let newer_cont: Cont::Binop2 = cons4(op, car, continuation, foo);
+                                    return (call, env, newer_cont, ret)
+                                }
+                                let (start) = decrement_bound(start);
+                                let args: Expr::Cons = cons2(start, args);
+                                let args: Expr::Cons = cons2(cdr, args);
+                                let call: Expr::Cons = cons2(sym, args);
+                                return (call, env, continuation, ret)
+                            }
                             Op2::StrCons => {
                                 match evaled_arg.tag {
                                     Expr::Char => {
@@ -1456,6 +2291,17 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                         let val = cast(val, Expr::U64);
                                         return (val, env, continuation, makethunk)
                                     }
+                                    Expr::I64 => {
+                                        let val = add(evaled_arg, result);
+                                        let not_overflow = lt(val, size_u64);
+                                        if not_overflow {
+                                            let val = cast(val, Expr::I64);
+                                            return (val, env, continuation, makethunk)
+                                        }
+                                        let val = sub(val, size_u64);
+                                        let val = cast(val, Expr::I64);
+                                        return (val, env, continuation, makethunk)
+                                    }
                                 }
                             }
                             Op2::Diff => {
@@ -1482,6 +2328,19 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                         let val = cast(val, Expr::U64);
                                         return (val, env, continuation, makethunk)
                                     }
+                                    Expr::I64 => {
+                                        // Same as for U64, since i64s are stored as the u64s of same bits
+                                        let val = sub(evaled_arg, result);
+                                        let is_neg = lt(val, zero);
+                                        let not_neg = not(is_neg);
+                                        if not_neg {
+                                            let val = cast(val, Expr::I64);
+                                            return (val, env, continuation, makethunk)
+                                        }
+                                        let val = add(val, size_u64);
+                                        let val = cast(val, Expr::I64);
+                                        return (val, env, continuation, makethunk)
+                                    }
                                 }
                             }
                             Op2::Product => {
@@ -1500,6 +2359,12 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                         let cast = cast(trunc, Expr::U64);
                                         return (cast, env, continuation, makethunk)
                                     }
+                                    Expr::I64 => {
+                                        let val = mul(evaled_arg, result);
+                                        let trunc = truncate(val, 64);
+                                        let cast = cast(trunc, Expr::I64);
+                                        return (cast, env, continuation, makethunk)
+                                    }
                                 }
                             }
                             Op2::Quotient => {
@@ -1516,8 +2381,25 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                         return (val, env, continuation, makethunk)
                                     }
                                     Expr::U64 => {
-                                        let (div, _rem) = div_rem64(evaled_arg, result);
-                                        let div = cast(div, Expr::U64);
+                                        let div = cast(quotient, Expr::U64);
+                                        return (div, env, continuation, makethunk)
+                                    }
+                                    Expr::I64 => {
+                                        // Divides the absolute values, rounding towards zero, and
+                                        // negates the quotient if the signs differ
+                                        let div = quotient;
+                                        let signs_diff = sub(sign1, sign2);
+                                        let signs_differ = mul(signs_diff, signs_diff);
+                                        let neg = mul(signs_differ, div);
+                                        let div = sub(div, neg);
+                                        let div = sub(div, neg);
+                                        let is_neg = lt(div, zero);
+                                        if is_neg {
+                                            let div = add(div, size_u64);
+                                            let div = cast(div, Expr::I64);
+                                            return (div, env, continuation, makethunk)
+                                        }
+                                        let div = cast(div, Expr::I64);
                                         return (div, env, continuation, makethunk)
                                     }
                                 }
@@ -1529,8 +2411,21 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                 }
                                 match args_num_type.tag {
                                     Expr::U64 => {
-                                        let (_div, rem) = div_rem64(evaled_arg, result);
-                                        let rem = cast(rem, Expr::U64);
+                                        let rem = cast(remainder, Expr::U64);
+                                        return (rem, env, continuation, makethunk)
+                                    }
+                                    Expr::I64 => {
+                                        // The remainder takes the sign of the dividend
+                                        let neg = mul(sign1, remainder);
+                                        let rem = sub(remainder, neg);
+                                        let rem = sub(rem, neg);
+                                        let is_neg = lt(rem, zero);
+                                        if is_neg {
+                                            let rem = add(rem, size_u64);
+                                            let rem = cast(rem, Expr::I64);
+                                            return (rem, env, continuation, makethunk)
+                                        }
+                                        let rem = cast(rem, Expr::I64);
                                         return (rem, env, continuation, makethunk)
                                     }
                                 };
@@ -1549,33 +2444,159 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                                 return (nil, env, continuation, makethunk)
                             }
                             Op2::Less => {
-                                let val = lt(evaled_arg, result);
+                                let val = lt(signed1, signed2);
                                 if val {
                                     return (t, env, continuation, makethunk)
                                 }
                                 return (nil, env, continuation, makethunk)
                             }
                             Op2::Greater => {
-                                let val = lt(result, evaled_arg);
+                                let val = lt(signed2, signed1);
                                 if val {
                                     return (t, env, continuation, makethunk)
                                 }
                                 return (nil, env, continuation, makethunk)
                             }
                             Op2::LessEqual => {
-                                let val = lt(result, evaled_arg);
+                                let val = lt(signed2, signed1);
                                 if val {
                                     return (nil, env, continuation, makethunk)
                                 }
                                 return (t, env, continuation, makethunk)
                             }
                             Op2::GreaterEqual => {
-                                let val = lt(evaled_arg, result);
+                                let val = lt(signed1, signed2);
                                 if val {
                                     return (nil, env, continuation, makethunk)
                                 }
                                 return (t, env, continuation, makethunk)
                             }
+                            Op2::LogAnd => {
+                                match args_num_type.tag {
+                                    Expr::U64 => {
+                                        let val = cast(conjunction, Expr::U64);
+                                        return (val, env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::LogIor => {
+                                match args_num_type.tag {
+                                    Expr::U64 => {
+                                        // a | b = a + b - (a & b)
+                                        let sum = add(evaled_arg, result);
+                                        let val = sub(sum, conjunction);
+                                        let val = cast(val, Expr::U64);
+                                        return (val, env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::LogXor => {
+                                match args_num_type.tag {
+                                    Expr::U64 => {
+                                        // a ^ b = a + b - 2 * (a & b)
+                                        let sum = add(evaled_arg, result);
+                                        let val = sub(sum, conjunction);
+                                        let val = sub(val, conjunction);
+                                        let val = cast(val, Expr::U64);
+                                        return (val, env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::ShiftLeft => {
+                                match args_num_type.tag {
+                                    Expr::U64 => {
+                                        let val = shl64(evaled_arg, result);
+                                        let val = cast(val, Expr::U64);
+                                        return (val, env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                            Op2::ShiftRight => {
+                                match args_num_type.tag {
+                                    Expr::U64 => {
+                                        let val = shr64(evaled_arg, result);
+                                        let val = cast(val, Expr::U64);
+                                        return (val, env, continuation, makethunk)
+                                    }
+                                };
+                                return (result, env, err, errctrl)
+                            }
+                        };
+                        return (result, env, err, errctrl)
+                    }
+                    Cont::Vector => {
+                        let (operator, vector, evaled_arg, continuation) = decons4(cont);
+                        let one = Num(1);
+                        let two = Num(2);
+                        match operator.tag {
+                            Op2::VectorRef => {
+                                let (val, newer_cont) = vector_ref_step(vector, result, continuation);
+                                match newer_cont.tag {
+                                    Cont::Error => {
+                                        return (result, env, err, errctrl)
+                                    }
+                                };
+                                return (val, env, newer_cont, makethunk)
+                            }
+                            Op2::MakeVector => {
+                                // A length is passed down until the empty vector is reached, and
+                                // then the vector made of each length is passed up
+                                match result.tag {
+                                    Expr::U64 => {
+                                        let (val, newer_cont) = make_vector_step(result, evaled_arg, cont);
+                                        return (val, env, newer_cont, makethunk)
+                                    }
+                                };
+                                let (_len_half, len_parity) = div_rem64(vector, two);
+                                let len_odd = eq_val(len_parity, one);
+                                if len_odd {
+                                    let content: Expr::Cons = cons2(evaled_arg, result);
+                                    let made: Expr::Vec = cons2(vector, content);
+                                    return (made, env, continuation, makethunk)
+                                }
+                                let content: Expr::Cons = cons2(result, result);
+                                let made: Expr::Vec = cons2(vector, content);
+                                return (made, env, continuation, makethunk)
+                            }
+                            Op2::VectorSet => {
+                                // The child to update, the index in it and the element are passed
+                                // down until the element is reached, and then each updated node is
+                                // passed up
+                                match result.tag {
+                                    Expr::Cons => {
+                                        let (child, more) = decons2(result);
+                                        let (i, elt) = decons2(more);
+                                        let (val, newer_cont) = vector_set_step(child, i, elt, cont);
+                                        match newer_cont.tag {
+                                            Cont::Error => {
+                                                return (result, env, err, errctrl)
+                                            }
+                                        };
+                                        return (val, env, newer_cont, makethunk)
+                                    }
+                                };
+                                // `result` is the updated child, which is the cdr of the content
+                                // if the length or the index is odd
+                                let (len, content) = decons2(vector);
+                                let (first, rest) = decons2(content);
+                                let (_len_half, len_parity) = div_rem64(len, two);
+                                let (_index_half, index_parity) = div_rem64(evaled_arg, two);
+                                let len_odd = eq_val(len_parity, one);
+                                let index_odd = eq_val(index_parity, one);
+                                let in_rest = or(len_odd, index_odd);
+                                if in_rest {
+                                    let content: Expr::Cons = cons2(first, result);
+                                    let updated: Expr::Vec = cons2(len, content);
+                                    return (updated, env, continuation, makethunk)
+                                }
+                                let content: Expr::Cons = cons2(result, rest);
+                                let updated: Expr::Vec = cons2(len, content);
+                                return (updated, env, continuation, makethunk)
+                            }
                         };
                         return (result, env, err, errctrl)
                     }
@@ -1609,7 +2630,12 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
                         let evaluated_args: Expr::Cons = cons2(result, evaluated_args);
                         match unevaled_args.tag {
                             Expr::Nil => {
-                                // nothing else to evaluate
+                                // nothing else to evaluate, but coprocessors can't be called
+                                // from within `eval-with-limit`
+                                let is_sandboxed = eq_val(sandboxed, t);
+                                if is_sandboxed {
+                                    return (result, env, err, errctrl)
+                                }
                                 let (expr, env, cont, ctrl) = choose_cproc_call(cproc_name, evaluated_args, env, cont);
                                 return (expr, env, cont, ctrl);
                             }
@@ -1633,6 +2659,94 @@ fn apply_cont(cprocs: &[(&Symbol, usize)], ivc: bool) -> Func {
     })
 }
 
+/// An error is thrown, as a keyword naming it consed onto the expression it's raised on. An error
+/// of `reduce` is thrown to the continuation of the step, and an error of `apply_cont` to the
+/// continuation the applied one returns to
+fn raise() -> Func {
+    func!(raise(expr, cont, applied, reduce_ctrl, result, env, new_cont, ctrl): 4 => {
+        match symbol ctrl {
+            "error" => {
+                let makethunk = Symbol("make-thunk");
+                let nil = Symbol("nil");
+                let nil = cast(nil, Expr::Nil);
+                let err: Cont::Error;
+                let thrown: Expr::Thunk = cons2(nil, err);
+                let invalid_form = Key("invalid-form");
+                let invalid_call = Key("invalid-call");
+                let invalid_arg = Key("invalid-arg");
+                match symbol reduce_ctrl {
+                    "error" => {
+                        match expr.tag {
+                            Expr::Sym => {
+                                let unbound_var = Key("unbound-var");
+                                let value: Expr::Cons = cons2(unbound_var, result);
+                                return (value, thrown, cont, makethunk)
+                            }
+                        };
+                        let value: Expr::Cons = cons2(invalid_form, result);
+                        return (value, thrown, cont, makethunk)
+                    }
+                };
+                match applied.tag {
+                    Cont::Call0 => {
+                        let (_saved_env, continuation, _foo, _foo) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_call, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Call | Cont::Call2 => {
+                        let (_foo, _foo, continuation, _foo) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_call, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Cproc => {
+                        let (_foo, _foo, _foo, continuation) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_call, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::If => {
+                        let (_unevaled_args, continuation, _foo, _foo) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_form, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Binop => {
+                        let (_foo, _foo, _foo, continuation) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_form, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Unop => {
+                        let (operator, continuation, _foo, _foo) = decons4(applied);
+                        match operator.tag {
+                            Op1::Car => {
+                                let car_of_non_list = Key("car-of-non-list");
+                                let value: Expr::Cons = cons2(car_of_non_list, result);
+                                return (value, thrown, continuation, makethunk)
+                            }
+                            Op1::Cdr => {
+                                let cdr_of_non_list = Key("cdr-of-non-list");
+                                let value: Expr::Cons = cons2(cdr_of_non_list, result);
+                                return (value, thrown, continuation, makethunk)
+                            }
+                        };
+                        let value: Expr::Cons = cons2(invalid_arg, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Binop2 => {
+                        let (_foo, _foo, continuation, _foo) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_arg, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                    Cont::Vector => {
+                        let (_foo, _foo, _foo, continuation) = decons4(applied);
+                        let value: Expr::Cons = cons2(invalid_arg, result);
+                        return (value, thrown, continuation, makethunk)
+                    }
+                }
+            }
+        };
+        return (result, env, new_cont, ctrl)
+    })
+}
+
 fn make_thunk() -> Func {
     func!(make_thunk(expr, env, cont, ctrl): 4 => {
         let ret = Symbol("return");
@@ -1643,9 +2757,22 @@ fn make_thunk() -> Func {
                         let (saved_env, saved_cont, _foo, _foo) = decons4(cont);
                         let thunk: Expr::Thunk = cons2(expr, saved_cont);
                         let cont: Cont::Dummy;
+                        // A thrown value keeps its env until it's caught
+                        match env.tag {
+                            Expr::Thunk => {
+                                return (thunk, env, cont, ret)
+                            }
+                        };
                         return (thunk, saved_env, cont, ret)
                     }
                     Cont::Outermost => {
+                        // A thrown value that is never caught is an error
+                        match env.tag {
+                            Expr::Thunk => {
+                                let cont: Cont::Error;
+                                return (expr, env, cont, ret)
+                            }
+                        };
                         let cont: Cont::Terminal;
                         return (expr, env, cont, ret)
                     }
@@ -1659,6 +2786,104 @@ fn make_thunk() -> Func {
     })
 }
 
+/// Unwraps the `Fuel` continuation of an `eval-with-limit`, returning the continuation to step
+/// under, whether the step is sandboxed and the other components of `cont`, which `refuel` takes
+/// so that the continuation is only opened once. Out of fuel, the evaluation takes the step of a
+/// terminated one, which leaves it as it is
+fn unfuel() -> Func {
+    func!(unfuel(cont): 5 => {
+        let t = Symbol("t");
+        let nil = Symbol("nil");
+        match cont.tag {
+            Cont::Fuel => {
+                let (inner, fuel, saved_env, continuation) = decons4(cont);
+                let zero = Num(0);
+                let out_of_fuel = eq_val(fuel, zero);
+                if out_of_fuel {
+                    let terminal: Cont::Terminal;
+                    return (terminal, t, fuel, saved_env, continuation)
+                }
+                return (inner, t, fuel, saved_env, continuation)
+            }
+        };
+        let foo: Expr::Nil;
+        return (cont, nil, foo, foo, foo)
+    })
+}
+
+/// Wraps the output of a step taken under a `Fuel` continuation, as told by `sandboxed`, back
+/// into it, with one unit of fuel less. Once the evaluation terminates or fails, its outcome
+/// (`:ok`, `:out-of-fuel` or `:error`) and its value are returned to the continuation of
+/// `eval-with-limit`
+fn refuel() -> Func {
+    func!(refuel(expr, env, new_cont, sandboxed, fuel, saved_env, continuation): 3 => {
+        let t = Symbol("t");
+        let is_sandboxed = eq_val(sandboxed, t);
+        if is_sandboxed {
+            match new_cont.tag {
+                Cont::Terminal => {
+                    let nil = Symbol("nil");
+                    let nil = cast(nil, Expr::Nil);
+                    let ok = Key("ok");
+                    let out_of_fuel = Key("out-of-fuel");
+                    let zero = Num(0);
+                    let dummy: Cont::Dummy;
+                    let fuel_is_zero = eq_val(fuel, zero);
+                    match expr.tag {
+                        Expr::Thunk => {
+                            let (value, _thunk_continuation) = decons2(expr);
+                            let value: Expr::Cons = cons2(value, nil);
+                            if fuel_is_zero {
+                                let result: Expr::Cons = cons2(out_of_fuel, value);
+                                let thunk: Expr::Thunk = cons2(result, continuation);
+                                return (thunk, saved_env, dummy)
+                            }
+                            let result: Expr::Cons = cons2(ok, value);
+                            let thunk: Expr::Thunk = cons2(result, continuation);
+                            return (thunk, saved_env, dummy)
+                        }
+                    };
+                    let value: Expr::Cons = cons2(expr, nil);
+                    if fuel_is_zero {
+                        let result: Expr::Cons = cons2(out_of_fuel, value);
+                        let thunk: Expr::Thunk = cons2(result, continuation);
+                        return (thunk, saved_env, dummy)
+                    }
+                    let result: Expr::Cons = cons2(ok, value);
+                    let thunk: Expr::Thunk = cons2(result, continuation);
+                    return (thunk, saved_env, dummy)
+                }
+                // Uncaught errors and nested `eval-with-limit` calls end with `:error`
+                Cont::Error | Cont::Fuel => {
+                    let nil = Symbol("nil");
+                    let nil = cast(nil, Expr::Nil);
+                    let error = Key("error");
+                    let dummy: Cont::Dummy;
+                    match expr.tag {
+                        Expr::Thunk => {
+                            let (value, _thunk_continuation) = decons2(expr);
+                            let value: Expr::Cons = cons2(value, nil);
+                            let result: Expr::Cons = cons2(error, value);
+                            let thunk: Expr::Thunk = cons2(result, continuation);
+                            return (thunk, saved_env, dummy)
+                        }
+                    };
+                    let value: Expr::Cons = cons2(expr, nil);
+                    let result: Expr::Cons = cons2(error, value);
+                    let thunk: Expr::Thunk = cons2(result, continuation);
+                    return (thunk, saved_env, dummy)
+                }
+            };
+            let one = Num(1);
+            let fuel = sub(fuel, one);
+            let fuel = cast(fuel, Expr::U64);
+            let cont: Cont::Fuel = cons4(new_cont, fuel, saved_env, continuation);
+            return (expr, env, cont)
+        }
+        return (expr, env, new_cont)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1671,12 +2896,12 @@ mod tests {
     use blstrs::Scalar as Fr;
 
     const NUM_INPUTS: usize = 1;
-    const NUM_AUX: usize = 10554;
-    const NUM_CONSTRAINTS: usize = 12904;
+    const NUM_AUX: usize = 19641;
+    const NUM_CONSTRAINTS: usize = 24445;
     const NUM_SLOTS: SlotsCounter = SlotsCounter {
-        hash4: 14,
+        hash4: 23,
         hash6: 3,
-        hash8: 4,
+        hash8: 7,
         commitment: 1,
         less_than: 1,
     };
@@ -1739,6 +2964,73 @@ mod tests {
         let char_conv_res = read("'a'");
         let char_overflow = read("(char 4294967393)");
         let char_overflow_res = read("'a'");
+        let logxor = read("(logxor 12u64 10u64)");
+        let logxor_res = read("6u64");
+        let lognot = read("(lognot 0u64)");
+        let lognot_res = read("18446744073709551615u64");
+        let char_to_u64 = read("(char->u64 'a')");
+        let char_to_u64_res = read("97u64");
+        let u64_to_char = read("(u64->char 97u64)");
+        let u64_to_char_res = read("'a'");
+        let symbol_to_string = read("(symbol->string 'foo)");
+        let symbol_to_string_res = read("\"foo\"");
+        let string_to_symbol = read("(string->symbol \"foo\")");
+        let string_to_symbol_res = read("foo");
+        let string_length = read("(string-length \"ab\")");
+        let string_length_res = read("2u64");
+        let string_append = read("(string-append \"a\" \"b\")");
+        let string_append_res = read("\"ab\"");
+        let substring = read("(substring \"abc\" 1 2u64)");
+        let substring_res = read("\"b\"");
+        let string_to_list = read("(string->list \"ab\")");
+        let string_to_list_res = read("(#\\a #\\b)");
+        let list_to_string = read("(list->string '(#\\a #\\b))");
+        let list_to_string_res = read("\"ab\"");
+        let catch = read("(catch (+ 1 (throw 41)) (lambda (e) (+ e 1)))");
+        let catch_res = read("42");
+        let catch_nothing = read("(catch (+ 1 2) (lambda (e) e))");
+        let catch_nothing_res = read("3");
+        let catch_from_call = read(
+            "(catch (let ((f (lambda (x) (throw (cons :oops x))))) (f 1))
+                    (lambda (e) e))",
+        );
+        let catch_from_call_res = read("(:oops . 1)");
+        let catch_error = read("(catch (car 1) (lambda (e) e))");
+        let catch_error_res = read("(:car-of-non-list . 1)");
+        let catch_unbound = read("(catch (+ 1 x) (lambda (e) (car e)))");
+        let catch_unbound_res = read(":unbound-var");
+        let shift_left = read("(shift-left 3u64 63u64)");
+        let shift_left_res = read("9223372036854775808u64");
+        let shift_right = read("(shift-right 1024u64 3u64)");
+        let shift_right_res = read("128u64");
+        let i64_sum = read("(+ -5i64 3i64)");
+        let i64_sum_res = read("-2i64");
+        let i64_div = read("(/ -7i64 2i64)");
+        let i64_div_res = read("-3i64");
+        let i64_rem = read("(% -7i64 2i64)");
+        let i64_rem_res = read("-1i64");
+        let i64_conv = read("(i64 -5)");
+        let i64_conv_res = read("-5i64");
+        let i64_num = read("(num -5i64)");
+        let i64_num_res = read("-5");
+        let i64_lt = read("(< -1i64 1i64)");
+        let make_vector = read("(vector-length (make-vector 0u64 nil))");
+        let make_vector_res = read("0u64");
+        let vector_length = read("(vector-length (make-vector 3u64 0))");
+        let vector_length_res = read("3u64");
+        let vector_ref = read("(vector-ref (make-vector 3u64 5) 2u64)");
+        let vector_ref_res = read("5");
+        let vector_set = read("(vector-ref (vector-set (make-vector 3u64 0) 1u64 7) 1u64)");
+        let vector_set_res = read("7");
+        let eval_with_limit = read("(eval-with-limit (quote (+ 1 2)) nil 10u64)");
+        let eval_with_limit_res = read("(:ok 3)");
+        let out_of_fuel = read("(eval-with-limit (quote (+ 1 2)) nil 0u64)");
+        let out_of_fuel_res = read("(:out-of-fuel (+ 1 2))");
+        let sandboxed_error = read("(eval-with-limit (quote (car 1)) nil 10u64)");
+        let sandboxed_error_res = read("(:error (:car-of-non-list . 1))");
+        let nested_eval_with_limit =
+            read("(eval-with-limit (quote (eval-with-limit 1 nil 1u64)) nil 10u64)");
+        let nested_eval_with_limit_res = read("(:error 1)");
         let t = read("t");
         let nil = read("nil");
         let le1 = read("(<= 4 8)");
@@ -1761,6 +3053,10 @@ mod tests {
         let lam0_res = read("1");
         let lam = read("((lambda (x y) (+ x y)) 3 4)");
         let lam_res = read("7");
+        let partial = read("(((lambda (x y) (+ x y)) 3) 4)");
+        let partial_res = read("7");
+        let rest = read("((lambda (x &rest ys) ys) 1 2 3)");
+        let rest_res = read("(2 3)");
         let fold = read(
             "(letrec ((build (lambda (x)
                                 (if (eq x 0)
@@ -1781,6 +3077,38 @@ mod tests {
             (mul_overflow, mul_overflow_res),
             (char_conv, char_conv_res),
             (char_overflow, char_overflow_res),
+            (logxor, logxor_res),
+            (lognot, lognot_res),
+            (char_to_u64, char_to_u64_res),
+            (u64_to_char, u64_to_char_res),
+            (symbol_to_string, symbol_to_string_res),
+            (string_to_symbol, string_to_symbol_res),
+            (string_length, string_length_res),
+            (string_append, string_append_res),
+            (substring, substring_res),
+            (string_to_list, string_to_list_res),
+            (list_to_string, list_to_string_res),
+            (catch, catch_res),
+            (catch_nothing, catch_nothing_res),
+            (catch_from_call, catch_from_call_res),
+            (catch_error, catch_error_res),
+            (catch_unbound, catch_unbound_res),
+            (shift_left, shift_left_res),
+            (shift_right, shift_right_res),
+            (i64_sum, i64_sum_res),
+            (i64_div, i64_div_res),
+            (i64_rem, i64_rem_res),
+            (i64_conv, i64_conv_res),
+            (i64_num, i64_num_res),
+            (i64_lt, t),
+            (make_vector, make_vector_res),
+            (vector_length, vector_length_res),
+            (vector_ref, vector_ref_res),
+            (vector_set, vector_set_res),
+            (eval_with_limit, eval_with_limit_res),
+            (out_of_fuel, out_of_fuel_res),
+            (sandboxed_error, sandboxed_error_res),
+            (nested_eval_with_limit, nested_eval_with_limit_res),
             (le1, t),
             (le2, t),
             (le3, nil),
@@ -1793,6 +3121,8 @@ mod tests {
             (let_, let_res),
             (lam0, lam0_res),
             (lam, lam_res),
+            (partial, partial_res),
+            (rest, rest_res),
             (fold, fold_res),
         ]
     }
@@ -314,6 +314,42 @@ impl Block {
                     bindings.insert_ptr(tgt[0].clone(), c1);
                     bindings.insert_ptr(tgt[1].clone(), c2);
                 }
+                Op::BitAnd64(tgt, a, b) => {
+                    let a = bindings.get_ptr(a)?;
+                    let b = bindings.get_ptr(b)?;
+                    let c = if let (Ptr::Atom(_, f), Ptr::Atom(_, g)) = (a, b) {
+                        let f = f.to_u64_unchecked();
+                        let g = g.to_u64_unchecked();
+                        Ptr::Atom(Tag::Expr(Num), F::from_u64(f & g))
+                    } else {
+                        bail!("`BitAnd64` only works on atoms")
+                    };
+                    bindings.insert_ptr(tgt.clone(), c);
+                }
+                Op::Shl64(tgt, a, b) => {
+                    let a = bindings.get_ptr(a)?;
+                    let b = bindings.get_ptr(b)?;
+                    let c = if let (Ptr::Atom(_, f), Ptr::Atom(_, g)) = (a, b) {
+                        let f = f.to_u64_unchecked();
+                        let g = g.to_u64_unchecked();
+                        Ptr::Atom(Tag::Expr(Num), F::from_u64(if g < 64 { f << g } else { 0 }))
+                    } else {
+                        bail!("`Shl64` only works on atoms")
+                    };
+                    bindings.insert_ptr(tgt.clone(), c);
+                }
+                Op::Shr64(tgt, a, b) => {
+                    let a = bindings.get_ptr(a)?;
+                    let b = bindings.get_ptr(b)?;
+                    let c = if let (Ptr::Atom(_, f), Ptr::Atom(_, g)) = (a, b) {
+                        let f = f.to_u64_unchecked();
+                        let g = g.to_u64_unchecked();
+                        Ptr::Atom(Tag::Expr(Num), F::from_u64(if g < 64 { f >> g } else { 0 }))
+                    } else {
+                        bail!("`Shr64` only works on atoms")
+                    };
+                    bindings.insert_ptr(tgt.clone(), c);
+                }
                 Op::Emit(a) => {
                     let a = bindings.get_ptr(a)?;
                     println!("{}", a.fmt_to_string(store, initial_lurk_state()));
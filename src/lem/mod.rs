@@ -186,7 +186,7 @@ impl Lit {
     }
 
     pub fn from_ptr<F: LurkField>(ptr: &Ptr<F>, store: &Store<F>) -> Option<Self> {
-        use ExprTag::{Num, Str, Sym};
+        use ExprTag::{Key, Num, Str, Sym};
         use Tag::Expr;
         match ptr.tag() {
             Expr(Num) => match ptr {
@@ -197,7 +197,7 @@ impl Lit {
                 _ => unreachable!(),
             },
             Expr(Str) => store.fetch_string(ptr).map(Lit::String),
-            Expr(Sym) => store.fetch_symbol(ptr).map(Lit::Symbol),
+            Expr(Sym | Key) => store.fetch_symbol(ptr).map(Lit::Symbol),
             _ => None,
         }
     }
@@ -280,6 +280,12 @@ pub enum Op {
     Trunc(Var, Var, u32),
     /// `DivRem64(ys, a, b)` binds `ys` to `(a / b, a % b)` as if they were u64
     DivRem64([Var; 2], Var, Var),
+    /// `BitAnd64(y, a, b)` binds `y` to the bitwise conjunction of `a` and `b` as if they were u64
+    BitAnd64(Var, Var, Var),
+    /// `Shl64(y, a, b)` binds `y` to `a` shifted left by `b` bits as if they were u64
+    Shl64(Var, Var, Var),
+    /// `Shr64(y, a, b)` binds `y` to `a` shifted right by `b` bits as if they were u64
+    Shr64(Var, Var, Var),
     /// `Emit(v)` simply prints out the value of `v` when interpreting the code
     Emit(Var),
     /// `Cons2(x, t, ys)` binds `x` to a `Ptr` with tag `t` and 2 children `ys`
@@ -395,7 +401,10 @@ impl Func {
                     | Op::Sub(tgt, a, b)
                     | Op::Mul(tgt, a, b)
                     | Op::Div(tgt, a, b)
-                    | Op::Lt(tgt, a, b) => {
+                    | Op::Lt(tgt, a, b)
+                    | Op::BitAnd64(tgt, a, b)
+                    | Op::Shl64(tgt, a, b)
+                    | Op::Shr64(tgt, a, b) => {
                         is_bound(a, map)?;
                         is_bound(b, map)?;
                         is_unique(tgt, map);
@@ -673,6 +682,24 @@ impl Block {
                     let tgt = insert_many(map, uniq, &tgt);
                     ops.push(Op::DivRem64(tgt.try_into().unwrap(), a, b))
                 }
+                Op::BitAnd64(tgt, a, b) => {
+                    let a = map.get_cloned(&a)?;
+                    let b = map.get_cloned(&b)?;
+                    let tgt = insert_one(map, uniq, &tgt);
+                    ops.push(Op::BitAnd64(tgt, a, b))
+                }
+                Op::Shl64(tgt, a, b) => {
+                    let a = map.get_cloned(&a)?;
+                    let b = map.get_cloned(&b)?;
+                    let tgt = insert_one(map, uniq, &tgt);
+                    ops.push(Op::Shl64(tgt, a, b))
+                }
+                Op::Shr64(tgt, a, b) => {
+                    let a = map.get_cloned(&a)?;
+                    let b = map.get_cloned(&b)?;
+                    let tgt = insert_one(map, uniq, &tgt);
+                    ops.push(Op::Shr64(tgt, a, b))
+                }
                 Op::Emit(a) => {
                     let a = map.get_cloned(&a)?;
                     ops.push(Op::Emit(a))
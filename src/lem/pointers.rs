@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     field::*,
-    tag::ExprTag::{Char, Comm, Nil, Num, U64},
+    tag::ExprTag::{Char, Comm, Nil, Num, I64, U64},
 };
 
 use super::Tag;
@@ -61,6 +61,11 @@ impl<F: LurkField> Ptr<F> {
         Ptr::Atom(Tag::Expr(U64), F::from_u64(u))
     }
 
+    #[inline]
+    pub fn i64(i: i64) -> Self {
+        Ptr::Atom(Tag::Expr(I64), F::from_u64(i as u64))
+    }
+
     #[inline]
     pub fn char(c: char) -> Self {
         Ptr::Atom(Tag::Expr(Char), F::from_char(c))
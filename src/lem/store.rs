@@ -14,7 +14,7 @@ use crate::{
     state::{lurk_sym, State},
     symbol::Symbol,
     syntax::Syntax,
-    tag::ExprTag::{Char, Comm, Cons, Cproc, Fun, Key, Nil, Num, Str, Sym, Thunk, U64},
+    tag::ExprTag::{Char, Comm, Cons, Cproc, Fun, Key, Nil, Num, Str, Sym, Thunk, I64, U64},
     uint::UInt,
 };
 
@@ -382,6 +382,7 @@ impl<F: LurkField> Store<F> {
         match syn {
             Syntax::Num(_, x) => Ptr::Atom(Tag::Expr(Num), x.into_scalar()),
             Syntax::UInt(_, UInt::U64(x)) => Ptr::Atom(Tag::Expr(U64), x.into()),
+            Syntax::I64(_, x) => Ptr::Atom(Tag::Expr(I64), (x as u64).into()),
             Syntax::Char(_, x) => Ptr::Atom(Tag::Expr(Char), (x as u64).into()),
             Syntax::Symbol(_, symbol) => self.intern_symbol(&symbol),
             Syntax::String(_, x) => self.intern_string(&x),
@@ -411,7 +412,7 @@ impl<F: LurkField> Store<F> {
         )
         .parse(Span::new(input))
         {
-            Ok((_, x)) => Ok(self.intern_syntax(x)),
+            Ok((_, x)) => Ok(self.intern_syntax(x.desugar()?)),
             Err(e) => bail!("{}", e),
         }
     }
@@ -424,7 +425,7 @@ impl<F: LurkField> Store<F> {
         match preceded(syntax::parse_space, syntax::parse_maybe_meta(state, false))
             .parse(input.into())
         {
-            Ok((i, Some((is_meta, x)))) => Ok((i, self.intern_syntax(x), is_meta)),
+            Ok((i, Some((is_meta, x)))) => Ok((i, self.intern_syntax(x.desugar()?), is_meta)),
             Ok((_, None)) => Err(Error::NoInput),
             Err(e) => Err(Error::Syntax(format!("{}", e))),
         }
@@ -627,6 +628,35 @@ impl<F: LurkField> Ptr<F> {
         Some((list, last))
     }
 
+    fn unfold_vec(&self, store: &Store<F>) -> Option<Vec<Ptr<F>>> {
+        let (len, content) = store.fetch_2_ptrs(self.get_index2()?)?;
+        let len = len.get_atom()?.to_u64()?;
+        match content.tag() {
+            Tag::Expr(Nil) if len == 0 => Some(vec![]),
+            Tag::Expr(Cons) => {
+                let (car, cdr) = store.fetch_2_ptrs(content.get_index2()?)?;
+                let elts = if len % 2 == 1 {
+                    let mut elts = vec![*car];
+                    elts.extend(cdr.unfold_vec(store)?);
+                    elts
+                } else {
+                    let evens = car.unfold_vec(store)?;
+                    let odds = cdr.unfold_vec(store)?;
+                    if evens.len() != odds.len() {
+                        return None;
+                    }
+                    evens
+                        .into_iter()
+                        .zip(odds)
+                        .flat_map(|(e, o)| [e, o])
+                        .collect()
+                };
+                (elts.len() as u64 == len).then_some(elts)
+            }
+            _ => None,
+        }
+    }
+
     pub fn fmt_to_string(&self, store: &Store<F>, state: &State) -> String {
         match self.tag() {
             Tag::Expr(t) => match t {
@@ -695,15 +725,23 @@ impl<F: LurkField> Ptr<F> {
                     Some(Some(u)) => format!("{u}u64"),
                     _ => "<Malformed U64>".into(),
                 },
+                I64 => match self.get_atom().map(F::to_u64) {
+                    Some(Some(u)) => format!("{}i64", u as i64),
+                    _ => "<Malformed I64>".into(),
+                },
                 Fun => match self.get_index3() {
                     None => "<Opaque Fun>".into(),
                     Some(idx) => {
-                        if let Some((arg, bod, _)) = store.fetch_3_ptrs(idx) {
+                        if let Some((params, bod, _)) = store.fetch_3_ptrs(idx) {
+                            let params = match params.tag() {
+                                Tag::Expr(Nil) => "()".into(),
+                                _ => params.fmt_to_string(store, state),
+                            };
                             match bod.tag() {
                                 Tag::Expr(Nil) => {
                                     format!(
-                                        "<FUNCTION ({}) {}>",
-                                        arg.fmt_to_string(store, state),
+                                        "<FUNCTION {} {}>",
+                                        params,
                                         bod.fmt_to_string(store, state)
                                     )
                                 }
@@ -711,8 +749,8 @@ impl<F: LurkField> Ptr<F> {
                                     if let Some(idx) = bod.get_index2() {
                                         if let Some((bod, _)) = store.fetch_2_ptrs(idx) {
                                             format!(
-                                                "<FUNCTION ({}) {}>",
-                                                arg.fmt_to_string(store, state),
+                                                "<FUNCTION {} {}>",
+                                                params,
                                                 bod.fmt_to_string(store, state)
                                             )
                                         } else {
@@ -754,6 +792,17 @@ impl<F: LurkField> Ptr<F> {
                     None => "<Malformed Comm>".into(),
                 },
                 Cproc => "<COPROCESSOR (TODO)>".into(),
+                crate::tag::ExprTag::Vec => {
+                    if let Some(elts) = self.unfold_vec(store) {
+                        let elts = elts
+                            .iter()
+                            .map(|p| format!(" {}", p.fmt_to_string(store, state)))
+                            .collect::<String>();
+                        format!("<VECTOR{elts}>")
+                    } else {
+                        "<Opaque Vec>".into()
+                    }
+                }
             },
             Tag::Cont(_) => "<CONTINUATION (TODO)>".into(),
             _ => unreachable!(),
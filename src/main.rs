@@ -14,7 +14,8 @@ fn main() -> Result<()> {
         .with(TeXRayLayer::new());
     tracing::subscriber::set_global_default(subscriber).unwrap();
 
-    println!(
+    // printed to stderr so that subcommands can produce machine-readable output
+    eprintln!(
         "commit: {} {}",
         env!("VERGEN_GIT_COMMIT_DATE"),
         env!("VERGEN_GIT_SHA")
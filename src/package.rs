@@ -1,4 +1,5 @@
 use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
@@ -8,7 +9,7 @@ use crate::Symbol;
 
 pub type SymbolRef = Arc<Symbol>;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Package {
     name: SymbolRef,
     symbols: HashMap<String, SymbolRef>,
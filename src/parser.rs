@@ -43,7 +43,7 @@ impl<F: LurkField> Store<F> {
         )
         .parse(Span::new(input))
         {
-            Ok((_i, x)) => Ok(self.intern_syntax(x)),
+            Ok((_i, x)) => Ok(self.intern_syntax(x.desugar()?)),
             Err(e) => Err(Error::Syntax(format!("{e}"))),
         }
     }
@@ -55,7 +55,7 @@ impl<F: LurkField> Store<F> {
         )
         .parse(Span::new(input))
         {
-            Ok((_i, x)) => Ok(self.intern_syntax(x)),
+            Ok((_i, x)) => Ok(self.intern_syntax(x.desugar()?)),
             Err(e) => Err(Error::Syntax(format!("{e}"))),
         }
     }
@@ -67,7 +67,7 @@ impl<F: LurkField> Store<F> {
     ) -> Result<(Span<'a>, Ptr<F>, bool), Error> {
         use syntax::*;
         match preceded(parse_space, parse_maybe_meta(state, false)).parse(input) {
-            Ok((i, Some((is_meta, x)))) => Ok((i, self.intern_syntax(x), is_meta)),
+            Ok((i, Some((is_meta, x)))) => Ok((i, self.intern_syntax(x.desugar()?), is_meta)),
             Ok((_, None)) => Err(Error::NoInput),
             Err(e) => Err(Error::Syntax(format!("{e}"))),
         }
@@ -226,6 +226,29 @@ pub fn parse_uint<F: LurkField>() -> impl Fn(Span<'_>) -> ParseResult<'_, F, Syn
     }
 }
 
+pub fn parse_int<F: LurkField>() -> impl Fn(Span<'_>) -> ParseResult<'_, F, Syntax<F>> {
+    move |from: Span<'_>| {
+        let (i, neg) = opt(tag("-"))(from)?;
+        let (i, base) = alt((
+            preceded(tag("0"), base::parse_litbase_code()),
+            success(base::LitBase::Dec),
+        ))(i)?;
+        let (i, digits) = base::parse_litbase_digits(base)(i)?;
+        let (upto, _) = tag("i64")(i)?;
+        // the sign is parsed along with the digits so that the most negative i64 fits
+        let digits = if neg.is_some() {
+            format!("-{digits}")
+        } else {
+            digits
+        };
+        let (_, x) = ParseError::res(i64::from_str_radix(&digits, base.radix()), from, |e| {
+            ParseErrorKind::ParseIntErr(e)
+        })?;
+        let pos = Pos::from_upto(from, upto);
+        Ok((upto, Syntax::I64(pos, x)))
+    }
+}
+
 fn f_from_le_bytes<F: LurkField>(bs: &[u8]) -> F {
     let mut res = F::ZERO;
     let mut bs = bs.iter().rev().peekable();
@@ -406,6 +429,7 @@ pub fn parse_syntax<F: LurkField>(
                 parse_list(state.clone(), meta, create_unknown_packages),
             ),
             parse_uint(),
+            parse_int(),
             parse_num(),
             context(
                 "symbol",
@@ -442,7 +466,7 @@ pub mod tests {
     use proptest::prelude::*;
 
     use super::*;
-    use crate::{char, keyword, list, num, str, symbol, uint};
+    use crate::{char, int, keyword, list, num, str, symbol, uint};
 
     fn test<'a, P, R>(mut p: P, i: &'a str, expected: Option<R>) -> bool
     where
@@ -935,6 +959,20 @@ pub mod tests {
             "11242421860377074631u64",
             Some(uint!(11242421860377074631))
         ));
+        assert!(test(
+            parse_syntax(state(), false, true),
+            "-5i64",
+            Some(int!(-5))
+        ));
+        assert!(test(
+            parse_syntax(state(), false, true),
+            "(-0x8000000000000000i64 9223372036854775807i64)",
+            Some(list!([
+                int!(-9223372036854775808),
+                int!(9223372036854775807)
+            ]))
+        ));
+        assert!(test(parse_int(), "9223372036854775808i64", None));
         assert!(test(
             parse_syntax(state(), false, true),
             ":\u{ae}\u{60500}\u{87}..)",
@@ -23,7 +23,7 @@ use std::sync::Arc;
 use crate::circuit::MultiFrame;
 use crate::coprocessor::Coprocessor;
 use crate::error::ProofError;
-use crate::eval::{lang::Lang, Meta, IO};
+use crate::eval::{lang::Lang, Frame, Meta, Witness, IO};
 use crate::field::LurkField;
 use crate::proof::{supernova::FoldingConfig, Provable, Prover, PublicParameters};
 use crate::ptr::Ptr;
@@ -141,14 +141,28 @@ impl<'a, C: Coprocessor<Scalar> + 'a, M: MultiFrameTrait<'a, Scalar, C>>
         env: Ptr<Scalar>,
         store: &Store<Scalar>,
         limit: usize,
-        mut rng: R,
+        rng: R,
         lang: Arc<Lang<Scalar, C>>,
     ) -> Result<(Proof<Bls12>, IO<Scalar>, IO<Scalar>), ProofError> {
         let frames = self.get_evaluation_frames(expr, env, store, limit, lang.clone())?;
+        self.prove_frames(params, srs, &frames, store, rng, lang)
+    }
+
+    /// Generates an outer Groth16 proof for already computed evaluation frames, using the
+    /// given parameters, SRS, store and random number generator.
+    pub fn prove_frames<R: RngCore + Clone>(
+        &self,
+        params: &groth16::Parameters<Bls12>,
+        srs: &GenericSRS<Bls12>,
+        frames: &[Frame<IO<Scalar>, Witness<Scalar>, Scalar, C>],
+        store: &Store<Scalar>,
+        mut rng: R,
+        lang: Arc<Lang<Scalar, C>>,
+    ) -> Result<(Proof<Bls12>, IO<Scalar>, IO<Scalar>), ProofError> {
         let reduction_count = self.reduction_count();
         let folding_config = Arc::new(FoldingConfig::new_ivc(lang, reduction_count));
         let multiframes =
-            MultiFrame::from_frames(reduction_count, &frames, store, folding_config.clone());
+            MultiFrame::from_frames(reduction_count, frames, store, folding_config.clone());
         let mut proofs = Vec::with_capacity(multiframes.len());
         let mut statements = Vec::with_capacity(multiframes.len());
 
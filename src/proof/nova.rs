@@ -126,6 +126,9 @@ pub type C2<F> = TrivialCircuit<<G2<F> as Group>::Scalar>;
 /// Type alias for Nova Public Parameters with the curve cycle types defined above.
 pub type NovaPublicParams<F, C1> = nova::PublicParams<G1<F>, G2<F>, C1, C2<F>>;
 
+/// Type alias for the Nova verifier key of compressed proofs.
+pub type NovaVerifierKey<F, C1> = VerifierKey<G1<F>, G2<F>, C1, C2<F>, SS1<F>, SS2<F>>;
+
 /// A struct that contains public parameters for the Nova proving system.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(bound = "")]
@@ -250,6 +253,17 @@ where
 {
 }
 
+impl<F: CurveCycleEquipped, C1: StepCircuit<F>> PublicParams<F, C1>
+where
+    <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+    <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
+{
+    /// Returns the verifier key, which is enough to verify compressed proofs.
+    pub fn vk(&self) -> &NovaVerifierKey<F, C1> {
+        &self.vk
+    }
+}
+
 impl<'a, F: CurveCycleEquipped, C: Coprocessor<F>, M: MultiFrameTrait<'a, F, C>> Prover<'a, F, C, M>
     for NovaProver<'a, F, C, M>
 where
@@ -655,6 +669,27 @@ where
         Ok(zi_primary == zi_primary_verified && zi_secondary == zi_secondary_verified)
     }
 
+    /// Verifies a compressed proof given only the verifier key, the number of steps, and the input and output values.
+    /// Recursive proofs can only be verified with the full public parameters, so they are rejected.
+    pub fn verify_with_vk(
+        &self,
+        vk: &NovaVerifierKey<F, M>,
+        num_steps: usize,
+        z0: &[F],
+        zi: &[F],
+    ) -> Result<bool, NovaError> {
+        match self {
+            Self::Recursive(_, _) => Err(NovaError::ProofVerifyError),
+            Self::Compressed(p, _) => {
+                let z0_secondary = Self::z0_secondary();
+                let zi_secondary = z0_secondary.clone();
+                let (zi_primary_verified, zi_secondary_verified) =
+                    p.verify(vk, num_steps, z0.to_vec(), z0_secondary)?;
+                Ok(zi == zi_primary_verified && zi_secondary == zi_secondary_verified)
+            }
+        }
+    }
+
     fn z0_secondary() -> Vec<<F::G2 as Group>::Scalar> {
         vec![<G2<F> as Group>::Scalar::ZERO]
     }
@@ -1038,7 +1073,7 @@ pub mod tests {
     #[test]
     fn test_prove_invalid_num_equal() {
         let s = &mut Store::<Fr>::default();
-        let expected = lurk_sym_ptr!(s, nil);
+        let expected = s.read("(:invalid-arg)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -1051,7 +1086,7 @@ pub mod tests {
             None,
         );
 
-        let expected = s.num(5);
+        let expected = s.read("(:invalid-arg . 5)").unwrap();
         test_aux::<_, _, M1<'_, _>>(
             s,
             "(= nil 5)",
@@ -1144,7 +1179,7 @@ pub mod tests {
     #[test]
     fn test_prove_if_end_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(5);
+        let expected = s.read("(:invalid-form . 5)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -1442,7 +1477,7 @@ pub mod tests {
     #[test]
     fn test_prove_binop_rest_is_nil() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(9);
+        let expected = s.read("(:invalid-form . 9)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -1578,7 +1613,7 @@ pub mod tests {
     #[test]
     fn test_prove_error_div_by_zero() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(0);
+        let expected = s.read("(:invalid-arg . 0)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -1595,7 +1630,7 @@ pub mod tests {
     #[test]
     fn test_prove_error_invalid_type_and_not_cons() {
         let s = &mut Store::<Fr>::default();
-        let expected = lurk_sym_ptr!(s, nil);
+        let expected = s.read("(:invalid-arg)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -1651,7 +1686,7 @@ pub mod tests {
     #[test]
     fn test_prove_current_env_rest_is_nil_error() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.read("(current-env a)").unwrap();
+        let expected = s.read("(:invalid-form current-env a)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -2063,7 +2098,7 @@ pub mod tests {
             None,
             Some(terminal),
             None,
-            69,
+            66,
             None,
         );
     }
@@ -2187,7 +2222,7 @@ pub mod tests {
             None,
             Some(error),
             None,
-            25,
+            28,
             None,
         );
     }
@@ -2293,11 +2328,11 @@ pub mod tests {
     fn test_prove_zero_arg_lambda3() {
         let s = &mut Store::<Fr>::default();
         let expected = {
-            let arg = s.user_sym("x");
+            let params = s.list(&[s.user_sym("x")]);
             let num = s.num(123);
             let body = s.list(&[num]);
             let env = lurk_sym_ptr!(s, nil);
-            s.intern_fun(arg, body, env)
+            s.intern_fun(params, body, env)
         };
         let terminal = s.get_cont_terminal();
         nova_test_full_aux::<_, _, M1<'_, _>>(
@@ -2326,7 +2361,7 @@ pub mod tests {
             None,
             Some(error),
             None,
-            3,
+            2,
             None,
         );
     }
@@ -2334,7 +2369,7 @@ pub mod tests {
     #[test]
     fn test_prove_zero_arg_lambda5() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.read("(123)").unwrap();
+        let expected = s.read("(:invalid-form 123)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(s, "(123)", Some(expected), None, Some(error), None, 1, None);
     }
@@ -2342,7 +2377,7 @@ pub mod tests {
     #[test]
     fn test_prove_zero_arg_lambda6() {
         let s = &mut Store::<Fr>::default();
-        let expected = s.num(123);
+        let expected = s.read("(:invalid-call . 123)").unwrap();
         let error = s.get_cont_error();
         test_aux::<_, _, M1<'_, _>>(
             s,
@@ -2576,7 +2611,7 @@ pub mod tests {
             None,
             Some(terminal),
             None,
-            242,
+            232,
             None,
         );
     }
@@ -2624,7 +2659,7 @@ pub mod tests {
             None,
             Some(terminal),
             None,
-            89,
+            83,
             5,
             false,
             None,
@@ -2692,7 +2727,7 @@ pub mod tests {
             None,
             Some(terminal),
             None,
-            39,
+            37,
             None,
         );
     }
@@ -2878,8 +2913,8 @@ pub mod tests {
     fn test_prove_car_cdr_invalid_tag_error_sym() {
         let s = &mut Store::<Fr>::default();
         let error = s.get_cont_error();
-        test_aux::<_, _, M1<'_, _>>(s, r#"(car car)"#, None, None, Some(error), None, 2, None);
-        test_aux::<_, _, M1<'_, _>>(s, r#"(cdr car)"#, None, None, Some(error), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, r#"(car car)"#, None, None, Some(error), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, r#"(cdr car)"#, None, None, Some(error), None, 3, None);
     }
 
     #[test]
@@ -3547,7 +3582,7 @@ pub mod tests {
         let res = s.num(6);
         let terminal = s.get_cont_terminal();
 
-        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 108, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 104, None);
     }
 
     #[test]
@@ -3561,7 +3596,7 @@ pub mod tests {
         let res = s.num(6);
         let terminal = s.get_cont_terminal();
 
-        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 152, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 108, None);
     }
 
     #[test]
@@ -3617,7 +3652,7 @@ pub mod tests {
         let expr = "(let ((a 1) (b 2)) c)";
 
         let error = s.get_cont_error();
-        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 7, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 9, None);
     }
 
     #[test]
@@ -3626,7 +3661,7 @@ pub mod tests {
         let expr = "(* 5 (eval '(+ 1 a) '((0 . 3))))"; // two-arg eval, optional second arg is env. This tests for error on malformed env.
         let error = s.get_cont_error();
 
-        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 8, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 10, None);
     }
 
     #[test]
@@ -3835,6 +3870,154 @@ pub mod tests {
         test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 3, None);
     }
 
+    #[test]
+    fn test_prove_test_i64_arithmetic() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(+ 9223372036854775807i64 1i64)";
+        let expr2 = "(- -5i64 3i64)";
+        let expr3 = "(* -5i64 3i64)";
+        let expr4 = "(/ 7i64 -2i64)";
+        let expr5 = "(% -7i64 2i64)";
+        let expr6 = "(% -6i64 2i64)";
+        let res = s.intern_i64(i64::MIN);
+        let res2 = s.intern_i64(-8);
+        let res3 = s.intern_i64(-15);
+        let res4 = s.intern_i64(-3);
+        let res5 = s.intern_i64(-1);
+        let res6 = s.intern_i64(0);
+        let terminal = s.get_cont_terminal();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res2), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, Some(res3), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, Some(res4), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr5, Some(res5), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr6, Some(res6), None, Some(terminal), None, 3, None);
+    }
+
+    #[test]
+    fn test_prove_test_i64_comp() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(< -1i64 1i64)";
+        let expr2 = "(>= -2i64 -1i64)";
+        let t = lurk_sym_ptr!(s, t);
+        let nil = lurk_sym_ptr!(s, nil);
+        let terminal = s.get_cont_terminal();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(t), None, Some(terminal), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(nil), None, Some(terminal), None, 3, None);
+    }
+
+    #[test]
+    fn test_prove_test_i64_conversion() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(i64 -5)";
+        let expr2 = "(num -5i64)";
+        let expr3 = "(u64 -1i64)";
+        let expr4 = "(+ 1i64 1)";
+        let res = s.intern_i64(-5);
+        let res2 = s.read("-5").unwrap();
+        let res3 = s.intern_u64(u64::MAX);
+        let terminal = s.get_cont_terminal();
+        let error = s.get_cont_error();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res2), None, Some(terminal), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, Some(res3), None, Some(terminal), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, None, None, Some(error), None, 3, None);
+    }
+
+    #[test]
+    fn test_prove_test_catch_throw() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(catch 1 (lambda (e) e))";
+        let expr2 = "(catch (throw 1) (lambda (e) e))";
+        let expr3 = "(catch (+ 1 (throw 41)) (lambda (e) (+ e 1)))";
+        let expr4 = "(throw 1)";
+        let expr5 = "(+ 1 (throw 2))";
+        let res = s.num(1);
+        let res3 = s.num(42);
+        let res5 = s.num(2);
+        let terminal = s.get_cont_terminal();
+        let error = s.get_cont_error();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res), None, Some(terminal), None, 7, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, Some(res3), None, Some(terminal), None, 13, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, Some(res), None, Some(error), None, 2, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr5, Some(res5), None, Some(error), None, 5, None);
+    }
+
+    #[test]
+    fn test_prove_test_catch_errors() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(catch (car 1) (lambda (e) e))";
+        let expr2 = "(catch (+ 1 (cdr 'x)) (lambda (e) (car e)))";
+        let expr3 = "(catch (foo) (lambda (e) e))";
+        let expr4 = "(car 1)";
+        let res = s.read("(:car-of-non-list . 1)").unwrap();
+        let res2 = s.key("cdr-of-non-list");
+        let res3 = s.read("(:unbound-var . foo)").unwrap();
+        let terminal = s.get_cont_terminal();
+        let error = s.get_cont_error();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 7, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res2), None, Some(terminal), None, 12, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, Some(res3), None, Some(terminal), None, 8, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, Some(res), None, Some(error), None, 2, None);
+    }
+
+    #[test]
+    fn test_prove_test_vectors() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(make-vector 3u64 0)";
+        let expr2 = "(vector-length (make-vector 3u64 0))";
+        let expr3 = "(vector-set (make-vector 2u64 0) 1u64 7)";
+        let expr4 = "(vector-ref (vector-set (make-vector 2u64 0) 1u64 7) 1u64)";
+        let expr5 = "(vector-ref (make-vector 2u64 0) 2u64)";
+        let res = s.intern_vec(&[s.num(0), s.num(0), s.num(0)]);
+        let res2 = s.uint64(3);
+        let res3 = s.intern_vec(&[s.num(0), s.num(7)]);
+        let res4 = s.num(7);
+        let terminal = s.get_cont_terminal();
+        let error = s.get_cont_error();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 9, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res2), None, Some(terminal), None, 10, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, Some(res3), None, Some(terminal), None, 17, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, Some(res4), None, Some(terminal), None, 21, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr5, None, None, Some(error), None, 10, None);
+    }
+
+    #[test]
+    fn test_prove_test_eval_with_limit() {
+        let s = &mut Store::<Fr>::default();
+
+        let expr = "(eval-with-limit (quote (+ 1 2)) nil 10u64)";
+        let expr2 = "(eval-with-limit (quote (+ 1 2)) nil 1u64)";
+        let expr3 = "(eval-with-limit 1 nil 1)";
+        let expr4 = "(eval-with-limit (quote (car 1)) nil 10u64)";
+        let expr5 = "(eval-with-limit (quote (eval-with-limit 1 nil 1u64)) nil 10u64)";
+        let res = s.read("(:ok 3)").unwrap();
+        let res2 = s.read("(:out-of-fuel 1)").unwrap();
+        let res4 = s.read("(:error (:car-of-non-list . 1))").unwrap();
+        let res5 = s.read("(:error 1)").unwrap();
+        let terminal = s.get_cont_terminal();
+        let error = s.get_cont_error();
+
+        test_aux::<_, _, M1<'_, _>>(s, expr, Some(res), None, Some(terminal), None, 12, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr2, Some(res2), None, Some(terminal), None, 11, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr3, None, None, Some(error), None, 8, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr4, Some(res4), None, Some(terminal), None, 11, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr5, Some(res5), None, Some(terminal), None, 17, None);
+    }
+
     #[test]
     fn test_prove_test_mod_by_zero_error() {
         let s = &mut Store::<Fr>::default();
@@ -3851,16 +4034,16 @@ pub mod tests {
         let expr = "(let ((a (lambda (x) (+ x 1)))) (a . 1))";
         let error = s.get_cont_error();
 
-        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 3, None);
+        test_aux::<_, _, M1<'_, _>>(s, expr, None, None, Some(error), None, 4, None);
     }
 
     #[test]
     fn test_prove_call_literal_fun() {
         let s = &mut Store::<Fr>::default();
         let empty_env = lurk_sym_ptr!(s, nil);
-        let arg = s.user_sym("x");
+        let params = s.list(&[s.user_sym("x")]);
         let body = s.read("((+ x 1))").unwrap();
-        let fun = s.intern_fun(arg, body, empty_env);
+        let fun = s.intern_fun(params, body, empty_env);
         let input = s.num(9);
         let expr = s.list(&[fun, input]);
         let res = s.num(10);
@@ -193,14 +193,33 @@ where
         z0: &[F],
         zi: &[F],
     ) -> Result<bool, SuperNovaError> {
-        let (z0_primary, _zi_primary) = (z0, zi);
+        let (z0_primary, zi_primary) = (z0, zi);
         let z0_secondary = Self::z0_secondary();
+        let zi_secondary = z0_secondary.clone();
 
-        match self {
+        let (zi_primary_verified, zi_secondary_verified) = match self {
             Self::Recursive(p) => p.verify(claim, z0_primary, &z0_secondary),
             Self::Compressed(_) => unimplemented!(),
         }?;
-        Ok(true)
+
+        Ok(zi_primary == zi_primary_verified && zi_secondary == zi_secondary_verified)
+    }
+
+    /// Verifies the proof against the running claim of the circuit with index
+    /// `circuit_index`, regenerating the running claims from `lang`.
+    pub fn verify_with_lang(
+        &self,
+        lang: Arc<Lang<F, C>>,
+        reduction_count: usize,
+        circuit_index: usize,
+        num_steps: usize,
+        z0: &[F],
+        zi: &[F],
+    ) -> Result<bool, SuperNovaError> {
+        let folding_config = Arc::new(FoldingConfig::new_nivc(lang, reduction_count));
+        let blank_step = NIVCStep::blank(folding_config, Meta::Lurk);
+        let running_claims = blank_step.setup_running_claims();
+        self.verify(&running_claims[circuit_index], None, num_steps, z0, zi)
     }
 
     fn z0_secondary() -> Vec<<F::G2 as Group>::Scalar> {
@@ -279,7 +298,7 @@ where
         &'a self,
         pp: Option<&PublicParams<F, C>>,
         frames: &[Frame<IO<F>, Witness<F>, F, C>],
-        store: &'a mut Store<F>,
+        store: &'a Store<F>,
         lang: Arc<Lang<F, C>>,
     ) -> Result<(Proof<F, C>, Vec<F>, Vec<F>, usize), ProofError> {
         let z0 = frames[0].input.to_vector(store)?;
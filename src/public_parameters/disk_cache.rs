@@ -1,16 +1,169 @@
-use std::fs::{create_dir_all, File};
-use std::io::{BufReader, BufWriter, Read};
+use std::fs::{create_dir_all, rename, File};
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::marker::PhantomData;
 
 use abomonation::{encode, Abomonation};
+use bincode::Options;
 use camino::{Utf8Path, Utf8PathBuf};
 use nova::traits::Group;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::coprocessor::Coprocessor;
 use crate::proof::nova::{CurveCycleEquipped, PublicParams, G1, G2};
 use crate::proof::MultiFrameTrait;
 use crate::public_parameters::error::Error;
 
+/// Identifies the files written by `PublicParamDiskCache::set_abomonated`
+const CACHE_MAGIC: [u8; 8] = *b"LURK-PP\0";
+
+/// Must be bumped whenever the header, the layout of the cached data or the circuits the
+/// public params are generated for change, since files are only told apart by this version,
+/// the Lurk version and the type of their contents
+const CACHE_FORMAT_VERSION: u32 = 4;
+
+/// Generous upper bound on the serialized size of a `CacheHeader`, so that
+/// garbage files can't trigger huge allocations
+const MAX_HEADER_SIZE: u64 = 4096;
+
+fn header_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(MAX_HEADER_SIZE)
+}
+
+/// The header written in front of abomonated public parameters.
+///
+/// Abomonated data is decoded in place, without any validation whatsoever, so a
+/// file produced by a different build or truncated on disk must be rejected
+/// before reaching `abomonation::decode`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CacheHeader {
+    magic: [u8; 8],
+    format_version: u32,
+    lurk_version: String,
+    arch: String,
+    type_name: String,
+    payload_len: u64,
+    checksum: [u8; 32],
+}
+
+impl CacheHeader {
+    fn new<V>(payload: &[u8]) -> Self {
+        Self {
+            magic: CACHE_MAGIC,
+            format_version: CACHE_FORMAT_VERSION,
+            lurk_version: env!("CARGO_PKG_VERSION").to_owned(),
+            arch: std::env::consts::ARCH.to_owned(),
+            type_name: std::any::type_name::<V>().to_owned(),
+            payload_len: payload.len() as u64,
+            checksum: Sha256::digest(payload).into(),
+        }
+    }
+
+    /// The type of the cached data
+    pub(crate) fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// The size of the cached data, in bytes
+    pub(crate) fn payload_len(&self) -> u64 {
+        self.payload_len
+    }
+
+    /// Checks that the file was written by a build compatible with the current one
+    fn check_build(&self) -> Result<(), String> {
+        if self.magic != CACHE_MAGIC {
+            return Err("not a Lurk public parameters file".into());
+        }
+        if self.format_version != CACHE_FORMAT_VERSION {
+            return Err(format!(
+                "format version {} doesn't match the expected {CACHE_FORMAT_VERSION}",
+                self.format_version
+            ));
+        }
+        let lurk_version = env!("CARGO_PKG_VERSION");
+        if self.lurk_version != lurk_version {
+            return Err(format!(
+                "written by Lurk {}, but this is Lurk {lurk_version}",
+                self.lurk_version
+            ));
+        }
+        let arch = std::env::consts::ARCH;
+        if self.arch != arch {
+            return Err(format!("written on {}, but this is {arch}", self.arch));
+        }
+        Ok(())
+    }
+
+    /// Checks that the file holds data of type `V`
+    fn check_type<V>(&self) -> Result<(), String> {
+        let type_name = std::any::type_name::<V>();
+        if self.type_name != type_name {
+            return Err(format!(
+                "holds a {}, but a {type_name} was expected",
+                self.type_name
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that `payload` is the data the header was computed for
+    fn check_payload(&self, payload: &[u8]) -> Result<(), String> {
+        if self.payload_len != payload.len() as u64 {
+            return Err(format!(
+                "expected {} bytes of data, found {} (truncated file?)",
+                self.payload_len,
+                payload.len()
+            ));
+        }
+        if self.checksum[..] != Sha256::digest(payload)[..] {
+            return Err("checksum mismatch".into());
+        }
+        Ok(())
+    }
+}
+
+/// Reads the header at the start of `reader` and checks it against the current build
+fn read_header<R: Read>(reader: &mut R, path: &Utf8Path) -> Result<CacheHeader, Error> {
+    let invalid = |reason| Error::InvalidCacheFile {
+        path: path.to_owned(),
+        reason,
+    };
+    let header: CacheHeader = header_options()
+        .deserialize_from(reader)
+        .map_err(|e| invalid(format!("missing or malformed header: {e}")))?;
+    header.check_build().map_err(invalid)?;
+    Ok(header)
+}
+
+/// Reads the cache file at `path`, checking its header against the current build
+/// and against its contents. Returns the header and the cached bytes.
+fn read_checked(path: &Utf8Path) -> Result<(CacheHeader, Vec<u8>), Error> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let header = read_header(&mut reader, path)?;
+    let mut payload = Vec::new();
+    reader.read_to_end(&mut payload)?;
+    header
+        .check_payload(&payload)
+        .map_err(|reason| Error::InvalidCacheFile {
+            path: path.to_owned(),
+            reason,
+        })?;
+    Ok((header, payload))
+}
+
+/// Reads the header of the cache file at `path`, checking it against the current
+/// build only. Use `verify_file` to check the contents of the file as well.
+pub(crate) fn inspect_file(path: &Utf8Path) -> Result<CacheHeader, Error> {
+    read_header(&mut BufReader::new(File::open(path)?), path)
+}
+
+/// Checks the integrity of the cache file at `path` without decoding its contents
+pub(crate) fn verify_file(path: &Utf8Path) -> Result<CacheHeader, Error> {
+    read_checked(path).map(|(header, _)| header)
+}
+
 pub(crate) struct PublicParamDiskCache<'a, F, C, M>
 where
     F: CurveCycleEquipped,
@@ -37,7 +190,7 @@ where
         })
     }
 
-    fn key_path(&self, key: &str) -> Utf8PathBuf {
+    pub(crate) fn key_path(&self, key: &str) -> Utf8PathBuf {
         self.dir.join(Utf8PathBuf::from(key))
     }
 
@@ -49,12 +202,16 @@ where
         })
     }
 
-    pub(crate) fn get_raw_bytes(&self, key: &str) -> Result<Vec<u8>, Error> {
-        let file = File::open(self.key_path(key))?;
-        let mut reader = BufReader::new(file);
-        let mut bytes = Vec::new();
-        reader.read_to_end(&mut bytes)?;
-        Ok(bytes)
+    /// Returns the abomonated bytes of a `V` stored under `key`, which are only
+    /// handed out if the file header matches the current build, the type `V` and
+    /// the contents of the file. Only then is it sound to `decode` them.
+    pub(crate) fn get_abomonated_bytes<V: Abomonation>(&self, key: &str) -> Result<Vec<u8>, Error> {
+        let path = self.key_path(key);
+        let (header, payload) = read_checked(&path)?;
+        header
+            .check_type::<V>()
+            .map_err(|reason| Error::InvalidCacheFile { path, reason })?;
+        Ok(payload)
     }
 
     pub(crate) fn set(&self, key: &str, data: &PublicParams<F, M>) -> Result<(), Error> {
@@ -65,9 +222,22 @@ where
         })
     }
 
+    /// Abomonates `data` under `key`, preceded by a `CacheHeader`. The file is
+    /// written to a temporary location first, so an interrupted write never
+    /// leaves a partial file behind.
     pub(crate) fn set_abomonated<V: Abomonation>(&self, key: &str, data: &V) -> Result<(), Error> {
-        let mut file = File::create(self.key_path(key))?;
-        unsafe { encode(data, &mut file).expect("failed to encode") };
+        let mut payload = Vec::new();
+        unsafe { encode(data, &mut payload)? };
+        let header = CacheHeader::new::<V>(&payload);
+
+        let tmp_path = self.key_path(&format!("{key}.tmp"));
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        header_options()
+            .serialize_into(&mut writer, &header)
+            .map_err(|e| Error::CacheError(format!("Header serialization error: {e}")))?;
+        writer.write_all(&payload)?;
+        writer.flush()?;
+        rename(tmp_path, self.key_path(key))?;
         Ok(())
     }
 }
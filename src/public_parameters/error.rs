@@ -1,3 +1,4 @@
+use camino::Utf8PathBuf;
 use std::io;
 use thiserror::Error;
 
@@ -8,6 +9,8 @@ pub enum Error {
     IOError(#[from] io::Error),
     #[error("Cache error: {0}")]
     CacheError(String),
+    #[error("Invalid public params cache file {path}: {reason}")]
+    InvalidCacheFile { path: Utf8PathBuf, reason: String },
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 }
@@ -1,5 +1,6 @@
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    any::TypeId,
+    collections::{HashMap, VecDeque},
     sync::{Arc, Mutex},
 };
 
@@ -20,22 +21,117 @@ use crate::{proof::nova::CurveCycleEquipped, public_parameters::error::Error};
 
 use super::disk_cache::PublicParamDiskCache;
 
+/// The number of public parameters kept in memory by default
+pub(crate) const DEFAULT_MEM_CACHE_CAPACITY: usize = 4;
+
 type AnyMap = anymap::Map<dyn core::any::Any + Send + Sync>;
-type PublicParamMap<F, M> = HashMap<(usize, bool), Arc<PublicParams<F, M>>>;
+/// Public parameters are identified by their reduction count and language key
+type ParamKey = (usize, String);
+
+/// Bookkeeping for an entry of one of the typed maps in the `AnyMap`, so that it
+/// can be evicted without knowing its type
+struct LruEntry {
+    type_id: TypeId,
+    key: ParamKey,
+    remove: fn(&mut AnyMap, &ParamKey),
+}
+
+fn remove_entry<P: Send + Sync + 'static>(params: &mut AnyMap, key: &ParamKey) {
+    if let Some(map) = params.get_mut::<HashMap<ParamKey, Arc<P>>>() {
+        map.remove(key);
+    }
+}
+
+struct MemCacheState {
+    params: AnyMap,
+    /// Cached entries, from the least to the most recently used
+    lru: VecDeque<LruEntry>,
+    capacity: usize,
+}
+
+impl MemCacheState {
+    /// Returns the cached value of type `P` for `key`, marking it as the most
+    /// recently used entry
+    fn get<P: Send + Sync + 'static>(&mut self, key: &ParamKey) -> Option<Arc<P>> {
+        let value = self
+            .params
+            .get::<HashMap<ParamKey, Arc<P>>>()
+            .and_then(|map| map.get(key))
+            .cloned()?;
+        let type_id = TypeId::of::<P>();
+        if let Some(pos) = self
+            .lru
+            .iter()
+            .position(|e| e.type_id == type_id && &e.key == key)
+        {
+            let entry = self.lru.remove(pos).unwrap();
+            self.lru.push_back(entry);
+        }
+        Some(value)
+    }
+
+    /// Caches `value` as the most recently used entry, replacing the one for `key`
+    /// if any, and evicting the least recently used ones if the capacity is exceeded
+    fn insert<P: Send + Sync + 'static>(&mut self, key: ParamKey, value: Arc<P>) {
+        self.params
+            .entry::<HashMap<ParamKey, Arc<P>>>()
+            .or_default()
+            .insert(key.clone(), value);
+        let type_id = TypeId::of::<P>();
+        self.lru
+            .retain(|entry| entry.type_id != type_id || entry.key != key);
+        self.lru.push_back(LruEntry {
+            type_id,
+            key,
+            remove: remove_entry::<P>,
+        });
+        self.shrink();
+    }
+
+    /// Drops the entries for `rc` and `lang_key`, returning whether there were any
+    fn evict(&mut self, rc: usize, lang_key: &str) -> bool {
+        let Self { params, lru, .. } = self;
+        let len = lru.len();
+        lru.retain(|entry| {
+            let matches = entry.key.0 == rc && entry.key.1 == lang_key;
+            if matches {
+                (entry.remove)(params, &entry.key);
+            }
+            !matches
+        });
+        lru.len() != len
+    }
+
+    /// Drops the least recently used entries until the capacity is respected
+    fn shrink(&mut self) {
+        while self.lru.len() > self.capacity {
+            let entry = self.lru.pop_front().unwrap();
+            info!(
+                "evicting public params for rc = {}, lang = {}",
+                entry.key.0, entry.key.1
+            );
+            (entry.remove)(&mut self.params, &entry.key);
+        }
+    }
+}
 
 /// This is a global registry for Coproc-specific parameters.
 /// It is used to cache parameters for each Coproc, so that they are not
 /// re-initialized on each call to `eval`.
 /// The use of AnyMap is a workaround for the fact that we need static storage for generic parameters,
 /// noting that this is not possible in Rust.
-#[derive(Clone)]
+/// At most `capacity` parameters are held at once, evicting the least recently used ones.
 pub(crate) struct PublicParamMemCache {
-    mem_cache: Arc<Mutex<AnyMap>>,
+    mem_cache: Mutex<MemCacheState>,
 }
 
 pub(crate) static PUBLIC_PARAM_MEM_CACHE: Lazy<PublicParamMemCache> =
     Lazy::new(|| PublicParamMemCache {
-        mem_cache: Arc::new(Mutex::new(AnyMap::new())),
+        mem_cache: Mutex::new(MemCacheState {
+            params: AnyMap::new(),
+            lru: VecDeque::new(),
+            capacity: DEFAULT_MEM_CACHE_CAPACITY,
+        }),
     });
 
 impl PublicParamMemCache {
@@ -67,26 +163,31 @@ impl PublicParamMemCache {
         let key = format!("public-params-rc-{rc}-coproc-{lang_key}{quick_suffix}");
         // read the file if it exists, otherwise initialize
         if abomonated {
-            match disk_cache.get_raw_bytes(&key) {
+            match disk_cache.get_abomonated_bytes::<PublicParams<F, M>>(&key) {
                 Ok(mut bytes) => {
                     info!("loading abomonated {lang_key}");
-                    let (pp, rest) = unsafe { decode::<PublicParams<F, M>>(&mut bytes).unwrap() };
-                    assert!(rest.is_empty());
-                    Ok(Arc::new(pp.clone())) // this clone is VERY expensive
+                    match unsafe { decode::<PublicParams<F, M>>(&mut bytes) } {
+                        // this clone is VERY expensive
+                        Some((pp, rest)) if rest.is_empty() => return Ok(Arc::new(pp.clone())),
+                        _ => warn!(
+                            "failed to decode public params from {}, regenerating them",
+                            disk_cache.key_path(&key)
+                        ),
+                    }
                 }
-                Err(Error::IOError(e)) => {
-                    warn!("{e}");
-                    info!("Generating fresh public parameters");
-                    let pp = default(lang);
-                    // maybe just directly write
-                    disk_cache
-                        .set_abomonated(&key, &*pp)
-                        .tap_ok(|_| info!("writing public params to disk-cache: {}", lang_key))
-                        .map_err(|e| Error::CacheError(format!("Disk write error: {}", e)))?;
-                    Ok(pp)
-                }
-                _ => unreachable!(),
+                Err(Error::IOError(e)) => warn!("{e}"),
+                // the header doesn't match this build or the file is corrupted, so decoding
+                // it would be unsound: the file is overwritten with fresh params instead
+                Err(e) => warn!("{e}, regenerating it"),
             }
+            info!("Generating fresh public parameters");
+            let pp = default(lang);
+            // maybe just directly write
+            disk_cache
+                .set_abomonated(&key, &*pp)
+                .tap_ok(|_| info!("writing public params to disk-cache: {}", lang_key))
+                .map_err(|e| Error::CacheError(format!("Disk write error: {}", e)))?;
+            Ok(pp)
         } else {
             // read the file if it exists, otherwise initialize
             if let Ok(pp) = disk_cache.get(&key) {
@@ -124,25 +225,90 @@ impl PublicParamMemCache {
         <<G1<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
         <<G2<F> as Group>::Scalar as ff::PrimeField>::Repr: Abomonation,
     {
+        let key = (rc, lang.key());
         // re-grab the lock
         let mut mem_cache = self.mem_cache.lock().unwrap();
-        // retrieve the per-Coproc public param table
-        let entry = mem_cache.entry::<PublicParamMap<F, M>>();
-        // deduce the map and populate it if needed
-        let param_entry = entry.or_default();
-        match param_entry.entry((rc, abomonated)) {
-            Entry::Occupied(o) => Ok(o.into_mut()),
-            Entry::Vacant(v) => {
-                let val = self.get_from_disk_cache_or_update_with(
-                    rc,
-                    true,
-                    default,
-                    lang,
-                    disk_cache_path,
-                )?;
-                Ok(v.insert(val))
-            }
+        if let Some(pp) = mem_cache.get::<PublicParams<F, M>>(&key) {
+            return Ok(pp);
+        }
+        let pp = self.get_from_disk_cache_or_update_with(
+            rc,
+            abomonated,
+            default,
+            lang,
+            disk_cache_path,
+        )?;
+        mem_cache.insert(key, pp.clone());
+        Ok(pp)
+    }
+
+    /// Sets the maximum number of public parameters kept in memory, evicting the
+    /// least recently used ones if needed
+    pub(crate) fn set_capacity(&self, capacity: usize) {
+        let mut mem_cache = self.mem_cache.lock().unwrap();
+        mem_cache.capacity = capacity;
+        mem_cache.shrink();
+    }
+
+    /// Drops the public parameters for reduction count `rc` and language key
+    /// `lang_key`, returning whether any were cached
+    pub(crate) fn evict(&self, rc: usize, lang_key: &str) -> bool {
+        self.mem_cache.lock().unwrap().evict(rc, lang_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(capacity: usize) -> MemCacheState {
+        MemCacheState {
+            params: AnyMap::new(),
+            lru: VecDeque::new(),
+            capacity,
         }
-        .cloned() // this clone is VERY expensive
+    }
+
+    fn key(rc: usize) -> ParamKey {
+        (rc, "none".into())
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut state = state(2);
+        state.insert(key(1), Arc::new(1u32));
+        state.insert(key(2), Arc::new(2u32));
+        // touching the first entry makes the second one the least recently used
+        assert_eq!(state.get::<u32>(&key(1)).as_deref(), Some(&1));
+        state.insert(key(3), Arc::new(3u32));
+        assert_eq!(state.get::<u32>(&key(1)).as_deref(), Some(&1));
+        assert_eq!(state.get::<u32>(&key(2)), None);
+        assert_eq!(state.get::<u32>(&key(3)).as_deref(), Some(&3));
+
+        // entries of different types share the capacity
+        state.insert(key(1), Arc::new(1u64));
+        assert_eq!(state.lru.len(), 2);
+        assert_eq!(state.get::<u32>(&key(1)), None);
+        assert_eq!(state.get::<u64>(&key(1)).as_deref(), Some(&1));
+
+        // replacing an entry doesn't take more room
+        state.insert(key(1), Arc::new(10u64));
+        assert_eq!(state.lru.len(), 2);
+        assert_eq!(state.get::<u64>(&key(1)).as_deref(), Some(&10));
+        assert_eq!(state.get::<u32>(&key(3)).as_deref(), Some(&3));
+    }
+
+    #[test]
+    fn evicts_by_key() {
+        let mut state = state(4);
+        state.insert(key(1), Arc::new(1u32));
+        state.insert(key(1), Arc::new(1u64));
+        state.insert(key(2), Arc::new(2u32));
+        assert!(state.evict(1, "none"));
+        assert!(!state.evict(1, "none"));
+        assert_eq!(state.lru.len(), 1);
+        assert_eq!(state.get::<u32>(&key(1)), None);
+        assert_eq!(state.get::<u64>(&key(1)), None);
+        assert_eq!(state.get::<u32>(&key(2)).as_deref(), Some(&2));
     }
 }
@@ -11,7 +11,7 @@ use crate::{
     proof::nova::{self, PublicParams},
 };
 
-mod disk_cache;
+pub(crate) mod disk_cache;
 pub mod error;
 mod mem_cache;
 
@@ -55,6 +55,19 @@ where
     )
 }
 
+/// Sets the maximum number of public parameters kept in memory by `public_params`,
+/// evicting the least recently used ones if needed. Defaults to 4.
+pub fn set_mem_cache_capacity(capacity: usize) {
+    mem_cache::PUBLIC_PARAM_MEM_CACHE.set_capacity(capacity)
+}
+
+/// Drops the public parameters for reduction count `rc` and language key `lang_key`
+/// (see `Lang::key`) from memory, returning whether any were cached. The disk
+/// cache is left untouched.
+pub fn evict_public_params(rc: usize, lang_key: &str) -> bool {
+    mem_cache::PUBLIC_PARAM_MEM_CACHE.evict(rc, lang_key)
+}
+
 /// Attempts to extract abomonated public parameters.
 /// To avoid all copying overhead, we zerocopy all of the data within the file;
 /// this leads to extremely high performance, but restricts the lifetime of the data
@@ -80,34 +93,28 @@ where
     // for this lang/coprocessor.
     let key = format!("public-params-rc-{rc}-coproc-{lang_key}-abomonated");
 
-    match disk_cache.get_raw_bytes(&key) {
-        Ok(mut bytes) => {
-            if let Some((pp, remaining)) = unsafe { decode(&mut bytes) } {
-                assert!(remaining.is_empty());
+    match disk_cache.get_abomonated_bytes::<PublicParams<F, M>>(&key) {
+        Ok(mut bytes) => match unsafe { decode::<PublicParams<F, M>>(&mut bytes) } {
+            Some((pp, remaining)) if remaining.is_empty() => {
                 eprintln!("Using disk-cached public params for lang {lang_key}");
-                Ok(bind(pp))
-            } else {
-                eprintln!("failed to decode bytes");
-                let pp = nova::public_params(rc, lang);
-                let mut bytes = Vec::new();
-                unsafe { abomonation::encode(&pp, &mut bytes)? };
-                // maybe just directly write
-                disk_cache
-                    .set_abomonated(&key, &pp)
-                    .map_err(|e| Error::CacheError(format!("Disk write error: {}", e)))?;
-                Ok(bind(&pp))
+                return Ok(bind(pp));
             }
-        }
-        Err(e) => {
-            eprintln!("{e}");
-            let pp = nova::public_params(rc, lang);
-            // maybe just directly write
-            disk_cache
-                .set_abomonated(&key, &pp)
-                .map_err(|e| Error::CacheError(format!("Disk write error: {}", e)))?;
-            Ok(bind(&pp))
-        }
+            _ => eprintln!(
+                "Failed to decode public params from {}, regenerating them",
+                disk_cache.key_path(&key)
+            ),
+        },
+        Err(Error::IOError(e)) => eprintln!("{e}"),
+        // the header doesn't match this build or the file is corrupted, so decoding
+        // it would be unsound: the file is overwritten with fresh params instead
+        Err(e) => eprintln!("{e}, regenerating it"),
     }
+    let pp = nova::public_params(rc, lang);
+    // maybe just directly write
+    disk_cache
+        .set_abomonated(&key, &pp)
+        .map_err(|e| Error::CacheError(format!("Disk write error: {}", e)))?;
+    Ok(bind(&pp))
 }
 
 #[cfg(test)]
@@ -132,7 +139,62 @@ mod tests {
             public_params::<S1, Coproc<S1>, OG>(10, true, lang.clone(), &public_params_dir)
                 .unwrap();
         // With disk cache, reads from tmpfile
+        let _public_params =
+            public_params::<S1, Coproc<S1>, OG>(10, true, lang.clone(), &public_params_dir)
+                .unwrap();
+
+        // A corrupted file is regenerated instead of being decoded
+        let path = public_params_dir.join(format!(
+            "public-params-rc-10-coproc-{}-abomonated",
+            lang.key()
+        ));
+        let file_bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &file_bytes[..file_bytes.len() - 8]).unwrap();
+        evict_public_params(10, &lang.key());
         let _public_params =
             public_params::<S1, Coproc<S1>, OG>(10, true, lang, &public_params_dir).unwrap();
+        assert!(disk_cache::verify_file(&path).is_ok());
+    }
+
+    #[test]
+    fn abomonated_cache_rejects_invalid_files() {
+        let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+        let public_params_dir = Utf8Path::from_path(tmp_dir.path())
+            .unwrap()
+            .join("public_params");
+
+        type OG = crate::proof::nova::C1<'static, S1, Coproc<S1>>;
+        let disk_cache =
+            disk_cache::PublicParamDiskCache::<S1, Coproc<S1>, OG>::new(&public_params_dir)
+                .unwrap();
+        let data: Vec<u64> = (0..100).collect();
+        disk_cache.set_abomonated("data", &data).unwrap();
+
+        let mut bytes = disk_cache.get_abomonated_bytes::<Vec<u64>>("data").unwrap();
+        let (decoded, rest) = unsafe { decode::<Vec<u64>>(&mut bytes) }.unwrap();
+        assert_eq!(decoded, &data);
+        assert!(rest.is_empty());
+
+        // wrong type
+        assert!(matches!(
+            disk_cache.get_abomonated_bytes::<Vec<u32>>("data"),
+            Err(Error::InvalidCacheFile { .. })
+        ));
+
+        // truncated file
+        let path = disk_cache.key_path("data");
+        let file_bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &file_bytes[..file_bytes.len() - 8]).unwrap();
+        assert!(matches!(
+            disk_cache.get_abomonated_bytes::<Vec<u64>>("data"),
+            Err(Error::InvalidCacheFile { .. })
+        ));
+
+        // headerless file
+        std::fs::write(&path, [0u8; 64]).unwrap();
+        assert!(matches!(
+            disk_cache.get_abomonated_bytes::<Vec<u64>>("data"),
+            Err(Error::InvalidCacheFile { .. })
+        ));
     }
 }
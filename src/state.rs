@@ -5,6 +5,7 @@
 
 use anyhow::{bail, Result};
 use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::Symbol;
@@ -12,7 +13,7 @@ use crate::Symbol;
 use super::package::{Package, SymbolRef};
 
 /// Keeps track of the current package for symbol resolution when reading and printing
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct State {
     current_package: SymbolRef,
     symbol_packages: HashMap<SymbolRef, Package>,
@@ -175,6 +176,27 @@ impl State {
         state.add_package(meta_package);
         state
     }
+
+    /// Returns the packages that aren't part of the initial Lurk state, along with
+    /// the built-in ones that were changed, e.g. by interning or importing symbols
+    pub fn user_packages(&self) -> Vec<&Package> {
+        let builtin_packages = &initial_lurk_state().symbol_packages;
+        self.symbol_packages
+            .values()
+            .filter(|package| builtin_packages.get(package.name()) != Some(*package))
+            .collect()
+    }
+
+    /// Rebuilds a state from the initial Lurk state and the packages returned by
+    /// `user_packages`, which replace the built-in ones with the same names
+    pub fn from_user_packages(packages: Vec<Package>, current_package: SymbolRef) -> Result<Self> {
+        let mut state = Self::init_lurk_state();
+        for package in packages {
+            state.add_package(package);
+        }
+        state.set_current_package(current_package)?;
+        Ok(state)
+    }
 }
 
 impl Default for State {
@@ -215,7 +237,7 @@ const LURK_PACKAGE_SYMBOL_NAME: &str = "lurk";
 const USER_PACKAGE_SYMBOL_NAME: &str = "user";
 const META_PACKAGE_SYMBOL_NAME: &str = "meta";
 
-const LURK_PACKAGE_SYMBOLS_NAMES: [&str; 36] = [
+const LURK_PACKAGE_SYMBOLS_NAMES: [&str; 67] = [
     "atom",
     "begin",
     "car",
@@ -236,6 +258,7 @@ const LURK_PACKAGE_SYMBOLS_NAMES: [&str; 36] = [
     "nil",
     "num",
     "u64",
+    "i64",
     "open",
     "quote",
     "secret",
@@ -251,12 +274,44 @@ const LURK_PACKAGE_SYMBOLS_NAMES: [&str; 36] = [
     ">",
     "<=",
     ">=",
+    "logand",
+    "logior",
+    "logxor",
+    "lognot",
+    "shift-left",
+    "shift-right",
+    "char->u64",
+    "u64->char",
+    "symbol->string",
+    "string->symbol",
+    "throw",
+    "catch",
+    "list",
+    "make-vector",
+    "vector-ref",
+    "vector-set",
+    "vector-length",
+    "eval-with-limit",
+    "string-length",
+    "string-append",
+    "substring",
+    "string->list",
+    "list->string",
+    "cond",
+    "and",
+    "or",
+    "when",
+    "unless",
+    "let*",
+    "&rest",
     "_",
 ];
 
-const META_PACKAGE_SYMBOLS_NAMES: [&str; 19] = [
+const META_PACKAGE_SYMBOLS_NAMES: [&str; 21] = [
     "def",
     "defrec",
+    "defmacro",
+    "macroexpand",
     "load",
     "assert",
     "assert-eq",
@@ -325,4 +380,29 @@ pub mod test {
             "my-other-symbol",
         );
     }
+
+    #[test]
+    fn test_user_packages_roundtrip() {
+        let mut state = State::init_lurk_state();
+        let user_sym = state.intern("user-sym");
+
+        let my_package_name = SymbolRef::new(Symbol::sym(&["my-package"]));
+        let mut my_package = Package::new(my_package_name.clone());
+        let my_symbol = my_package.intern("my-symbol".into());
+        state.add_package(my_package);
+        let user_package_name = state.get_current_package_name().clone();
+        state.set_current_package(my_package_name.clone()).unwrap();
+
+        // only `my-package` and the changed `.lurk.user` are kept, even though
+        // `.lurk.user` isn't the current package anymore
+        let packages = state.user_packages();
+        assert_eq!(packages.len(), 2);
+        let packages = packages.into_iter().cloned().collect();
+
+        let mut state = State::from_user_packages(packages, my_package_name).unwrap();
+        test_printing_helper(&state, my_symbol, "my-symbol");
+        state.set_current_package(user_package_name).unwrap();
+        test_printing_helper(&state, user_sym, "user-sym");
+        test_printing_helper(&state, SymbolRef::new(lurk_sym("lambda")), "lambda");
+    }
 }
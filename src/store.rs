@@ -41,10 +41,12 @@ pub struct Store<F: LurkField> {
 
     /// Holds a Str, which is a char head and a string tail
     pub str_store: IndexSet<Box<(Ptr<F>, Ptr<F>)>>,
+    /// Holds a Vec node, which is a length and a content
+    pub vec_store: IndexSet<Box<(Ptr<F>, Ptr<F>)>>,
     pub thunk_store: IndexSet<Box<Thunk<F>>>,
     pub call0_store: IndexSet<Box<(Ptr<F>, ContPtr<F>)>>,
     pub call_store: IndexSet<Box<(Ptr<F>, Ptr<F>, ContPtr<F>)>>,
-    pub call2_store: IndexSet<Box<(Ptr<F>, Ptr<F>, ContPtr<F>)>>,
+    pub call2_store: IndexSet<Box<(Ptr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>>,
     pub tail_store: IndexSet<Box<(Ptr<F>, ContPtr<F>)>>,
     pub lookup_store: IndexSet<Box<(Ptr<F>, ContPtr<F>)>>,
     pub unop_store: IndexSet<Box<(Op1, ContPtr<F>)>>,
@@ -54,6 +56,8 @@ pub struct Store<F: LurkField> {
     pub let_store: IndexSet<Box<(Ptr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>>,
     pub letrec_store: IndexSet<Box<(Ptr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>>,
     pub emit_store: IndexSet<Box<ContPtr<F>>>,
+    pub vector_store: IndexSet<Box<(Op2, Ptr<F>, Ptr<F>, ContPtr<F>)>>,
+    pub fuel_store: IndexSet<Box<(ContPtr<F>, Ptr<F>, Ptr<F>, ContPtr<F>)>>,
 
     /// Holds opaque pointers
     pub opaque_ptrs: IndexSet<Box<ZExprPtr<F>>>,
@@ -92,6 +96,7 @@ impl<F: LurkField> Default for Store<F> {
             num_store: Default::default(),
             fun_store: Default::default(),
             str_store: Default::default(),
+            vec_store: Default::default(),
             thunk_store: Default::default(),
             call0_store: Default::default(),
             call_store: Default::default(),
@@ -105,6 +110,8 @@ impl<F: LurkField> Default for Store<F> {
             let_store: Default::default(),
             letrec_store: Default::default(),
             emit_store: Default::default(),
+            vector_store: Default::default(),
+            fuel_store: Default::default(),
             opaque_ptrs: Default::default(),
             opaque_cont_ptrs: Default::default(),
             z_expr_ptr_map: Default::default(),
@@ -309,6 +316,41 @@ impl<F: LurkField> Store<F> {
         ptr
     }
 
+    /// Interns a node of a vector, made of its length and its content. The content of an empty
+    /// vector is nil. Otherwise, the content of a vector of odd length is its first element
+    /// consed onto the vector of the others, and that of a vector of even length is the vector
+    /// of its elements at even indices consed onto the vector of those at odd indices.
+    pub fn intern_vec_node(&self, len: Ptr<F>, content: Ptr<F>) -> Ptr<F> {
+        if len.is_opaque() || content.is_opaque() {
+            self.hash_expr(&len);
+            self.hash_expr(&content);
+        }
+
+        let (p, inserted) = self.vec_store.insert_probe(Box::new((len, content)));
+        let ptr = Ptr::index(ExprTag::Vec, p);
+        if inserted {
+            self.dehydrated.load().push(Box::new(ptr));
+        }
+        ptr
+    }
+
+    /// Interns the vector of `elts`, whose elements are reached in a number of steps
+    /// logarithmic in its length. See `Store::intern_vec_node()`.
+    pub fn intern_vec(&self, elts: &[Ptr<F>]) -> Ptr<F> {
+        let content = match elts {
+            [] => lurk_sym_ptr!(self, nil),
+            [first, rest @ ..] if elts.len() % 2 == 1 => {
+                self.intern_cons(*first, self.intern_vec(rest))
+            }
+            _ => {
+                let evens: Vec<_> = elts.iter().step_by(2).copied().collect();
+                let odds: Vec<_> = elts.iter().skip(1).step_by(2).copied().collect();
+                self.intern_cons(self.intern_vec(&evens), self.intern_vec(&odds))
+            }
+        };
+        self.intern_vec_node(self.intern_u64(elts.len() as u64), content)
+    }
+
     pub fn intern_strcons(&self, car: Ptr<F>, cdr: Ptr<F>) -> Ptr<F> {
         if car.is_opaque() || cdr.is_opaque() {
             self.hash_expr(&car);
@@ -523,6 +565,11 @@ impl<F: LurkField> Store<F> {
         Ptr::index(ExprTag::U64, n as usize)
     }
 
+    /// Interns an `i64`, which is represented by the `u64` of same bits
+    pub fn intern_i64(&self, n: i64) -> Ptr<F> {
+        Ptr::index(ExprTag::I64, n as u64 as usize)
+    }
+
     pub fn intern_string(&self, s: &str) -> Ptr<F> {
         match self.str_cache.get(s) {
             Some(ptr) => *ptr,
@@ -536,12 +583,17 @@ impl<F: LurkField> Store<F> {
         }
     }
 
-    pub fn intern_fun(&self, arg: Ptr<F>, body: Ptr<F>, closed_env: Ptr<F>) -> Ptr<F> {
+    /// Interns a function of the whole list of its parameters, even if there's only one of them.
+    /// This encoding isn't the one of Lurk 0.2, see `notes/reduction-notes.md`.
+    pub fn intern_fun(&self, params: Ptr<F>, body: Ptr<F>, closed_env: Ptr<F>) -> Ptr<F> {
         // TODO: closed_env must be an env
-        assert!(matches!(arg.tag, ExprTag::Sym), "ARG must be a symbol");
+        assert!(
+            matches!(params.tag, ExprTag::Nil | ExprTag::Cons),
+            "PARAMS must be a list"
+        );
         let (p, inserted) = self
             .fun_store
-            .insert_probe(Box::new((arg, body, closed_env)));
+            .insert_probe(Box::new((params, body, closed_env)));
         let ptr = Ptr::index(ExprTag::Fun, p);
         if inserted {
             self.dehydrated.load().push(Box::new(ptr));
@@ -595,6 +647,15 @@ impl<F: LurkField> Store<F> {
         ])
     }
 
+    /// The env standing in place of the real one while a value is being thrown.
+    /// No value is a thunk, so programs can't build it.
+    pub fn intern_thrown_env(&self) -> Ptr<F> {
+        self.intern_thunk(Thunk {
+            value: lurk_sym_ptr!(self, nil),
+            continuation: self.intern_cont_error(),
+        })
+    }
+
     pub fn intern_cont_error(&self) -> ContPtr<F> {
         self.mark_dehydrated_cont(self.get_cont_error())
     }
@@ -723,6 +784,15 @@ impl<F: LurkField> Store<F> {
         }
     }
 
+    pub fn fetch_vec_node(&self, ptr: &Ptr<F>) -> Option<&(Ptr<F>, Ptr<F>)> {
+        debug_assert!(matches!(ptr.tag, ExprTag::Vec));
+        if ptr.raw.is_opaque() {
+            None
+        } else {
+            self.vec_store.get_index(ptr.raw.idx()?)
+        }
+    }
+
     pub fn fetch_comm(&self, ptr: &Ptr<F>) -> Option<&(FWrap<F>, Ptr<F>)> {
         debug_assert!(matches!(ptr.tag, ExprTag::Comm));
         if ptr.raw.is_opaque() {
@@ -751,6 +821,11 @@ impl<F: LurkField> Store<F> {
         }
     }
 
+    pub fn fetch_i64(&self, ptr: &Ptr<F>) -> Option<i64> {
+        debug_assert!(matches!(ptr.tag, ExprTag::I64));
+        Some(ptr.raw.idx()? as u64 as i64)
+    }
+
     pub fn fetch(&self, ptr: &Ptr<F>) -> Option<Expression<F>> {
         if ptr.is_opaque() {
             return None;
@@ -778,6 +853,10 @@ impl<F: LurkField> Store<F> {
                 .map(|(car, cdr)| Expression::Str(car, cdr)),
             ExprTag::Char => self.fetch_char(ptr).map(Expression::Char),
             ExprTag::U64 => self.fetch_uint(ptr).map(Expression::UInt),
+            ExprTag::I64 => self.fetch_i64(ptr).map(Expression::I64),
+            ExprTag::Vec => self
+                .fetch_vec_node(ptr)
+                .map(|(len, content)| Expression::Vec(*len, *content)),
             ExprTag::Cproc => unreachable!("Lurk Alpha doesn't produce such expressions"),
         }
     }
@@ -803,10 +882,51 @@ impl<F: LurkField> Store<F> {
         Some(list)
     }
 
+    /// Returns a `Vec` of `Ptr`s representing the elements of a vector, `ptr`.
+    /// This is intended to be the inverse of `Store::intern_vec()`.
+    /// IF `ptr` isn't a well-formed vector, return None.
+    pub fn fetch_vec(&self, ptr: &Ptr<F>) -> Option<Vec<Ptr<F>>> {
+        match self.fetch(ptr)? {
+            Expression::Vec(len, content) => self.fetch_vec_elements(&len, &content),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of the vector node of length `len` and content `content`
+    pub fn fetch_vec_elements(&self, len: &Ptr<F>, content: &Ptr<F>) -> Option<Vec<Ptr<F>>> {
+        if len.tag != ExprTag::U64 {
+            return None;
+        }
+        let len = u64::from(self.fetch_uint(len)?);
+        match self.fetch(content)? {
+            Expression::Nil if len == 0 => Some(Vec::new()),
+            Expression::Cons(first, rest) if len % 2 == 1 => {
+                let mut elts = vec![first];
+                elts.extend(self.fetch_vec(&rest)?);
+                (elts.len() as u64 == len).then_some(elts)
+            }
+            Expression::Cons(evens, odds) if len > 0 => {
+                let evens = self.fetch_vec(&evens)?;
+                let odds = self.fetch_vec(&odds)?;
+                if evens.len() as u64 != len / 2 || odds.len() as u64 != len / 2 {
+                    return None;
+                }
+                Some(
+                    evens
+                        .into_iter()
+                        .zip(odds)
+                        .flat_map(|(e, o)| [e, o])
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
     pub fn fetch_cont(&self, ptr: &ContPtr<F>) -> Option<Continuation<F>> {
         use ContTag::{
-            Binop, Binop2, Call, Call0, Call2, Cproc, Dummy, Emit, Error, If, Let, LetRec, Lookup,
-            Outermost, Tail, Terminal, Unop,
+            Binop, Binop2, Call, Call0, Call2, Cproc, Dummy, Emit, Error, Fuel, If, Let, LetRec,
+            Lookup, Outermost, Tail, Terminal, Unop, Vector,
         };
         match ptr.tag {
             Outermost => Some(Continuation::Outermost),
@@ -821,19 +941,19 @@ impl<F: LurkField> Store<F> {
                 .call_store
                 .get_index(ptr.raw.idx()?)
                 .map(|(a, b, c)| Continuation::Call {
-                    unevaled_arg: *a,
+                    unevaled_args: *a,
                     saved_env: *b,
                     continuation: *c,
                 }),
-            Call2 => {
-                self.call2_store
-                    .get_index(ptr.raw.idx()?)
-                    .map(|(a, b, c)| Continuation::Call2 {
-                        function: *a,
-                        saved_env: *b,
-                        continuation: *c,
-                    })
-            }
+            Call2 => self
+                .call2_store
+                .get_index(ptr.raw.idx()?)
+                .map(|(a, b, c, d)| Continuation::Call2 {
+                    function: *a,
+                    saved_env: *b,
+                    unevaled_args: *c,
+                    continuation: *d,
+                }),
             Tail => self
                 .tail_store
                 .get_index(ptr.raw.idx()?)
@@ -907,6 +1027,24 @@ impl<F: LurkField> Store<F> {
                 .map(|continuation| Continuation::Emit {
                     continuation: *continuation,
                 }),
+            Vector => self
+                .vector_store
+                .get_index(ptr.raw.idx()?)
+                .map(|(a, b, c, d)| Continuation::Vector {
+                    operator: *a,
+                    vector: *b,
+                    evaled_arg: *c,
+                    continuation: *d,
+                }),
+            Fuel => self
+                .fuel_store
+                .get_index(ptr.raw.idx()?)
+                .map(|(a, b, c, d)| Continuation::Fuel {
+                    inner: *a,
+                    fuel: *b,
+                    saved_env: *c,
+                    continuation: *d,
+                }),
             Cproc => unreachable!("Lurk Alpha doesn't produce such continuations"),
         }
     }
@@ -967,6 +1105,10 @@ impl<F: LurkField> Store<F> {
                     let z_expr = ZExpr::UInt(u);
                     (z_expr.z_ptr(&self.poseidon_cache), Some(z_expr))
                 }
+                Some(Expression::I64(n)) => {
+                    let z_expr = ZExpr::I64(n);
+                    (z_expr.z_ptr(&self.poseidon_cache), Some(z_expr))
+                }
                 Some(Expression::EmptyStr) => (
                     ZExpr::EmptyStr.z_ptr(&self.poseidon_cache),
                     Some(ZExpr::EmptyStr),
@@ -997,6 +1139,12 @@ impl<F: LurkField> Store<F> {
                     let z_expr = ZExpr::Key(z_car, z_cdr);
                     (z_expr.z_ptr(&self.poseidon_cache), Some(z_expr))
                 }
+                Some(Expression::Vec(len, content)) => {
+                    let (z_len, _) = self.get_z_expr(&len, z_store)?;
+                    let (z_content, _) = self.get_z_expr(&content, z_store)?;
+                    let z_expr = ZExpr::Vec(z_len, z_content);
+                    (z_expr.z_ptr(&self.poseidon_cache), Some(z_expr))
+                }
                 None => return Err(Error("get_z_expr unknown opaque".into())),
             };
             self.z_expr_ptr_map.insert(z_ptr, Box::new(*ptr));
@@ -1066,14 +1214,14 @@ impl<F: LurkField> Store<F> {
                 }
                 Some(Continuation::Call {
                     saved_env,
-                    unevaled_arg,
+                    unevaled_args,
                     continuation,
                 }) => {
                     let (z_env_ptr, _) = self.get_z_expr(&saved_env, z_store)?;
-                    let (z_arg_ptr, _) = self.get_z_expr(&unevaled_arg, z_store)?;
+                    let (z_args_ptr, _) = self.get_z_expr(&unevaled_args, z_store)?;
                     let (z_cont_ptr, _) = self.get_z_cont(&continuation, z_store)?;
                     let z_cont = ZCont::<F>::Call {
-                        unevaled_arg: z_arg_ptr,
+                        unevaled_args: z_args_ptr,
                         saved_env: z_env_ptr,
                         continuation: z_cont_ptr,
                     };
@@ -1083,14 +1231,17 @@ impl<F: LurkField> Store<F> {
                 Some(Continuation::Call2 {
                     saved_env,
                     function,
+                    unevaled_args,
                     continuation,
                 }) => {
                     let (z_env_ptr, _) = self.get_z_expr(&saved_env, z_store)?;
                     let (z_fun_ptr, _) = self.get_z_expr(&function, z_store)?;
+                    let (z_args_ptr, _) = self.get_z_expr(&unevaled_args, z_store)?;
                     let (z_cont_ptr, _) = self.get_z_cont(&continuation, z_store)?;
                     let z_cont = ZCont::<F>::Call2 {
                         function: z_fun_ptr,
                         saved_env: z_env_ptr,
+                        unevaled_args: z_args_ptr,
                         continuation: z_cont_ptr,
                     };
                     let z_ptr = z_cont.z_ptr(&self.poseidon_cache);
@@ -1231,6 +1382,43 @@ impl<F: LurkField> Store<F> {
                     let z_ptr = z_cont.z_ptr(&self.poseidon_cache);
                     (z_ptr, Some(z_cont))
                 }
+                Some(Continuation::Vector {
+                    operator,
+                    vector,
+                    evaled_arg,
+                    continuation,
+                }) => {
+                    let (z_vector_ptr, _) = self.get_z_expr(&vector, z_store)?;
+                    let (z_arg_ptr, _) = self.get_z_expr(&evaled_arg, z_store)?;
+                    let (z_cont_ptr, _) = self.get_z_cont(&continuation, z_store)?;
+                    let z_cont = ZCont::<F>::Vector {
+                        operator,
+                        vector: z_vector_ptr,
+                        evaled_arg: z_arg_ptr,
+                        continuation: z_cont_ptr,
+                    };
+                    let z_ptr = z_cont.z_ptr(&self.poseidon_cache);
+                    (z_ptr, Some(z_cont))
+                }
+                Some(Continuation::Fuel {
+                    inner,
+                    fuel,
+                    saved_env,
+                    continuation,
+                }) => {
+                    let (z_inner_ptr, _) = self.get_z_cont(&inner, z_store)?;
+                    let (z_fuel_ptr, _) = self.get_z_expr(&fuel, z_store)?;
+                    let (z_saved_env_ptr, _) = self.get_z_expr(&saved_env, z_store)?;
+                    let (z_cont_ptr, _) = self.get_z_cont(&continuation, z_store)?;
+                    let z_cont = ZCont::<F>::Fuel {
+                        inner: z_inner_ptr,
+                        fuel: z_fuel_ptr,
+                        saved_env: z_saved_env_ptr,
+                        continuation: z_cont_ptr,
+                    };
+                    let z_ptr = z_cont.z_ptr(&self.poseidon_cache);
+                    (z_ptr, Some(z_cont))
+                }
                 Some(Continuation::Dummy) => {
                     let z_cont = ZCont::<F>::Dummy;
                     let z_ptr = z_cont.z_ptr(&self.poseidon_cache);
@@ -1428,7 +1616,7 @@ impl<F: LurkField> Store<F> {
             Some(ptr)
         } else {
             use ZExpr::{
-                Char, Comm, Cons, EmptyStr, Fun, Key, Nil, Num, RootSym, Str, Sym, Thunk, UInt,
+                Char, Comm, Cons, EmptyStr, Fun, Key, Nil, Num, RootSym, Str, Sym, Thunk, UInt, I64,
             };
             match (z_ptr.tag(), z_store.get_expr(z_ptr)) {
                 (ExprTag::Nil, Some(Nil)) => {
@@ -1443,6 +1631,13 @@ impl<F: LurkField> Store<F> {
                     self.create_z_expr_ptr(ptr, *z_ptr.value());
                     Some(ptr)
                 }
+                (ExprTag::Vec, Some(ZExpr::Vec(len, content))) => {
+                    let len = self.intern_z_expr_ptr(&len, z_store)?;
+                    let content = self.intern_z_expr_ptr(&content, z_store)?;
+                    let ptr = self.intern_vec_node(len, content);
+                    self.create_z_expr_ptr(ptr, *z_ptr.value());
+                    Some(ptr)
+                }
                 (ExprTag::Comm, Some(Comm(secret, payload))) => {
                     let payload = self.intern_z_expr_ptr(&payload, z_store)?;
                     let ptr = self.intern_comm(secret, payload);
@@ -1492,6 +1687,7 @@ impl<F: LurkField> Store<F> {
                 }
                 (ExprTag::Char, Some(Char(x))) => Some(x.into()),
                 (ExprTag::U64, Some(UInt(x))) => Some(self.intern_uint(x)),
+                (ExprTag::I64, Some(I64(x))) => Some(self.intern_i64(x)),
                 (ExprTag::Thunk, Some(Thunk(value, continuation))) => {
                     let value = self.intern_z_expr_ptr(&value, z_store)?;
                     let continuation = self.intern_z_cont_ptr(&continuation, z_store)?;
@@ -1542,8 +1738,8 @@ impl<F: LurkField> Store<F> {
         z_store: &ZStore<F>,
     ) -> Option<ContPtr<F>> {
         use ZCont::{
-            Binop, Binop2, Call, Call0, Call2, Dummy, Emit, Error, If, Let, LetRec, Lookup,
-            Outermost, Tail, Terminal, Unop,
+            Binop, Binop2, Call, Call0, Call2, Dummy, Emit, Error, Fuel, If, Let, LetRec, Lookup,
+            Outermost, Tail, Terminal, Unop, Vector,
         };
         let tag: ContTag = z_ptr.tag();
 
@@ -1561,20 +1757,22 @@ impl<F: LurkField> Store<F> {
                 },
                 Call {
                     saved_env,
-                    unevaled_arg,
+                    unevaled_args,
                     continuation,
                 } => Continuation::Call {
                     saved_env: self.intern_z_expr_ptr(&saved_env, z_store)?,
-                    unevaled_arg: self.intern_z_expr_ptr(&unevaled_arg, z_store)?,
+                    unevaled_args: self.intern_z_expr_ptr(&unevaled_args, z_store)?,
                     continuation: self.intern_z_cont_ptr(&continuation, z_store)?,
                 },
                 Call2 {
                     saved_env,
                     function,
+                    unevaled_args,
                     continuation,
                 } => Continuation::Call2 {
                     saved_env: self.intern_z_expr_ptr(&saved_env, z_store)?,
                     function: self.intern_z_expr_ptr(&function, z_store)?,
+                    unevaled_args: self.intern_z_expr_ptr(&unevaled_args, z_store)?,
                     continuation: self.intern_z_cont_ptr(&continuation, z_store)?,
                 },
                 Tail {
@@ -1651,6 +1849,28 @@ impl<F: LurkField> Store<F> {
                 Emit { continuation } => Continuation::Emit {
                     continuation: self.intern_z_cont_ptr(&continuation, z_store)?,
                 },
+                Vector {
+                    operator,
+                    vector,
+                    evaled_arg,
+                    continuation,
+                } => Continuation::Vector {
+                    operator,
+                    vector: self.intern_z_expr_ptr(&vector, z_store)?,
+                    evaled_arg: self.intern_z_expr_ptr(&evaled_arg, z_store)?,
+                    continuation: self.intern_z_cont_ptr(&continuation, z_store)?,
+                },
+                Fuel {
+                    inner,
+                    fuel,
+                    saved_env,
+                    continuation,
+                } => Continuation::Fuel {
+                    inner: self.intern_z_cont_ptr(&inner, z_store)?,
+                    fuel: self.intern_z_expr_ptr(&fuel, z_store)?,
+                    saved_env: self.intern_z_expr_ptr(&saved_env, z_store)?,
+                    continuation: self.intern_z_cont_ptr(&continuation, z_store)?,
+                },
             };
 
             if continuation.cont_tag() == tag {
@@ -1746,11 +1966,46 @@ pub struct NamedConstants<F: LurkField> {
     pub commit: ConstantPtrs<F>,
     pub num: ConstantPtrs<F>,
     pub u64: ConstantPtrs<F>,
+    pub i64: ConstantPtrs<F>,
     pub comm: ConstantPtrs<F>,
     pub char: ConstantPtrs<F>,
     pub eval: ConstantPtrs<F>,
     pub open: ConstantPtrs<F>,
     pub secret: ConstantPtrs<F>,
+    pub logand: ConstantPtrs<F>,
+    pub logior: ConstantPtrs<F>,
+    pub logxor: ConstantPtrs<F>,
+    pub lognot: ConstantPtrs<F>,
+    pub shift_left: ConstantPtrs<F>,
+    pub shift_right: ConstantPtrs<F>,
+    pub char_to_u64: ConstantPtrs<F>,
+    pub u64_to_char: ConstantPtrs<F>,
+    pub symbol_to_string: ConstantPtrs<F>,
+    pub string_to_symbol: ConstantPtrs<F>,
+    pub string_length: ConstantPtrs<F>,
+    pub string_append: ConstantPtrs<F>,
+    pub substring: ConstantPtrs<F>,
+    pub string_to_list: ConstantPtrs<F>,
+    pub list_to_string: ConstantPtrs<F>,
+    pub throw: ConstantPtrs<F>,
+    pub catch: ConstantPtrs<F>,
+    pub list: ConstantPtrs<F>,
+    pub make_vector: ConstantPtrs<F>,
+    pub vector_ref: ConstantPtrs<F>,
+    pub vector_set: ConstantPtrs<F>,
+    pub vector_length: ConstantPtrs<F>,
+    pub eval_with_limit: ConstantPtrs<F>,
+    pub ok: ConstantPtrs<F>,
+    pub out_of_fuel: ConstantPtrs<F>,
+    pub error: ConstantPtrs<F>,
+    pub unbound_var: ConstantPtrs<F>,
+    pub invalid_form: ConstantPtrs<F>,
+    pub invalid_call: ConstantPtrs<F>,
+    pub invalid_arg: ConstantPtrs<F>,
+    pub car_of_non_list: ConstantPtrs<F>,
+    pub cdr_of_non_list: ConstantPtrs<F>,
+    pub rest: ConstantPtrs<F>,
+    pub user_package: ConstantPtrs<F>,
     pub dummy: ConstantPtrs<F>,
 }
 
@@ -1764,6 +2019,11 @@ impl<F: LurkField> NamedConstants<F> {
             let maybe_z_ptr = store.hash_expr(&ptr);
             ConstantPtrs(maybe_z_ptr, ptr)
         };
+        let hash_key = |name: &str| {
+            let ptr = store.key(name);
+            let maybe_z_ptr = store.hash_expr(&ptr);
+            ConstantPtrs(maybe_z_ptr, ptr)
+        };
 
         let nil = ConstantPtrs(nil_z_ptr, nil_ptr);
         let t = hash_sym("t");
@@ -1795,11 +2055,46 @@ impl<F: LurkField> NamedConstants<F> {
         let commit = hash_sym("commit");
         let num = hash_sym("num");
         let u64 = hash_sym("u64");
+        let i64 = hash_sym("i64");
         let comm = hash_sym("comm");
         let char = hash_sym("char");
         let eval = hash_sym("eval");
         let open = hash_sym("open");
         let secret = hash_sym("secret");
+        let logand = hash_sym("logand");
+        let logior = hash_sym("logior");
+        let logxor = hash_sym("logxor");
+        let lognot = hash_sym("lognot");
+        let shift_left = hash_sym("shift-left");
+        let shift_right = hash_sym("shift-right");
+        let char_to_u64 = hash_sym("char->u64");
+        let u64_to_char = hash_sym("u64->char");
+        let symbol_to_string = hash_sym("symbol->string");
+        let string_to_symbol = hash_sym("string->symbol");
+        let string_length = hash_sym("string-length");
+        let string_append = hash_sym("string-append");
+        let substring = hash_sym("substring");
+        let string_to_list = hash_sym("string->list");
+        let list_to_string = hash_sym("list->string");
+        let throw = hash_sym("throw");
+        let catch = hash_sym("catch");
+        let list = hash_sym("list");
+        let make_vector = hash_sym("make-vector");
+        let vector_ref = hash_sym("vector-ref");
+        let vector_set = hash_sym("vector-set");
+        let vector_length = hash_sym("vector-length");
+        let eval_with_limit = hash_sym("eval-with-limit");
+        let ok = hash_key("ok");
+        let out_of_fuel = hash_key("out-of-fuel");
+        let error = hash_key("error");
+        let unbound_var = hash_key("unbound-var");
+        let invalid_form = hash_key("invalid-form");
+        let invalid_call = hash_key("invalid-call");
+        let invalid_arg = hash_key("invalid-arg");
+        let car_of_non_list = hash_key("car-of-non-list");
+        let cdr_of_non_list = hash_key("cdr-of-non-list");
+        let rest = hash_sym("&rest");
+        let user_package = hash_sym("user");
         let dummy = hash_sym("_");
 
         Self {
@@ -1833,11 +2128,46 @@ impl<F: LurkField> NamedConstants<F> {
             commit,
             num,
             u64,
+            i64,
             comm,
             char,
             eval,
             open,
             secret,
+            logand,
+            logior,
+            logxor,
+            lognot,
+            shift_left,
+            shift_right,
+            char_to_u64,
+            u64_to_char,
+            symbol_to_string,
+            string_to_symbol,
+            string_length,
+            string_append,
+            substring,
+            string_to_list,
+            list_to_string,
+            throw,
+            catch,
+            list,
+            make_vector,
+            vector_ref,
+            vector_set,
+            vector_length,
+            eval_with_limit,
+            ok,
+            out_of_fuel,
+            error,
+            unbound_var,
+            invalid_form,
+            invalid_call,
+            invalid_arg,
+            car_of_non_list,
+            cdr_of_non_list,
+            rest,
+            user_package,
             dummy,
         }
     }
@@ -1906,13 +2236,16 @@ pub mod test {
         assert_eq!(8, ExprTag::Comm as u64);
         assert_eq!(9, ExprTag::U64 as u64);
         assert_eq!(10, ExprTag::Key as u64);
+        assert_eq!(11, ExprTag::Cproc as u64);
+        assert_eq!(12, ExprTag::I64 as u64);
+        assert_eq!(13, ExprTag::Vec as u64);
     }
 
     #[test]
     fn cont_tag_vals() {
         use super::ContTag::{
-            Binop, Binop2, Call, Call0, Call2, Dummy, Emit, Error, If, Let, LetRec, Lookup,
-            Outermost, Tail, Terminal, Unop,
+            Binop, Binop2, Call, Call0, Call2, Dummy, Emit, Error, Fuel, If, Let, LetRec, Lookup,
+            Outermost, Tail, Terminal, Unop, Vector,
         };
 
         assert_eq!(0b0001_0000_0000_0000, Outermost as u16);
@@ -1931,6 +2264,8 @@ pub mod test {
         assert_eq!(0b0001_0000_0000_1101, Dummy as u16);
         assert_eq!(0b0001_0000_0000_1110, Terminal as u16);
         assert_eq!(0b0001_0000_0000_1111, Emit as u16);
+        assert_eq!(0b0001_0000_0001_0001, Vector as u16);
+        assert_eq!(0b0001_0000_0001_0010, Fuel as u16);
     }
 
     #[test]
@@ -1966,14 +2301,14 @@ pub mod test {
     fn opaque_fun() {
         let store = Store::<Fr>::default();
 
-        let arg = store.sym("A");
+        let params = store.list(&[store.sym("A")]);
         let body_form = store.num(123);
         let body2_form = store.num(987);
         let body = store.list(&[body_form]);
         let body2 = store.list(&[body2_form]);
         let empty_env = empty_sym_env(&store);
-        let fun = store.intern_fun(arg, body, empty_env);
-        let fun2 = store.intern_fun(arg, body2, empty_env);
+        let fun = store.intern_fun(params, body, empty_env);
+        let fun2 = store.intern_fun(params, body2, empty_env);
         let fun_hash = store.hash_expr(&fun).unwrap();
         let fun_hash2 = store.hash_expr(&fun2).unwrap();
         let opaque_fun = store.intern_opaque_fun(*fun_hash.value());
@@ -1,3 +1,5 @@
+mod desugar;
+
 use std::fmt;
 
 use crate::expr::Expression;
@@ -21,6 +23,8 @@ pub enum Syntax<F: LurkField> {
     Num(Pos, Num<F>),
     // A u64 integer: 1u64, 0xffu64
     UInt(Pos, UInt),
+    // A signed 64-bit integer: -1i64, 0xffi64
+    I64(Pos, i64),
     // A hierarchical symbol foo, foo.bar.baz or keyword :foo
     Symbol(Pos, SymbolRef),
     // A string literal: "foobar", "foo\nbar"
@@ -45,6 +49,7 @@ impl<Fr: LurkField> Arbitrary for Syntax<Fr> {
         let leaf = prop_oneof![
             any::<Num<Fr>>().prop_map(|x| Syntax::Num(Pos::No, x)),
             any::<UInt>().prop_map(|x| Syntax::UInt(Pos::No, x)),
+            any::<i64>().prop_map(|x| Syntax::I64(Pos::No, x)),
             any::<Symbol>().prop_map(|x| Syntax::Symbol(Pos::No, x.into())),
             any::<String>().prop_map(|x| Syntax::String(Pos::No, x)),
             any::<char>().prop_map(|x| Syntax::Char(Pos::No, x))
@@ -70,6 +75,7 @@ impl<F: LurkField> fmt::Display for Syntax<F> {
         match self {
             Self::Num(_, x) => write!(f, "{x}"),
             Self::UInt(_, x) => write!(f, "{x}u64"),
+            Self::I64(_, x) => write!(f, "{x}i64"),
             Self::Symbol(_, x) => write!(f, "{x}"),
             Self::String(_, x) => write!(f, "\"{}\"", x.escape_default()),
             Self::Char(_, x) => {
@@ -111,6 +117,7 @@ impl<F: LurkField> Store<F> {
         match syn {
             Syntax::Num(_, x) => self.intern_num(x),
             Syntax::UInt(_, x) => self.intern_uint(x),
+            Syntax::I64(_, x) => self.intern_i64(x),
             Syntax::Char(_, x) => self.intern_char(x),
             Syntax::Symbol(_, symbol) => self.intern_symbol(&symbol),
             Syntax::String(_, x) => self.intern_string(&x),
@@ -171,6 +178,7 @@ impl<F: LurkField> Store<F> {
             ExprTag::Num => Some(Syntax::Num(Pos::No, *self.fetch_num(&ptr)?)),
             ExprTag::Char => Some(Syntax::Char(Pos::No, self.fetch_char(&ptr)?)),
             ExprTag::U64 => Some(Syntax::UInt(Pos::No, self.fetch_uint(&ptr)?)),
+            ExprTag::I64 => Some(Syntax::I64(Pos::No, self.fetch_i64(&ptr)?)),
             ExprTag::Str => Some(Syntax::String(Pos::No, self.fetch_string(&ptr)?)),
             ExprTag::Nil => Some(Syntax::Symbol(Pos::No, lurk_sym("nil").into())),
             ExprTag::Cons => self.fetch_syntax_list(ptr),
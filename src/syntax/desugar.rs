@@ -0,0 +1,362 @@
+//! Desugaring of the derived forms `cond`, `and`, `or`, `when`, `unless` and
+//! `let*` into the core forms understood by the evaluator, the circuit and LEM.
+//!
+//! The rewriting happens right after parsing, so the derived forms are just
+//! syntax and don't cost anything beyond the core forms they expand to:
+//!
+//! * `(cond (c e ...) ...)` becomes nested `if`s, with the bodies in a `begin`,
+//!   and a clause `(c)` returns the value of `c` if it isn't `nil`
+//! * `(and a b ...)` becomes `(if a (and b ...) nil)` and `(and)` is `t`
+//! * `(or a b ...)` becomes `(let ((or a)) (if or or (or b ...)))` and `(or)` is
+//!   `nil`. If `or` occurs in `(or b ...)`, the value of `a` is bound to the first
+//!   of `or1`, `or2`, ... that doesn't, so that it can't be captured
+//! * `(when c e ...)` becomes `(if c (begin e ...) nil)`
+//! * `(unless c e ...)` becomes `(if c nil (begin e ...))`
+//! * `(let* ...)` becomes `(let ...)`, since `let` already binds sequentially
+//!
+//! The parameters of a `lambda` may end with `&rest r`, which the evaluator
+//! binds to the list of the remaining arguments, so only the placement of
+//! `&rest` is checked here.
+//!
+//! Quoted data is left untouched and so are the derived forms whose names are
+//! bound by an enclosing `lambda`, `let` or `letrec`.
+
+use crate::{
+    field::LurkField,
+    package::SymbolRef,
+    parser::{position::Pos, Error},
+    state::lurk_sym,
+    Symbol,
+};
+
+use super::Syntax;
+
+const DERIVED_FORMS: [&str; 6] = ["cond", "and", "or", "when", "unless", "let*"];
+
+impl<F: LurkField> Syntax<F> {
+    /// Rewrites the derived forms in `self` into core forms
+    pub fn desugar(self) -> Result<Self, Error> {
+        desugar(self, &[])
+    }
+}
+
+#[inline]
+fn lurk<F: LurkField>(pos: Pos, name: &str) -> Syntax<F> {
+    Syntax::Symbol(pos, lurk_sym(name).into())
+}
+
+#[inline]
+fn is_lurk_sym<F: LurkField>(syn: &Syntax<F>, name: &str) -> bool {
+    matches!(syn, Syntax::Symbol(_, sym) if **sym == lurk_sym(name))
+}
+
+/// The name of the derived form `head` stands for, unless it's shadowed
+fn derived_form<F: LurkField>(head: &Syntax<F>, shadowed: &[SymbolRef]) -> Option<&'static str> {
+    match head {
+        Syntax::Symbol(_, sym) if !shadowed.contains(sym) => DERIVED_FORMS
+            .into_iter()
+            .find(|name| **sym == lurk_sym(name)),
+        _ => None,
+    }
+}
+
+/// Extends `shadowed` with `var` if it's the name of a derived form
+fn shadow<F: LurkField>(shadowed: &mut Vec<SymbolRef>, var: &Syntax<F>) {
+    if let Some(form) = derived_form(var, shadowed) {
+        shadowed.push(lurk_sym(form).into())
+    }
+}
+
+/// Checks that `&rest` is only followed by a single parameter, if it's among
+/// the parameters of a `lambda`
+fn check_rest_param<F: LurkField>(params: &[Syntax<F>]) -> Result<(), Error> {
+    let Some(i) = params.iter().position(|param| is_lurk_sym(param, "&rest")) else {
+        return Ok(());
+    };
+    let is_single_var = i + 2 == params.len()
+        && matches!(params[i + 1], Syntax::Symbol(..))
+        && !is_lurk_sym(&params[i + 1], "&rest");
+    if !is_single_var {
+        return Err(Error::Syntax(
+            "`&rest` must be followed by exactly one parameter".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn desugar_all<F: LurkField>(
+    xs: Vec<Syntax<F>>,
+    shadowed: &[SymbolRef],
+) -> Result<Vec<Syntax<F>>, Error> {
+    xs.into_iter().map(|x| desugar(x, shadowed)).collect()
+}
+
+fn desugar<F: LurkField>(syn: Syntax<F>, shadowed: &[SymbolRef]) -> Result<Syntax<F>, Error> {
+    match syn {
+        Syntax::List(pos, xs) => desugar_list(pos, xs, shadowed),
+        Syntax::Improper(pos, xs, end) => {
+            if let Some(form) = xs.first().and_then(|x| derived_form(x, shadowed)) {
+                return Err(Error::Syntax(format!(
+                    "`{form}` can't be applied to an improper list"
+                )));
+            }
+            let xs = desugar_all(xs, shadowed)?;
+            let end = desugar(*end, shadowed)?;
+            Ok(Syntax::Improper(pos, xs, Box::new(end)))
+        }
+        syn => Ok(syn),
+    }
+}
+
+fn desugar_list<F: LurkField>(
+    pos: Pos,
+    mut xs: Vec<Syntax<F>>,
+    shadowed: &[SymbolRef],
+) -> Result<Syntax<F>, Error> {
+    let Some(head) = xs.first() else {
+        return Ok(Syntax::List(pos, xs));
+    };
+    if let Some(form) = derived_form(head, shadowed) {
+        let args = xs.split_off(1);
+        return expand(pos, form, args, shadowed);
+    }
+    if is_lurk_sym(head, "quote") {
+        Ok(Syntax::List(pos, xs))
+    } else if is_lurk_sym(head, "lambda") && xs.len() > 1 {
+        let body = xs.split_off(2);
+        let mut shadowed = shadowed.to_vec();
+        if let Syntax::List(_, params) = &xs[1] {
+            check_rest_param(params)?;
+            params.iter().for_each(|param| shadow(&mut shadowed, param));
+        }
+        xs.extend(desugar_all(body, &shadowed)?);
+        Ok(Syntax::List(pos, xs))
+    } else if (is_lurk_sym(head, "let") || is_lurk_sym(head, "letrec")) && xs.len() > 1 {
+        let is_letrec = is_lurk_sym(head, "letrec");
+        let body = xs.split_off(2);
+        let mut shadowed = shadowed.to_vec();
+        if let Syntax::List(bindings_pos, bindings) = xs.pop().expect("bindings are present") {
+            let mut desugared_bindings = Vec::with_capacity(bindings.len());
+            for binding in bindings {
+                match binding {
+                    Syntax::List(binding_pos, mut binding) if !binding.is_empty() => {
+                        let values = binding.split_off(1);
+                        if is_letrec {
+                            shadow(&mut shadowed, &binding[0]);
+                        }
+                        binding.extend(desugar_all(values, &shadowed)?);
+                        if !is_letrec {
+                            shadow(&mut shadowed, &binding[0]);
+                        }
+                        desugared_bindings.push(Syntax::List(binding_pos, binding));
+                    }
+                    binding => desugared_bindings.push(binding),
+                }
+            }
+            xs.push(Syntax::List(bindings_pos, desugared_bindings));
+        }
+        xs.extend(desugar_all(body, &shadowed)?);
+        Ok(Syntax::List(pos, xs))
+    } else {
+        Ok(Syntax::List(pos, desugar_all(xs, shadowed)?))
+    }
+}
+
+/// A single expression out of a (desugared) body
+fn begin<F: LurkField>(pos: Pos, mut body: Vec<Syntax<F>>) -> Syntax<F> {
+    match body.len() {
+        0 => lurk(pos, "nil"),
+        1 => body.pop().unwrap(),
+        _ => {
+            body.insert(0, lurk(pos, "begin"));
+            Syntax::List(pos, body)
+        }
+    }
+}
+
+#[inline]
+fn if_<F: LurkField>(pos: Pos, cond: Syntax<F>, then: Syntax<F>, other: Syntax<F>) -> Syntax<F> {
+    Syntax::List(pos, vec![lurk(pos, "if"), cond, then, other])
+}
+
+/// Whether `sym` occurs in `syn`, quoted or not
+fn occurs<F: LurkField>(sym: &Symbol, syn: &Syntax<F>) -> bool {
+    match syn {
+        Syntax::Symbol(_, s) => **s == *sym,
+        Syntax::Quote(_, x) => occurs(sym, x),
+        Syntax::List(_, xs) => xs.iter().any(|x| occurs(sym, x)),
+        Syntax::Improper(_, xs, end) => xs.iter().any(|x| occurs(sym, x)) || occurs(sym, end),
+        _ => false,
+    }
+}
+
+/// `(or x other)` for an `x` that's evaluated only once
+fn or<F: LurkField>(pos: Pos, x: Syntax<F>, other: Syntax<F>) -> Syntax<F> {
+    let var = std::iter::once(lurk_sym("or"))
+        .chain((1..).map(|i| lurk_sym(&format!("or{i}"))))
+        .find(|var| !occurs(var, &other))
+        .expect("`other` is finite");
+    let var = Syntax::Symbol(pos, var.into());
+    let binding = Syntax::List(pos, vec![var.clone(), x]);
+    let body = if_(pos, var.clone(), var, other);
+    Syntax::List(
+        pos,
+        vec![lurk(pos, "let"), Syntax::List(pos, vec![binding]), body],
+    )
+}
+
+/// The `if` for the `cond` clause `(c body ...)`, falling back to `other`
+fn cond_clause<F: LurkField>(
+    clause: Syntax<F>,
+    other: Syntax<F>,
+    shadowed: &[SymbolRef],
+) -> Result<Syntax<F>, Error> {
+    let (pos, clause) = match clause {
+        Syntax::List(pos, clause) if !clause.is_empty() => (pos, clause),
+        clause => {
+            return Err(Error::Syntax(format!(
+                "`cond` clauses must be non-empty lists, got {clause}"
+            )))
+        }
+    };
+    let mut clause = desugar_all(clause, shadowed)?;
+    let body = clause.split_off(1);
+    let cond = clause.pop().unwrap();
+    if body.is_empty() {
+        Ok(or(pos, cond, other))
+    } else {
+        Ok(if_(pos, cond, begin(pos, body), other))
+    }
+}
+
+fn expand<F: LurkField>(
+    pos: Pos,
+    form: &str,
+    mut args: Vec<Syntax<F>>,
+    shadowed: &[SymbolRef],
+) -> Result<Syntax<F>, Error> {
+    match form {
+        "cond" => args
+            .into_iter()
+            .rev()
+            .try_fold(lurk(pos, "nil"), |other, clause| {
+                cond_clause(clause, other, shadowed)
+            }),
+        "and" => {
+            let mut args = desugar_all(args, shadowed)?;
+            let Some(last) = args.pop() else {
+                return Ok(lurk(pos, "t"));
+            };
+            Ok(args
+                .into_iter()
+                .rev()
+                .fold(last, |then, x| if_(pos, x, then, lurk(pos, "nil"))))
+        }
+        "or" => {
+            let mut args = desugar_all(args, shadowed)?;
+            let Some(last) = args.pop() else {
+                return Ok(lurk(pos, "nil"));
+            };
+            Ok(args
+                .into_iter()
+                .rev()
+                .fold(last, |other, x| or(pos, x, other)))
+        }
+        "when" | "unless" => {
+            if args.is_empty() {
+                return Err(Error::Syntax(format!("`{form}` needs a condition")));
+            }
+            let mut args = desugar_all(args, shadowed)?;
+            let body = begin(pos, args.split_off(1));
+            let cond = args.pop().unwrap();
+            if form == "when" {
+                Ok(if_(pos, cond, body, lurk(pos, "nil")))
+            } else {
+                Ok(if_(pos, cond, lurk(pos, "nil"), body))
+            }
+        }
+        "let*" => {
+            args.insert(0, lurk(pos, "let"));
+            desugar_list(pos, args, shadowed)
+        }
+        _ => unreachable!("not a derived form: {form}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use blstrs::Scalar as Fr;
+
+    use crate::{state::State, store::Store, writer::Write};
+
+    fn assert_desugars_to(input: &str, expected: &str) {
+        let store = Store::<Fr>::default();
+        let state = State::init_lurk_state().rccell();
+        let ptr = store.read(input).unwrap();
+        assert_eq!(ptr.fmt_to_string(&store, &state.borrow()), expected);
+    }
+
+    #[test]
+    fn desugar_derived_forms() {
+        assert_desugars_to("(and)", "t");
+        assert_desugars_to("(and a b c)", "(if a (if b c nil) nil)");
+        assert_desugars_to("(or)", "nil");
+        assert_desugars_to("(or a b)", "(let ((or a)) (if or or b))");
+        assert_desugars_to("(when a b c)", "(if a (begin b c) nil)");
+        assert_desugars_to("(unless a b)", "(if a nil b)");
+        assert_desugars_to(
+            "(cond ((= x 1) a) (b) (t (or c d) e))",
+            "(if (= x 1) a (let ((.lurk.or1 b)) (if .lurk.or1 .lurk.or1 (if t (begin (let ((or c)) (if or or d)) e) nil))))",
+        );
+        // the value of the first argument can't be captured by the others
+        assert_desugars_to(
+            "(or a or)",
+            "(let ((.lurk.or1 a)) (if .lurk.or1 .lurk.or1 or))",
+        );
+        assert_desugars_to(
+            "(or a b c)",
+            "(let ((.lurk.or1 a)) (if .lurk.or1 .lurk.or1 (let ((or b)) (if or or c))))",
+        );
+        assert_desugars_to("(let* ((a 1) (b a)) b)", "(let ((a 1) (b a)) b)");
+    }
+
+    #[test]
+    fn desugar_respects_quotes_and_shadowing() {
+        assert_desugars_to("'(and a b)", "(quote (and a b))");
+        assert_desugars_to(
+            "(lambda (cond) ((cond a) b))",
+            "(lambda (cond) ((cond a) b))",
+        );
+        assert_desugars_to("(let ((when 1)) (when 2))", "(let ((when 1)) (when 2))");
+        assert_desugars_to(
+            "(let ((x (when a b)) (when 1)) (when 2))",
+            "(let ((x (if a b nil)) (when 1)) (when 2))",
+        );
+        assert_desugars_to(
+            "(letrec ((and (lambda (x) (and x)))) (and 1))",
+            "(letrec ((and (lambda (x) (and x)))) (and 1))",
+        );
+    }
+
+    #[test]
+    fn desugar_rest_params() {
+        assert_desugars_to("(lambda (a &rest r) r)", "(lambda (a &rest r) r)");
+        assert_desugars_to(
+            "(lambda (&rest r) (when r 1))",
+            "(lambda (&rest r) (if r 1 nil))",
+        );
+        assert_desugars_to("'(lambda (&rest r) r)", "(quote (lambda (&rest r) r))");
+    }
+
+    #[test]
+    fn desugar_errors() {
+        let store = Store::<Fr>::default();
+        assert!(store.read("(cond a)").is_err());
+        assert!(store.read("(cond ())").is_err());
+        assert!(store.read("(when)").is_err());
+        assert!(store.read("(and a . b)").is_err());
+        assert!(store.read("(lambda (&rest) a)").is_err());
+        assert!(store.read("(lambda (&rest a b) a)").is_err());
+        assert!(store.read("(lambda (a &rest &rest) a)").is_err());
+    }
+}
@@ -21,6 +21,16 @@ macro_rules! uint {
     };
 }
 
+#[macro_export]
+macro_rules! int {
+    ($f:ty, $i:literal) => {
+        $crate::syntax::Syntax::<$f>::I64(Pos::No, $i)
+    };
+    ($i:literal) => {
+        $crate::syntax::Syntax::I64(Pos::No, $i)
+    };
+}
+
 #[macro_export]
 macro_rules! str {
     ($f:ty, $i:literal) => {
@@ -40,6 +40,8 @@ pub enum ExprTag {
     U64,
     Key,
     Cproc,
+    I64,
+    Vec,
 }
 
 impl From<ExprTag> for u16 {
@@ -69,6 +71,8 @@ impl fmt::Display for ExprTag {
             ExprTag::Comm => write!(f, "comm#"),
             ExprTag::U64 => write!(f, "u64#"),
             ExprTag::Cproc => write!(f, "cproc#"),
+            ExprTag::I64 => write!(f, "i64#"),
+            ExprTag::Vec => write!(f, "vec#"),
         }
     }
 }
@@ -88,7 +92,9 @@ impl TypePredicates for ExprTag {
             | Self::Comm
             | Self::U64
             | Self::Key
-            | Self::Cproc => true,
+            | Self::Cproc
+            | Self::I64
+            | Self::Vec => true,
         }
     }
 
@@ -138,6 +144,8 @@ pub enum ContTag {
     Terminal,
     Emit,
     Cproc,
+    Vector,
+    Fuel,
 }
 
 impl From<ContTag> for u16 {
@@ -189,6 +197,8 @@ impl fmt::Display for ContTag {
             ContTag::Terminal => write!(f, "terminal#"),
             ContTag::Emit => write!(f, "emit#"),
             ContTag::Cproc => write!(f, "cproc#"),
+            ContTag::Vector => write!(f, "vector#"),
+            ContTag::Fuel => write!(f, "fuel#"),
         }
     }
 }
@@ -220,6 +230,17 @@ pub enum Op1 {
     Char,
     Eval,
     U64,
+    LogNot,
+    I64,
+    CharToU64,
+    U64ToChar,
+    SymbolToString,
+    StringToSymbol,
+    StringLength,
+    StringToList,
+    ListToString,
+    Throw,
+    VectorLength,
 }
 
 impl From<Op1> for u16 {
@@ -278,6 +299,17 @@ impl Op for Op1 {
             Op1::Char => "char",
             Op1::Eval => "eval",
             Op1::U64 => "u64",
+            Op1::LogNot => "lognot",
+            Op1::I64 => "i64",
+            Op1::CharToU64 => "char->u64",
+            Op1::U64ToChar => "u64->char",
+            Op1::SymbolToString => "symbol->string",
+            Op1::StringToSymbol => "string->symbol",
+            Op1::StringLength => "string-length",
+            Op1::StringToList => "string->list",
+            Op1::ListToString => "list->string",
+            Op1::Throw => "throw",
+            Op1::VectorLength => "vector-length",
         }
     }
 
@@ -295,6 +327,17 @@ impl Op for Op1 {
             &Op1::Char,
             &Op1::Eval,
             &Op1::U64,
+            &Op1::LogNot,
+            &Op1::I64,
+            &Op1::CharToU64,
+            &Op1::U64ToChar,
+            &Op1::SymbolToString,
+            &Op1::StringToSymbol,
+            &Op1::StringLength,
+            &Op1::StringToList,
+            &Op1::ListToString,
+            &Op1::Throw,
+            &Op1::VectorLength,
         ]
     }
 
@@ -318,6 +361,17 @@ impl fmt::Display for Op1 {
             Op1::Char => write!(f, "char#"),
             Op1::Eval => write!(f, "eval#"),
             Op1::U64 => write!(f, "u64#"),
+            Op1::LogNot => write!(f, "lognot#"),
+            Op1::I64 => write!(f, "i64#"),
+            Op1::CharToU64 => write!(f, "char->u64#"),
+            Op1::U64ToChar => write!(f, "u64->char#"),
+            Op1::SymbolToString => write!(f, "symbol->string#"),
+            Op1::StringToSymbol => write!(f, "string->symbol#"),
+            Op1::StringLength => write!(f, "string-length#"),
+            Op1::StringToList => write!(f, "string->list#"),
+            Op1::ListToString => write!(f, "list->string#"),
+            Op1::Throw => write!(f, "throw#"),
+            Op1::VectorLength => write!(f, "vector-length#"),
         }
     }
 }
@@ -353,6 +407,19 @@ pub enum Op2 {
     Hide,
     Modulo,
     Eval,
+    LogAnd,
+    LogIor,
+    LogXor,
+    ShiftLeft,
+    ShiftRight,
+    StringAppend,
+    Substring,
+    Catch,
+    List,
+    MakeVector,
+    VectorRef,
+    VectorSet,
+    EvalWithLimit,
 }
 
 impl From<Op2> for u16 {
@@ -400,6 +467,14 @@ impl Op2 {
                 | Op2::Modulo
         )
     }
+
+    /// Whether the operator works on the bits of its `u64` arguments
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            Op2::LogAnd | Op2::LogIor | Op2::LogXor | Op2::ShiftLeft | Op2::ShiftRight
+        )
+    }
 }
 
 impl Op for Op2 {
@@ -421,6 +496,19 @@ impl Op for Op2 {
             Op2::Hide => "hide",
             Op2::Modulo => "%",
             Op2::Eval => "eval",
+            Op2::LogAnd => "logand",
+            Op2::LogIor => "logior",
+            Op2::LogXor => "logxor",
+            Op2::ShiftLeft => "shift-left",
+            Op2::ShiftRight => "shift-right",
+            Op2::StringAppend => "string-append",
+            Op2::Substring => "substring",
+            Op2::Catch => "catch",
+            Op2::List => "list",
+            Op2::MakeVector => "make-vector",
+            Op2::VectorRef => "vector-ref",
+            Op2::VectorSet => "vector-set",
+            Op2::EvalWithLimit => "eval-with-limit",
         }
     }
 
@@ -442,11 +530,29 @@ impl Op for Op2 {
             &Op2::Hide,
             &Op2::Modulo,
             &Op2::Eval,
+            &Op2::LogAnd,
+            &Op2::LogIor,
+            &Op2::LogXor,
+            &Op2::ShiftLeft,
+            &Op2::ShiftRight,
+            &Op2::StringAppend,
+            &Op2::Substring,
+            &Op2::Catch,
+            &Op2::List,
+            &Op2::MakeVector,
+            &Op2::VectorRef,
+            &Op2::VectorSet,
+            &Op2::EvalWithLimit,
         ]
     }
 
     fn supports_arity(&self, n: usize) -> bool {
-        matches!((self, n), (Op2::Begin, _) | (Op2::Eval, 1 | 2) | (_, 2))
+        match self {
+            Op2::Begin | Op2::List => true,
+            Op2::Eval => matches!(n, 1 | 2),
+            Op2::Substring | Op2::VectorSet | Op2::EvalWithLimit => n == 3,
+            _ => n == 2,
+        }
     }
 }
 
@@ -469,6 +575,19 @@ impl fmt::Display for Op2 {
             Op2::Hide => write!(f, "hide"),
             Op2::Modulo => write!(f, "modulo"),
             Op2::Eval => write!(f, "eval#"),
+            Op2::LogAnd => write!(f, "logand#"),
+            Op2::LogIor => write!(f, "logior#"),
+            Op2::LogXor => write!(f, "logxor#"),
+            Op2::ShiftLeft => write!(f, "shift-left#"),
+            Op2::ShiftRight => write!(f, "shift-right#"),
+            Op2::StringAppend => write!(f, "string-append#"),
+            Op2::Substring => write!(f, "substring#"),
+            Op2::Catch => write!(f, "catch#"),
+            Op2::List => write!(f, "list#"),
+            Op2::MakeVector => write!(f, "make-vector#"),
+            Op2::VectorRef => write!(f, "vector-ref#"),
+            Op2::VectorSet => write!(f, "vector-set#"),
+            Op2::EvalWithLimit => write!(f, "eval-with-limit#"),
         }
     }
 }
@@ -5,7 +5,7 @@ use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
-    ops::{Add, Div, Mul, Rem, Sub},
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub},
 };
 
 /// Unsigned fixed-width integer type for Lurk.
@@ -87,3 +87,51 @@ impl Rem for UInt {
         }
     }
 }
+impl BitAnd for UInt {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        match (self, other) {
+            (UInt::U64(a), UInt::U64(b)) => UInt::U64(a & b),
+        }
+    }
+}
+impl BitOr for UInt {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        match (self, other) {
+            (UInt::U64(a), UInt::U64(b)) => UInt::U64(a | b),
+        }
+    }
+}
+impl BitXor for UInt {
+    type Output = Self;
+    fn bitxor(self, other: Self) -> Self {
+        match (self, other) {
+            (UInt::U64(a), UInt::U64(b)) => UInt::U64(a ^ b),
+        }
+    }
+}
+impl Not for UInt {
+    type Output = Self;
+    fn not(self) -> Self {
+        match self {
+            UInt::U64(a) => UInt::U64(!a),
+        }
+    }
+}
+impl Shl for UInt {
+    type Output = Self;
+    fn shl(self, other: Self) -> Self {
+        match (self, other) {
+            (UInt::U64(a), UInt::U64(b)) => UInt::U64(if b < 64 { a << b } else { 0 }),
+        }
+    }
+}
+impl Shr for UInt {
+    type Output = Self;
+    fn shr(self, other: Self) -> Self {
+        match (self, other) {
+            (UInt::U64(a), UInt::U64(b)) => UInt::U64(if b < 64 { a >> b } else { 0 }),
+        }
+    }
+}
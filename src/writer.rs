@@ -63,7 +63,8 @@ fn write_symbol<W: io::Write>(w: &mut W, sym: Symbol, state: &State) -> io::Resu
 impl<F: LurkField> Write<F> for Expression<F> {
     fn fmt<W: io::Write>(&self, store: &Store<F>, state: &State, w: &mut W) -> io::Result<()> {
         use Expression::{
-            Char, Comm, Cons, EmptyStr, Fun, Key, Nil, Num, RootKey, RootSym, Str, Sym, Thunk, UInt,
+            Char, Comm, Cons, EmptyStr, Fun, Key, Nil, Num, RootKey, RootSym, Str, Sym, Thunk,
+            UInt, I64,
         };
 
         match self {
@@ -87,17 +88,20 @@ impl<F: LurkField> Write<F> for Expression<F> {
                 let tail = store.fetch_string(cdr).expect("missing string tail");
                 write!(w, "\"{head}{tail}\"")
             }
-            Fun(arg, body, _closed_env) => {
-                let is_zero_arg = *arg == lurk_sym_ptr!(store, dummy);
-                let arg = store.fetch(arg).unwrap();
-                write!(w, "<FUNCTION (")?;
-                if !is_zero_arg {
-                    arg.fmt(store, state, w)?;
+            Fun(params, body, _closed_env) => {
+                let body = store.fetch(body).unwrap();
+                write!(w, "<FUNCTION ")?;
+                // The parameters are printed as they were written, `&rest` included
+                if params.is_nil() {
+                    write!(w, "()")?;
+                } else {
+                    let params = store.fetch(params).unwrap();
+                    params.fmt(store, state, w)?;
                 }
-                write!(w, ") ")?;
+                write!(w, " ")?;
 
                 //Assume body is a single-element cons, ignore the cdr
-                match store.fetch(body).unwrap() {
+                match body {
                     Expression::Cons(expr, _) => {
                         let expr = store.fetch(&expr).unwrap();
                         expr.fmt(store, state, w)?;
@@ -136,6 +140,18 @@ impl<F: LurkField> Write<F> for Expression<F> {
                 write!(w, "'{c}'")
             }
             UInt(n) => write!(w, "{n}u64"),
+            I64(n) => write!(w, "{n}i64"),
+            Expression::Vec(len, content) => match store.fetch_vec_elements(len, content) {
+                Some(elts) => {
+                    write!(w, "<VECTOR")?;
+                    for elt in elts {
+                        write!(w, " ")?;
+                        elt.fmt(store, state, w)?;
+                    }
+                    write!(w, ">")
+                }
+                None => write!(w, "<Malformed VECTOR>"),
+            },
         }
     }
 }
@@ -210,12 +226,12 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 write!(w, " }}")
             }
             Continuation::Call {
-                unevaled_arg,
+                unevaled_args,
                 saved_env,
                 continuation,
             } => {
-                write!(w, "Call{{ unevaled_arg: ")?;
-                unevaled_arg.fmt(store, state, w)?;
+                write!(w, "Call{{ unevaled_args: ")?;
+                unevaled_args.fmt(store, state, w)?;
                 write!(w, ", saved_env: ")?;
                 saved_env.fmt(store, state, w)?;
                 write!(w, ", continuation: ")?;
@@ -225,12 +241,15 @@ impl<F: LurkField> Write<F> for Continuation<F> {
             Continuation::Call2 {
                 function,
                 saved_env,
+                unevaled_args,
                 continuation,
             } => {
                 write!(w, "Call2{{ function: ")?;
                 function.fmt(store, state, w)?;
                 write!(w, ", saved_env: ")?;
                 saved_env.fmt(store, state, w)?;
+                write!(w, ", unevaled_args: ")?;
+                unevaled_args.fmt(store, state, w)?;
                 write!(w, ", continuation: ")?;
                 continuation.fmt(store, state, w)?;
                 write!(w, " }}")
@@ -332,6 +351,36 @@ impl<F: LurkField> Write<F> for Continuation<F> {
                 continuation.fmt(store, state, w)?;
                 write!(w, " }}")
             }
+            Continuation::Vector {
+                operator,
+                vector,
+                evaled_arg,
+                continuation,
+            } => {
+                write!(w, "Vector{{ operator: {operator}, vector: ")?;
+                vector.fmt(store, state, w)?;
+                write!(w, ", evaled_arg: ")?;
+                evaled_arg.fmt(store, state, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, state, w)?;
+                write!(w, " }}")
+            }
+            Continuation::Fuel {
+                inner,
+                fuel,
+                saved_env,
+                continuation,
+            } => {
+                write!(w, "Fuel{{ inner: ")?;
+                inner.fmt(store, state, w)?;
+                write!(w, ", fuel: ")?;
+                fuel.fmt(store, state, w)?;
+                write!(w, ", saved_env: ")?;
+                saved_env.fmt(store, state, w)?;
+                write!(w, ", continuation: ")?;
+                continuation.fmt(store, state, w)?;
+                write!(w, " }}")
+            }
             Continuation::Dummy => write!(w, "Dummy"),
             Continuation::Terminal => write!(w, "Terminal"),
             Continuation::Emit {
@@ -27,16 +27,17 @@ pub enum ZCont<F: LurkField> {
         saved_env: ZExprPtr<F>,
         continuation: ZContPtr<F>,
     },
-    /// A function call with one argument
+    /// A function call with arguments, while the function is evaluated
     Call {
         saved_env: ZExprPtr<F>,
-        unevaled_arg: ZExprPtr<F>,
+        unevaled_args: ZExprPtr<F>,
         continuation: ZContPtr<F>,
     },
-    /// A nested function call
+    /// A function call with arguments, while an argument is evaluated
     Call2 {
         saved_env: ZExprPtr<F>,
         function: ZExprPtr<F>,
+        unevaled_args: ZExprPtr<F>,
         continuation: ZContPtr<F>,
     },
     Tail {
@@ -82,6 +83,18 @@ pub enum ZCont<F: LurkField> {
     Emit {
         continuation: ZContPtr<F>,
     },
+    Vector {
+        operator: Op2,
+        vector: ZExprPtr<F>,
+        evaled_arg: ZExprPtr<F>,
+        continuation: ZContPtr<F>,
+    },
+    Fuel {
+        inner: ZContPtr<F>,
+        fuel: ZExprPtr<F>,
+        saved_env: ZExprPtr<F>,
+        continuation: ZContPtr<F>,
+    },
     Dummy,
     Terminal,
 }
@@ -93,13 +106,13 @@ impl<F: LurkField> ZCont<F> {
             Self::Outermost | Self::Error | Self::Dummy | Self::Terminal => [F::ZERO; 8],
             Self::Call {
                 saved_env,
-                unevaled_arg,
+                unevaled_args,
                 continuation,
             } => [
                 saved_env.0.to_field(),
                 saved_env.1,
-                unevaled_arg.0.to_field(),
-                unevaled_arg.1,
+                unevaled_args.0.to_field(),
+                unevaled_args.1,
                 continuation.0.to_field(),
                 continuation.1,
                 F::ZERO,
@@ -108,6 +121,7 @@ impl<F: LurkField> ZCont<F> {
             Self::Call2 {
                 saved_env,
                 function,
+                unevaled_args,
                 continuation,
             } => [
                 saved_env.0.to_field(),
@@ -116,8 +130,8 @@ impl<F: LurkField> ZCont<F> {
                 function.1,
                 continuation.0.to_field(),
                 continuation.1,
-                F::ZERO,
-                F::ZERO,
+                unevaled_args.0.to_field(),
+                unevaled_args.1,
             ],
             Self::Call0 {
                 saved_env,
@@ -226,6 +240,36 @@ impl<F: LurkField> ZCont<F> {
                 F::ZERO,
                 F::ZERO,
             ],
+            Self::Vector {
+                operator,
+                vector,
+                evaled_arg,
+                continuation,
+            } => [
+                operator.to_field(),
+                F::ZERO,
+                vector.0.to_field(),
+                vector.1,
+                evaled_arg.0.to_field(),
+                evaled_arg.1,
+                continuation.0.to_field(),
+                continuation.1,
+            ],
+            Self::Fuel {
+                inner,
+                fuel,
+                saved_env,
+                continuation,
+            } => [
+                inner.0.to_field(),
+                inner.1,
+                fuel.0.to_field(),
+                fuel.1,
+                saved_env.0.to_field(),
+                saved_env.1,
+                continuation.0.to_field(),
+                continuation.1,
+            ],
         }
     }
 
@@ -247,6 +291,8 @@ impl<F: LurkField> ZCont<F> {
             Self::Let { .. } => ZPtr(ContTag::Let, hash),
             Self::LetRec { .. } => ZPtr(ContTag::LetRec, hash),
             Self::Emit { .. } => ZPtr(ContTag::Emit, hash),
+            Self::Vector { .. } => ZPtr(ContTag::Vector, hash),
+            Self::Fuel { .. } => ZPtr(ContTag::Fuel, hash),
             Self::Dummy => ZPtr(ContTag::Dummy, hash),
             Self::Terminal => ZPtr(ContTag::Terminal, hash),
         }
@@ -268,16 +314,17 @@ impl<F: LurkField> Arbitrary for ZCont<F> {
                 }
             }),
             any::<(ZExprPtr<F>, ZExprPtr<F>, ZContPtr<F>)>().prop_map(
-                |(saved_env, unevaled_arg, continuation)| ZCont::Call {
+                |(saved_env, unevaled_args, continuation)| ZCont::Call {
                     saved_env,
-                    unevaled_arg,
+                    unevaled_args,
                     continuation
                 }
             ),
-            any::<(ZExprPtr<F>, ZExprPtr<F>, ZContPtr<F>)>().prop_map(
-                |(saved_env, function, continuation)| ZCont::Call2 {
+            any::<(ZExprPtr<F>, ZExprPtr<F>, ZExprPtr<F>, ZContPtr<F>)>().prop_map(
+                |(saved_env, function, unevaled_args, continuation)| ZCont::Call2 {
                     saved_env,
                     function,
+                    unevaled_args,
                     continuation
                 }
             ),
@@ -334,6 +381,22 @@ impl<F: LurkField> Arbitrary for ZCont<F> {
                 }
             ),
             any::<ZContPtr<F>>().prop_map(|continuation| ZCont::Emit { continuation }),
+            any::<(Op2, ZExprPtr<F>, ZExprPtr<F>, ZContPtr<F>)>().prop_map(
+                |(operator, vector, evaled_arg, continuation)| ZCont::Vector {
+                    operator,
+                    vector,
+                    evaled_arg,
+                    continuation
+                }
+            ),
+            any::<(ZContPtr<F>, ZExprPtr<F>, ZExprPtr<F>, ZContPtr<F>)>().prop_map(
+                |(inner, fuel, saved_env, continuation)| ZCont::Fuel {
+                    inner,
+                    fuel,
+                    saved_env,
+                    continuation
+                }
+            ),
             Just(ZCont::Dummy),
             Just(ZCont::Terminal),
         ]
@@ -49,6 +49,10 @@ pub enum ZExpr<F: LurkField> {
     Thunk(ZExprPtr<F>, ZContPtr<F>),
     Char(char),
     UInt(UInt),
+    /// A signed integer, represented by the `u64` of same bits
+    I64(#[serde(with = "i64_bits")] i64),
+    /// A node of a vector, which contains its length and a pointer to its content
+    Vec(ZExprPtr<F>, ZExprPtr<F>),
 }
 
 impl<F: LurkField> std::fmt::Display for ZExpr<F> {
@@ -74,6 +78,8 @@ impl<F: LurkField> std::fmt::Display for ZExpr<F> {
             ZExpr::Char(x) => write!(f, "(char {x})"),
             ZExpr::Num(x) => write!(f, "(num  {x:?})"),
             ZExpr::UInt(x) => write!(f, "(uint {x})"),
+            ZExpr::I64(x) => write!(f, "(i64 {x})"),
+            ZExpr::Vec(x, y) => write!(f, "(vec {x} {y})"),
         }
     }
 }
@@ -128,6 +134,11 @@ impl<F: LurkField> ZExpr<F> {
             ZExpr::UInt(x) => match x {
                 UInt::U64(x) => ZPtr(ExprTag::U64, F::from_u64(*x)),
             },
+            ZExpr::I64(x) => ZPtr(ExprTag::I64, F::from_u64(*x as u64)),
+            ZExpr::Vec(x, y) => ZPtr(
+                ExprTag::Vec,
+                cache.hash4(&[x.0.to_field(), x.1, y.0.to_field(), y.1]),
+            ),
         }
     }
 
@@ -165,6 +176,10 @@ impl<F: LurkField> ZExpr<F> {
             }),
             ExprTag::Char => store.fetch_char(ptr).map(ZExpr::Char),
             ExprTag::U64 => store.fetch_uint(ptr).map(ZExpr::UInt),
+            ExprTag::I64 => store.fetch_i64(ptr).map(ZExpr::I64),
+            ExprTag::Vec => store.fetch_vec_node(ptr).and_then(|(len, content)| {
+                Some(ZExpr::Vec(store.hash_expr(len)?, store.hash_expr(content)?))
+            }),
             ExprTag::Thunk => store.fetch_thunk(ptr).and_then(|thunk| {
                 Some(ZExpr::Thunk(
                     store.hash_expr(&thunk.value)?,
@@ -202,11 +217,26 @@ impl<F: LurkField> Arbitrary for ZExpr<F> {
             any::<(ZExprPtr<F>, ZContPtr<F>)>().prop_map(|(x, y)| ZExpr::Thunk(x, y)),
             any::<char>().prop_map(|x| Self::Char(x)),
             any::<u64>().prop_map(|x| Self::UInt(UInt::U64(x))),
+            any::<i64>().prop_map(|x| Self::I64(x)),
+            any::<(ZExprPtr<F>, ZExprPtr<F>)>().prop_map(|(x, y)| ZExpr::Vec(x, y)),
         ]
         .boxed()
     }
 }
 
+/// Serializes `i64`s as the `u64`s of same bits, since `ZData` has no signed integers
+mod i64_bits {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(x: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        (*x as u64).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        u64::deserialize(deserializer).map(|x| x as i64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +100,10 @@ impl<F: LurkField> ZStore<F> {
                 let x = F::to_u64(val)?;
                 Some(ZExpr::UInt(UInt::U64(x)))
             }
+            ZPtr(ExprTag::I64, val) => {
+                let x = F::to_u64(val)?;
+                Some(ZExpr::I64(x as i64))
+            }
             ZPtr(ExprTag::Char, val) => {
                 let x = F::to_char(val)?;
                 Some(ZExpr::Char(x))
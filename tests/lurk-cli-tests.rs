@@ -112,3 +112,554 @@ fn test_prove_and_verify() {
 
     cmd.assert().success();
 }
+
+#[test]
+fn test_save_and_load_session() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let session_file = tmp_dir.join("session");
+    let save_file = tmp_dir.join("save.lurk");
+    let load_file = tmp_dir.join("load.lurk");
+
+    let mut file = File::create(&save_file).unwrap();
+    file.write_all(b"!(def foo 123)\n").unwrap();
+    // the import changes `.lurk.user`, which must be saved even though it's not
+    // the current package when the session is saved
+    file.write_all(b"!(defpackage abc)\n").unwrap();
+    file.write_all(b"!(in-package abc)\n").unwrap();
+    file.write_all(b"!(def two (.lurk.+ 1 1))\n").unwrap();
+    file.write_all(b"!(in-package .lurk.user)\n").unwrap();
+    file.write_all(b"!(import .lurk.user.abc.two)\n").unwrap();
+    file.write_all(b"!(in-package abc)\n").unwrap();
+    file.write_all(format!("!(save-session \"{session_file}\")\n").as_bytes())
+        .unwrap();
+
+    let mut file = File::create(&load_file).unwrap();
+    file.write_all(format!("!(load-session \"{session_file}\")\n").as_bytes())
+        .unwrap();
+    file.write_all(b"!(in-package .lurk.user)\n").unwrap();
+    file.write_all(b"!(assert-eq foo 123)\n").unwrap();
+    file.write_all(b"!(assert-eq two 2)\n").unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(save_file.into_string());
+    cmd.assert().success();
+    assert!(session_file.exists());
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(load_file.into_string());
+    cmd.assert().success();
+}
+
+#[test]
+fn test_defmacro() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let lurk_file = tmp_dir.join("macros.lurk");
+
+    let mut file = File::create(&lurk_file).unwrap();
+    file.write_all(b"!(defmacro if-not (c x y) (cons 'if (cons c (cons y (cons x nil)))))\n")
+        .unwrap();
+    file.write_all(b"!(assert-eq (if-not (= 1 2) 3 4) 3)\n")
+        .unwrap();
+    file.write_all(b"!(assert-eq '(if-not 1 2 3) (cons 'if-not '(1 2 3)))\n")
+        .unwrap();
+    file.write_all(b"!(defmacro swap (f x y) (cons f (cons y (cons x nil))))\n")
+        .unwrap();
+    file.write_all(b"!(assert-eq (swap - 1 (if-not nil 3 4)) 2)\n")
+        .unwrap();
+    // Macros are shadowed by the variables of the same name
+    file.write_all(b"!(assert-eq (let ((swap (lambda (f x y) x))) (swap 1 2 3)) 2)\n")
+        .unwrap();
+    file.write_all(b"!(assert-eq ((lambda (if-not) (if-not 1 2 3)) (lambda (c x y) y)) 3)\n")
+        .unwrap();
+    file.write_all(b"!(assert-eq (letrec ((swap (lambda (x) (if x (swap nil) 4)))) (swap t)) 4)\n")
+        .unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(lurk_file.into_string());
+    cmd.assert().success();
+
+    // A macro that expands to a call of itself is reported
+    let lurk_file = tmp_dir.join("macros_forever.lurk");
+    let mut file = File::create(&lurk_file).unwrap();
+    file.write_all(b"!(defmacro forever (x) (cons 'forever (cons x nil)))\n")
+        .unwrap();
+    file.write_all(b"(forever 1)\n").unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(lurk_file.into_string());
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_prove_snarkpack_plus() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let proof_dir = tmp_dir.join("proofs");
+    let commit_dir = tmp_dir.join("commits");
+    let lurk_file = tmp_dir.join("prove_snarkpack.lurk");
+
+    let mut file = File::create(lurk_file.clone()).unwrap();
+    file.write_all(b"(+ 1 1)\n").unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(lurk_file.into_string());
+    cmd.arg("--prove");
+    cmd.arg("--backend");
+    cmd.arg("snarkpack+");
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(commit_dir);
+
+    cmd.assert().success();
+
+    let proof_key = proof_dir
+        .read_dir_utf8()
+        .unwrap()
+        .map(|entry| entry.unwrap().into_path())
+        .find(|path| path.extension() == Some("proof"))
+        .and_then(|path| path.file_stem().map(String::from))
+        .unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("verify");
+    cmd.arg(proof_key);
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("verified"));
+}
+
+#[test]
+fn test_prove_replaces_corrupted_cached_proof() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let public_param_dir = tmp_dir.join("public_params");
+    let proof_dir = tmp_dir.join("proofs");
+    let commit_dir = tmp_dir.join("commits");
+    let lurk_file = tmp_dir.join("prove_verify.lurk");
+
+    let proof_key =
+        "Nova_Pallas_10_3f2526abf20fc9006dd93c0d3ff49954ef070ef52d2e88426974de42cc27bdb2";
+    std::fs::create_dir_all(&proof_dir).unwrap();
+    let mut corrupted = File::create(proof_dir.join(format!("{proof_key}.proof"))).unwrap();
+    corrupted.write_all(b"not a proof").unwrap();
+
+    let mut file = File::create(lurk_file.clone()).unwrap();
+    file.write_all(b"!(prove (+ 1 1))\n").unwrap();
+    file.write_all(format!("!(verify \"{proof_key}\")\n").as_bytes())
+        .unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("load");
+    cmd.arg(lurk_file.into_string());
+    cmd.arg("--public-params-dir");
+    cmd.arg(public_param_dir);
+    cmd.arg("--proofs-dir");
+    cmd.arg(proof_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(commit_dir);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(&format!("✓ Proof \"{proof_key}\" verified")));
+}
+
+#[test]
+fn test_eval_json_output() {
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(begin (emit 1) (+ 1 1))");
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["result"], "2");
+    assert_eq!(report["status"], "terminal");
+    assert_eq!(report["emitted"], serde_json::json!(["1"]));
+    assert!(report.get("proof_key").is_none());
+
+    // values emitted by every expression are reported, not only by the last one
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(emit 1) (begin (emit 2) 3)");
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["result"], "3");
+    assert_eq!(report["emitted"], serde_json::json!(["1", "2"]));
+
+    // fields without a REPL yet are reported as errors
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(+ 1 1)");
+    cmd.arg("--field");
+    cmd.arg("vesta");
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("is not supported yet"));
+}
+
+#[test]
+fn test_eval_with_coprocessors() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let config_file = tmp_dir.join("lurk.toml");
+    std::fs::write(&config_file, "[coprocessors]\ntrie-new = \"\"\n").unwrap();
+
+    // `trie-lookup` is bound to `.lurk.user.lookup`
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(lookup (.lurk.trie.new) 123)");
+    cmd.arg("--coprocessor");
+    cmd.arg("trie-new");
+    cmd.arg("--coprocessor");
+    cmd.arg("trie-lookup=lookup");
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "terminal");
+
+    // without the flag, the coprocessors come from the config file
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(.lurk.trie.new)");
+    cmd.arg("--config");
+    cmd.arg(&config_file);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "terminal");
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("eval");
+    cmd.arg("(+ 1 1)");
+    cmd.arg("--coprocessor");
+    cmd.arg("unknown");
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_prove_json_output() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("prove");
+    cmd.arg("(+ 1 1)");
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("public_params"));
+    cmd.arg("--proofs-dir");
+    cmd.arg(tmp_dir.join("proofs"));
+    cmd.arg("--commits-dir");
+    cmd.arg(tmp_dir.join("commits"));
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["result"], "2");
+    assert_eq!(
+        report["proof_key"],
+        "Nova_Pallas_10_3f2526abf20fc9006dd93c0d3ff49954ef070ef52d2e88426974de42cc27bdb2"
+    );
+}
+
+#[test]
+fn test_prove_and_verify_supernova() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let proof_dir = tmp_dir.join("proofs");
+
+    let prove = |input: &str, coprocessors: &[&str]| {
+        let mut cmd = lurk_cmd();
+        cmd.arg("prove");
+        cmd.arg(input);
+        cmd.arg("--backend");
+        cmd.arg("supernova");
+        for coprocessor in coprocessors {
+            cmd.arg("--coprocessor");
+            cmd.arg(coprocessor);
+        }
+        cmd.arg("--public-params-dir");
+        cmd.arg(tmp_dir.join("public_params"));
+        cmd.arg("--proofs-dir");
+        cmd.arg(&proof_dir);
+        cmd.arg("--commits-dir");
+        cmd.arg(tmp_dir.join("commits"));
+
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        report["proof_key"].as_str().unwrap().to_owned()
+    };
+
+    let verify = |proof_key: &str| {
+        let mut cmd = lurk_cmd();
+        cmd.arg("verify");
+        cmd.arg(proof_key);
+        cmd.arg("--public-params-dir");
+        cmd.arg(tmp_dir.join("public_params"));
+        cmd.arg("--proofs-dir");
+        cmd.arg(&proof_dir);
+
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        assert!(String::from_utf8(output.stdout)
+            .unwrap()
+            .contains("verified"));
+    };
+
+    let proof_key = prove("(.lurk.trie.new)", &["trie-new"]);
+    assert!(proof_key.starts_with("SuperNova_Pallas_10_"));
+    verify(&proof_key);
+
+    // without coprocessors there's nothing to fold separately, so Nova proves it
+    let proof_key = prove("(+ 1 1)", &[]);
+    assert_eq!(
+        proof_key,
+        "Nova_Pallas_10_3f2526abf20fc9006dd93c0d3ff49954ef070ef52d2e88426974de42cc27bdb2"
+    );
+    verify(&proof_key);
+}
+
+#[test]
+fn test_verify_with_vk() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let proof_dir = tmp_dir.join("proofs");
+    let proof_key =
+        "Nova_Pallas_10_3f2526abf20fc9006dd93c0d3ff49954ef070ef52d2e88426974de42cc27bdb2";
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("prove");
+    cmd.arg("(+ 1 1)");
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("public_params"));
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(tmp_dir.join("commits"));
+    cmd.assert().success();
+
+    // the verifier key is shared by the proofs with the same reduction count and
+    // coprocessors
+    let vk_path = proof_dir.join("Nova_Pallas_10_none.vk");
+
+    // an empty public params directory ensures they're not regenerated
+    let mut cmd = lurk_cmd();
+    cmd.arg("verify");
+    cmd.arg(proof_key);
+    cmd.arg("--vk");
+    cmd.arg(&vk_path);
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("empty_public_params"));
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("verified"));
+    assert!(tmp_dir
+        .join("empty_public_params")
+        .read_dir_utf8()
+        .unwrap()
+        .next()
+        .is_none());
+
+    // a key other than the one recorded in the proof meta is rejected
+    let mut vk_bytes = std::fs::read(&vk_path).unwrap();
+    vk_bytes.push(0);
+    let other_vk_path = tmp_dir.join("other.vk");
+    std::fs::write(&other_vk_path, vk_bytes).unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("verify");
+    cmd.arg(proof_key);
+    cmd.arg("--vk");
+    cmd.arg(&other_vk_path);
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("empty_public_params"));
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_params_clear_invalid() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let public_params_dir = tmp_dir.join("public_params");
+    std::fs::create_dir_all(&public_params_dir).unwrap();
+    let bogus_file = public_params_dir.join("public-params-rc-10-coproc-bogus-abomonated");
+    std::fs::write(&bogus_file, "not public params").unwrap();
+    // files other than abomonated public params aren't cache entries
+    let other_file = public_params_dir.join("notes.txt");
+    std::fs::write(&other_file, "not a cache entry").unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("params");
+    cmd.arg("verify");
+    cmd.arg("--public-params-dir");
+    cmd.arg(&public_params_dir);
+    cmd.assert().failure();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("params");
+    cmd.arg("clear");
+    cmd.arg("--invalid");
+    cmd.arg("--public-params-dir");
+    cmd.arg(&public_params_dir);
+    cmd.assert().success();
+    assert!(!bogus_file.exists());
+    assert!(other_file.exists());
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("params");
+    cmd.arg("verify");
+    cmd.arg("--public-params-dir");
+    cmd.arg(&public_params_dir);
+    cmd.assert().success();
+
+    // a missing directory has no cached params
+    let mut cmd = lurk_cmd();
+    cmd.arg("params");
+    cmd.arg("list");
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("missing"));
+    cmd.assert().success();
+}
+
+#[test]
+fn test_inspect() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let proof_dir = tmp_dir.join("proofs");
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("prove");
+    cmd.arg("(+ 1 1)");
+    cmd.arg("--public-params-dir");
+    cmd.arg(tmp_dir.join("public_params"));
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(tmp_dir.join("commits"));
+    cmd.assert().success();
+
+    let mut cmd = lurk_cmd();
+    cmd.arg("inspect");
+    cmd.arg("Nova_Pallas_10_3f2526abf20fc9006dd93c0d3ff49954ef070ef52d2e88426974de42cc27bdb2");
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proof_dir);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Backend: Nova"));
+    assert!(stdout.contains("Reduction count: 10"));
+    assert!(stdout.contains("Expr out: 2"));
+}
+
+#[test]
+fn test_store_export_import() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let src_proofs_dir = tmp_dir.join("src_proofs");
+    let dst_proofs_dir = tmp_dir.join("dst_proofs");
+    let commits_dir = tmp_dir.join("commits");
+    let archive = tmp_dir.join("proofs.archive");
+
+    let proof_key = "Nova_Pallas_10_0123abcd";
+    std::fs::create_dir_all(&src_proofs_dir).unwrap();
+    std::fs::write(src_proofs_dir.join(format!("{proof_key}.proof")), b"proof").unwrap();
+    std::fs::write(src_proofs_dir.join(format!("{proof_key}.meta")), b"meta").unwrap();
+
+    let mut cmd = lurk_cmd();
+    cmd.args(["store", "export", archive.as_str(), proof_key]);
+    cmd.arg("--proofs-dir");
+    cmd.arg(&src_proofs_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(&commits_dir);
+    cmd.assert().success();
+
+    let mut cmd = lurk_cmd();
+    cmd.args(["store", "import", archive.as_str()]);
+    cmd.arg("--proofs-dir");
+    cmd.arg(&dst_proofs_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(&commits_dir);
+    cmd.assert().success();
+
+    let imported_proof = std::fs::read(dst_proofs_dir.join(format!("{proof_key}.proof"))).unwrap();
+    let imported_meta = std::fs::read(dst_proofs_dir.join(format!("{proof_key}.meta"))).unwrap();
+    assert_eq!(imported_proof, b"proof");
+    assert_eq!(imported_meta, b"meta");
+}
+
+#[test]
+fn test_store_gc() {
+    let tmp_dir = Builder::new().prefix("tmp").tempdir().unwrap();
+    let tmp_dir = Utf8Path::from_path(tmp_dir.path()).unwrap();
+    let proofs_dir = tmp_dir.join("proofs");
+    let commits_dir = tmp_dir.join("commits");
+
+    let proof_key = "Nova_Pallas_10_0123abcd";
+    let proof = proofs_dir.join(format!("{proof_key}.proof"));
+    let meta = proofs_dir.join(format!("{proof_key}.meta"));
+    let orphan_meta = proofs_dir.join("Nova_Pallas_10_4567abcd.meta");
+    let claim = commits_dir.join("0123abcd.commit");
+    let user_commitment = commits_dir.join("89abcdef.commit");
+    std::fs::create_dir_all(&proofs_dir).unwrap();
+    std::fs::create_dir_all(&commits_dir).unwrap();
+    for path in [&proof, &meta, &orphan_meta, &claim, &user_commitment] {
+        std::fs::write(path, b"data").unwrap();
+    }
+
+    let gc = |extra_args: &[&str]| {
+        let mut cmd = lurk_cmd();
+        cmd.args(["store", "gc", "--older-than", "0"]);
+        cmd.args(extra_args);
+        cmd.arg("--proofs-dir");
+        cmd.arg(&proofs_dir);
+        cmd.arg("--commits-dir");
+        cmd.arg(&commits_dir);
+        cmd.assert().success();
+    };
+
+    gc(&[]);
+    assert!(!proof.exists());
+    assert!(!meta.exists());
+    assert!(!orphan_meta.exists());
+    assert!(!claim.exists());
+    assert!(user_commitment.exists());
+
+    gc(&["--commitments"]);
+    assert!(!user_commitment.exists());
+
+    let mut cmd = lurk_cmd();
+    cmd.args(["store", "gc", "--older-than", &u64::MAX.to_string()]);
+    cmd.arg("--proofs-dir");
+    cmd.arg(&proofs_dir);
+    cmd.arg("--commits-dir");
+    cmd.arg(&commits_dir);
+    cmd.assert().failure();
+}